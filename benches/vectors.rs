@@ -0,0 +1,93 @@
+//! Timing and accuracy of the hot vector arithmetic.
+//!
+//! Run it once with and once without fused multiply-add to compare:
+//!
+//! ```text
+//! cargo bench --bench vectors
+//! RUSTFLAGS="-C target-cpu=native" cargo bench --bench vectors --features fma
+//! ```
+//!
+//! Without a target that has an FMA instruction the fused version falls back to a much
+//! slower software routine.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use i_mth::vector3d::Vector3D;
+
+const COUNT: usize = 100_000;
+const ROUNDS: u32 = 50;
+
+/// Returns a pseudo random integer in [-2^40, 2^40) from a linear congruential generator.
+fn next(state: &mut u64) -> i64 {
+    *state = state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407);
+    (*state >> 23) as i64 - (1 << 40)
+}
+
+/// Returns vectors whose components are integers scaled by 2<sup>-40</sup>, so products
+/// of components are exact in i128 and the exact dot product is known.
+fn vectors(state: &mut u64) -> Vec<[i64; 3]> {
+    (0..COUNT)
+        .map(|_| [next(state), next(state), next(state)])
+        .collect()
+}
+
+fn to_vector(v: &[i64; 3]) -> Vector3D {
+    let scale = 2f64.powi(-40);
+    Vector3D::new(
+        v[0] as f64 * scale,
+        v[1] as f64 * scale,
+        v[2] as f64 * scale,
+    )
+}
+
+fn time(name: &str, mut f: impl FnMut() -> f64) {
+    let mut best = Duration::MAX;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        black_box(f());
+        best = best.min(start.elapsed());
+    }
+    let per_op = best.as_secs_f64() * 1e9 / COUNT as f64;
+    println!("{name:<16} {per_op:>8.3} ns/op");
+}
+
+fn main() {
+    println!(
+        "fused multiply-add: {}",
+        if cfg!(feature = "fma") { "on" } else { "off" }
+    );
+
+    let mut state = 0x5eed;
+    let (a, b) = (vectors(&mut state), vectors(&mut state));
+    let (va, vb): (Vec<_>, Vec<_>) = (
+        a.iter().map(to_vector).collect(),
+        b.iter().map(to_vector).collect(),
+    );
+
+    time("dot", || va.iter().zip(&vb).map(|(a, b)| a.dot(*b)).sum());
+    time("cross", || {
+        va.iter().zip(&vb).map(|(a, b)| a.cross(*b).x).sum()
+    });
+    time("squared_mag", || {
+        va.iter().map(|a| a.squared_magnitude()).sum()
+    });
+    time("add_scaled", || {
+        va.iter()
+            .zip(&vb)
+            .fold(Vector3D::origin(), |acc, (a, b)| {
+                acc.add_scaled(*a, 1e-3).add_scaled(*b, -1e-3)
+            })
+            .x
+    });
+
+    let mut error = 0.0;
+    for ((a, b), (va, vb)) in a.iter().zip(&b).zip(va.iter().zip(&vb)) {
+        let exact: i128 = (0..3).map(|i| a[i] as i128 * b[i] as i128).sum();
+        let exact = exact as f64 * 2f64.powi(-80);
+        error += (va.dot(*vb) - exact).abs();
+    }
+    println!("dot mean abs error {:e}", error / COUNT as f64);
+}
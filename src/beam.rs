@@ -0,0 +1,758 @@
+//! # Beams
+//!
+//! Statically determinate straight beams, with positions measured by the distance x from
+//! the left end of the beam.
+//!
+//! Load intensities and point loads are positive downwards, in the direction of gravity,
+//! reactions are positive upwards and applied couples are positive anticlockwise. The
+//! shear force V is the sum of the upward forces to the left of a section and the bending
+//! moment M is positive when it sags the beam, so dV/dx = -w and dM/dx = V.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::calculus::gauss_legendre;
+use crate::error::ImthError;
+use crate::fitting::polynomial_fit;
+use crate::polynomial::{PiecewisePolynomial, Polynomial, PolynomialSegment};
+use crate::screw::Wrench;
+use crate::series::DataSeries;
+use crate::vector3d::Vector3D;
+
+/// Number of Gauss-Legendre sub-intervals used to integrate a custom load, which is also
+/// the number of cubic pieces it is replaced by in the beam solver.
+const CUSTOM_INTERVALS: usize = 32;
+
+/// The intensity of a distributed load along its span.
+#[derive(Clone)]
+enum Intensity {
+    /// An exact polynomial in x.
+    Polynomial(Polynomial),
+    /// Any function of x, integrated numerically.
+    Custom(Arc<dyn Fn(f64) -> f64 + Send + Sync>),
+}
+
+/// A load distributed along the span `[start, end]` of a beam, with an intensity w(x) in
+/// N.m<sup>-1</sup>.
+///
+/// # Example
+/// ```rust
+/// use i_mth::beam::DistributedLoad;
+///
+/// // 6 kN/m at x = 1 m falling to 2 kN/m at x = 5 m
+/// let load = DistributedLoad::trapezoid(6e3, 2e3, 1.0, 5.0);
+///
+/// assert!((load.resultant().unwrap() - 16e3).abs() < 1e-9);
+/// assert!((load.centroid().unwrap() - (1.0 + 4.0 * 5.0 / 12.0)).abs() < 1e-12);
+/// ```
+#[derive(Clone)]
+pub struct DistributedLoad {
+    /// Start of the loaded span in m.
+    pub start: f64,
+    /// End of the loaded span in m.
+    pub end: f64,
+    intensity: Intensity,
+}
+
+impl DistributedLoad {
+    /// Returns a load of constant intensity `w` over `[start, end]`.
+    #[inline]
+    pub fn uniform(w: f64, start: f64, end: f64) -> Self {
+        Self::polynomial(Polynomial::constant(w), start, end)
+    }
+
+    /// Returns a load rising linearly from zero at `start` to `peak` at `end`. Swap the
+    /// ends for a load falling to zero.
+    #[inline]
+    pub fn ramp(peak: f64, start: f64, end: f64) -> Self {
+        Self::trapezoid(0.0, peak, start, end)
+    }
+
+    /// Returns a load varying linearly from `w_start` at `start` to `w_end` at `end`.
+    pub fn trapezoid(w_start: f64, w_end: f64, start: f64, end: f64) -> Self {
+        if start == end {
+            return Self::uniform(0.0, start, end);
+        }
+        let slope = (w_end - w_start) / (end - start);
+        Self::polynomial(
+            Polynomial::new(vec![w_start - slope * start, slope]),
+            start,
+            end,
+        )
+    }
+
+    /// Returns a load whose intensity is the passed polynomial in x over `[start, end]`.
+    #[inline]
+    pub fn polynomial(w: Polynomial, start: f64, end: f64) -> Self {
+        Self {
+            start: start.min(end),
+            end: start.max(end),
+            intensity: Intensity::Polynomial(w),
+        }
+    }
+
+    /// Returns a load whose intensity is the passed function of x over `[start, end]`.
+    /// Its resultant and centroid are found by numerical integration.
+    pub fn custom<F: Fn(f64) -> f64 + Send + Sync + 'static>(w: F, start: f64, end: f64) -> Self {
+        Self {
+            start: start.min(end),
+            end: start.max(end),
+            intensity: Intensity::Custom(Arc::new(w)),
+        }
+    }
+
+    /// Returns the intensity of the load at x, which is zero outside of its span.
+    pub fn intensity(&self, x: f64) -> f64 {
+        if x < self.start || x > self.end {
+            return 0.0;
+        }
+        match &self.intensity {
+            Intensity::Polynomial(w) => w.evaluate(x),
+            Intensity::Custom(w) => w(x),
+        }
+    }
+
+    /// Returns the resultant force of the load in N.
+    ///
+    /// Returns an error if a custom load could not be integrated to a finite value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::beam::DistributedLoad;
+    ///
+    /// let load = DistributedLoad::custom(|x| x * x, 0.0, 3.0);
+    /// assert!((load.resultant().unwrap() - 9.0).abs() < 1e-9);
+    ///
+    /// // a load undefined over part of its span is reported instead of being dropped
+    /// let broken = DistributedLoad::custom(|x| if x < 2.0 { 1.0 } else { f64::NAN }, 0.0, 3.0);
+    /// assert!(broken.resultant().is_err());
+    /// assert!(broken.centroid().is_none());
+    /// ```
+    pub fn resultant(&self) -> Result<f64, ImthError> {
+        match &self.intensity {
+            Intensity::Polynomial(w) => Ok(w.integrate(self.start, self.end)),
+            Intensity::Custom(w) => self.integrate_custom(|x| w(x)),
+        }
+    }
+
+    /// Returns the moment of the load about the point x = `about` in N.m, positive when the
+    /// load lies to the right of the point.
+    ///
+    /// Returns an error if a custom load could not be integrated to a finite value.
+    pub fn moment_about(&self, about: f64) -> Result<f64, ImthError> {
+        match &self.intensity {
+            Intensity::Polynomial(w) => {
+                Ok((w * &Polynomial::new(vec![-about, 1.0])).integrate(self.start, self.end))
+            }
+            Intensity::Custom(w) => self.integrate_custom(|x| w(x) * (x - about)),
+        }
+    }
+
+    /// Returns the position of the line of action of the resultant, or None if the
+    /// resultant is zero or a custom load could not be integrated.
+    #[inline]
+    pub fn centroid(&self) -> Option<f64> {
+        let resultant = self.resultant().ok()?;
+        if resultant == 0.0 {
+            return None;
+        }
+        Some(self.moment_about(0.0).ok()? / resultant)
+    }
+
+    /// Returns the equivalent point load as the pair of the resultant and its position,
+    /// or None if the resultant is zero or a custom load could not be integrated.
+    #[inline]
+    pub fn equivalent_point_load(&self) -> Option<(f64, f64)> {
+        Some((self.resultant().ok()?, self.centroid()?))
+    }
+
+    /// Returns the load as a wrench about the start of the beam, for a beam starting at
+    /// `origin` along the direction `axis` with the load acting along `direction`, like
+    /// -**k** for gravity. Returns None if either direction is zero or a custom load could
+    /// not be integrated.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::beam::DistributedLoad;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // 2 kN/m over a 3 m cantilever along y, fixed at (1, 0, 0)
+    /// let load = DistributedLoad::uniform(2e3, 0.0, 3.0);
+    /// let wrench = load.to_wrench(Vector3D::i(), Vector3D::j(), Vector3D::new(0.0, 0.0, -1.0)).unwrap();
+    ///
+    /// assert!(wrench.force.is_approx_equal_to(Vector3D::new(0.0, 0.0, -6e3), 1e-9));
+    /// assert!(wrench.moment.is_approx_equal_to(Vector3D::new(-9e3, 0.0, 0.0), 1e-9));
+    /// ```
+    pub fn to_wrench(
+        &self,
+        origin: Vector3D,
+        axis: Vector3D,
+        direction: Vector3D,
+    ) -> Option<Wrench> {
+        let (axis, direction) = (axis.normalized()?, direction.normalized()?);
+        Some(Wrench::new(
+            direction.scale(self.resultant().ok()?),
+            axis.cross(direction).scale(self.moment_about(0.0).ok()?),
+            origin,
+        ))
+    }
+
+    /// Returns the load as polynomial pieces `(start, end, w)`. A custom load is replaced by
+    /// cubics through 4 points on each of [`CUSTOM_INTERVALS`] equal sub-spans, and an
+    /// error is returned if one of them cannot be fitted.
+    fn pieces(&self) -> Result<Vec<(f64, f64, Polynomial)>, ImthError> {
+        match &self.intensity {
+            Intensity::Polynomial(w) => Ok(vec![(self.start, self.end, w.clone())]),
+            Intensity::Custom(_) if self.start == self.end => Ok(Vec::new()),
+            Intensity::Custom(w) => {
+                let h = (self.end - self.start) / CUSTOM_INTERVALS as f64;
+                (0..CUSTOM_INTERVALS)
+                    .map(|i| {
+                        let a = self.start + h * i as f64;
+                        let xs: Vec<f64> = (0..4).map(|j| a + h * j as f64 / 3.0).collect();
+                        let ys: Vec<f64> = xs.iter().map(|x| w(*x)).collect();
+                        let w = polynomial_fit(&xs, &ys, 3)
+                            .filter(|w| w.coefficients.iter().all(|c| c.is_finite()))
+                            .ok_or(ImthError::InvalidArgument(
+                                "custom load could not be fitted",
+                            ))?;
+                        Ok((a, a + h, w))
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Returns the integral of `f` over the span of a custom load.
+    fn integrate_custom<F: Fn(f64) -> f64>(&self, f: F) -> Result<f64, ImthError> {
+        gauss_legendre(f, self.start, self.end, 5, CUSTOM_INTERVALS)
+            .filter(|v| v.is_finite())
+            .ok_or(ImthError::InvalidArgument(
+                "custom load could not be integrated",
+            ))
+    }
+}
+
+impl fmt::Debug for DistributedLoad {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("DistributedLoad");
+        s.field("start", &self.start).field("end", &self.end);
+        match &self.intensity {
+            Intensity::Polynomial(w) => s.field("intensity", w),
+            Intensity::Custom(_) => s.field("intensity", &"custom"),
+        }
+        .finish()
+    }
+}
+
+/// A support of a beam at the passed position in m.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Support {
+    /// A pin, which resists vertical and horizontal movement.
+    Pin(f64),
+    /// A roller, which resists vertical movement only.
+    Roller(f64),
+    /// A fixed end, which also resists rotation.
+    Fixed(f64),
+}
+
+impl Support {
+    /// Returns the position of the support.
+    #[inline]
+    pub fn position(&self) -> f64 {
+        match *self {
+            Support::Pin(x) | Support::Roller(x) | Support::Fixed(x) => x,
+        }
+    }
+}
+
+/// The vertical force and moment a support exerts on the beam.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[repr(C)]
+pub struct Reaction {
+    /// Position of the support in m.
+    pub position: f64,
+    /// Force in N, positive upwards.
+    pub force: f64,
+    /// Moment in N.m, positive anticlockwise, which is zero unless the support is fixed.
+    pub moment: f64,
+}
+
+/// A straight beam with its supports and loads.
+///
+/// # Example
+/// ```rust
+/// use i_mth::beam::{Beam, DistributedLoad};
+///
+/// // 6 m simply supported beam, 20 kN at 2 m and 5 kN/m over the right 3 m
+/// let beam = Beam::simply_supported(6.0)
+///     .with_point_load(2.0, 20e3)
+///     .with_distributed_load(DistributedLoad::uniform(5e3, 3.0, 6.0));
+/// let solution = beam.solve().unwrap();
+///
+/// assert!((solution.reactions[0].force - 17_083.333_333_333_332).abs() < 1e-6);
+/// assert!((solution.reactions[1].force - 17_916.666_666_666_668).abs() < 1e-6);
+///
+/// // the shear changes sign under the point load, where the moment peaks
+/// assert_eq!(vec![2.0], solution.zero_shear());
+/// let (x, m) = solution.moment.max().unwrap();
+/// assert!((x - 2.0).abs() < 1e-12 && (m - 34_166.666_666_666_664).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Beam {
+    /// Length of the beam in m.
+    pub length: f64,
+    /// The supports of the beam.
+    pub supports: Vec<Support>,
+    /// Point loads as `(x, P)` pairs, positive downwards, in N.
+    pub point_loads: Vec<(f64, f64)>,
+    /// Applied couples as `(x, C)` pairs, positive anticlockwise, in N.m.
+    pub couples: Vec<(f64, f64)>,
+    /// Distributed loads.
+    pub distributed_loads: Vec<DistributedLoad>,
+}
+
+impl Beam {
+    /// Returns an unsupported and unloaded beam of the passed length in m.
+    #[inline]
+    pub fn new(length: f64) -> Self {
+        Self {
+            length,
+            ..Self::default()
+        }
+    }
+
+    /// Returns a beam with a pin at the left end and a roller at the right end.
+    #[inline]
+    pub fn simply_supported(length: f64) -> Self {
+        Self::new(length)
+            .with_support(Support::Pin(0.0))
+            .with_support(Support::Roller(length))
+    }
+
+    /// Returns a beam fixed at the left end and free at the right end.
+    #[inline]
+    pub fn cantilever(length: f64) -> Self {
+        Self::new(length).with_support(Support::Fixed(0.0))
+    }
+
+    /// Returns this beam with the passed support added.
+    #[inline]
+    pub fn with_support(mut self, support: Support) -> Self {
+        self.supports.push(support);
+        self
+    }
+
+    /// Returns this beam with a point load of `p` N, positive downwards, at `x`.
+    #[inline]
+    pub fn with_point_load(mut self, x: f64, p: f64) -> Self {
+        self.point_loads.push((x, p));
+        self
+    }
+
+    /// Returns this beam with a couple of `c` N.m, positive anticlockwise, at `x`.
+    #[inline]
+    pub fn with_couple(mut self, x: f64, c: f64) -> Self {
+        self.couples.push((x, c));
+        self
+    }
+
+    /// Returns this beam with the passed distributed load added.
+    #[inline]
+    pub fn with_distributed_load(mut self, load: DistributedLoad) -> Self {
+        self.distributed_loads.push(load);
+        self
+    }
+
+    /// Returns the support reactions, in the order of `supports`, with the shear force and
+    /// bending moment as exact piecewise polynomials over the length of the beam.
+    /// Custom distributed loads are first replaced by cubics on 32 equal parts of their span.
+    ///
+    /// Returns an error if a support or load lies outside of the beam, a custom load takes
+    /// values that are not finite, or the beam is not statically determinate, which needs
+    /// either a single fixed support or two pin or roller supports at different positions.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::beam::{Beam, DistributedLoad};
+    ///
+    /// let load = DistributedLoad::custom(|x| if x < 2.0 { 1.0 } else { f64::NAN }, 0.0, 3.0);
+    /// assert!(Beam::simply_supported(4.0).with_distributed_load(load).solve().is_err());
+    /// ```
+    pub fn solve(&self) -> Result<BeamSolution, ImthError> {
+        if self.length <= 0.0 {
+            return Err(ImthError::InvalidArgument("beam length must be positive"));
+        }
+        let on_beam = |x: f64| (0.0..=self.length).contains(&x);
+        let pieces: Vec<(f64, f64, Polynomial)> = self
+            .distributed_loads
+            .iter()
+            .map(|load| load.pieces())
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+        if !self.supports.iter().all(|s| on_beam(s.position()))
+            || !self.point_loads.iter().all(|(x, _)| on_beam(*x))
+            || !self.couples.iter().all(|(x, _)| on_beam(*x))
+            || !pieces.iter().all(|(a, b, _)| on_beam(*a) && on_beam(*b))
+        {
+            return Err(ImthError::InvalidArgument(
+                "supports and loads must lie on the beam",
+            ));
+        }
+
+        let reactions = self.reactions(&pieces)?;
+
+        // upward point forces and anticlockwise couples acting on the beam
+        let forces: Vec<(f64, f64)> = reactions
+            .iter()
+            .map(|r| (r.position, r.force))
+            .chain(self.point_loads.iter().map(|(x, p)| (*x, -p)))
+            .collect();
+        let couples: Vec<(f64, f64)> = reactions
+            .iter()
+            .map(|r| (r.position, r.moment))
+            .chain(self.couples.iter().copied())
+            .collect();
+
+        let mut breaks: Vec<f64> = vec![0.0, self.length];
+        breaks.extend(forces.iter().map(|f| f.0));
+        breaks.extend(couples.iter().map(|c| c.0));
+        breaks.extend(pieces.iter().flat_map(|(a, b, _)| [*a, *b]));
+        breaks.sort_by(f64::total_cmp);
+        breaks.dedup();
+
+        let mut shear = Vec::with_capacity(breaks.len());
+        let mut moment = Vec::with_capacity(breaks.len());
+        let mut m_left = 0.0;
+        for window in breaks.windows(2) {
+            let (x0, x1) = (window[0], window[1]);
+            let point: f64 = forces.iter().filter(|f| f.0 <= x0).map(|f| f.1).sum();
+            let mut v = Polynomial::constant(point);
+            for (a, b, w) in &pieces {
+                let load = w.antiderivative(0.0);
+                if *b <= x0 {
+                    v = &v - &Polynomial::constant(load.evaluate(*b) - load.evaluate(*a));
+                } else if *a <= x0 && x1 <= *b {
+                    v = &(&v - &load) + &Polynomial::constant(load.evaluate(*a));
+                }
+            }
+
+            let jump: f64 = couples.iter().filter(|c| c.0 == x0).map(|c| c.1).sum();
+            let mut m = v.antiderivative(0.0);
+            m.coefficients[0] = m_left - jump - m.evaluate(x0);
+            m_left = m.evaluate(x1);
+
+            shear.push(PolynomialSegment::new(x0, x1, v.trimmed()));
+            moment.push(PolynomialSegment::new(x0, x1, m.trimmed()));
+        }
+
+        Ok(BeamSolution {
+            reactions,
+            shear: PiecewisePolynomial::new(shear),
+            moment: PiecewisePolynomial::new(moment),
+        })
+    }
+
+    /// Returns the slope and deflection of the beam for a flexural rigidity `ei` in N.m<sup>2</sup>,
+    /// found by integrating EIy'' = M twice with the supports fixing the constants.
+    /// Deflections are positive upwards, so a sagging beam deflects negatively.
+    ///
+    /// Returns an error if `ei` is not positive or the beam can not be solved.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::beam::{Beam, DistributedLoad};
+    ///
+    /// // 5wL⁴/384EI at mid span of a simply supported beam under a uniform load
+    /// let (w, l, ei) = (10e3, 4.0, 2e7);
+    /// let beam = Beam::simply_supported(l).with_distributed_load(DistributedLoad::uniform(w, 0.0, l));
+    /// let deflection = beam.deflection(ei).unwrap();
+    ///
+    /// let (x, y) = deflection.max;
+    /// assert!((x - 2.0).abs() < 1e-9);
+    /// assert!((y + 5.0 * w * l.powi(4) / (384.0 * ei)).abs() < 1e-12);
+    /// assert!((deflection.slope.evaluate(0.0).unwrap() + w * l.powi(3) / (24.0 * ei)).abs() < 1e-12);
+    /// ```
+    pub fn deflection(&self, ei: f64) -> Result<Deflection, ImthError> {
+        if ei <= 0.0 {
+            return Err(ImthError::InvalidArgument(
+                "flexural rigidity must be positive",
+            ));
+        }
+        let solution = self.solve()?;
+        let slope = solution.moment.scale(1.0 / ei).antiderivative(0.0);
+        let deflection = slope.antiderivative(0.0);
+        let at = |f: &PiecewisePolynomial, x: f64| f.evaluate(x).unwrap_or(0.0);
+
+        // slope + c1 and deflection + c1 x + c2 must meet the support conditions
+        let (c1, c2) = match self.supports.as_slice() {
+            [Support::Fixed(x)] => {
+                let c1 = -at(&slope, *x);
+                (c1, -at(&deflection, *x) - c1 * x)
+            }
+            [a, b] => {
+                let (xa, xb) = (a.position(), b.position());
+                let c1 = -(at(&deflection, xb) - at(&deflection, xa)) / (xb - xa);
+                (c1, -at(&deflection, xa) - c1 * xa)
+            }
+            _ => unreachable!("solve only accepts determinate supports"),
+        };
+        let slope = slope.add_constant(c1);
+        let deflection = deflection.add_polynomial(&Polynomial::new(vec![c2, c1]));
+        let max = deflection.abs_max().unwrap_or_default();
+        Ok(Deflection {
+            slope,
+            deflection,
+            max,
+        })
+    }
+
+    /// Returns the influence line of the passed quantity, its value as a downward unit load
+    /// moves across the beam at `samples` evenly spaced positions, ignoring the loads on the
+    /// beam. The critical load position is where the magnitude is largest. A shear
+    /// influence line holds both sides of its jump with the load on the section. With the
+    /// `rayon` feature the beam is solved for the load positions in parallel.
+    ///
+    /// Returns an error if the reaction index or section is not on the beam, or the beam
+    /// can not be solved.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::beam::{Beam, InfluenceQuantity};
+    ///
+    /// // moment at 3 m on a 10 m simply supported beam peaks at ab/L with the load there
+    /// let beam = Beam::simply_supported(10.0);
+    /// let line = beam.influence_line(InfluenceQuantity::Moment(3.0), 101).unwrap();
+    ///
+    /// let (x, m) = line.critical;
+    /// assert!((x - 3.0).abs() < 1e-12 && (m - 2.1).abs() < 1e-12);
+    ///
+    /// // shear at 3 m is -a/L with the load left of the section and 1 - a/L right of it,
+    /// // jumping by the unit load as it crosses
+    /// let line = beam.influence_line(InfluenceQuantity::Shear(3.0), 101).unwrap();
+    /// for &(a, v) in &line.series.points {
+    ///     if a != 3.0 {
+    ///         let exact = if a < 3.0 { -a / 10.0 } else { 1.0 - a / 10.0 };
+    ///         assert!((v - exact).abs() < 1e-12);
+    ///     }
+    /// }
+    /// let (x, v) = line.critical;
+    /// assert!((x - 3.0).abs() < 1e-12 && (v.abs() - 0.7).abs() < 1e-12);
+    /// ```
+    pub fn influence_line(
+        &self,
+        quantity: InfluenceQuantity,
+        samples: usize,
+    ) -> Result<InfluenceLine, ImthError> {
+        let valid = match quantity {
+            InfluenceQuantity::Reaction(i) => i < self.supports.len(),
+            InfluenceQuantity::Shear(x) | InfluenceQuantity::Moment(x) => {
+                (0.0..=self.length).contains(&x)
+            }
+        };
+        if !valid {
+            return Err(ImthError::InvalidArgument(
+                "influence quantity must be a support or a section of the beam",
+            ));
+        }
+
+        let step = self.length / samples.saturating_sub(1).max(1) as f64;
+        let mut positions: Vec<f64> = (0..samples.max(1)).map(|i| step * i as f64).collect();
+        if let InfluenceQuantity::Shear(x) | InfluenceQuantity::Moment(x) = quantity {
+            positions.push(x);
+        }
+
+        let unloaded = Beam {
+            length: self.length,
+            supports: self.supports.clone(),
+            ..Beam::default()
+        };
+        positions.sort_by(f64::total_cmp);
+        positions.dedup();
+        let line_at = |position: f64| -> Result<Vec<(f64, f64)>, ImthError> {
+            let solution = unloaded.clone().with_point_load(position, 1.0).solve()?;
+            Ok(match quantity {
+                InfluenceQuantity::Reaction(i) => vec![(position, solution.reactions[i].force)],
+                // with the load on the section the shear jumps by the load, so both sides
+                // of the jump belong to the line
+                InfluenceQuantity::Shear(x) if position == x => vec![
+                    (position, solution.shear.evaluate_left(x).unwrap_or(0.0)),
+                    (position, solution.shear.evaluate(x).unwrap_or(0.0)),
+                ],
+                InfluenceQuantity::Shear(x) => {
+                    vec![(position, solution.shear.evaluate(x).unwrap_or(0.0))]
+                }
+                InfluenceQuantity::Moment(x) => {
+                    vec![(position, solution.moment.evaluate(x).unwrap_or(0.0))]
+                }
+            })
+        };
+        // every load position is a separate solve, spread over all cores with `rayon`
+        #[cfg(feature = "rayon")]
+        let lines: Result<Vec<_>, _> = {
+            use rayon::prelude::*;
+            positions.into_par_iter().map(line_at).collect()
+        };
+        #[cfg(not(feature = "rayon"))]
+        let lines: Result<Vec<_>, _> = positions.into_iter().map(line_at).collect();
+        let points: Vec<(f64, f64)> = lines?.into_iter().flatten().collect();
+
+        let series = DataSeries::new(&quantity.to_string(), points)
+            .with_labels("load position (m)", quantity.unit());
+        let critical = series.abs_max().unwrap_or_default();
+        Ok(InfluenceLine { series, critical })
+    }
+
+    /// Returns the reactions from the equilibrium of the whole beam, with the distributed
+    /// loads given as polynomial pieces so they match the shear and moment exactly.
+    fn reactions(&self, pieces: &[(f64, f64, Polynomial)]) -> Result<Vec<Reaction>, ImthError> {
+        let total: f64 = self.point_loads.iter().map(|(_, p)| p).sum::<f64>()
+            + pieces
+                .iter()
+                .map(|(a, b, w)| w.integrate(*a, *b))
+                .sum::<f64>();
+        // clockwise moment of the loads about x
+        let load_moment = |x: f64| {
+            let arm = Polynomial::new(vec![-x, 1.0]);
+            self.point_loads
+                .iter()
+                .map(|(a, p)| p * (a - x))
+                .sum::<f64>()
+                + pieces
+                    .iter()
+                    .map(|(a, b, w)| (w * &arm).integrate(*a, *b))
+                    .sum::<f64>()
+                - self.couples.iter().map(|(_, c)| c).sum::<f64>()
+        };
+
+        match self.supports.as_slice() {
+            [Support::Fixed(x)] => Ok(vec![Reaction {
+                position: *x,
+                force: total,
+                moment: load_moment(*x),
+            }]),
+            [a, b]
+                if !matches!(a, Support::Fixed(_))
+                    && !matches!(b, Support::Fixed(_))
+                    && a.position() != b.position() =>
+            {
+                let (xa, xb) = (a.position(), b.position());
+                let rb = load_moment(xa) / (xb - xa);
+                Ok(vec![
+                    Reaction {
+                        position: xa,
+                        force: total - rb,
+                        moment: 0.0,
+                    },
+                    Reaction {
+                        position: xb,
+                        force: rb,
+                        moment: 0.0,
+                    },
+                ])
+            }
+            _ => Err(ImthError::InvalidArgument(
+                "beam must have one fixed support or two pin or roller supports",
+            )),
+        }
+    }
+}
+
+/// The reactions, shear force and bending moment of a solved [`Beam`].
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct BeamSolution {
+    /// The support reactions in the order of the supports of the beam.
+    pub reactions: Vec<Reaction>,
+    /// Shear force in N along the beam.
+    pub shear: PiecewisePolynomial,
+    /// Bending moment in N.m along the beam, positive sagging.
+    pub moment: PiecewisePolynomial,
+}
+
+impl BeamSolution {
+    /// Returns the positions where the shear force is zero or changes sign, which is
+    /// where the bending moment has its turning points.
+    #[inline]
+    pub fn zero_shear(&self) -> Vec<f64> {
+        self.shear.roots()
+    }
+
+    /// Returns the shear force diagram sampled at about `samples` points.
+    #[inline]
+    pub fn shear_diagram(&self, samples: usize) -> DataSeries {
+        self.shear
+            .sample("Shear force", samples)
+            .with_labels("x (m)", "V (N)")
+    }
+
+    /// Returns the bending moment diagram sampled at about `samples` points.
+    #[inline]
+    pub fn moment_diagram(&self, samples: usize) -> DataSeries {
+        self.moment
+            .sample("Bending moment", samples)
+            .with_labels("x (m)", "M (N.m)")
+    }
+}
+
+/// The slope and deflection of a [`Beam`].
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Deflection {
+    /// Slope in radians along the beam, positive anticlockwise.
+    pub slope: PiecewisePolynomial,
+    /// Deflection in m along the beam, positive upwards.
+    pub deflection: PiecewisePolynomial,
+    /// The position and value of the deflection with the largest magnitude.
+    pub max: (f64, f64),
+}
+
+impl Deflection {
+    /// Returns the deflected shape sampled at about `samples` points.
+    #[inline]
+    pub fn diagram(&self, samples: usize) -> DataSeries {
+        self.deflection
+            .sample("Deflection", samples)
+            .with_labels("x (m)", "y (m)")
+    }
+}
+
+/// The quantity an influence line is drawn for.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum InfluenceQuantity {
+    /// The reaction of the support with the passed index in `supports`.
+    Reaction(usize),
+    /// The shear force just to the right of the section at the passed position.
+    Shear(f64),
+    /// The bending moment at the section at the passed position.
+    Moment(f64),
+}
+
+impl InfluenceQuantity {
+    /// Returns the unit of the quantity per newton of load.
+    fn unit(&self) -> &'static str {
+        match self {
+            InfluenceQuantity::Reaction(_) | InfluenceQuantity::Shear(_) => "N/N",
+            InfluenceQuantity::Moment(_) => "N.m/N",
+        }
+    }
+}
+
+impl fmt::Display for InfluenceQuantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InfluenceQuantity::Reaction(i) => write!(f, "Reaction {i}"),
+            InfluenceQuantity::Shear(x) => write!(f, "Shear at {x} m"),
+            InfluenceQuantity::Moment(x) => write!(f, "Moment at {x} m"),
+        }
+    }
+}
+
+/// An influence line sampled along a [`Beam`].
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct InfluenceLine {
+    /// The value of the quantity against the position of the unit load.
+    pub series: DataSeries,
+    /// The load position and value where the magnitude of the quantity is largest.
+    pub critical: (f64, f64),
+}
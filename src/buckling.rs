@@ -0,0 +1,193 @@
+//! # Buckling
+//!
+//! Elastic (Euler) buckling of straight, axially loaded columns.
+//!
+//! A column of length L with bending stiffness EI buckles at\
+//! P<sub>cr</sub> = π<sup>2</sup>EI / (KL)<sup>2</sup>\
+//! where the effective length factor K depends on how the ends are held. The section
+//! buckles about its weakest axis, so I is the minimum principal second moment of area.
+
+use std::fmt;
+
+use crate::constants::PI;
+use crate::polygon::Polygon2D;
+
+/// How the ends of a column are restrained.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum EndCondition {
+    /// Both ends pinned, free to rotate but not to move sideways.
+    #[default]
+    PinnedPinned,
+    /// One end fixed and the other free, like a flagpole.
+    FixedFree,
+    /// One end fixed and the other pinned.
+    FixedPinned,
+    /// Both ends fixed against rotation and sideways movement.
+    FixedFixed,
+}
+
+impl EndCondition {
+    /// Returns the theoretical effective length factor K of this end condition.
+    #[inline]
+    pub fn effective_length_factor(&self) -> f64 {
+        match self {
+            EndCondition::PinnedPinned => 1.0,
+            EndCondition::FixedFree => 2.0,
+            EndCondition::FixedPinned => 0.699,
+            EndCondition::FixedFixed => 0.5,
+        }
+    }
+}
+
+/// The way a column under increasing axial load fails first.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FailureMode {
+    /// The column buckles elastically before the material yields.
+    Buckling,
+    /// The material yields in compression before the column buckles.
+    Yielding,
+}
+
+impl fmt::Display for FailureMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FailureMode::Buckling => write!(f, "buckling"),
+            FailureMode::Yielding => write!(f, "yielding"),
+        }
+    }
+}
+
+/// A straight column of uniform section loaded along its axis.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Column {
+    /// Length of the column between its ends in m.
+    pub length: f64,
+    /// Young's modulus of the material in Pa.
+    pub elastic_modulus: f64,
+    /// Area of the cross section in m<sup>2</sup>.
+    pub area: f64,
+    /// Smallest principal second moment of area of the section in m<sup>4</sup>.
+    pub second_moment: f64,
+    /// Restraint at the ends of the column.
+    pub end_condition: EndCondition,
+}
+
+impl Column {
+    /// Returns the column with the passed length, Young's modulus, section area, minimum
+    /// second moment of area and end condition.
+    #[inline]
+    pub fn new(
+        length: f64,
+        elastic_modulus: f64,
+        area: f64,
+        second_moment: f64,
+        end_condition: EndCondition,
+    ) -> Self {
+        Self {
+            length,
+            elastic_modulus,
+            area,
+            second_moment,
+            end_condition,
+        }
+    }
+
+    /// Returns the column with the passed cross section, taking its area and minimum
+    /// principal second moment of area, or None if the section has no area.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::buckling::{Column, EndCondition, FailureMode};
+    /// use i_mth::polygon::Polygon2D;
+    /// use i_mth::vector2d::Vector2D;
+    ///
+    /// // a 3 m steel strut of 50 mm by 30 mm bar, pinned at both ends
+    /// let section = Polygon2D::new(vec![
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(0.05, 0.0),
+    ///     Vector2D::new(0.05, 0.03),
+    ///     Vector2D::new(0.0, 0.03),
+    /// ]);
+    /// let column = Column::from_section(&section, 3.0, 200e9, EndCondition::PinnedPinned).unwrap();
+    ///
+    /// let i_min = 0.05 * 0.03f64.powi(3) / 12.0;
+    /// assert!((column.second_moment - i_min).abs() < 1e-15);
+    /// assert!((column.slenderness_ratio() - 3.0 / (0.03 / 12f64.sqrt())).abs() < 1e-9);
+    ///
+    /// let pcr = std::f64::consts::PI.powi(2) * 200e9 * i_min / 9.0;
+    /// assert!((column.critical_load() - pcr).abs() < 1e-6);
+    ///
+    /// let (load, mode) = column.failure_load(250e6);
+    /// assert_eq!(FailureMode::Buckling, mode);
+    /// assert!((load - pcr).abs() < 1e-6);
+    ///
+    /// // a short stub of the same bar squashes instead
+    /// let stub = Column { length: 0.2, ..column };
+    /// assert_eq!(FailureMode::Yielding, stub.failure_load(250e6).1);
+    /// ```
+    pub fn from_section(
+        section: &Polygon2D,
+        length: f64,
+        elastic_modulus: f64,
+        end_condition: EndCondition,
+    ) -> Option<Self> {
+        let (_, second_moment) = section.second_moments()?.principal();
+        Some(Self::new(
+            length,
+            elastic_modulus,
+            section.area(),
+            second_moment,
+            end_condition,
+        ))
+    }
+
+    /// Returns the effective length KL of this column.
+    #[inline]
+    pub fn effective_length(&self) -> f64 {
+        self.end_condition.effective_length_factor() * self.length
+    }
+
+    /// Returns the least radius of gyration r = √(I / A) of the section.
+    #[inline]
+    pub fn radius_of_gyration(&self) -> f64 {
+        (self.second_moment / self.area).sqrt()
+    }
+
+    /// Returns the slenderness ratio KL / r of this column.
+    #[inline]
+    pub fn slenderness_ratio(&self) -> f64 {
+        self.effective_length() / self.radius_of_gyration()
+    }
+
+    /// Returns the Euler critical buckling load P<sub>cr</sub> = π<sup>2</sup>EI / (KL)<sup>2</sup> in N.
+    #[inline]
+    pub fn critical_load(&self) -> f64 {
+        PI * PI * self.elastic_modulus * self.second_moment / self.effective_length().powi(2)
+    }
+
+    /// Returns the average compressive stress P<sub>cr</sub> / A at the critical load.
+    #[inline]
+    pub fn critical_stress(&self) -> f64 {
+        PI * PI * self.elastic_modulus / self.slenderness_ratio().powi(2)
+    }
+
+    /// Returns the slenderness ratio π√(E / σ<sub>y</sub>) below which a column of this
+    /// material yields before it buckles.
+    #[inline]
+    pub fn transition_slenderness(&self, yield_stress: f64) -> f64 {
+        PI * (self.elastic_modulus / yield_stress).sqrt()
+    }
+
+    /// Returns the axial load at which this column first fails, the smaller of the Euler
+    /// critical load and the squash load σ<sub>y</sub>A, with the mode of failure.
+    #[inline]
+    pub fn failure_load(&self, yield_stress: f64) -> (f64, FailureMode) {
+        let buckling = self.critical_load();
+        let squash = yield_stress * self.area;
+        if buckling < squash {
+            (buckling, FailureMode::Buckling)
+        } else {
+            (squash, FailureMode::Yielding)
+        }
+    }
+}
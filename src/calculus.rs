@@ -0,0 +1,507 @@
+//! # Calculus
+//!
+//! Numerical differentiation and integration of functions and of sampled data.
+//!
+//! Every function is generic over [`StateVector`] so the same routines work for scalar
+//! functions returning `f64` and for vector-valued functions returning `Vector2D`,
+//! `Vector3D` or `Vec<f64>`. The Jacobian helpers differentiate functions of several
+//! variables instead, like the residuals of a system of nonlinear equations.
+
+use crate::integrators::StateVector;
+use crate::matrix3::Matrix3;
+use crate::matrixn::MatrixN;
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+/// Returns the derivative of `f` at `x` estimated with the forward difference
+/// (f(x + h) - f(x)) / h. First order accurate.
+#[inline]
+pub fn forward_difference<S, F>(f: F, x: f64, h: f64) -> S
+where
+    S: StateVector,
+    F: Fn(f64) -> S,
+{
+    combine(&[(&f(x + h), 1.0 / h), (&f(x), -1.0 / h)])
+}
+
+/// Returns the derivative of `f` at `x` estimated with the backward difference
+/// (f(x) - f(x - h)) / h. First order accurate.
+#[inline]
+pub fn backward_difference<S, F>(f: F, x: f64, h: f64) -> S
+where
+    S: StateVector,
+    F: Fn(f64) -> S,
+{
+    combine(&[(&f(x), 1.0 / h), (&f(x - h), -1.0 / h)])
+}
+
+/// Returns the derivative of `f` at `x` estimated with the central difference
+/// (f(x + h) - f(x - h)) / 2h. Second order accurate.
+///
+/// # Example
+/// ```rust
+/// use i_mth::calculus::central_difference;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // velocity of a particle moving on a helix
+/// let position = |t: f64| Vector3D::new(t.cos(), t.sin(), 2.0 * t);
+/// let velocity = central_difference(position, 0.0, 1e-5);
+///
+/// assert!((velocity - Vector3D::new(0.0, 1.0, 2.0)).magnitude() < 1e-9);
+/// ```
+#[inline]
+pub fn central_difference<S, F>(f: F, x: f64, h: f64) -> S
+where
+    S: StateVector,
+    F: Fn(f64) -> S,
+{
+    let inv_2h = 0.5 / h;
+    combine(&[(&f(x + h), inv_2h), (&f(x - h), -inv_2h)])
+}
+
+/// Returns the second derivative of `f` at `x` estimated with the central difference
+/// (f(x + h) - 2f(x) + f(x - h)) / h<sup>2</sup>. Second order accurate.
+#[inline]
+pub fn second_derivative<S, F>(f: F, x: f64, h: f64) -> S
+where
+    S: StateVector,
+    F: Fn(f64) -> S,
+{
+    let inv_h2 = 1.0 / (h * h);
+    combine(&[
+        (&f(x + h), inv_h2),
+        (&f(x), -2.0 * inv_h2),
+        (&f(x - h), inv_h2),
+    ])
+}
+
+/// Returns the Jacobian matrix J<sub>ij</sub> = ∂f<sub>i</sub>/∂x<sub>j</sub> of `f` at `x`
+/// estimated with central differences, or None if `x` is empty or `f` does not return the
+/// same number of values at every point.\
+/// Each variable is stepped by `h` times the larger of one and its magnitude, so variables
+/// of very different sizes get steps of the same relative size.
+///
+/// # Example
+/// ```rust
+/// use i_mth::calculus::jacobian;
+///
+/// // polar to cartesian coordinates
+/// let f = |p: &[f64]| vec![p[0] * p[1].cos(), p[0] * p[1].sin()];
+/// let (r, theta) = (2.0f64, 0.5f64);
+/// let j = jacobian(f, &[r, theta], 1e-6).unwrap();
+///
+/// assert!((j[(0, 0)] - theta.cos()).abs() < 1e-9);
+/// assert!((j[(0, 1)] + r * theta.sin()).abs() < 1e-9);
+/// assert!((j[(1, 0)] - theta.sin()).abs() < 1e-9);
+/// assert!((j[(1, 1)] - r * theta.cos()).abs() < 1e-9);
+///
+/// // a function gaining a value away from x has no Jacobian
+/// let growing = |p: &[f64]| if p[0] == 1.0 { vec![1.0, 2.0] } else { vec![1.0, 2.0, 3.0] };
+/// assert!(jacobian(growing, &[1.0], 1e-6).is_none());
+/// ```
+pub fn jacobian<F>(f: F, x: &[f64], h: f64) -> Option<MatrixN>
+where
+    F: Fn(&[f64]) -> Vec<f64>,
+{
+    let m = f(x).len();
+    let mut columns = Vec::with_capacity(x.len());
+    for j in 0..x.len() {
+        let step = h * x[j].abs().max(1.0);
+        let moved = |t: f64| {
+            let mut point = x.to_vec();
+            point[j] += t;
+            f(&point)
+        };
+        let (forward, backward) = (moved(step), moved(-step));
+        if forward.len() != m || backward.len() != m {
+            return None;
+        }
+        let inv_2h = 0.5 / step;
+        columns.push(
+            forward
+                .iter()
+                .zip(&backward)
+                .map(|(a, b)| (a - b) * inv_2h)
+                .collect::<Vec<f64>>(),
+        );
+    }
+    let data = (0..m)
+        .flat_map(|i| columns.iter().map(move |c| c[i]))
+        .collect();
+    MatrixN::from_vec(m, x.len(), data).filter(|_| !x.is_empty())
+}
+
+/// Returns the Jacobian matrix of a function of the plane, rows of the partial
+/// derivatives of f<sub>x</sub> and f<sub>y</sub>, estimated like [`jacobian`].
+#[inline]
+pub fn jacobian_2d<F>(f: F, x: Vector2D, h: f64) -> [[f64; 2]; 2]
+where
+    F: Fn(Vector2D) -> Vector2D,
+{
+    let dx = central_difference(
+        |t| f(x.add_scaled(Vector2D::i(), t)),
+        0.0,
+        h * x.x.abs().max(1.0),
+    );
+    let dy = central_difference(
+        |t| f(x.add_scaled(Vector2D::j(), t)),
+        0.0,
+        h * x.y.abs().max(1.0),
+    );
+    [[dx.x, dy.x], [dx.y, dy.y]]
+}
+
+/// Returns the Jacobian matrix of a function of space estimated like [`jacobian`].
+///
+/// # Example
+/// ```rust
+/// use i_mth::calculus::jacobian_3d;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // the Jacobian of ω × r with respect to r is the cross product matrix of ω
+/// let omega = Vector3D::new(0.5, -1.0, 2.0);
+/// let j = jacobian_3d(|r| omega.cross(r), Vector3D::new(1.0, 2.0, 3.0), 1e-6);
+///
+/// assert!(j.is_approx_equal_to(&omega.hat(), 1e-9));
+/// ```
+#[inline]
+pub fn jacobian_3d<F>(f: F, x: Vector3D, h: f64) -> Matrix3
+where
+    F: Fn(Vector3D) -> Vector3D,
+{
+    let column = |axis: Vector3D, scale: f64| {
+        central_difference(|t| f(x.add_scaled(axis, t)), 0.0, h * scale.abs().max(1.0))
+    };
+    Matrix3::from_column_vectors(
+        column(Vector3D::i(), x.x),
+        column(Vector3D::j(), x.y),
+        column(Vector3D::k(), x.z),
+    )
+}
+
+/// Returns the first derivative of sampled `(t, value)` data at every sample time, like a
+/// velocity estimated from recorded positions. The samples must be sorted by time but do not
+/// need to be evenly spaced.
+///
+/// Interior samples use the three point central formula for uneven spacing and the end samples
+/// use one sided differences. Returns an empty Vec when there are less than two samples.
+///
+/// # Example
+/// ```rust
+/// use i_mth::calculus::differentiate_samples;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // free fall recorded every 0.1 s
+/// let samples: Vec<(f64, Vector3D)> = (0..20)
+///     .map(|i| i as f64 * 0.1)
+///     .map(|t| (t, Vector3D::new(3.0 * t, -4.905 * t * t, 0.0)))
+///     .collect();
+///
+/// let velocities = differentiate_samples(&samples);
+/// let (t, v) = velocities[10];
+/// assert!((v - Vector3D::new(3.0, -9.81 * t, 0.0)).magnitude() < 1e-9);
+/// ```
+pub fn differentiate_samples<S: StateVector>(samples: &[(f64, S)]) -> Vec<(f64, S)> {
+    let n = samples.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    let mut derivatives = Vec::with_capacity(n);
+
+    let (t0, y0) = &samples[0];
+    let (t1, y1) = &samples[1];
+    derivatives.push((
+        *t0,
+        combine(&[(y1, 1.0 / (t1 - t0)), (y0, -1.0 / (t1 - t0))]),
+    ));
+
+    for window in samples.windows(3) {
+        let [(ta, ya), (tb, yb), (tc, yc)] = window else {
+            unreachable!()
+        };
+        let h1 = tb - ta;
+        let h2 = tc - tb;
+        derivatives.push((
+            *tb,
+            combine(&[
+                (ya, -h2 / (h1 * (h1 + h2))),
+                (yb, (h2 - h1) / (h1 * h2)),
+                (yc, h1 / (h2 * (h1 + h2))),
+            ]),
+        ));
+    }
+
+    let (ta, ya) = &samples[n - 2];
+    let (tb, yb) = &samples[n - 1];
+    derivatives.push((
+        *tb,
+        combine(&[(yb, 1.0 / (tb - ta)), (ya, -1.0 / (tb - ta))]),
+    ));
+    derivatives
+}
+
+/// Returns the second derivative of sampled `(t, value)` data at every sample time, like an
+/// acceleration estimated from recorded positions. The samples must be sorted by time but do
+/// not need to be evenly spaced.
+///
+/// Interior samples use the three point formula for uneven spacing and the end samples reuse
+/// the value of their neighbour. Returns an empty Vec when there are less than three samples.
+pub fn second_derivative_samples<S: StateVector>(samples: &[(f64, S)]) -> Vec<(f64, S)> {
+    let n = samples.len();
+    if n < 3 {
+        return Vec::new();
+    }
+    let mut derivatives = Vec::with_capacity(n);
+    for window in samples.windows(3) {
+        let [(ta, ya), (tb, yb), (tc, yc)] = window else {
+            unreachable!()
+        };
+        let h1 = tb - ta;
+        let h2 = tc - tb;
+        derivatives.push((
+            *tb,
+            combine(&[
+                (ya, 2.0 / (h1 * (h1 + h2))),
+                (yb, -2.0 / (h1 * h2)),
+                (yc, 2.0 / (h2 * (h1 + h2))),
+            ]),
+        ));
+    }
+    derivatives.insert(0, (samples[0].0, derivatives[0].1.clone()));
+    derivatives.push((
+        samples[n - 1].0,
+        derivatives[derivatives.len() - 1].1.clone(),
+    ));
+    derivatives
+}
+
+/// Returns the integral of `f` over `[a, b]` using the composite trapezoidal rule with
+/// `intervals` equal sub-intervals. Second order accurate.
+pub fn trapezoid<S, F>(f: F, a: f64, b: f64, intervals: usize) -> S
+where
+    S: StateVector,
+    F: Fn(f64) -> S,
+{
+    let n = intervals.max(1);
+    let h = (b - a) / n as f64;
+    let ends = combine(&[(&f(a), 0.5 * h), (&f(b), 0.5 * h)]);
+    (1..n).fold(ends, |acc, i| acc.add_scaled(&f(a + i as f64 * h), h))
+}
+
+/// Returns the integral of `f` over `[a, b]` using the composite Simpson's rule with
+/// `intervals` equal sub-intervals, rounded up to an even number. Fourth order accurate.
+///
+/// # Example
+/// ```rust
+/// use i_mth::calculus::simpson;
+///
+/// // work done by a spring force F = kx with k = 200 N/m stretched from 0 to 0.3 m
+/// let work = simpson(|x| 200.0 * x, 0.0, 0.3, 10);
+///
+/// assert!((work - 9.0).abs() < 1e-12);
+/// ```
+pub fn simpson<S, F>(f: F, a: f64, b: f64, intervals: usize) -> S
+where
+    S: StateVector,
+    F: Fn(f64) -> S,
+{
+    let n = intervals.max(2).next_multiple_of(2);
+    let h = (b - a) / n as f64;
+    let ends = combine(&[(&f(a), h / 3.0), (&f(b), h / 3.0)]);
+    (1..n).fold(ends, |acc, i| {
+        let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+        acc.add_scaled(&f(a + i as f64 * h), weight * h / 3.0)
+    })
+}
+
+/// Returns the integral of `f` over `[a, b]` using Gauss-Legendre quadrature with `points`
+/// nodes on each of `intervals` equal sub-intervals, or None if `points` is not between
+/// 1 and 5. Exact for polynomials of degree up to `2 * points - 1` on each sub-interval.
+///
+/// # Example
+/// ```rust
+/// use i_mth::calculus::gauss_legendre;
+/// use i_mth::vector2d::Vector2D;
+///
+/// // centroid of the parabolic spandrel y = x² for x in [0, 1]
+/// let area: f64 = gauss_legendre(|x| x * x, 0.0, 1.0, 3, 1).unwrap();
+/// let moments = gauss_legendre(|x| Vector2D::new(x * x * x, 0.5 * x.powi(4)), 0.0, 1.0, 3, 1).unwrap();
+/// let centroid = moments.scale(1.0 / area);
+///
+/// assert!((centroid.x - 0.75).abs() < 1e-12);
+/// assert!((centroid.y - 0.3).abs() < 1e-12);
+///
+/// // short intervals keep their relative accuracy
+/// let tiny: f64 = gauss_legendre(|x| 3.0 * x * x, 0.0, 1e-6, 2, 1).unwrap();
+/// assert!((tiny - 1e-18).abs() < 1e-30);
+/// ```
+pub fn gauss_legendre<S, F>(f: F, a: f64, b: f64, points: usize, intervals: usize) -> Option<S>
+where
+    S: StateVector,
+    F: Fn(f64) -> S,
+{
+    let (nodes, weights): (&[f64], &[f64]) = match points {
+        1 => (&[0.0], &[2.0]),
+        2 => (
+            &[-0.577_350_269_189_625_8, 0.577_350_269_189_625_8],
+            &[1.0, 1.0],
+        ),
+        3 => (
+            &[-0.774_596_669_241_483_4, 0.0, 0.774_596_669_241_483_4],
+            &[
+                0.555_555_555_555_555_6,
+                0.888_888_888_888_889,
+                0.555_555_555_555_555_6,
+            ],
+        ),
+        4 => (
+            &[
+                -0.861_136_311_594_052_6,
+                -0.339_981_043_584_856_3,
+                0.339_981_043_584_856_3,
+                0.861_136_311_594_052_6,
+            ],
+            &[
+                0.347_854_845_137_453_9,
+                0.652_145_154_862_546_1,
+                0.652_145_154_862_546_1,
+                0.347_854_845_137_453_9,
+            ],
+        ),
+        5 => (
+            &[
+                -0.906_179_845_938_664,
+                -0.538_469_310_105_683_1,
+                0.0,
+                0.538_469_310_105_683_1,
+                0.906_179_845_938_664,
+            ],
+            &[
+                0.236_926_885_056_189_1,
+                0.478_628_670_499_366_5,
+                0.568_888_888_888_888_9,
+                0.478_628_670_499_366_5,
+                0.236_926_885_056_189_1,
+            ],
+        ),
+        _ => return None,
+    };
+
+    let n = intervals.max(1);
+    let h = (b - a) / n as f64;
+    let half_h = 0.5 * h;
+    let mut total: Option<S> = None;
+    for i in 0..n {
+        let mid = a + (i as f64 + 0.5) * h;
+        for (node, weight) in nodes.iter().zip(weights) {
+            let value = f(mid + half_h * node);
+            total = Some(match total {
+                Some(acc) => acc.add_scaled(&value, weight * half_h),
+                None => value.scale(weight * half_h),
+            });
+        }
+    }
+    total
+}
+
+/// Returns the integral of sampled `(t, value)` data using the trapezoidal rule, like the
+/// impulse of a recorded force. The samples must be sorted by time but do not need to be
+/// evenly spaced. Returns None when there are less than two samples.
+///
+/// # Example
+/// ```rust
+/// use i_mth::calculus::trapezoid_samples;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // force measured during an impact
+/// let force = [
+///     (0.000, Vector3D::new(0.0, 0.0, 0.0)),
+///     (0.001, Vector3D::new(400.0, 100.0, 0.0)),
+///     (0.002, Vector3D::new(0.0, 0.0, 0.0)),
+/// ];
+/// let impulse = trapezoid_samples(&force).unwrap();
+///
+/// assert!((impulse - Vector3D::new(0.4, 0.1, 0.0)).magnitude() < 1e-12);
+/// ```
+pub fn trapezoid_samples<S: StateVector>(samples: &[(f64, S)]) -> Option<S> {
+    if samples.len() < 2 {
+        return None;
+    }
+    cumulative_trapezoid(samples).pop().map(|(_, total)| total)
+}
+
+/// Returns the running integral of sampled `(t, value)` data using the trapezoidal rule,
+/// starting at zero on the first sample, like a velocity history obtained from recorded
+/// accelerations. The samples must be sorted by time.
+///
+/// # Example
+/// ```rust
+/// use i_mth::calculus::cumulative_trapezoid;
+///
+/// // velocity from an acceleration starting out unbounded
+/// let running = cumulative_trapezoid(&[(0.0, f64::INFINITY), (1.0, 4.0), (2.0, 6.0)]);
+///
+/// assert_eq!((0.0, 0.0), running[0]);
+/// assert_eq!(f64::INFINITY, running[2].1);
+/// ```
+pub fn cumulative_trapezoid<S: StateVector>(samples: &[(f64, S)]) -> Vec<(f64, S)> {
+    let Some((t0, y0)) = samples.first() else {
+        return Vec::new();
+    };
+    let mut running = Vec::with_capacity(samples.len());
+    running.push((*t0, y0.zero_like()));
+    for window in samples.windows(2) {
+        let (ta, ya) = &window[0];
+        let (tb, yb) = &window[1];
+        let half_h = 0.5 * (tb - ta);
+        let total = running[running.len() - 1]
+            .1
+            .add_scaled(ya, half_h)
+            .add_scaled(yb, half_h);
+        running.push((*tb, total));
+    }
+    running
+}
+
+/// Returns the integral of sampled `(t, value)` data using Simpson's rule for uneven spacing
+/// on pairs of intervals, with the trapezoidal rule on the last interval when the number of
+/// intervals is odd. The samples must be sorted by time. Returns None when there are less
+/// than two samples.
+pub fn simpson_samples<S: StateVector>(samples: &[(f64, S)]) -> Option<S> {
+    let n = samples.len();
+    if n < 2 {
+        return None;
+    }
+    let (_, y0) = &samples[0];
+    let mut total = y0.zero_like();
+    let mut i = 0;
+    while i + 2 < n {
+        let (ta, ya) = &samples[i];
+        let (tb, yb) = &samples[i + 1];
+        let (tc, yc) = &samples[i + 2];
+        let h0 = tb - ta;
+        let h1 = tc - tb;
+        let sum = h0 + h1;
+        total = total
+            .add_scaled(ya, sum / 6.0 * (2.0 - h1 / h0))
+            .add_scaled(yb, sum / 6.0 * sum * sum / (h0 * h1))
+            .add_scaled(yc, sum / 6.0 * (2.0 - h0 / h1));
+        i += 2;
+    }
+    if i + 1 < n {
+        let (ta, ya) = &samples[i];
+        let (tb, yb) = &samples[i + 1];
+        let half_h = 0.5 * (tb - ta);
+        total = total.add_scaled(ya, half_h).add_scaled(yb, half_h);
+    }
+    Some(total)
+}
+
+/// Returns the linear combination of the passed states with the passed weights.
+/// Expects at least one term.
+pub(crate) fn combine<S: StateVector>(terms: &[(&S, f64)]) -> S {
+    let (first, weight) = terms[0];
+    terms[1..]
+        .iter()
+        .fold(first.scale(weight), |acc, (s, w)| acc.add_scaled(s, *w))
+}
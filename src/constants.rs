@@ -1,68 +1,232 @@
 //! # CONSTANTS
 //!
-//! Stores common mathematical and physical constants as well as
+//! Stores common mathematical constants, like PI, TAU and E, and physical constants: the
+//! gravitational constant, the speed of light, the vacuum permeability and permittivity,
+//! and the standard gravity, mass, radius and sidereal day of the Earth.
 //!
 //! Again each constant is stored as an f64 type and uncertainty of these
-//! constants are specified in the links.
+//! constants are specified in the links. The measured physical constants are also
+//! available as [`PhysicalConstant`]s in the [`physical`] module, which carry their
+//! unit and standard uncertainty along with the value.
 //!
 //! [Sources for mathematical constants](https://en.wikipedia.org/wiki/List_of_mathematical_constants)
 //! [Sources for physical constants](https://en.wikipedia.org/wiki/List_of_physical_constants)
 //! [More Sources](https://cosmologist.info/teaching/Cosmology/Physical_constants.pdf)
 
+use std::fmt;
 
 /// **Acceleration due to gravity**\
 /// unit: ms<sup>-2</sup>
 /// value: -9.806_65
-pub const EARTH_GRAVITY: f64 = -9.806_65;
+pub const EARTH_GRAVITY: f64 = physical::EARTH_GRAVITY.value;
 
 /// **Mass of planet Earth**
 /// unit: kg
 /// value: 5.972_168e24
 /// [More Info](https://en.wikipedia.org/wiki/Earth)
-pub const EARTH_MASS: f64 = 5.972_168e24;
+pub const EARTH_MASS: f64 = physical::EARTH_MASS.value;
 
 /// **Radius of planet Earth**
 /// unit: m
 /// value: 6_371e3
 /// [More Info](https://en.wikipedia.org/wiki/Earth)
-pub const EARTH_RADIUS: f64 = 6_371e3;
+pub const EARTH_RADIUS: f64 = physical::EARTH_RADIUS.value;
+
+/// **Equatorial radius of planet Earth**
+/// unit: m
+/// value: 6_378_137
+/// [More Info](https://en.wikipedia.org/wiki/Earth_radius#Equatorial_radius)
+pub const EARTH_EQUATORIAL_RADIUS: f64 = physical::EARTH_EQUATORIAL_RADIUS.value;
+
+/// **Sidereal rotation period of planet Earth**\
+/// unit: s\
+/// value: 86_164.090_5
+/// [More Info](https://en.wikipedia.org/wiki/Sidereal_time)
+pub const EARTH_SIDEREAL_DAY: f64 = physical::EARTH_SIDEREAL_DAY.value;
 
 /// **Newtonian gravitational constant**\
 /// unit: m<sup>3</sup>kg<sup>-1</sup>s<sup>-2<sup>\
 /// value: 6.674_30e-11
 /// [More Info](https://en.wikipedia.org/wiki/Gravitational_constant)
-pub const G: f64 = 6.674_30e-11;
+pub const G: f64 = physical::G.value;
 
 /// **The constant PI**\
 /// unit: dimensionless\
 /// value: 3.14159_26535_89793
 /// [More Info](https://en.wikipedia.org/wiki/Pi)
-pub const PI: f64 = 3.14159_26535_89793;
+pub const PI: f64 = std::f64::consts::PI;
 
 /// **The constant Tau**
 /// value: 6.28318_53071_79586
 /// [More Info](https://tauday.com/tau-manifesto)
-pub const TAU: f64 = 6.28318_53071_79586;
+pub const TAU: f64 = std::f64::consts::TAU;
 
 /// **The Speed of light in a vacuum**\
 /// unit: ms<sup>-1</sup>\
 /// value: 299_792_458
 /// [More Info](https://en.wikipedia.org/wiki/Speed_of_light)
-pub const C: usize = 299_792_458;
+pub const C: f64 = physical::C.value;
 
 /// **Euler's Number**\
 /// value: 2.718_281_828_459_045
 /// [More Info](https://en.wikipedia.org/wiki/E_(mathematical_constant))
-pub const E: f64 = 2.718_281_828_459_045;
+pub const E: f64 = std::f64::consts::E;
 
 /// **Vacuum Permeability**\
 /// unit: m.kg.s<sup>-2</sup>A<sup>-2</sup>\
-/// value: 1.256_637_06e-6
+/// value: 1.256_637_062_12e-6
 /// [More Info](https://en.wikipedia.org/wiki/Vacuum_permeability)
-pub const VACUUM_PERMEABILITY: f64 = 1.256_637_06e-6;
+pub const VACUUM_PERMEABILITY: f64 = physical::VACUUM_PERMEABILITY.value;
 
 /// **Vacuum Permittivity**\
 /// unit: Fm<sup>-1</sup>\
 /// value: 8.854_187_812_8e-12
 /// [More Info](https://en.wikipedia.org/wiki/Vacuum_permittivity)
-pub const VACUUM_PERMITTIVITY: f64 = 8.854_187_812_8e-12;
\ No newline at end of file
+pub const VACUUM_PERMITTIVITY: f64 = physical::VACUUM_PERMITTIVITY.value;
+
+/// A measured physical constant together with its unit and standard uncertainty.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PhysicalConstant {
+    /// Name of the constant.
+    pub name: &'static str,
+    /// Value of the constant in SI units.
+    pub value: f64,
+    /// SI unit of the value, like `"m^3 kg^-1 s^-2"`.
+    pub unit: &'static str,
+    /// Standard uncertainty of the value, zero for exact constants.
+    pub uncertainty: f64,
+}
+
+impl PhysicalConstant {
+    /// Returns the value of the constant as an f64.
+    #[inline]
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns true if the value of the constant is exact by definition.
+    #[inline]
+    pub fn is_exact(&self) -> bool {
+        self.uncertainty == 0.0
+    }
+
+    /// Returns the uncertainty as a fraction of the magnitude of the value.
+    #[inline]
+    pub fn relative_uncertainty(&self) -> f64 {
+        self.uncertainty / self.value.abs()
+    }
+}
+
+impl From<PhysicalConstant> for f64 {
+    #[inline]
+    fn from(constant: PhysicalConstant) -> f64 {
+        constant.value
+    }
+}
+
+impl fmt::Display for PhysicalConstant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_exact() {
+            write!(f, "{} = {} {} (exact)", self.name, self.value, self.unit)
+        } else {
+            write!(
+                f,
+                "{} = {} ± {} {}",
+                self.name, self.value, self.uncertainty, self.unit
+            )
+        }
+    }
+}
+
+/// The measured physical constants with their units and uncertainties, following
+/// the [CODATA 2018](https://physics.nist.gov/cuu/Constants/) recommended values.
+///
+/// # Example
+/// ```rust
+/// use i_mth::constants::{self, physical};
+///
+/// assert_eq!(constants::G, physical::G.value());
+/// assert!(physical::C.is_exact());
+/// assert!(physical::G.relative_uncertainty() < 1e-4);
+/// ```
+pub mod physical {
+    use super::PhysicalConstant;
+
+    /// **Standard acceleration due to gravity**, pointing down.
+    pub const EARTH_GRAVITY: PhysicalConstant = PhysicalConstant {
+        name: "standard acceleration of gravity",
+        value: -9.806_65,
+        unit: "m s^-2",
+        uncertainty: 0.0,
+    };
+
+    /// **Mass of planet Earth**
+    pub const EARTH_MASS: PhysicalConstant = PhysicalConstant {
+        name: "Earth mass",
+        value: 5.972_168e24,
+        unit: "kg",
+        uncertainty: 6e20,
+    };
+
+    /// **Mean radius of planet Earth**, the IUGG mean radius rounded to the kilometre,
+    /// with half a kilometre of uncertainty for the rounding.
+    /// [Source](https://en.wikipedia.org/wiki/Earth_radius#Mean_radius)
+    pub const EARTH_RADIUS: PhysicalConstant = PhysicalConstant {
+        name: "Earth mean radius",
+        value: 6_371e3,
+        unit: "m",
+        uncertainty: 500.0,
+    };
+
+    /// **Equatorial radius of planet Earth**, the semi-major axis of the WGS 84 ellipsoid,
+    /// exact by definition.
+    /// [Source](https://en.wikipedia.org/wiki/Earth_radius#Equatorial_radius)
+    pub const EARTH_EQUATORIAL_RADIUS: PhysicalConstant = PhysicalConstant {
+        name: "Earth equatorial radius",
+        value: 6_378_137.0,
+        unit: "m",
+        uncertainty: 0.0,
+    };
+
+    /// **Sidereal rotation period of planet Earth**, the IERS value rounded to a tenth of
+    /// a millisecond, with half of that as uncertainty for the rounding.
+    /// [Source](https://en.wikipedia.org/wiki/Sidereal_time)
+    pub const EARTH_SIDEREAL_DAY: PhysicalConstant = PhysicalConstant {
+        name: "Earth sidereal day",
+        value: 86_164.090_5,
+        unit: "s",
+        uncertainty: 5e-5,
+    };
+
+    /// **Newtonian gravitational constant**
+    pub const G: PhysicalConstant = PhysicalConstant {
+        name: "Newtonian constant of gravitation",
+        value: 6.674_30e-11,
+        unit: "m^3 kg^-1 s^-2",
+        uncertainty: 1.5e-15,
+    };
+
+    /// **The Speed of light in a vacuum**
+    pub const C: PhysicalConstant = PhysicalConstant {
+        name: "speed of light in vacuum",
+        value: 299_792_458.0,
+        unit: "m s^-1",
+        uncertainty: 0.0,
+    };
+
+    /// **Vacuum Permeability**
+    pub const VACUUM_PERMEABILITY: PhysicalConstant = PhysicalConstant {
+        name: "vacuum magnetic permeability",
+        value: 1.256_637_062_12e-6,
+        unit: "N A^-2",
+        uncertainty: 1.9e-16,
+    };
+
+    /// **Vacuum Permittivity**
+    pub const VACUUM_PERMITTIVITY: PhysicalConstant = PhysicalConstant {
+        name: "vacuum electric permittivity",
+        value: 8.854_187_812_8e-12,
+        unit: "F m^-1",
+        uncertainty: 1.3e-21,
+    };
+}
@@ -0,0 +1,262 @@
+//! # Conversions
+//!
+//! Conversions between SI and the US customary units that statics and dynamics problems
+//! mix constantly. Each constant gives the number of SI units in one customary unit, so
+//! multiplying converts to SI and dividing converts back. The helper functions do the
+//! same with the direction spelled out.
+//!
+//! Every factor is exact by definition of the customary unit, up to f64 rounding.
+//!
+//! # Example
+//! ```rust
+//! use i_mth::conversions::{self, POUND_FORCE};
+//!
+//! // a 150 lbf load is about 667 N
+//! assert!((150.0 * POUND_FORCE - 667.233).abs() < 1e-3);
+//! assert!((conversions::degrees_to_radians(180.0) - std::f64::consts::PI).abs() < 1e-15);
+//! ```
+
+use crate::constants::{PI, TAU};
+use crate::units::{AngularVelocity, Energy, Force, Length, Mass};
+
+/// **Pound-force**\
+/// unit: N
+pub const POUND_FORCE: f64 = 4.448_221_615_260_5;
+
+/// **Foot**\
+/// unit: m
+pub const FOOT: f64 = 0.304_8;
+
+/// **Inch**\
+/// unit: m
+pub const INCH: f64 = 0.025_4;
+
+/// **Slug**, the mass accelerated at 1 ft s<sup>-2</sup> by 1 lbf\
+/// unit: kg
+pub const SLUG: f64 = POUND_FORCE / FOOT;
+
+/// **Pound-mass**\
+/// unit: kg
+pub const POUND_MASS: f64 = 0.453_592_37;
+
+/// **Pound-force per square inch**\
+/// unit: Pa
+pub const PSI: f64 = POUND_FORCE / (INCH * INCH);
+
+/// **Foot pound-force**\
+/// unit: J
+pub const FOOT_POUND: f64 = FOOT * POUND_FORCE;
+
+/// **Degree**\
+/// unit: rad
+pub const DEGREE: f64 = PI / 180.0;
+
+/// **Revolution**\
+/// unit: rad
+pub const REVOLUTION: f64 = TAU;
+
+/// **Revolution per minute**\
+/// unit: rad s<sup>-1</sup>
+pub const RPM: f64 = REVOLUTION / 60.0;
+
+/// Returns the passed force in newtons converted to pound-force.
+#[inline]
+pub fn newtons_to_pound_force(newtons: f64) -> f64 {
+    newtons / POUND_FORCE
+}
+
+/// Returns the passed force in pound-force converted to newtons.
+#[inline]
+pub fn pound_force_to_newtons(pound_force: f64) -> f64 {
+    pound_force * POUND_FORCE
+}
+
+/// Returns the passed length in metres converted to feet.
+#[inline]
+pub fn metres_to_feet(metres: f64) -> f64 {
+    metres / FOOT
+}
+
+/// Returns the passed length in feet converted to metres.
+#[inline]
+pub fn feet_to_metres(feet: f64) -> f64 {
+    feet * FOOT
+}
+
+/// Returns the passed length in metres converted to inches.
+#[inline]
+pub fn metres_to_inches(metres: f64) -> f64 {
+    metres / INCH
+}
+
+/// Returns the passed length in inches converted to metres.
+#[inline]
+pub fn inches_to_metres(inches: f64) -> f64 {
+    inches * INCH
+}
+
+/// Returns the passed mass in kilograms converted to slugs.
+#[inline]
+pub fn kilograms_to_slugs(kilograms: f64) -> f64 {
+    kilograms / SLUG
+}
+
+/// Returns the passed mass in slugs converted to kilograms.
+#[inline]
+pub fn slugs_to_kilograms(slugs: f64) -> f64 {
+    slugs * SLUG
+}
+
+/// Returns the passed pressure or stress in pascals converted to psi.
+#[inline]
+pub fn pascals_to_psi(pascals: f64) -> f64 {
+    pascals / PSI
+}
+
+/// Returns the passed pressure or stress in psi converted to pascals.
+#[inline]
+pub fn psi_to_pascals(psi: f64) -> f64 {
+    psi * PSI
+}
+
+/// Returns the passed energy in joules converted to foot pound-force.
+#[inline]
+pub fn joules_to_foot_pounds(joules: f64) -> f64 {
+    joules / FOOT_POUND
+}
+
+/// Returns the passed energy in foot pound-force converted to joules.
+#[inline]
+pub fn foot_pounds_to_joules(foot_pounds: f64) -> f64 {
+    foot_pounds * FOOT_POUND
+}
+
+/// Returns the passed angle in degrees converted to radians.
+#[inline]
+pub fn degrees_to_radians(degrees: f64) -> f64 {
+    degrees * DEGREE
+}
+
+/// Returns the passed angle in radians converted to degrees.
+#[inline]
+pub fn radians_to_degrees(radians: f64) -> f64 {
+    radians / DEGREE
+}
+
+/// Returns the passed angle in revolutions converted to radians.
+#[inline]
+pub fn revolutions_to_radians(revolutions: f64) -> f64 {
+    revolutions * REVOLUTION
+}
+
+/// Returns the passed angle in radians converted to revolutions.
+#[inline]
+pub fn radians_to_revolutions(radians: f64) -> f64 {
+    radians / REVOLUTION
+}
+
+/// Returns the passed angle in revolutions converted to degrees.
+#[inline]
+pub fn revolutions_to_degrees(revolutions: f64) -> f64 {
+    revolutions * 360.0
+}
+
+/// Returns the passed angle in degrees converted to revolutions.
+#[inline]
+pub fn degrees_to_revolutions(degrees: f64) -> f64 {
+    degrees / 360.0
+}
+
+/// Returns the passed angular speed in revolutions per minute converted to rad/s.
+#[inline]
+pub fn rpm_to_rad_per_sec(rpm: f64) -> f64 {
+    rpm * RPM
+}
+
+/// Returns the passed angular speed in rad/s converted to revolutions per minute.
+#[inline]
+pub fn rad_per_sec_to_rpm(rad_per_sec: f64) -> f64 {
+    rad_per_sec / RPM
+}
+
+impl Force {
+    /// Returns a force of the passed value in pound-force.
+    #[inline]
+    pub fn from_pound_force(pound_force: f64) -> Self {
+        Self(pound_force * POUND_FORCE)
+    }
+
+    /// Returns the value of the force in pound-force.
+    #[inline]
+    pub fn to_pound_force(&self) -> f64 {
+        self.0 / POUND_FORCE
+    }
+}
+
+impl Length {
+    /// Returns a length of the passed value in feet.
+    #[inline]
+    pub fn from_feet(feet: f64) -> Self {
+        Self(feet * FOOT)
+    }
+
+    /// Returns a length of the passed value in inches.
+    #[inline]
+    pub fn from_inches(inches: f64) -> Self {
+        Self(inches * INCH)
+    }
+
+    /// Returns the value of the length in feet.
+    #[inline]
+    pub fn to_feet(&self) -> f64 {
+        self.0 / FOOT
+    }
+
+    /// Returns the value of the length in inches.
+    #[inline]
+    pub fn to_inches(&self) -> f64 {
+        self.0 / INCH
+    }
+}
+
+impl Mass {
+    /// Returns a mass of the passed value in slugs.
+    #[inline]
+    pub fn from_slugs(slugs: f64) -> Self {
+        Self(slugs * SLUG)
+    }
+
+    /// Returns the value of the mass in slugs.
+    #[inline]
+    pub fn to_slugs(&self) -> f64 {
+        self.0 / SLUG
+    }
+}
+
+impl Energy {
+    /// Returns an energy of the passed value in foot pound-force.
+    #[inline]
+    pub fn from_foot_pounds(foot_pounds: f64) -> Self {
+        Self(foot_pounds * FOOT_POUND)
+    }
+
+    /// Returns the value of the energy in foot pound-force.
+    #[inline]
+    pub fn to_foot_pounds(&self) -> f64 {
+        self.0 / FOOT_POUND
+    }
+}
+
+impl AngularVelocity {
+    /// Returns an angular velocity of the passed value in revolutions per minute.
+    #[inline]
+    pub fn from_rpm(rpm: f64) -> Self {
+        Self(rpm * RPM)
+    }
+
+    /// Returns the value of the angular velocity in revolutions per minute.
+    #[inline]
+    pub fn to_rpm(&self) -> f64 {
+        self.0 / RPM
+    }
+}
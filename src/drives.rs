@@ -0,0 +1,347 @@
+//! # Drives
+//!
+//! Transmission of rotation and torque through gear meshes and belt drives.
+//!
+//! Each stage of a drive turns its output at a fixed multiple of its input speed, the
+//! speed ratio e = ω<sub>out</sub> / ω<sub>in</sub>, which is negative when the stage
+//! reverses the direction of rotation like an external gear mesh. The stages of a compound
+//! train are mounted on shared shafts, so their speed ratios multiply. Power is conserved
+//! up to the efficiency η of each stage, so the output torque is
+//! T<sub>out</sub> = ηT<sub>in</sub> / e.
+//!
+//! A shaft turning at ω while carrying a torque T transmits the power P = Tω. A belt
+//! transmits it through the difference of the tensions on its tight and slack sides,
+//! P = (T<sub>1</sub> - T<sub>2</sub>)v, while friction on the pulleys limits their ratio.
+
+use crate::constants::PI;
+
+/// One stage of a drive: a gear mesh or a belt running over two pulleys.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DriveStage {
+    /// Output speed per unit input speed, negative if the stage reverses the rotation.
+    pub speed_ratio: f64,
+    /// Fraction of the input power delivered at the output.
+    pub efficiency: f64,
+}
+
+impl Default for DriveStage {
+    fn default() -> Self {
+        Self::new(1.0, 1.0)
+    }
+}
+
+impl DriveStage {
+    /// Returns the stage with the passed speed ratio and efficiency.
+    #[inline]
+    pub fn new(speed_ratio: f64, efficiency: f64) -> Self {
+        Self {
+            speed_ratio,
+            efficiency,
+        }
+    }
+
+    /// Returns a lossless external gear mesh where the gear with `driver_teeth` teeth
+    /// drives the one with `driven_teeth`, which turns the other way.
+    #[inline]
+    pub fn gears(driver_teeth: u32, driven_teeth: u32) -> Self {
+        Self::new(-f64::from(driver_teeth) / f64::from(driven_teeth), 1.0)
+    }
+
+    /// Returns a lossless internal gear mesh, a pinion with `pinion_teeth` teeth driving a
+    /// ring gear with `ring_teeth` teeth the same way round.
+    #[inline]
+    pub fn internal_gears(pinion_teeth: u32, ring_teeth: u32) -> Self {
+        Self::new(f64::from(pinion_teeth) / f64::from(ring_teeth), 1.0)
+    }
+
+    /// Returns a lossless open belt drive from a pulley of `driver_diameter` to one of
+    /// `driven_diameter`, both turning the same way.
+    #[inline]
+    pub fn pulleys(driver_diameter: f64, driven_diameter: f64) -> Self {
+        Self::new(driver_diameter / driven_diameter, 1.0)
+    }
+
+    /// Returns this stage with the passed efficiency instead.
+    #[inline]
+    pub fn with_efficiency(self, efficiency: f64) -> Self {
+        Self { efficiency, ..self }
+    }
+
+    /// Returns the output torque per unit input torque, η / e.
+    #[inline]
+    pub fn torque_ratio(&self) -> f64 {
+        self.efficiency / self.speed_ratio
+    }
+}
+
+/// A compound train of gear and belt stages, each driving the next through a shared shaft.
+///
+/// # Example
+/// ```rust
+/// use i_mth::drives::GearTrain;
+///
+/// // a 1450 rpm motor driving a 20:60 gear pair at 98% efficiency, then a 100 mm to
+/// // 250 mm belt drive at 95%
+/// let train = GearTrain::new()
+///     .with_gears(20, 60, 0.98)
+///     .with_pulleys(0.1, 0.25, 0.95);
+///
+/// let e = -(20.0 / 60.0) * (0.1 / 0.25);
+/// assert!((train.speed_ratio() - e).abs() < 1e-12);
+/// assert!((train.reduction() - 7.5).abs() < 1e-12);
+/// assert!((train.efficiency() - 0.98 * 0.95).abs() < 1e-12);
+///
+/// // the output turns the other way, slower and with more torque
+/// assert!((train.output_speed(1450.0) - 1450.0 * e).abs() < 1e-9);
+/// let torque = train.output_torque(10.0);
+/// assert!((torque - 10.0 * 0.98 * 0.95 / e).abs() < 1e-9);
+/// assert!((torque * train.output_speed(1.0) - 10.0 * train.efficiency()).abs() < 1e-9);
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct GearTrain {
+    /// The stages of the train from the input to the output.
+    pub stages: Vec<DriveStage>,
+}
+
+impl GearTrain {
+    /// Returns a train with no stages, which passes its input straight through.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns this train with the passed stage added at the output.
+    #[inline]
+    pub fn with_stage(mut self, stage: DriveStage) -> Self {
+        self.stages.push(stage);
+        self
+    }
+
+    /// Returns this train with an external gear mesh added at the output, see
+    /// [`DriveStage::gears`].
+    #[inline]
+    pub fn with_gears(self, driver_teeth: u32, driven_teeth: u32, efficiency: f64) -> Self {
+        self.with_stage(DriveStage::gears(driver_teeth, driven_teeth).with_efficiency(efficiency))
+    }
+
+    /// Returns this train with a belt drive added at the output, see
+    /// [`DriveStage::pulleys`].
+    #[inline]
+    pub fn with_pulleys(self, driver_diameter: f64, driven_diameter: f64, efficiency: f64) -> Self {
+        self.with_stage(
+            DriveStage::pulleys(driver_diameter, driven_diameter).with_efficiency(efficiency),
+        )
+    }
+
+    /// Returns the overall speed ratio ω<sub>out</sub> / ω<sub>in</sub>, the product of the
+    /// speed ratios of the stages.
+    #[inline]
+    pub fn speed_ratio(&self) -> f64 {
+        self.stages.iter().map(|s| s.speed_ratio).product()
+    }
+
+    /// Returns the speed reduction |ω<sub>in</sub> / ω<sub>out</sub>| of the train.
+    #[inline]
+    pub fn reduction(&self) -> f64 {
+        1.0 / self.speed_ratio().abs()
+    }
+
+    /// Returns the overall efficiency, the product of the efficiencies of the stages.
+    #[inline]
+    pub fn efficiency(&self) -> f64 {
+        self.stages.iter().map(|s| s.efficiency).product()
+    }
+
+    /// Returns the overall output torque per unit input torque.
+    #[inline]
+    pub fn torque_ratio(&self) -> f64 {
+        self.efficiency() / self.speed_ratio()
+    }
+
+    /// Returns true if the output turns the opposite way to the input.
+    #[inline]
+    pub fn reverses(&self) -> bool {
+        self.speed_ratio() < 0.0
+    }
+
+    /// Returns the speed of the output shaft when the input turns at `input_speed`, in the
+    /// same units.
+    #[inline]
+    pub fn output_speed(&self, input_speed: f64) -> f64 {
+        input_speed * self.speed_ratio()
+    }
+
+    /// Returns the torque on the output shaft when `input_torque` drives the input, in the
+    /// same units.
+    #[inline]
+    pub fn output_torque(&self, input_torque: f64) -> f64 {
+        input_torque * self.torque_ratio()
+    }
+
+    /// Returns the power delivered at the output for `input_power` at the input.
+    #[inline]
+    pub fn output_power(&self, input_power: f64) -> f64 {
+        input_power * self.efficiency()
+    }
+}
+
+/// Returns the power in W transmitted by a shaft carrying `torque` N.m while turning at
+/// `speed` rad/s, P = Tω. See [`Moment`](crate::units::Moment) and
+/// [`AngularVelocity`](crate::units::AngularVelocity) for the same relation on quantities.
+///
+/// # Example
+/// ```rust
+/// use i_mth::drives::shaft_power;
+/// use i_mth::units::{AngularVelocity, Moment, Power};
+///
+/// // 98.8 N.m at 1450 rpm
+/// let speed = AngularVelocity::from_rpm(1450.0);
+/// let power: Power = Moment::new(98.8) * speed;
+///
+/// assert!((power.value() - 15e3).abs() < 5.0);
+/// assert_eq!(power.value(), shaft_power(98.8, speed.value()));
+/// ```
+#[inline]
+pub fn shaft_power(torque: f64, speed: f64) -> f64 {
+    torque * speed
+}
+
+/// Returns the torque in N.m on a shaft transmitting `power` W while turning at `speed`
+/// rad/s, T = P / ω.
+#[inline]
+pub fn shaft_torque(power: f64, speed: f64) -> f64 {
+    power / speed
+}
+
+/// Returns the speed in rad/s a shaft carrying `torque` N.m must turn at to transmit
+/// `power` W, ω = P / T.
+#[inline]
+pub fn shaft_speed(power: f64, torque: f64) -> f64 {
+    power / torque
+}
+
+/// Returns the largest ratio T<sub>1</sub> / T<sub>2</sub> = e<sup>μθ</sup> of the tensions
+/// on either side of a flat belt or rope wrapped `wrap_angle` rad around a pulley before it
+/// slips, with the coefficient of friction `friction_coefficient`.
+#[inline]
+pub fn belt_tension_ratio(friction_coefficient: f64, wrap_angle: f64) -> f64 {
+    (friction_coefficient * wrap_angle).exp()
+}
+
+/// An open flat belt running over two pulleys.
+///
+/// # Example
+/// ```rust
+/// use i_mth::conversions::rpm_to_rad_per_sec;
+/// use i_mth::drives::{belt_tension_ratio, BeltDrive};
+///
+/// // 5 kW through a 200 mm pulley at 1450 rpm to a 500 mm pulley 1 m away, with a
+/// // 0.2 kg/m belt and μ = 0.3
+/// let belt = BeltDrive::new(0.2, 0.5, 1.0, 0.3).with_mass_per_length(0.2);
+/// let speed = rpm_to_rad_per_sec(1450.0);
+/// let (tight, slack) = belt.tensions(5e3, speed).unwrap();
+///
+/// let v = belt.belt_speed(speed);
+/// let tc = 0.2 * v * v;
+/// let theta = belt.wrap_angle().unwrap();
+/// assert!(((tight - slack) * v - 5e3).abs() < 1e-9);
+/// assert!(((tight - tc) / (slack - tc) - belt_tension_ratio(0.3, theta)).abs() < 1e-12);
+///
+/// // the drive is at its limit when the tight side carries that tension
+/// assert!((belt.max_power(tight, speed) - 5e3).abs() < 1e-9);
+/// assert!((belt.stage().speed_ratio - 0.4).abs() < 1e-12);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct BeltDrive {
+    /// Diameter of the driving pulley in m.
+    pub driver_diameter: f64,
+    /// Diameter of the driven pulley in m.
+    pub driven_diameter: f64,
+    /// Distance between the centres of the pulleys in m.
+    pub centre_distance: f64,
+    /// Coefficient of friction between the belt and the pulleys.
+    pub friction_coefficient: f64,
+    /// Mass of the belt per unit length in kg m<sup>-1</sup>, for its centrifugal tension.
+    pub mass_per_length: f64,
+}
+
+impl BeltDrive {
+    /// Returns the drive with the passed pulley diameters, centre distance and coefficient of
+    /// friction, with a belt of negligible mass.
+    #[inline]
+    pub fn new(
+        driver_diameter: f64,
+        driven_diameter: f64,
+        centre_distance: f64,
+        friction_coefficient: f64,
+    ) -> Self {
+        Self {
+            driver_diameter,
+            driven_diameter,
+            centre_distance,
+            friction_coefficient,
+            mass_per_length: 0.0,
+        }
+    }
+
+    /// Returns this drive with a belt of the passed mass per unit length instead.
+    #[inline]
+    pub fn with_mass_per_length(self, mass_per_length: f64) -> Self {
+        Self {
+            mass_per_length,
+            ..self
+        }
+    }
+
+    /// Returns the drive as a lossless stage of a [`GearTrain`].
+    #[inline]
+    pub fn stage(&self) -> DriveStage {
+        DriveStage::pulleys(self.driver_diameter, self.driven_diameter)
+    }
+
+    /// Returns the angle of contact in rad on the smaller pulley, where the belt slips first,
+    /// π - 2 asin((D - d) / 2C), or None if the pulleys overlap.
+    #[inline]
+    pub fn wrap_angle(&self) -> Option<f64> {
+        let s = (self.driven_diameter - self.driver_diameter).abs() / (2.0 * self.centre_distance);
+        (s < 1.0).then(|| PI - 2.0 * s.asin())
+    }
+
+    /// Returns the speed of the belt in ms<sup>-1</sup> when the driving pulley turns at
+    /// `driver_speed` rad/s.
+    #[inline]
+    pub fn belt_speed(&self, driver_speed: f64) -> f64 {
+        0.5 * driver_speed.abs() * self.driver_diameter
+    }
+
+    /// Returns the tensions in N on the tight and slack sides of the belt when it transmits
+    /// `power` W with the driving pulley at `driver_speed` rad/s, on the point of slipping
+    /// so the belt is no tighter than it needs to be. Returns None if the belt does not
+    /// move or can not grip.
+    #[inline]
+    pub fn tensions(&self, power: f64, driver_speed: f64) -> Option<(f64, f64)> {
+        let v = self.belt_speed(driver_speed);
+        let ratio = belt_tension_ratio(self.friction_coefficient, self.wrap_angle()?);
+        if v == 0.0 || ratio <= 1.0 {
+            return None;
+        }
+        let centrifugal = self.mass_per_length * v * v;
+        let difference = power.abs() / v;
+        let slack = centrifugal + difference / (ratio - 1.0);
+        Some((slack + difference, slack))
+    }
+
+    /// Returns the largest power in W the drive transmits with the driving pulley at
+    /// `driver_speed` rad/s before the belt slips, when its tight side carries
+    /// `max_tension` N.
+    #[inline]
+    pub fn max_power(&self, max_tension: f64, driver_speed: f64) -> f64 {
+        let v = self.belt_speed(driver_speed);
+        let Some(angle) = self.wrap_angle() else {
+            return 0.0;
+        };
+        let ratio = belt_tension_ratio(self.friction_coefficient, angle);
+        let grip = (max_tension - self.mass_per_length * v * v).max(0.0);
+        grip * (1.0 - 1.0 / ratio) * v
+    }
+}
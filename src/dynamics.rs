@@ -0,0 +1,694 @@
+//! # Dynamics
+//!
+//! Newton's second law applied to bodies moving along straight paths, with Coulomb friction
+//! at the surfaces they slide on.
+//!
+//! The bodies of a [`CordSystem`] are tied together by ideal cords running over massless,
+//! frictionless pulleys. Each body i moves along its own path with coordinate x<sub>i</sub>,
+//! and each cord k keeps its length constant, Σ c<sub>ki</sub>x<sub>i</sub> = const. A cord
+//! with tension T<sub>k</sub> pulls body i with -c<sub>ki</sub>T<sub>k</sub> along its
+//! path, so the accelerations and tensions solve the linear system\
+//! m<sub>i</sub>a<sub>i</sub> + Σ c<sub>ki</sub>T<sub>k</sub> = F<sub>i</sub>\
+//! Σ c<sub>ki</sub>a<sub>i</sub> = 0\
+//! where F<sub>i</sub> is the sum of the weight, friction and applied force along the path.
+//!
+//! A single block on a rough slope is covered by [`InclinedPlane`], and a vehicle taking a
+//! banked curve by [`BankedCurve`].
+
+use crate::constants::{EARTH_GRAVITY, PI};
+use crate::error::ImthError;
+use crate::matrixn::{MatrixN, SolveDiagnostics};
+use crate::vector2d::Vector2D;
+
+/// Coulomb friction between two surfaces.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Friction {
+    /// Coefficient of static friction μ<sub>s</sub>.
+    pub static_coefficient: f64,
+    /// Coefficient of kinetic friction μ<sub>k</sub>.
+    pub kinetic_coefficient: f64,
+}
+
+impl Friction {
+    /// Returns the friction with the passed static and kinetic coefficients.
+    #[inline]
+    pub fn new(static_coefficient: f64, kinetic_coefficient: f64) -> Self {
+        Self {
+            static_coefficient,
+            kinetic_coefficient,
+        }
+    }
+
+    /// Returns frictionless contact.
+    #[inline]
+    pub fn frictionless() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if both coefficients are zero.
+    #[inline]
+    pub fn is_frictionless(&self) -> bool {
+        self.static_coefficient == 0.0 && self.kinetic_coefficient == 0.0
+    }
+
+    /// Returns the largest friction force μ<sub>s</sub>N the surfaces can hold without
+    /// sliding under the normal force `normal`.
+    #[inline]
+    pub fn max_static(&self, normal: f64) -> f64 {
+        self.static_coefficient * normal.abs()
+    }
+
+    /// Returns the friction force μ<sub>k</sub>N while the surfaces slide under the normal
+    /// force `normal`.
+    #[inline]
+    pub fn kinetic(&self, normal: f64) -> f64 {
+        self.kinetic_coefficient * normal.abs()
+    }
+}
+
+/// A body moving along a straight path in a [`CordSystem`].
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct CordBody {
+    /// Mass of the body in kg.
+    pub mass: f64,
+    /// Angle in rad of the positive direction of the path above the horizontal. A block on
+    /// a table has a grade of 0 and a hanging body measured downwards has a grade of -π/2.
+    pub grade: f64,
+    /// Friction between the body and the surface it slides on.
+    pub friction: Friction,
+    /// Applied force along the path in N, besides the weight, friction and cords.
+    pub force: f64,
+    /// Velocity along the path in ms<sup>-1</sup>, which sets the direction of kinetic
+    /// friction.
+    pub velocity: f64,
+}
+
+impl CordBody {
+    /// Returns a frictionless body of mass `mass` at rest on a path at `grade` rad above the
+    /// horizontal.
+    #[inline]
+    pub fn new(mass: f64, grade: f64) -> Self {
+        Self {
+            mass,
+            grade,
+            ..Self::default()
+        }
+    }
+
+    /// Returns a hanging body of mass `mass` at rest, with its path measured downwards.
+    #[inline]
+    pub fn hanging(mass: f64) -> Self {
+        Self::new(mass, -PI / 2.0)
+    }
+
+    /// Returns this body sliding with the passed friction.
+    #[inline]
+    pub fn with_friction(self, friction: Friction) -> Self {
+        Self { friction, ..self }
+    }
+
+    /// Returns this body with the passed applied force along its path.
+    #[inline]
+    pub fn with_force(self, force: f64) -> Self {
+        Self { force, ..self }
+    }
+
+    /// Returns this body moving at the passed velocity along its path.
+    #[inline]
+    pub fn with_velocity(self, velocity: f64) -> Self {
+        Self { velocity, ..self }
+    }
+
+    /// Returns the component -mg sin θ of the weight of this body along its path under
+    /// gravity `g`.
+    #[inline]
+    pub fn weight_component(&self, g: f64) -> f64 {
+        -self.mass * g * self.grade.sin()
+    }
+
+    /// Returns the normal force mg cos θ of the surface on this body under gravity `g`.
+    #[inline]
+    pub fn normal_force(&self, g: f64) -> f64 {
+        self.mass * g * self.grade.cos()
+    }
+}
+
+/// An ideal cord of a [`CordSystem`], given by the bodies it links and the rate its length
+/// changes with each of their coordinates.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Cord {
+    /// `(body, c)` pairs, the length of the cord changing by c per unit of the coordinate of
+    /// the body.
+    pub links: Vec<(usize, f64)>,
+}
+
+impl Cord {
+    /// Returns the cord with the passed `(body, c)` links.
+    #[inline]
+    pub fn new(links: &[(usize, f64)]) -> Self {
+        Self {
+            links: links.to_vec(),
+        }
+    }
+}
+
+/// The accelerations of the bodies of a [`CordSystem`] and the tensions in its cords.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct CordSolution {
+    /// Acceleration of each body along its path in ms<sup>-2</sup>.
+    pub accelerations: Vec<f64>,
+    /// Tension of each cord in N. A negative tension means the cord would go slack.
+    pub tensions: Vec<f64>,
+    /// Whether the bodies slide, or static friction holds them at rest.
+    pub slipping: bool,
+}
+
+/// Bodies connected by ideal cords over pulleys, like an Atwood machine or blocks on
+/// inclines pulled by hanging weights.
+///
+/// # Example
+/// ```rust
+/// use i_mth::dynamics::{CordBody, CordSystem};
+///
+/// // an Atwood machine, both paths measured downwards so the cord gives x1 + x2 = const
+/// let (m1, m2, g) = (3.0, 5.0, 9.81);
+/// let atwood = CordSystem::new()
+///     .with_body(CordBody::hanging(m1))
+///     .with_body(CordBody::hanging(m2))
+///     .with_cord(&[(0, 1.0), (1, 1.0)])
+///     .with_gravity(g);
+/// let solution = atwood.solve().unwrap();
+///
+/// let a = (m2 - m1) / (m1 + m2) * g;
+/// assert!((solution.accelerations[1] - a).abs() < 1e-12);
+/// assert!((solution.accelerations[0] + a).abs() < 1e-12);
+/// assert!((solution.tensions[0] - 2.0 * m1 * m2 * g / (m1 + m2)).abs() < 1e-12);
+///
+/// // a movable pulley carrying 4 kg, with the cord over it pulled down by 3 kg, so
+/// // 2 x1 + x2 = const
+/// let hoist = CordSystem::new()
+///     .with_body(CordBody::hanging(4.0))
+///     .with_body(CordBody::hanging(3.0))
+///     .with_cord(&[(0, 2.0), (1, 1.0)])
+///     .with_gravity(g)
+///     .solve()
+///     .unwrap();
+/// assert!((hoist.accelerations[1] - g / 4.0).abs() < 1e-12);
+/// assert!((hoist.accelerations[0] + 0.5 * hoist.accelerations[1]).abs() < 1e-12);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct CordSystem {
+    /// The bodies of the system.
+    pub bodies: Vec<CordBody>,
+    /// The cords linking the bodies.
+    pub cords: Vec<Cord>,
+    /// Magnitude of the acceleration due to gravity in ms<sup>-2</sup>.
+    pub gravity: f64,
+}
+
+impl Default for CordSystem {
+    fn default() -> Self {
+        Self {
+            bodies: Vec::new(),
+            cords: Vec::new(),
+            gravity: EARTH_GRAVITY.abs(),
+        }
+    }
+}
+
+impl CordSystem {
+    /// Returns an empty system under standard gravity.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns this system with the passed body added.
+    #[inline]
+    pub fn with_body(mut self, body: CordBody) -> Self {
+        self.bodies.push(body);
+        self
+    }
+
+    /// Returns this system with a cord added linking the passed `(body, c)` pairs.
+    #[inline]
+    pub fn with_cord(mut self, links: &[(usize, f64)]) -> Self {
+        self.cords.push(Cord::new(links));
+        self
+    }
+
+    /// Returns this system under gravity of magnitude `gravity` instead.
+    #[inline]
+    pub fn with_gravity(self, gravity: f64) -> Self {
+        Self { gravity, ..self }
+    }
+
+    /// Returns the number of degrees of freedom of the system, the bodies less the cords.
+    #[inline]
+    pub fn degrees_of_freedom(&self) -> usize {
+        self.bodies.len().saturating_sub(self.cords.len())
+    }
+
+    /// Returns the accelerations of the bodies and the tensions in the cords.
+    ///
+    /// Kinetic friction opposes the velocity of a moving body, or for a body at rest the
+    /// way it would accelerate without friction. When every body is at rest the system
+    /// first checks whether static friction can hold it, which needs a single degree of
+    /// freedom. A system held at rest has every surface taking the same share of its static
+    /// friction limit.
+    ///
+    /// Fails if a cord links a body that does not exist, the equations have no unique
+    /// solution, or a system at rest with friction has more than one degree of freedom.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::dynamics::{CordBody, CordSystem, Friction};
+    ///
+    /// // a 4 kg block on a table pulled over the edge by a hanging 2 kg mass
+    /// let g = 9.81;
+    /// let system = |friction| {
+    ///     CordSystem::new()
+    ///         .with_body(CordBody::new(4.0, 0.0).with_friction(friction))
+    ///         .with_body(CordBody::hanging(2.0))
+    ///         .with_cord(&[(0, -1.0), (1, 1.0)])
+    ///         .with_gravity(g)
+    /// };
+    ///
+    /// let sliding = system(Friction::new(0.3, 0.2)).solve().unwrap();
+    /// assert!(sliding.slipping);
+    /// assert!((sliding.accelerations[0] - 0.2 * g).abs() < 1e-12);
+    /// assert!((sliding.tensions[0] - 2.0 * (g - 0.2 * g)).abs() < 1e-12);
+    ///
+    /// // rough enough to hold the weight, which then hangs from the cord
+    /// let held = system(Friction::new(0.6, 0.5)).solve().unwrap();
+    /// assert!(!held.slipping);
+    /// assert!(held.accelerations.iter().all(|a| a.abs() < 1e-12));
+    /// assert!((held.tensions[0] - 2.0 * g).abs() < 1e-12);
+    /// ```
+    pub fn solve(&self) -> Result<CordSolution, ImthError> {
+        Ok(self.solve_with(false)?.0)
+    }
+
+    /// Returns the solution of [`CordSystem::solve`] together with diagnostics of the solve
+    /// of the equations of motion. Estimating the condition number costs another pass over
+    /// the factorized equations, so [`CordSystem::solve`] leaves it out.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::dynamics::{CordBody, CordSystem};
+    ///
+    /// let atwood = CordSystem::new()
+    ///     .with_body(CordBody::hanging(3.0))
+    ///     .with_body(CordBody::hanging(1.0))
+    ///     .with_cord(&[(0, 1.0), (1, 1.0)]);
+    /// let (solution, diagnostics) = atwood.solve_with_diagnostics().unwrap();
+    ///
+    /// assert_eq!(atwood.solve().unwrap(), solution);
+    /// assert!(!diagnostics.is_ill_conditioned(1e6));
+    /// ```
+    pub fn solve_with_diagnostics(&self) -> Result<(CordSolution, SolveDiagnostics), ImthError> {
+        self.solve_with(true)
+    }
+
+    /// Returns the solution, with the diagnostics of its last solve of the equations of
+    /// motion if `diagnose` is set or default ones otherwise.
+    fn solve_with(&self, diagnose: bool) -> Result<(CordSolution, SolveDiagnostics), ImthError> {
+        let n = self.bodies.len();
+        if self
+            .cords
+            .iter()
+            .any(|c| c.links.iter().any(|&(body, _)| body >= n))
+        {
+            return Err(ImthError::InvalidArgument("cord links an unknown body"));
+        }
+
+        let g = self.gravity;
+        let driving: Vec<f64> = self
+            .bodies
+            .iter()
+            .map(|b| b.force + b.weight_component(g))
+            .collect();
+        let (free, diagnostics) = self.equations(&driving, diagnose)?;
+        let rough = self
+            .bodies
+            .iter()
+            .any(|b| !b.friction.is_frictionless() && b.normal_force(g) > 0.0);
+        if !rough {
+            return Ok((free, diagnostics));
+        }
+
+        let at_rest = self.bodies.iter().all(|b| b.velocity == 0.0);
+        if at_rest {
+            if self.degrees_of_freedom() != 1 {
+                return Err(ImthError::InvalidArgument(
+                    "static friction needs a system with one degree of freedom",
+                ));
+            }
+            // the frictionless accelerations follow the only mode the system can move in,
+            // so weighting the forces by them gives the generalized force along it
+            let a = &free.accelerations;
+            let pull: f64 = a.iter().zip(&driving).map(|(a, f)| a * f).sum();
+            let hold: f64 = self
+                .bodies
+                .iter()
+                .zip(a)
+                .map(|(b, a)| a.abs() * b.friction.max_static(b.normal_force(g)))
+                .sum();
+            if pull <= hold {
+                let share = if hold > 0.0 { pull / hold } else { 0.0 };
+                let forces: Vec<f64> = self
+                    .bodies
+                    .iter()
+                    .zip(a)
+                    .zip(&driving)
+                    .map(|((b, a), f)| {
+                        f - share * a.signum() * b.friction.max_static(b.normal_force(g))
+                    })
+                    .collect();
+                let (mut held, diagnostics) = self.equations(&forces, diagnose)?;
+                held.accelerations.iter_mut().for_each(|a| *a = 0.0);
+                held.slipping = false;
+                return Ok((held, diagnostics));
+            }
+        }
+
+        let forces: Vec<f64> = self
+            .bodies
+            .iter()
+            .zip(&free.accelerations)
+            .zip(&driving)
+            .map(|((b, a), f)| {
+                let direction = if b.velocity != 0.0 { b.velocity } else { *a };
+                f - direction.signum() * b.friction.kinetic(b.normal_force(g))
+            })
+            .collect();
+        self.equations(&forces, diagnose)
+    }
+
+    /// Returns the solution of the equations of motion with the passed total force along
+    /// the path of each body, and the diagnostics of the solve if `diagnose` is set.
+    fn equations(
+        &self,
+        forces: &[f64],
+        diagnose: bool,
+    ) -> Result<(CordSolution, SolveDiagnostics), ImthError> {
+        let n = self.bodies.len();
+        let size = n + self.cords.len();
+        let mut a = MatrixN::zeros(size, size);
+        for (i, body) in self.bodies.iter().enumerate() {
+            a[(i, i)] = body.mass;
+        }
+        for (k, cord) in self.cords.iter().enumerate() {
+            for &(body, c) in &cord.links {
+                a[(body, n + k)] += c;
+                a[(n + k, body)] += c;
+            }
+        }
+        let mut b = forces.to_vec();
+        b.resize(size, 0.0);
+
+        let solved = if diagnose {
+            a.solve_with_diagnostics(&b)
+        } else {
+            a.solve(&b).map(|x| (x, SolveDiagnostics::default()))
+        };
+        let (x, diagnostics) = solved.ok_or(ImthError::InvalidArgument(
+            "cord system has no unique solution",
+        ))?;
+        let (accelerations, tensions) = x.split_at(n);
+        let solution = CordSolution {
+            accelerations: accelerations.to_vec(),
+            tensions: tensions.to_vec(),
+            slipping: true,
+        };
+        Ok((solution, diagnostics))
+    }
+}
+
+/// A block on a rough plane inclined at `angle` to the horizontal.
+///
+/// Forces are given in the vertical plane of the slope, with x horizontal towards the top
+/// of the slope and y up. Positive values along the slope point up it.
+///
+/// # Example
+/// ```rust
+/// use i_mth::dynamics::{Friction, InclinedPlane};
+/// use i_mth::vector2d::Vector2D;
+///
+/// // a 10 kg crate on a 30° ramp too slippery to hold it
+/// let (m, g, theta) = (10.0, 9.81, 30f64.to_radians());
+/// let ramp = InclinedPlane::new(theta, m, Friction::new(0.4, 0.3)).with_gravity(g);
+///
+/// assert!(ramp.moves());
+/// let a = -g * (theta.sin() - 0.3 * theta.cos());
+/// assert!((ramp.acceleration(0.0) - a).abs() < 1e-12);
+/// assert!((ramp.force_to_start_up() - m * g * (theta.sin() + 0.4 * theta.cos())).abs() < 1e-12);
+/// assert_eq!(0.0, ramp.force_to_maintain_down());
+///
+/// // pushing it horizontally with 100 N presses it into the ramp and holds it
+/// let pushed = ramp.with_force(Vector2D::new(100.0, 0.0));
+/// assert!((pushed.normal_force() - (m * g * theta.cos() + 100.0 * theta.sin())).abs() < 1e-12);
+/// assert!(!pushed.moves());
+/// assert_eq!(0.0, pushed.acceleration(0.0));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct InclinedPlane {
+    /// Angle of the slope above the horizontal in rad.
+    pub angle: f64,
+    /// Mass of the block in kg.
+    pub mass: f64,
+    /// Friction between the block and the slope.
+    pub friction: Friction,
+    /// Applied force on the block in N, besides its weight and the reaction of the slope.
+    pub force: Vector2D,
+    /// Magnitude of the acceleration due to gravity in ms<sup>-2</sup>.
+    pub gravity: f64,
+}
+
+impl InclinedPlane {
+    /// Returns a block of mass `mass` on a slope at `angle` rad with the passed friction,
+    /// under standard gravity and no applied force.
+    #[inline]
+    pub fn new(angle: f64, mass: f64, friction: Friction) -> Self {
+        Self {
+            angle,
+            mass,
+            friction,
+            force: Vector2D::origin(),
+            gravity: EARTH_GRAVITY.abs(),
+        }
+    }
+
+    /// Returns this block with the passed applied force instead.
+    #[inline]
+    pub fn with_force(self, force: Vector2D) -> Self {
+        Self { force, ..self }
+    }
+
+    /// Returns this block under gravity of magnitude `gravity` instead.
+    #[inline]
+    pub fn with_gravity(self, gravity: f64) -> Self {
+        Self { gravity, ..self }
+    }
+
+    /// Returns the unit vector up the slope.
+    #[inline]
+    pub fn up_slope(&self) -> Vector2D {
+        Vector2D::new(self.angle.cos(), self.angle.sin())
+    }
+
+    /// Returns the unit normal out of the slope.
+    #[inline]
+    pub fn normal(&self) -> Vector2D {
+        Vector2D::new(-self.angle.sin(), self.angle.cos())
+    }
+
+    /// Returns the net force up the slope from the weight and the applied force, before
+    /// friction.
+    #[inline]
+    pub fn driving_force(&self) -> f64 {
+        -self.mass * self.gravity * self.angle.sin() + self.force.dot(self.up_slope())
+    }
+
+    /// Returns the normal force of the slope on the block, zero if the applied force lifts
+    /// the block off the slope.
+    #[inline]
+    pub fn normal_force(&self) -> f64 {
+        (self.mass * self.gravity * self.angle.cos() - self.force.dot(self.normal())).max(0.0)
+    }
+
+    /// Returns true if the block at rest starts to slide, when the driving force is larger
+    /// than static friction can hold.
+    #[inline]
+    pub fn moves(&self) -> bool {
+        self.driving_force().abs() > self.friction.max_static(self.normal_force())
+    }
+
+    /// Returns the acceleration of the block up the slope while it moves at `velocity` up
+    /// the slope, with kinetic friction opposing the motion. A block at rest stays there if
+    /// static friction holds it.
+    #[inline]
+    pub fn acceleration(&self, velocity: f64) -> f64 {
+        let driving = self.driving_force();
+        let direction = if velocity != 0.0 {
+            velocity.signum()
+        } else if self.moves() {
+            driving.signum()
+        } else {
+            return 0.0;
+        };
+        (driving - direction * self.friction.kinetic(self.normal_force())) / self.mass
+    }
+
+    /// Returns the extra force along the slope needed to start the block moving up it.
+    #[inline]
+    pub fn force_to_start_up(&self) -> f64 {
+        (self.friction.max_static(self.normal_force()) - self.driving_force()).max(0.0)
+    }
+
+    /// Returns the extra force along the slope needed to start the block moving down it.
+    #[inline]
+    pub fn force_to_start_down(&self) -> f64 {
+        (self.friction.max_static(self.normal_force()) + self.driving_force()).max(0.0)
+    }
+
+    /// Returns the extra force along the slope needed to keep the block moving up it at a
+    /// constant speed.
+    #[inline]
+    pub fn force_to_maintain_up(&self) -> f64 {
+        (self.friction.kinetic(self.normal_force()) - self.driving_force()).max(0.0)
+    }
+
+    /// Returns the extra force along the slope needed to keep the block moving down it at
+    /// a constant speed.
+    #[inline]
+    pub fn force_to_maintain_down(&self) -> f64 {
+        (self.friction.kinetic(self.normal_force()) + self.driving_force()).max(0.0)
+    }
+}
+
+/// A vehicle going round a curve of constant radius on a road banked at `bank_angle`.
+///
+/// Vectors are in the vertical plane through the center of the curve, with x horizontal
+/// towards the center and y up, so the road surface rises away from the center.
+///
+/// # Example
+/// ```rust
+/// use i_mth::dynamics::{BankedCurve, Friction};
+///
+/// // a 120 m curve banked at 10° with μs = 0.5
+/// let (r, g, theta) = (120.0, 9.81, 10f64.to_radians());
+/// let curve = BankedCurve::new(r, theta, Friction::new(0.5, 0.4)).with_gravity(g);
+///
+/// // at the design speed the road needs no friction at all
+/// let v = curve.design_speed();
+/// assert!((v - (r * g * theta.tan()).sqrt()).abs() < 1e-12);
+/// assert!(curve.required_friction(v).abs() < 1e-12);
+/// assert!(curve.friction_force(1200.0, v).magnitude() < 1e-9);
+///
+/// // at the maximum speed the friction needed reaches μs
+/// let v_max = curve.max_speed().unwrap();
+/// assert!((curve.required_friction(v_max) - 0.5).abs() < 1e-12);
+/// assert_eq!(0.0, curve.min_speed());
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BankedCurve {
+    /// Radius of the curve in m.
+    pub radius: f64,
+    /// Angle of the road surface above the horizontal in rad.
+    pub bank_angle: f64,
+    /// Friction between the tyres and the road, sideways.
+    pub friction: Friction,
+    /// Magnitude of the acceleration due to gravity in ms<sup>-2</sup>.
+    pub gravity: f64,
+}
+
+impl BankedCurve {
+    /// Returns the curve of the passed radius, bank angle and friction under standard
+    /// gravity.
+    #[inline]
+    pub fn new(radius: f64, bank_angle: f64, friction: Friction) -> Self {
+        Self {
+            radius,
+            bank_angle,
+            friction,
+            gravity: EARTH_GRAVITY.abs(),
+        }
+    }
+
+    /// Returns this curve under gravity of magnitude `gravity` instead.
+    #[inline]
+    pub fn with_gravity(self, gravity: f64) -> Self {
+        Self { gravity, ..self }
+    }
+
+    /// Returns the speed √(rg tan θ) at which the curve can be taken with no friction.
+    #[inline]
+    pub fn design_speed(&self) -> f64 {
+        (self.radius * self.gravity * self.bank_angle.tan()).sqrt()
+    }
+
+    /// Returns the unit normal out of the road surface.
+    #[inline]
+    pub fn normal(&self) -> Vector2D {
+        Vector2D::new(self.bank_angle.sin(), self.bank_angle.cos())
+    }
+
+    /// Returns the unit vector down the road surface, towards the center of the curve.
+    #[inline]
+    pub fn down_bank(&self) -> Vector2D {
+        Vector2D::new(self.bank_angle.cos(), -self.bank_angle.sin())
+    }
+
+    /// Returns the total force the road exerts on a vehicle of mass `mass` at `speed`,
+    /// giving it the centripetal acceleration v<sup>2</sup>/r while holding up its weight.
+    #[inline]
+    pub fn road_force(&self, mass: f64, speed: f64) -> Vector2D {
+        Vector2D::new(mass * speed * speed / self.radius, mass * self.gravity)
+    }
+
+    /// Returns the normal force of the road on a vehicle of mass `mass` at `speed`.
+    #[inline]
+    pub fn normal_force(&self, mass: f64, speed: f64) -> Vector2D {
+        let normal = self.normal();
+        normal.scale(self.road_force(mass, speed).dot(normal))
+    }
+
+    /// Returns the sideways friction force the road must provide on a vehicle of mass
+    /// `mass` at `speed`, down the bank above the design speed and up it below.
+    #[inline]
+    pub fn friction_force(&self, mass: f64, speed: f64) -> Vector2D {
+        let down = self.down_bank();
+        down.scale(self.road_force(mass, speed).dot(down))
+    }
+
+    /// Returns the coefficient of friction needed to take the curve at `speed`, the ratio of
+    /// the friction force to the normal force.
+    #[inline]
+    pub fn required_friction(&self, speed: f64) -> f64 {
+        let force = self.road_force(1.0, speed);
+        force.dot(self.down_bank()).abs() / force.dot(self.normal())
+    }
+
+    /// Returns the highest speed before the vehicle slides up the bank, or None if static
+    /// friction can hold it at any speed.
+    #[inline]
+    pub fn max_speed(&self) -> Option<f64> {
+        let (sin, cos) = self.bank_angle.sin_cos();
+        let mu = self.friction.static_coefficient;
+        let denominator = cos - mu * sin;
+        (denominator > 0.0)
+            .then(|| (self.radius * self.gravity * (sin + mu * cos) / denominator).sqrt())
+    }
+
+    /// Returns the lowest speed before the vehicle slides down the bank, zero if it can
+    /// stand still on the curve.
+    #[inline]
+    pub fn min_speed(&self) -> f64 {
+        let (sin, cos) = self.bank_angle.sin_cos();
+        let mu = self.friction.static_coefficient;
+        (self.radius * self.gravity * (sin - mu * cos) / (cos + mu * sin))
+            .max(0.0)
+            .sqrt()
+    }
+}
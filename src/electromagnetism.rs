@@ -0,0 +1,122 @@
+//! # Electromagnetism
+//!
+//! Forces and fields of point charges in a vacuum, built on
+//! [`VACUUM_PERMITTIVITY`](crate::constants::VACUUM_PERMITTIVITY) and
+//! [`VACUUM_PERMEABILITY`](crate::constants::VACUUM_PERMEABILITY).
+//! Fields obey superposition, so the field of several sources is the sum of their fields.
+
+use crate::constants::{PI, VACUUM_PERMEABILITY, VACUUM_PERMITTIVITY};
+use crate::error::ImthError;
+use crate::vector3d::Vector3D;
+
+/// **Coulomb's constant** 1 / (4πε<sub>0</sub>)\
+/// unit: Nm<sup>2</sup>C<sup>-2</sup>\
+/// value: 8.987_551_792e9
+pub const COULOMB_CONSTANT: f64 = 1.0 / (4.0 * PI * VACUUM_PERMITTIVITY);
+
+/// Returns the electrostatic force on the point charge `q1` at `r1` due to the point
+/// charge `q2` at `r2` (Coulomb's law). Like charges repel, so the force points away
+/// from `r2` when `q1` and `q2` have the same sign. Returns an error if the charges are at
+/// the same position.\
+/// [More Info](https://en.wikipedia.org/wiki/Coulomb%27s_law)
+///
+/// # Example
+/// ```rust
+/// use i_mth::electromagnetism::coulomb_force;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // two 1 µC charges 10 cm apart
+/// let force = coulomb_force(1e-6, 1e-6, Vector3D::new(0.1, 0.0, 0.0), Vector3D::origin()).unwrap();
+///
+/// assert!((force.x - 0.898_755).abs() < 1e-6);
+/// assert!(coulomb_force(1e-6, 1e-6, Vector3D::origin(), Vector3D::origin()).is_err());
+/// ```
+#[inline]
+pub fn coulomb_force(q1: f64, q2: f64, r1: Vector3D, r2: Vector3D) -> Result<Vector3D, ImthError> {
+    Ok(point_charge_field(q2, r2, r1)?.scale(q1))
+}
+
+/// Returns the electric field at `point` due to the point charge `q` at `source`,
+/// pointing away from positive charges, or an error if `point` is at the charge, where
+/// the field is undefined.
+#[inline]
+pub fn point_charge_field(
+    q: f64,
+    source: Vector3D,
+    point: Vector3D,
+) -> Result<Vector3D, ImthError> {
+    let separation = point - source;
+    let distance = separation.magnitude();
+    if distance == 0.0 {
+        return Err(ImthError::InvalidArgument(
+            "point must not be at the position of the charge",
+        ));
+    }
+    Ok(separation.scale(COULOMB_CONSTANT * q / distance.powi(3)))
+}
+
+/// Returns the electric field at `point` due to all of the passed `(charge, position)`
+/// point charges, or an error if `point` is at one of the charges.
+///
+/// # Example
+/// ```rust
+/// use i_mth::electromagnetism::electric_field;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // the fields of a charge and its opposite cancel halfway along the line between them
+/// // in the direction across it
+/// let dipole = [(1e-9, Vector3D::new(-0.01, 0.0, 0.0)), (-1e-9, Vector3D::new(0.01, 0.0, 0.0))];
+/// let e = electric_field(&dipole, Vector3D::new(0.0, 0.05, 0.0)).unwrap();
+///
+/// assert!(e.y.abs() < 1e-9 && e.x > 0.0);
+/// assert!(electric_field(&dipole, dipole[0].1).is_err());
+/// ```
+#[inline]
+pub fn electric_field(charges: &[(f64, Vector3D)], point: Vector3D) -> Result<Vector3D, ImthError> {
+    let fields = charges
+        .iter()
+        .map(|(q, source)| point_charge_field(*q, *source, point))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(superpose(fields))
+}
+
+/// Returns the magnetic field at `point` due to the point charge `q` at `source` moving
+/// with velocity `v`, μ<sub>0</sub> q v × r̂ / (4π r<sup>2</sup>), valid for speeds well below
+/// the speed of light.\
+/// [More Info](https://en.wikipedia.org/wiki/Biot%E2%80%93Savart_law#Point_charge_at_constant_velocity)
+#[inline]
+pub fn moving_charge_field(q: f64, source: Vector3D, v: Vector3D, point: Vector3D) -> Vector3D {
+    let separation = point - source;
+    let distance = separation.magnitude();
+    v.cross(separation)
+        .scale(VACUUM_PERMEABILITY * q / (4.0 * PI * distance.powi(3)))
+}
+
+/// Returns the sum of the passed fields at a point.
+#[inline]
+pub fn superpose<I: IntoIterator<Item = Vector3D>>(fields: I) -> Vector3D {
+    fields
+        .into_iter()
+        .fold(Vector3D::origin(), |sum, field| sum + field)
+}
+
+/// Returns the Lorentz force q(**E** + **v** × **B**) on a charge `q` moving with
+/// velocity `v` through the electric field `e_field` and the magnetic field `b_field`.\
+/// [More Info](https://en.wikipedia.org/wiki/Lorentz_force)
+///
+/// # Example
+/// ```rust
+/// use i_mth::electromagnetism::lorentz_force;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // a velocity selector passes charges moving at |E| / |B| undeflected
+/// let e = Vector3D::new(0.0, 2e3, 0.0);
+/// let b = Vector3D::new(0.0, 0.0, 0.1);
+/// let v = Vector3D::new(2e4, 0.0, 0.0);
+///
+/// assert!(lorentz_force(1.6e-19, e, v, b).magnitude() < 1e-30);
+/// ```
+#[inline]
+pub fn lorentz_force(q: f64, e_field: Vector3D, v: Vector3D, b_field: Vector3D) -> Vector3D {
+    (e_field + v.cross(b_field)).scale(q)
+}
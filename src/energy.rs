@@ -0,0 +1,295 @@
+//! # Energy methods
+//!
+//! The principle of work and energy, T<sub>1</sub> + U<sub>1→2</sub> = T<sub>2</sub>,
+//! applied to systems of connected rigid bodies.
+//!
+//! Ideal cords, pulleys and gears tie the speed of every body in a system to one reference
+//! speed v, so each body moves at v<sub>i</sub> = k<sub>i</sub>v and turns at
+//! ω<sub>i</sub> = c<sub>i</sub>v. The kinetic energy of the whole system is then
+//! m<sub>eff</sub>v<sup>2</sup> / 2, with the effective mass\
+//! m<sub>eff</sub> = Σ (m<sub>i</sub>k<sub>i</sub><sup>2</sup> + I<sub>i</sub>c<sub>i</sub><sup>2</sup>)\
+//! which turns the energy equation into a single equation in v.
+//!
+//! A [`Flywheel`] stores energy in its rotation and gives it back as it slows down. Its
+//! moment of inertia can be taken about any axis of a body from the
+//! [`inertia`](crate::inertia) module.
+
+use crate::inertia::RigidBodyInertia;
+use crate::utils::{ke, rotational_ke};
+use crate::vector3d::Vector3D;
+
+/// A rigid body of a connected system with its speeds given as multiples of the reference
+/// speed of the system.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct LinkedBody {
+    /// Mass of the body in kg.
+    pub mass: f64,
+    /// Moment of inertia of the body about its center of mass in kg.m<sup>2</sup>.
+    pub inertia: f64,
+    /// Speed of the center of mass of the body per unit reference speed.
+    pub speed_ratio: f64,
+    /// Angular speed of the body in rad s<sup>-1</sup> per unit reference speed in
+    /// ms<sup>-1</sup>.
+    pub angular_ratio: f64,
+}
+
+impl LinkedBody {
+    /// Returns the body with the passed mass, moment of inertia and speed ratios.
+    #[inline]
+    pub fn new(mass: f64, inertia: f64, speed_ratio: f64, angular_ratio: f64) -> Self {
+        Self {
+            mass,
+            inertia,
+            speed_ratio,
+            angular_ratio,
+        }
+    }
+
+    /// Returns the kinetic energy of this body when the system moves at `speed`.
+    #[inline]
+    pub fn kinetic_energy(&self, speed: f64) -> f64 {
+        ke(
+            self.mass,
+            Vector3D::new(self.speed_ratio * speed, 0.0, 0.0),
+            self.inertia,
+            self.angular_ratio * speed,
+        )
+    }
+}
+
+/// A set of rigid bodies whose speeds are tied together by ideal constraints.
+///
+/// # Example
+/// ```rust
+/// use i_mth::energy::ConnectedSystem;
+///
+/// // a 10 kg block hangs from a cord wound on a 0.2 m drum with I = 0.4 kg.m² and falls
+/// // 1.5 m from rest
+/// let (m, r, i, h, g) = (10.0, 0.2, 0.4, 1.5, 9.81);
+/// let system = ConnectedSystem::new()
+///     .with_translating(m, 1.0)
+///     .with_rotating(i, 1.0 / r);
+///
+/// let v = system.speed_after(0.0, m * g * h).unwrap();
+///
+/// assert!((system.effective_mass() - (m + i / (r * r))).abs() < 1e-12);
+/// assert!((v - (2.0 * m * g * h / (m + i / (r * r))).sqrt()).abs() < 1e-12);
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ConnectedSystem {
+    /// The bodies of the system.
+    pub bodies: Vec<LinkedBody>,
+}
+
+impl ConnectedSystem {
+    /// Returns a system with no bodies.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns this system with the passed body added.
+    #[inline]
+    pub fn with_body(mut self, body: LinkedBody) -> Self {
+        self.bodies.push(body);
+        self
+    }
+
+    /// Returns this system with a body of mass `mass` that only translates, at
+    /// `speed_ratio` times the reference speed, like a block on a cord.
+    #[inline]
+    pub fn with_translating(self, mass: f64, speed_ratio: f64) -> Self {
+        self.with_body(LinkedBody::new(mass, 0.0, speed_ratio, 0.0))
+    }
+
+    /// Returns this system with a body of moment of inertia `inertia` that turns about a
+    /// fixed axis through its center of mass, at `angular_ratio` rad/s per unit reference
+    /// speed, like a pulley or gear. A cord running at the reference speed over a pulley of
+    /// radius r gives a ratio of 1 / r.
+    #[inline]
+    pub fn with_rotating(self, inertia: f64, angular_ratio: f64) -> Self {
+        self.with_body(LinkedBody::new(0.0, inertia, 0.0, angular_ratio))
+    }
+
+    /// Returns this system with a body that rolls without slipping with its center moving
+    /// at `speed_ratio` times the reference speed, so it turns at `speed_ratio / radius`.
+    #[inline]
+    pub fn with_rolling(self, mass: f64, inertia: f64, radius: f64, speed_ratio: f64) -> Self {
+        self.with_body(LinkedBody::new(
+            mass,
+            inertia,
+            speed_ratio,
+            speed_ratio / radius,
+        ))
+    }
+
+    /// Returns the effective mass of the system, twice its kinetic energy at unit
+    /// reference speed.
+    #[inline]
+    pub fn effective_mass(&self) -> f64 {
+        self.bodies
+            .iter()
+            .map(|b| b.mass * b.speed_ratio.powi(2) + b.inertia * b.angular_ratio.powi(2))
+            .sum()
+    }
+
+    /// Returns the total kinetic energy of the system moving at the reference speed `speed`.
+    #[inline]
+    pub fn kinetic_energy(&self, speed: f64) -> f64 {
+        self.bodies.iter().map(|b| b.kinetic_energy(speed)).sum()
+    }
+
+    /// Returns the reference speed of the system after the passed net work in J is done on
+    /// it, starting at `initial_speed`, or None if the system has no mass or the work would
+    /// take more energy out than it has, so it stops first.
+    #[inline]
+    pub fn speed_after(&self, initial_speed: f64, work: f64) -> Option<f64> {
+        let m = self.effective_mass();
+        let energy = self.kinetic_energy(initial_speed) + work;
+        (m > 0.0 && energy >= 0.0).then(|| (2.0 * energy / m).sqrt())
+    }
+
+    /// Returns the net work in J needed to take the system from `initial_speed` to
+    /// `final_speed`.
+    #[inline]
+    pub fn work_required(&self, initial_speed: f64, final_speed: f64) -> f64 {
+        self.kinetic_energy(final_speed) - self.kinetic_energy(initial_speed)
+    }
+}
+
+/// Returns the work in J done by a constant force `force` over the straight displacement
+/// `displacement`, **F** · **d**.
+#[inline]
+pub fn work_of_force(force: Vector3D, displacement: Vector3D) -> f64 {
+    force.dot(displacement)
+}
+
+/// Returns the work in J done by a constant couple `moment` turning a body through `angle`
+/// rad, Mθ.
+#[inline]
+pub fn work_of_couple(moment: f64, angle: f64) -> f64 {
+    moment * angle
+}
+
+/// Returns the work in J done by a linear spring of stiffness `k` on a body as the spring's
+/// extension changes from `s1` to `s2`, -k(s<sub>2</sub><sup>2</sup> - s<sub>1</sub><sup>2</sup>) / 2.
+#[inline]
+pub fn work_of_spring(k: f64, s1: f64, s2: f64) -> f64 {
+    -0.5 * k * (s2 * s2 - s1 * s1)
+}
+
+/// A flywheel storing energy in its rotation.
+///
+/// The speed of a flywheel smoothing an engine or press swings between ω<sub>max</sub> and
+/// ω<sub>min</sub> about the mean ω = (ω<sub>max</sub> + ω<sub>min</sub>) / 2. The energy
+/// it takes in or gives out over the swing is\
+/// ΔE = I(ω<sub>max</sub><sup>2</sup> - ω<sub>min</sub><sup>2</sup>) / 2 = IC<sub>s</sub>ω<sup>2</sup>\
+/// with the coefficient of fluctuation C<sub>s</sub> = (ω<sub>max</sub> - ω<sub>min</sub>) / ω.
+///
+/// # Example
+/// ```rust
+/// use i_mth::energy::Flywheel;
+///
+/// // a press takes 12 kJ per stroke from a flywheel turning at about 30 rad/s, and the
+/// // speed must stay within 4% of the mean
+/// let flywheel = Flywheel::for_fluctuation(12e3, 30.0, 0.04);
+/// assert!((flywheel.inertia - 12e3 / (0.04 * 900.0)).abs() < 1e-9);
+///
+/// let (w_max, w_min) = (30.0 * 1.02, 30.0 * 0.98);
+/// assert!((flywheel.energy_change(w_max, w_min) + 12e3).abs() < 1e-6);
+/// assert!((Flywheel::coefficient_of_fluctuation(w_max, w_min) - 0.04).abs() < 1e-12);
+///
+/// // built as a thin rim of radius 0.6 m, that is about 926 kg
+/// let rim = Flywheel::rim(flywheel.inertia / 0.36, 0.6);
+/// assert!((rim.inertia - flywheel.inertia).abs() < 1e-9);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Flywheel {
+    /// Moment of inertia about the axis of rotation in kg.m<sup>2</sup>.
+    pub inertia: f64,
+}
+
+impl Flywheel {
+    /// Returns the flywheel with the passed moment of inertia.
+    #[inline]
+    pub fn new(inertia: f64) -> Self {
+        Self { inertia }
+    }
+
+    /// Returns the flywheel made by the passed body turning about the axis through its
+    /// center of mass along `axis`, or None if `axis` has no length.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::energy::Flywheel;
+    /// use i_mth::inertia::RigidBodyInertia;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // a 100 kg disc of radius 0.4 m and 50 mm thick spinning about its own axis
+    /// let disc = RigidBodyInertia::solid_cylinder(100.0, 0.4, 0.05);
+    /// let flywheel = Flywheel::from_body(&disc, Vector3D::k()).unwrap();
+    ///
+    /// assert!((flywheel.inertia - Flywheel::solid_disc(100.0, 0.4).inertia).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn from_body(body: &RigidBodyInertia, axis: Vector3D) -> Option<Self> {
+        Some(Self::new(body.moment_about(axis)?))
+    }
+
+    /// Returns a uniform solid disc flywheel of mass `mass` and radius `radius`,
+    /// I = mr<sup>2</sup> / 2.
+    #[inline]
+    pub fn solid_disc(mass: f64, radius: f64) -> Self {
+        Self::new(0.5 * mass * radius * radius)
+    }
+
+    /// Returns a flywheel with all of its mass `mass` in a thin rim of radius `radius`,
+    /// I = mr<sup>2</sup>.
+    #[inline]
+    pub fn rim(mass: f64, radius: f64) -> Self {
+        Self::new(mass * radius * radius)
+    }
+
+    /// Returns a uniform annular flywheel of mass `mass` between the radii `inner_radius`
+    /// and `outer_radius`, I = m(r<sub>i</sub><sup>2</sup> + r<sub>o</sub><sup>2</sup>) / 2.
+    #[inline]
+    pub fn annulus(mass: f64, inner_radius: f64, outer_radius: f64) -> Self {
+        Self::new(0.5 * mass * (inner_radius.powi(2) + outer_radius.powi(2)))
+    }
+
+    /// Returns the flywheel whose speed swings by the coefficient of fluctuation
+    /// `coefficient` about `mean_speed` in rad/s while taking in or giving out `energy` J,
+    /// I = ΔE / (C<sub>s</sub>ω<sup>2</sup>).
+    #[inline]
+    pub fn for_fluctuation(energy: f64, mean_speed: f64, coefficient: f64) -> Self {
+        Self::new(energy.abs() / (coefficient * mean_speed * mean_speed))
+    }
+
+    /// Returns the coefficient of fluctuation (ω<sub>max</sub> - ω<sub>min</sub>) / ω of a
+    /// speed swinging between `max_speed` and `min_speed`.
+    #[inline]
+    pub fn coefficient_of_fluctuation(max_speed: f64, min_speed: f64) -> f64 {
+        2.0 * (max_speed - min_speed) / (max_speed + min_speed)
+    }
+
+    /// Returns the kinetic energy stored at `speed` rad/s.
+    #[inline]
+    pub fn kinetic_energy(&self, speed: f64) -> f64 {
+        rotational_ke(self.inertia, speed)
+    }
+
+    /// Returns the energy the flywheel takes in going from `initial_speed` to `final_speed`,
+    /// negative when it slows down and delivers energy.
+    #[inline]
+    pub fn energy_change(&self, initial_speed: f64, final_speed: f64) -> f64 {
+        self.kinetic_energy(final_speed) - self.kinetic_energy(initial_speed)
+    }
+
+    /// Returns the speed after the flywheel takes in `energy` J starting at `initial_speed`,
+    /// or None if it would have to deliver more energy than it stores.
+    #[inline]
+    pub fn speed_after(&self, initial_speed: f64, energy: f64) -> Option<f64> {
+        let stored = self.kinetic_energy(initial_speed) + energy;
+        (self.inertia > 0.0 && stored >= 0.0).then(|| (2.0 * stored / self.inertia).sqrt())
+    }
+}
@@ -0,0 +1,157 @@
+//! # Errors
+//!
+//! The error type returned by the fallible operations of the crate.
+
+use std::error::Error;
+use std::fmt;
+
+/// Describes why an operation of the crate failed.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ImthError {
+    /// An argument was outside of the range the operation accepts.
+    InvalidArgument(&'static str),
+    /// The operation needed to divide by a value that is zero, like a zero derivative
+    /// in Newton's method.
+    DivisionByZero,
+    /// An iterative method did not meet its tolerance within the allowed number of iterations.
+    MaxIterationsExceeded(usize),
+    /// An adaptive integrator had to shrink its step below the minimum allowed step size.
+    StepSizeUnderflow {
+        /// Time at which the integrator gave up.
+        t: f64,
+        /// The step size that was still too large for the requested tolerance.
+        step: f64,
+    },
+    /// A structural model, like a truss, is set up in a way that cannot be solved.
+    InvalidModel(ModelError),
+}
+
+impl fmt::Display for ImthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImthError::InvalidArgument(reason) => write!(f, "invalid argument: {reason}"),
+            ImthError::DivisionByZero => write!(f, "division by zero"),
+            ImthError::MaxIterationsExceeded(iterations) => {
+                write!(f, "no convergence after {iterations} iterations")
+            }
+            ImthError::StepSizeUnderflow { t, step } => {
+                write!(f, "step size {step} fell below the minimum at t = {t}")
+            }
+            ImthError::InvalidModel(error) => write!(f, "invalid model: {error}"),
+        }
+    }
+}
+
+impl Error for ImthError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ImthError::InvalidModel(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ModelError> for ImthError {
+    #[inline]
+    fn from(error: ModelError) -> Self {
+        ImthError::InvalidModel(error)
+    }
+}
+
+/// Describes what is wrong with a structural model, found by checking it before solving.
+/// Indices refer to the position of the item in the list it was added to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ModelError {
+    /// A member is connected to a joint that does not exist.
+    UnknownMemberJoint {
+        /// Index of the member.
+        member: usize,
+        /// Index of the missing joint.
+        joint: usize,
+    },
+    /// A support is placed at a joint that does not exist.
+    UnknownSupportJoint {
+        /// Index of the support.
+        support: usize,
+        /// Index of the missing joint.
+        joint: usize,
+    },
+    /// A load is applied to a joint that does not exist.
+    UnknownLoadJoint {
+        /// Index of the load.
+        load: usize,
+        /// Index of the missing joint.
+        joint: usize,
+    },
+    /// A member joins a joint to itself or to another joint at the same position.
+    ZeroLengthMember(usize),
+    /// Two members join the same pair of joints.
+    DuplicateMember(usize, usize),
+    /// A joint is not connected to any member.
+    DisconnectedJoint(usize),
+    /// A support is given a zero direction, like a roller with a zero normal.
+    DegenerateSupport(usize),
+    /// The support reactions are all parallel or all meet at a point (or a line in space),
+    /// so they cannot stop the whole structure from moving as a rigid body.
+    ImproperSupports,
+    /// There are more unknown member forces and reactions than equilibrium equations.
+    StaticallyIndeterminate {
+        /// Number of unknown forces.
+        unknowns: usize,
+        /// Number of equilibrium equations.
+        equations: usize,
+    },
+    /// There are fewer unknown member forces and reactions than equilibrium equations,
+    /// so the structure can move.
+    Mechanism {
+        /// Number of unknown forces.
+        unknowns: usize,
+        /// Number of equilibrium equations.
+        equations: usize,
+    },
+}
+
+impl fmt::Display for ModelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModelError::UnknownMemberJoint { member, joint } => {
+                write!(f, "member {member} refers to missing joint {joint}")
+            }
+            ModelError::UnknownSupportJoint { support, joint } => {
+                write!(f, "support {support} refers to missing joint {joint}")
+            }
+            ModelError::UnknownLoadJoint { load, joint } => {
+                write!(f, "load {load} refers to missing joint {joint}")
+            }
+            ModelError::ZeroLengthMember(member) => write!(f, "member {member} has zero length"),
+            ModelError::DuplicateMember(first, second) => {
+                write!(f, "members {first} and {second} join the same joints")
+            }
+            ModelError::DisconnectedJoint(joint) => {
+                write!(f, "joint {joint} is not connected to any member")
+            }
+            ModelError::DegenerateSupport(support) => {
+                write!(f, "support {support} has a zero direction")
+            }
+            ModelError::ImproperSupports => {
+                write!(f, "supports cannot prevent rigid body motion")
+            }
+            ModelError::StaticallyIndeterminate {
+                unknowns,
+                equations,
+            } => write!(
+                f,
+                "statically indeterminate with {unknowns} unknowns and {equations} equations"
+            ),
+            ModelError::Mechanism {
+                unknowns,
+                equations,
+            } => write!(
+                f,
+                "mechanism with {unknowns} unknowns and {equations} equations"
+            ),
+        }
+    }
+}
+
+impl Error for ModelError {}
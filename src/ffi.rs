@@ -0,0 +1,539 @@
+//! # FFI
+//!
+//! `extern "C"` functions over the vector and matrix types and the truss and beam solvers,
+//! for calling the crate from C, C++ or a MATLAB MEX wrapper. Enabled with the `ffi`
+//! feature. A C library is built with
+//! `cargo rustc --release --features ffi --crate-type cdylib` (or `staticlib`).
+//!
+//! [`Vector2D`], [`Vector3D`], [`Matrix3`], [`Member`] and [`Reaction`] are `#[repr(C)]`
+//! and are passed as plain structs of doubles and `size_t`s:
+//!
+//! ```c
+//! typedef struct { double x, y; } ImthVector2D;
+//! typedef struct { double x, y, z; } ImthVector3D;
+//! typedef struct { double rows[3][3]; } ImthMatrix3;
+//! typedef struct { size_t start, end; } ImthMember;
+//! typedef struct { double position, force, moment; } ImthReaction;
+//! ```
+//!
+//! Functions that can fail return [`IMTH_OK`] or one of the other `IMTH_` status codes and
+//! write their results through the passed output pointers, which are left untouched on
+//! failure. Arrays are passed as a pointer and a length, and a null pointer is only
+//! accepted with a length of zero.
+//!
+//! # Example
+//! ```rust
+//! use i_mth::ffi::*;
+//! use i_mth::truss::Member;
+//! use i_mth::vector2d::Vector2D;
+//!
+//! // the triangular truss of `Truss2D`, called the way C would call it
+//! let joints = [Vector2D::new(0.0, 0.0), Vector2D::new(4.0, 0.0), Vector2D::new(2.0, 3.0)];
+//! let members = [Member::new(0, 1), Member::new(0, 2), Member::new(1, 2)];
+//! let supports = [
+//!     ImthSupport2D { joint: 0, kind: IMTH_SUPPORT_PIN, direction: Vector2D::origin() },
+//!     ImthSupport2D { joint: 1, kind: IMTH_SUPPORT_ROLLER, direction: Vector2D::j() },
+//! ];
+//! let loads = [ImthLoad2D { joint: 2, force: Vector2D::new(0.0, -12e3) }];
+//! let mut forces = [0.0; 3];
+//! let mut reactions = [Vector2D::origin(); 2];
+//!
+//! let status = unsafe {
+//!     imth_truss2d_solve(
+//!         joints.as_ptr(), joints.len(),
+//!         members.as_ptr(), members.len(),
+//!         supports.as_ptr(), supports.len(),
+//!         loads.as_ptr(), loads.len(),
+//!         forces.as_mut_ptr(), reactions.as_mut_ptr(),
+//!     )
+//! };
+//! assert_eq!(IMTH_OK, status);
+//! assert!((forces[0] - 4e3).abs() < 1e-9);
+//! assert!((reactions[1].y - 6e3).abs() < 1e-9);
+//! ```
+
+use std::slice;
+
+use crate::beam::{Beam, DistributedLoad, Reaction, Support};
+use crate::error::ImthError;
+use crate::matrix3::Matrix3;
+use crate::truss::{Member, Support2D, Support3D, Truss2D, Truss3D};
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+/// The call succeeded.
+pub const IMTH_OK: i32 = 0;
+/// An argument was out of range, see [`ImthError::InvalidArgument`].
+pub const IMTH_INVALID_ARGUMENT: i32 = 1;
+/// A division by zero, like inverting a singular matrix, see [`ImthError::DivisionByZero`].
+pub const IMTH_DIVISION_BY_ZERO: i32 = 2;
+/// An iterative method did not converge, see [`ImthError::MaxIterationsExceeded`].
+pub const IMTH_MAX_ITERATIONS_EXCEEDED: i32 = 3;
+/// An adaptive integrator gave up, see [`ImthError::StepSizeUnderflow`].
+pub const IMTH_STEP_SIZE_UNDERFLOW: i32 = 4;
+/// A structural model cannot be solved, see [`ImthError::InvalidModel`].
+pub const IMTH_INVALID_MODEL: i32 = 5;
+/// A null pointer was passed for an array with entries or for an output.
+pub const IMTH_NULL_POINTER: i32 = 6;
+
+/// [`ImthSupport2D::kind`] of a pin, [`ImthSupport3D::kind`] of a ball and socket and
+/// [`ImthBeamSupport::kind`] of a pin.
+pub const IMTH_SUPPORT_PIN: i32 = 0;
+/// Kind of a roller, on the surface with the normal `direction` for a truss.
+pub const IMTH_SUPPORT_ROLLER: i32 = 1;
+/// [`ImthSupport3D::kind`] of a joint sliding along `direction`.
+pub const IMTH_SUPPORT_SLIDER: i32 = 2;
+/// [`ImthBeamSupport::kind`] of a fixed end.
+pub const IMTH_SUPPORT_FIXED: i32 = 3;
+
+/// A support of a plane truss, see [`Support2D`].
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[repr(C)]
+pub struct ImthSupport2D {
+    /// Index of the supported joint.
+    pub joint: usize,
+    /// [`IMTH_SUPPORT_PIN`] or [`IMTH_SUPPORT_ROLLER`].
+    pub kind: i32,
+    /// Normal of the surface of a roller, ignored for a pin.
+    pub direction: Vector2D,
+}
+
+/// A support of a space truss, see [`Support3D`].
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[repr(C)]
+pub struct ImthSupport3D {
+    /// Index of the supported joint.
+    pub joint: usize,
+    /// [`IMTH_SUPPORT_PIN`] for a ball and socket, [`IMTH_SUPPORT_ROLLER`] or
+    /// [`IMTH_SUPPORT_SLIDER`].
+    pub kind: i32,
+    /// Normal of the surface of a roller or direction of a slider, ignored for a ball and
+    /// socket.
+    pub direction: Vector3D,
+}
+
+/// A force in N applied at a joint of a plane truss.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[repr(C)]
+pub struct ImthLoad2D {
+    /// Index of the loaded joint.
+    pub joint: usize,
+    /// The applied force.
+    pub force: Vector2D,
+}
+
+/// A force in N applied at a joint of a space truss.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[repr(C)]
+pub struct ImthLoad3D {
+    /// Index of the loaded joint.
+    pub joint: usize,
+    /// The applied force.
+    pub force: Vector3D,
+}
+
+/// A support of a beam, see [`Support`].
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[repr(C)]
+pub struct ImthBeamSupport {
+    /// [`IMTH_SUPPORT_PIN`], [`IMTH_SUPPORT_ROLLER`] or [`IMTH_SUPPORT_FIXED`].
+    pub kind: i32,
+    /// Position of the support in m.
+    pub position: f64,
+}
+
+/// A load on a beam of `start_intensity` N/m at `start` varying linearly to
+/// `end_intensity` N/m at `end`, positive downwards. Equal ends give a point load of
+/// `start_intensity` N.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[repr(C)]
+pub struct ImthBeamLoad {
+    /// Position in m where the load starts.
+    pub start: f64,
+    /// Position in m where the load ends.
+    pub end: f64,
+    /// Intensity at the start in N/m, or the force in N of a point load.
+    pub start_intensity: f64,
+    /// Intensity at the end in N/m, ignored for a point load.
+    pub end_intensity: f64,
+}
+
+/// Returns the status code of the passed error.
+#[inline]
+fn status(error: &ImthError) -> i32 {
+    match error {
+        ImthError::InvalidArgument(_) => IMTH_INVALID_ARGUMENT,
+        ImthError::DivisionByZero => IMTH_DIVISION_BY_ZERO,
+        ImthError::MaxIterationsExceeded(_) => IMTH_MAX_ITERATIONS_EXCEEDED,
+        ImthError::StepSizeUnderflow { .. } => IMTH_STEP_SIZE_UNDERFLOW,
+        ImthError::InvalidModel(_) => IMTH_INVALID_MODEL,
+    }
+}
+
+/// Returns the array at `ptr` of `len` entries, or None for a null pointer with entries.
+///
+/// # Safety
+/// A non-null `ptr` must point to `len` initialized values that outlive the slice.
+#[inline]
+unsafe fn array<'a, T>(ptr: *const T, len: usize) -> Option<&'a [T]> {
+    match (ptr.is_null(), len) {
+        (_, 0) => Some(&[]),
+        (true, _) => None,
+        (false, _) => Some(slice::from_raw_parts(ptr, len)),
+    }
+}
+
+/// Returns the writable array at `ptr` of `len` entries, or None for a null pointer with
+/// entries.
+///
+/// # Safety
+/// A non-null `ptr` must point to `len` values that are not otherwise borrowed.
+#[inline]
+unsafe fn array_mut<'a, T>(ptr: *mut T, len: usize) -> Option<&'a mut [T]> {
+    match (ptr.is_null(), len) {
+        (_, 0) => Some(&mut []),
+        (true, _) => None,
+        (false, _) => Some(slice::from_raw_parts_mut(ptr, len)),
+    }
+}
+
+/// Returns the sum of two vectors.
+#[no_mangle]
+pub extern "C" fn imth_vector3d_add(a: Vector3D, b: Vector3D) -> Vector3D {
+    a + b
+}
+
+/// Returns the difference of two vectors.
+#[no_mangle]
+pub extern "C" fn imth_vector3d_sub(a: Vector3D, b: Vector3D) -> Vector3D {
+    a - b
+}
+
+/// Returns the vector scaled by the passed value.
+#[no_mangle]
+pub extern "C" fn imth_vector3d_scale(v: Vector3D, value: f64) -> Vector3D {
+    v.scale(value)
+}
+
+/// Returns the dot product of two vectors.
+#[no_mangle]
+pub extern "C" fn imth_vector3d_dot(a: Vector3D, b: Vector3D) -> f64 {
+    a.dot(b)
+}
+
+/// Returns the cross product of two vectors, like the moment r × F.
+#[no_mangle]
+pub extern "C" fn imth_vector3d_cross(a: Vector3D, b: Vector3D) -> Vector3D {
+    a.cross(b)
+}
+
+/// Returns the magnitude of a vector.
+#[no_mangle]
+pub extern "C" fn imth_vector3d_magnitude(v: Vector3D) -> f64 {
+    v.magnitude()
+}
+
+/// Writes the unit vector along `v` to `out`, failing with [`IMTH_DIVISION_BY_ZERO`] for
+/// a zero vector.
+///
+/// # Safety
+/// `out` must be null or point to a writable vector.
+#[no_mangle]
+pub unsafe extern "C" fn imth_vector3d_normalize(v: Vector3D, out: *mut Vector3D) -> i32 {
+    let Some(out) = out.as_mut() else {
+        return IMTH_NULL_POINTER;
+    };
+    match v.try_normalize() {
+        Ok(unit) => {
+            *out = unit;
+            IMTH_OK
+        }
+        Err(error) => status(&error),
+    }
+}
+
+/// Returns the dot product of two plane vectors.
+#[no_mangle]
+pub extern "C" fn imth_vector2d_dot(a: Vector2D, b: Vector2D) -> f64 {
+    a.dot(b)
+}
+
+/// Returns the z component of the cross product of two plane vectors, like the moment of
+/// a force in the plane.
+#[no_mangle]
+pub extern "C" fn imth_vector2d_cross(a: Vector2D, b: Vector2D) -> f64 {
+    a.cross(b)
+}
+
+/// Returns the magnitude of a plane vector.
+#[no_mangle]
+pub extern "C" fn imth_vector2d_magnitude(v: Vector2D) -> f64 {
+    v.magnitude()
+}
+
+/// Returns the product of two matrices.
+#[no_mangle]
+pub extern "C" fn imth_matrix3_mul(a: Matrix3, b: Matrix3) -> Matrix3 {
+    a * b
+}
+
+/// Returns the product of a matrix and a column vector.
+#[no_mangle]
+pub extern "C" fn imth_matrix3_mul_vec(m: Matrix3, v: Vector3D) -> Vector3D {
+    m.mul_vec(v)
+}
+
+/// Returns the transpose of a matrix.
+#[no_mangle]
+pub extern "C" fn imth_matrix3_transpose(m: Matrix3) -> Matrix3 {
+    m.transpose()
+}
+
+/// Returns the determinant of a matrix.
+#[no_mangle]
+pub extern "C" fn imth_matrix3_determinant(m: Matrix3) -> f64 {
+    m.determinant()
+}
+
+/// Writes the inverse of `m` to `out`, failing with [`IMTH_DIVISION_BY_ZERO`] for a
+/// singular matrix.
+///
+/// # Safety
+/// `out` must be null or point to a writable matrix.
+#[no_mangle]
+pub unsafe extern "C" fn imth_matrix3_inverse(m: Matrix3, out: *mut Matrix3) -> i32 {
+    let Some(out) = out.as_mut() else {
+        return IMTH_NULL_POINTER;
+    };
+    match m.try_inverse() {
+        Ok(inverse) => {
+            *out = inverse;
+            IMTH_OK
+        }
+        Err(error) => status(&error),
+    }
+}
+
+/// Solves a plane truss, see [`Truss2D::solve`], writing the force in each member,
+/// positive in tension, to `member_forces` and the reaction at each support to
+/// `reactions`, which must hold `member_count` and `support_count` entries.
+///
+/// # Safety
+/// Every pointer must be null with a count of zero or point to the stated number of
+/// entries, and the outputs must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn imth_truss2d_solve(
+    joints: *const Vector2D,
+    joint_count: usize,
+    members: *const Member,
+    member_count: usize,
+    supports: *const ImthSupport2D,
+    support_count: usize,
+    loads: *const ImthLoad2D,
+    load_count: usize,
+    member_forces: *mut f64,
+    reactions: *mut Vector2D,
+) -> i32 {
+    let (
+        Some(joints),
+        Some(members),
+        Some(supports),
+        Some(loads),
+        Some(member_forces),
+        Some(reactions),
+    ) = (
+        array(joints, joint_count),
+        array(members, member_count),
+        array(supports, support_count),
+        array(loads, load_count),
+        array_mut(member_forces, member_count),
+        array_mut(reactions, support_count),
+    )
+    else {
+        return IMTH_NULL_POINTER;
+    };
+    let mut truss = Truss2D::new(joints.to_vec());
+    truss.members = members.to_vec();
+    for support in supports {
+        let kind = match support.kind {
+            IMTH_SUPPORT_PIN => Support2D::Pin,
+            IMTH_SUPPORT_ROLLER => Support2D::Roller(support.direction),
+            _ => return IMTH_INVALID_ARGUMENT,
+        };
+        truss = truss.with_support(support.joint, kind);
+    }
+    for load in loads {
+        truss = truss.with_load(load.joint, load.force);
+    }
+    match truss.solve() {
+        Ok(solution) => {
+            member_forces.copy_from_slice(&solution.member_forces);
+            for (out, (_, r)) in reactions.iter_mut().zip(solution.reactions) {
+                *out = r;
+            }
+            IMTH_OK
+        }
+        Err(error) => status(&error),
+    }
+}
+
+/// Solves a space truss, see [`Truss3D::solve`], writing the force in each member,
+/// positive in tension, to `member_forces` and the reaction at each support to
+/// `reactions`, which must hold `member_count` and `support_count` entries.
+///
+/// # Safety
+/// Every pointer must be null with a count of zero or point to the stated number of
+/// entries, and the outputs must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn imth_truss3d_solve(
+    joints: *const Vector3D,
+    joint_count: usize,
+    members: *const Member,
+    member_count: usize,
+    supports: *const ImthSupport3D,
+    support_count: usize,
+    loads: *const ImthLoad3D,
+    load_count: usize,
+    member_forces: *mut f64,
+    reactions: *mut Vector3D,
+) -> i32 {
+    let (
+        Some(joints),
+        Some(members),
+        Some(supports),
+        Some(loads),
+        Some(member_forces),
+        Some(reactions),
+    ) = (
+        array(joints, joint_count),
+        array(members, member_count),
+        array(supports, support_count),
+        array(loads, load_count),
+        array_mut(member_forces, member_count),
+        array_mut(reactions, support_count),
+    )
+    else {
+        return IMTH_NULL_POINTER;
+    };
+    let mut truss = Truss3D::new(joints.to_vec());
+    truss.members = members.to_vec();
+    for support in supports {
+        let kind = match support.kind {
+            IMTH_SUPPORT_PIN => Support3D::BallAndSocket,
+            IMTH_SUPPORT_ROLLER => Support3D::Roller(support.direction),
+            IMTH_SUPPORT_SLIDER => Support3D::Slider(support.direction),
+            _ => return IMTH_INVALID_ARGUMENT,
+        };
+        truss = truss.with_support(support.joint, kind);
+    }
+    for load in loads {
+        truss = truss.with_load(load.joint, load.force);
+    }
+    match truss.solve() {
+        Ok(solution) => {
+            member_forces.copy_from_slice(&solution.member_forces);
+            for (out, (_, r)) in reactions.iter_mut().zip(solution.reactions) {
+                *out = r;
+            }
+            IMTH_OK
+        }
+        Err(error) => status(&error),
+    }
+}
+
+/// Solves a beam of the passed length, see [`Beam::solve`], writing the reaction at each
+/// support to `reactions`, which must hold `support_count` entries, and the shear force
+/// and bending moment at each of the `sample_count` positions `x` to `shear` and
+/// `moment`. Positions off the beam get NaN.
+///
+/// # Safety
+/// Every pointer must be null with a count of zero or point to the stated number of
+/// entries, and the outputs must not overlap the inputs.
+///
+/// # Example
+/// ```rust
+/// use i_mth::ffi::*;
+/// use i_mth::beam::Reaction;
+///
+/// // 6 m simply supported beam, 20 kN at 2 m and 5 kN/m over the right 3 m
+/// let supports = [
+///     ImthBeamSupport { kind: IMTH_SUPPORT_PIN, position: 0.0 },
+///     ImthBeamSupport { kind: IMTH_SUPPORT_ROLLER, position: 6.0 },
+/// ];
+/// let loads = [
+///     ImthBeamLoad { start: 2.0, end: 2.0, start_intensity: 20e3, end_intensity: 0.0 },
+///     ImthBeamLoad { start: 3.0, end: 6.0, start_intensity: 5e3, end_intensity: 5e3 },
+/// ];
+/// let mut reactions = [Reaction::default(); 2];
+/// let (x, mut shear, mut moment) = ([2.0], [0.0], [0.0]);
+///
+/// let status = unsafe {
+///     imth_beam_solve(
+///         6.0,
+///         supports.as_ptr(), supports.len(),
+///         loads.as_ptr(), loads.len(),
+///         reactions.as_mut_ptr(),
+///         x.as_ptr(), x.len(), shear.as_mut_ptr(), moment.as_mut_ptr(),
+///     )
+/// };
+/// assert_eq!(IMTH_OK, status);
+/// assert!((reactions[0].force - 17_083.333_333_333_332).abs() < 1e-6);
+/// assert!((moment[0] - 34_166.666_666_666_664).abs() < 1e-6);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn imth_beam_solve(
+    length: f64,
+    supports: *const ImthBeamSupport,
+    support_count: usize,
+    loads: *const ImthBeamLoad,
+    load_count: usize,
+    reactions: *mut Reaction,
+    x: *const f64,
+    sample_count: usize,
+    shear: *mut f64,
+    moment: *mut f64,
+) -> i32 {
+    let (Some(supports), Some(loads), Some(reactions), Some(x), Some(shear), Some(moment)) = (
+        array(supports, support_count),
+        array(loads, load_count),
+        array_mut(reactions, support_count),
+        array(x, sample_count),
+        array_mut(shear, sample_count),
+        array_mut(moment, sample_count),
+    ) else {
+        return IMTH_NULL_POINTER;
+    };
+    let mut beam = Beam::new(length);
+    for support in supports {
+        let support = match support.kind {
+            IMTH_SUPPORT_PIN => Support::Pin(support.position),
+            IMTH_SUPPORT_ROLLER => Support::Roller(support.position),
+            IMTH_SUPPORT_FIXED => Support::Fixed(support.position),
+            _ => return IMTH_INVALID_ARGUMENT,
+        };
+        beam = beam.with_support(support);
+    }
+    for load in loads {
+        beam = if load.start == load.end {
+            beam.with_point_load(load.start, load.start_intensity)
+        } else {
+            beam.with_distributed_load(DistributedLoad::trapezoid(
+                load.start_intensity,
+                load.end_intensity,
+                load.start,
+                load.end,
+            ))
+        };
+    }
+    match beam.solve() {
+        Ok(solution) => {
+            reactions.copy_from_slice(&solution.reactions);
+            for (i, &x) in x.iter().enumerate() {
+                shear[i] = solution.shear.evaluate(x).unwrap_or(f64::NAN);
+                moment[i] = solution.moment.evaluate(x).unwrap_or(f64::NAN);
+            }
+            IMTH_OK
+        }
+        Err(error) => status(&error),
+    }
+}
@@ -0,0 +1,151 @@
+//! # Fitting
+//!
+//! Least-squares fits of measured `(x, y)` data, like velocity-versus-time readings from
+//! a cart on a track, with straight lines and polynomials.
+
+use crate::matrixn::MatrixN;
+use crate::polynomial::Polynomial;
+
+/// The least-squares straight line y = slope * x + intercept through a set of points.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct LinearFit {
+    /// Slope of the line.
+    pub slope: f64,
+    /// Value of the line at x = 0.
+    pub intercept: f64,
+    /// Coefficient of determination R<sup>2</sup>, the fraction of the variance in y
+    /// explained by the line.
+    pub r_squared: f64,
+}
+
+impl LinearFit {
+    /// Returns the value of the fitted line at `x`.
+    #[inline]
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.slope * x + self.intercept
+    }
+
+    /// Returns the fitted line as a polynomial.
+    #[inline]
+    pub fn to_polynomial(&self) -> Polynomial {
+        Polynomial::new(vec![self.intercept, self.slope])
+    }
+}
+
+/// Returns the least-squares straight line through the points `(x[i], y[i])`, or None if
+/// `x` and `y` differ in length, there are less than two points or every x is the same.
+///
+/// # Example
+/// ```rust
+/// use i_mth::fitting::linear_regression;
+///
+/// // velocity in m/s of a cart accelerating at about 2 m/s², sampled every second
+/// let t = [0.0, 1.0, 2.0, 3.0, 4.0];
+/// let v = [0.1, 2.0, 4.1, 5.9, 8.0];
+/// let fit = linear_regression(&t, &v).unwrap();
+///
+/// assert!((fit.slope - 1.97).abs() < 1e-12);
+/// assert!((fit.intercept - 0.08).abs() < 1e-12);
+/// assert!(fit.r_squared > 0.99);
+/// ```
+pub fn linear_regression(x: &[f64], y: &[f64]) -> Option<LinearFit> {
+    if x.len() != y.len() || x.len() < 2 {
+        return None;
+    }
+    let n = x.len() as f64;
+    let mean_x = x.iter().sum::<f64>() / n;
+    let mean_y = y.iter().sum::<f64>() / n;
+    let sxx: f64 = x.iter().map(|xi| (xi - mean_x).powi(2)).sum();
+    let sxy: f64 = x
+        .iter()
+        .zip(y)
+        .map(|(xi, yi)| (xi - mean_x) * (yi - mean_y))
+        .sum();
+    if sxx == 0.0 {
+        return None;
+    }
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+    let fit = LinearFit {
+        slope,
+        intercept,
+        r_squared: 0.0,
+    };
+    Some(LinearFit {
+        r_squared: r_squared(x, y, |xi| fit.evaluate(xi)),
+        ..fit
+    })
+}
+
+/// Returns the least-squares polynomial of the passed degree through the points
+/// `(x[i], y[i])`, or None if `x` and `y` differ in length or there are not enough distinct
+/// x values to determine it.
+///
+/// The normal equations are solved in x shifted and scaled onto [-1, 1], which keeps them
+/// well conditioned for the low degrees used to fit experimental data.
+///
+/// # Example
+/// ```rust
+/// use i_mth::fitting::polynomial_fit;
+///
+/// // height in m of a ball thrown upwards at 12 m/s
+/// let t: Vec<f64> = (0..10).map(|i| i as f64 * 0.25).collect();
+/// let h: Vec<f64> = t.iter().map(|t| 1.5 + 12.0 * t - 4.905 * t * t).collect();
+/// let p = polynomial_fit(&t, &h, 2).unwrap();
+///
+/// assert_eq!(3, p.coefficients.len());
+/// for (c, expected) in p.coefficients.iter().zip([1.5, 12.0, -4.905]) {
+///     assert!((c - expected).abs() < 1e-9);
+/// }
+/// ```
+pub fn polynomial_fit(x: &[f64], y: &[f64], degree: usize) -> Option<Polynomial> {
+    if x.len() != y.len() || x.len() <= degree {
+        return None;
+    }
+    let min = x.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = x.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let center = 0.5 * (min + max);
+    let half_width = if max > min { 0.5 * (max - min) } else { 1.0 };
+
+    let terms = degree + 1;
+    let mut normal = MatrixN::zeros(terms, terms);
+    let mut rhs = vec![0.0; terms];
+    for (xi, yi) in x.iter().zip(y) {
+        let u = (xi - center) / half_width;
+        let powers: Vec<f64> = (0..terms).map(|i| u.powi(i as i32)).collect();
+        for i in 0..terms {
+            for j in 0..terms {
+                normal[(i, j)] += powers[i] * powers[j];
+            }
+            rhs[i] += powers[i] * yi;
+        }
+    }
+    let scaled = normal.solve(&rhs)?;
+
+    // map the fit in u = (x - center) / half_width back to powers of x
+    let u = Polynomial::new(vec![-center / half_width, 1.0 / half_width]);
+    let (lead, rest) = scaled.split_last()?;
+    let p = rest
+        .iter()
+        .rev()
+        .fold(Polynomial::constant(*lead), |acc, c| {
+            &(&acc * &u) + &Polynomial::constant(*c)
+        });
+    Some(p)
+}
+
+/// Returns the coefficient of determination R<sup>2</sup> of the model `f` over the points
+/// `(x[i], y[i])`, which is one for a perfect fit. Returns one if every y is the same
+/// and the model matches them exactly, and zero if every y is the same and it does not.
+pub fn r_squared<F>(x: &[f64], y: &[f64], f: F) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let mean_y = y.iter().sum::<f64>() / y.len() as f64;
+    let total: f64 = y.iter().map(|yi| (yi - mean_y).powi(2)).sum();
+    let residual: f64 = x.iter().zip(y).map(|(xi, yi)| (yi - f(*xi)).powi(2)).sum();
+    if total == 0.0 {
+        return if residual == 0.0 { 1.0 } else { 0.0 };
+    }
+    1.0 - residual / total
+}
@@ -0,0 +1,115 @@
+//! # Fluids
+//!
+//! Fluid statics: the buoyant force on submerged bodies (Archimedes' principle) and the
+//! depth at which simple shapes float.
+
+use crate::constants::PI;
+use crate::roots::brent;
+use crate::vector3d::Vector3D;
+
+/// Returns the buoyant force on a body displacing `displaced_volume` of a fluid with
+/// density `fluid_density` in the gravitational field `gravity`. The force is the weight
+/// of the displaced fluid, pointing against gravity.\
+/// [More Info](https://en.wikipedia.org/wiki/Archimedes%27_principle)
+///
+/// # Example
+/// ```rust
+/// use i_mth::constants::EARTH_GRAVITY;
+/// use i_mth::fluids::buoyant_force;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // a 1 litre block fully under water
+/// let force = buoyant_force(1000.0, 1e-3, Vector3D::new(0.0, 0.0, EARTH_GRAVITY));
+///
+/// assert!((force.z - 9.806_65).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn buoyant_force(fluid_density: f64, displaced_volume: f64, gravity: Vector3D) -> Vector3D {
+    gravity.scale(-fluid_density * displaced_volume)
+}
+
+/// A simple shape floating with a known orientation, used to find how deep it sits.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum FloatingShape {
+    /// A prism with vertical sides, like a box or an upright cylinder, of the passed height.
+    Prism {
+        /// Height of the prism in m.
+        height: f64,
+    },
+    /// A sphere of the passed radius.
+    Sphere {
+        /// Radius of the sphere in m.
+        radius: f64,
+    },
+    /// A cylinder lying on its side, like a log, of the passed radius.
+    HorizontalCylinder {
+        /// Radius of the cylinder in m.
+        radius: f64,
+    },
+}
+
+impl FloatingShape {
+    /// Returns the height of the shape.
+    #[inline]
+    pub fn height(&self) -> f64 {
+        match *self {
+            FloatingShape::Prism { height } => height,
+            FloatingShape::Sphere { radius } | FloatingShape::HorizontalCylinder { radius } => {
+                2.0 * radius
+            }
+        }
+    }
+
+    /// Returns the fraction of the volume of the shape that is below the passed depth,
+    /// measured from its lowest point.
+    pub fn submerged_fraction(&self, depth: f64) -> f64 {
+        let h = depth.clamp(0.0, self.height());
+        match *self {
+            FloatingShape::Prism { height } => h / height,
+            FloatingShape::Sphere { radius } => h * h * (3.0 * radius - h) / (4.0 * radius.powi(3)),
+            FloatingShape::HorizontalCylinder { radius } => {
+                let d = radius - h;
+                (radius * radius * (d / radius).acos() - d * (2.0 * radius * h - h * h).sqrt())
+                    / (PI * radius * radius)
+            }
+        }
+    }
+
+    /// Returns the depth below the free surface of the lowest point of the shape floating
+    /// in equilibrium, when it is made of a uniform material of density `body_density`
+    /// in a fluid of density `fluid_density`. Returns None if the shape does not float,
+    /// that is if it is at least as dense as the fluid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::fluids::FloatingShape;
+    ///
+    /// // a pine log of radius 0.2 m in water
+    /// let log = FloatingShape::HorizontalCylinder { radius: 0.2 };
+    /// let depth = log.equilibrium_depth(500.0, 1000.0).unwrap();
+    ///
+    /// assert!((depth - 0.2).abs() < 1e-9);
+    /// assert!(log.equilibrium_depth(1200.0, 1000.0).is_none());
+    /// ```
+    pub fn equilibrium_depth(&self, body_density: f64, fluid_density: f64) -> Option<f64> {
+        let fraction = body_density / fluid_density;
+        if !(0.0..1.0).contains(&fraction) {
+            return None;
+        }
+        match *self {
+            FloatingShape::Prism { height } => Some(fraction * height),
+            _ => {
+                let height = self.height();
+                brent(
+                    |h| self.submerged_fraction(h) - fraction,
+                    0.0,
+                    height,
+                    1e-12 * height,
+                    100,
+                )
+                .ok()
+                .map(|root| root.root)
+            }
+        }
+    }
+}
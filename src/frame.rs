@@ -0,0 +1,187 @@
+//! # Frames
+//!
+//! Coordinate frames for moving between body-fixed and world coordinates.
+//!
+//! A [`Frame`] is an origin and three orthonormal, right-handed axes, all expressed in the
+//! parent (usually world) coordinates. Points change by both the translation and the
+//! rotation between frames, free vectors like forces and velocities only by the rotation.
+
+use crate::vector3d::Vector3D;
+
+/// Tolerance used to decide if a set of axes is orthonormal.
+const ORTHONORMAL_TOLERANCE: f64 = crate::EPSILON;
+
+/// A right-handed orthonormal coordinate frame.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Frame {
+    /// Origin of the frame in parent coordinates.
+    pub origin: Vector3D,
+    /// Unit x axis of the frame in parent coordinates.
+    pub x_axis: Vector3D,
+    /// Unit y axis of the frame in parent coordinates.
+    pub y_axis: Vector3D,
+    /// Unit z axis of the frame in parent coordinates.
+    pub z_axis: Vector3D,
+}
+
+impl Default for Frame {
+    #[inline]
+    fn default() -> Self {
+        Self::world()
+    }
+}
+
+impl Frame {
+    /// Returns the frame that coincides with its parent.
+    #[inline]
+    pub fn world() -> Self {
+        Self {
+            origin: Vector3D::origin(),
+            x_axis: Vector3D::i(),
+            y_axis: Vector3D::j(),
+            z_axis: Vector3D::k(),
+        }
+    }
+
+    /// Returns the frame with the passed origin and axes, or None if the axes are not
+    /// orthonormal and right-handed.
+    #[inline]
+    pub fn new(
+        origin: Vector3D,
+        x_axis: Vector3D,
+        y_axis: Vector3D,
+        z_axis: Vector3D,
+    ) -> Option<Self> {
+        let frame = Self {
+            origin,
+            x_axis,
+            y_axis,
+            z_axis,
+        };
+        if !frame.is_orthonormal(ORTHONORMAL_TOLERANCE) {
+            return None;
+        }
+        Some(frame)
+    }
+
+    /// Returns the frame whose x axis points along `x` and whose y axis lies in the plane
+    /// of `x` and `xy`, on the same side as `xy`. Returns None if `x` and `xy` are parallel
+    /// or either is zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::frame::Frame;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // a body frame at (1, 2, 0) turned 90 degrees about z
+    /// let body = Frame::from_axes(Vector3D::new(1.0, 2.0, 0.0), Vector3D::j(), Vector3D::new(-1.0, 0.0, 0.0)).unwrap();
+    ///
+    /// assert_eq!(Vector3D::new(1.0, 3.0, 0.0), body.to_global_point(Vector3D::i()));
+    /// assert_eq!(Vector3D::new(0.0, -1.0, 0.0), body.to_local_vector(Vector3D::i()));
+    /// assert_eq!(Vector3D::new(5.0, 7.0, 0.0), body.to_global_point(body.to_local_point(Vector3D::new(5.0, 7.0, 0.0))));
+    /// ```
+    pub fn from_axes(origin: Vector3D, x: Vector3D, xy: Vector3D) -> Option<Self> {
+        let x_axis = x.normalized()?;
+        let z_axis = x_axis.cross(xy).normalized()?;
+        let y_axis = z_axis.cross(x_axis);
+        Some(Self {
+            origin,
+            x_axis,
+            y_axis,
+            z_axis,
+        })
+    }
+
+    /// Returns a frame whose x axis points along `x`, like the local axes of a member built
+    /// from its direction alone, with the y and z axes from
+    /// [`Vector3D::orthonormal_basis_from`]. Returns None if `x` is the zero vector.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::frame::Frame;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let member = Frame::from_x_axis(Vector3D::origin(), Vector3D::new(4.0, 3.0, 0.0)).unwrap();
+    ///
+    /// assert!(member.is_orthonormal(1e-12));
+    /// assert!((member.to_local_vector(Vector3D::new(4.0, 3.0, 0.0)) - Vector3D::new(5.0, 0.0, 0.0)).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn from_x_axis(origin: Vector3D, x: Vector3D) -> Option<Self> {
+        let (x_axis, y_axis, z_axis) = Vector3D::orthonormal_basis_from(x)?;
+        Some(Self {
+            origin,
+            x_axis,
+            y_axis,
+            z_axis,
+        })
+    }
+
+    /// Returns true if the axes of this frame are unit length, perpendicular to each other
+    /// and right-handed, to within the passed tolerance.
+    pub fn is_orthonormal(&self, tolerance: f64) -> bool {
+        let (x, y, z) = (self.x_axis, self.y_axis, self.z_axis);
+        (x.squared_magnitude() - 1.0).abs() <= tolerance
+            && (y.squared_magnitude() - 1.0).abs() <= tolerance
+            && (z.squared_magnitude() - 1.0).abs() <= tolerance
+            && x.dot(y).abs() <= tolerance
+            && y.dot(z).abs() <= tolerance
+            && z.dot(x).abs() <= tolerance
+            && (x.triple_scalar_prod(y, z) - 1.0).abs() <= tolerance
+    }
+
+    /// Returns the free vector given in parent coordinates in the coordinates of this frame.
+    #[inline]
+    pub fn to_local_vector(&self, v: Vector3D) -> Vector3D {
+        Vector3D::new(v.dot(self.x_axis), v.dot(self.y_axis), v.dot(self.z_axis))
+    }
+
+    /// Returns the free vector given in the coordinates of this frame in parent coordinates.
+    #[inline]
+    pub fn to_global_vector(&self, v: Vector3D) -> Vector3D {
+        self.x_axis.scale(v.x) + self.y_axis.scale(v.y) + self.z_axis.scale(v.z)
+    }
+
+    /// Returns the point given in parent coordinates in the coordinates of this frame.
+    #[inline]
+    pub fn to_local_point(&self, p: Vector3D) -> Vector3D {
+        self.to_local_vector(p - self.origin)
+    }
+
+    /// Returns the point given in the coordinates of this frame in parent coordinates.
+    #[inline]
+    pub fn to_global_point(&self, p: Vector3D) -> Vector3D {
+        self.origin + self.to_global_vector(p)
+    }
+
+    /// Returns the passed frame, given in the coordinates of this frame, in the parent
+    /// coordinates of this frame. Composing a body frame with a frame attached to the body
+    /// gives the attached frame in world coordinates.
+    #[inline]
+    pub fn compose(&self, child: &Frame) -> Frame {
+        Frame {
+            origin: self.to_global_point(child.origin),
+            x_axis: self.to_global_vector(child.x_axis),
+            y_axis: self.to_global_vector(child.y_axis),
+            z_axis: self.to_global_vector(child.z_axis),
+        }
+    }
+
+    /// Returns the parent frame expressed in the coordinates of this frame, so that
+    /// composing a frame with its inverse gives the world frame.
+    #[inline]
+    pub fn inverse(&self) -> Frame {
+        Frame {
+            origin: self.to_local_point(Vector3D::origin()),
+            x_axis: self.to_local_vector(Vector3D::i()),
+            y_axis: self.to_local_vector(Vector3D::j()),
+            z_axis: self.to_local_vector(Vector3D::k()),
+        }
+    }
+
+    /// Returns the passed frame, given in parent coordinates, in the coordinates of this frame.
+    #[inline]
+    pub fn relative(&self, other: &Frame) -> Frame {
+        self.inverse().compose(other)
+    }
+}
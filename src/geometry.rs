@@ -0,0 +1,4 @@
+pub mod aabb;
+pub mod line;
+pub mod polygon;
+pub mod triangle;
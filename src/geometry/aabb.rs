@@ -0,0 +1,156 @@
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+/// Defines an axis-aligned bounding box type over the passed vector type and components.
+macro_rules! aabb {
+    ($(#[$meta:meta])* $name:ident, $vector:ident, $($c:ident),+) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Clone, Copy, Default)]
+        pub struct $name {
+            /// The corner with the smallest components.
+            pub min: $vector,
+            /// The corner with the largest components.
+            pub max: $vector,
+        }
+
+        impl $name {
+            /// Returns the box with the passed opposite corners, in any order.
+            #[inline]
+            pub fn new(a: $vector, b: $vector) -> Self {
+                Self {
+                    min: $vector { $($c: a.$c.min(b.$c)),+ },
+                    max: $vector { $($c: a.$c.max(b.$c)),+ },
+                }
+            }
+
+            /// Returns the smallest box containing all of the passed points,
+            /// or None if there are no points.
+            pub fn from_points(points: &[$vector]) -> Option<Self> {
+                let (first, rest) = points.split_first()?;
+                Some(rest.iter().fold(Self::new(*first, *first), |aabb, p| aabb.expand_to(*p)))
+            }
+
+            /// Returns the centre of this box.
+            #[inline]
+            pub fn center(&self) -> $vector {
+                (self.min + self.max).scale(0.5)
+            }
+
+            /// Returns the size of this box along each axis.
+            #[inline]
+            pub fn extent(&self) -> $vector {
+                self.max - self.min
+            }
+
+            /// Returns half of the size of this box along each axis.
+            #[inline]
+            pub fn half_extent(&self) -> $vector {
+                self.extent().scale(0.5)
+            }
+
+            /// Returns the smallest box containing this box and the passed point.
+            #[inline]
+            pub fn expand_to(&self, p: $vector) -> Self {
+                Self {
+                    min: $vector { $($c: self.min.$c.min(p.$c)),+ },
+                    max: $vector { $($c: self.max.$c.max(p.$c)),+ },
+                }
+            }
+
+            /// Returns this box grown by `margin` on every side.
+            #[inline]
+            pub fn inflate(&self, margin: f64) -> Self {
+                Self {
+                    min: $vector { $($c: self.min.$c - margin),+ },
+                    max: $vector { $($c: self.max.$c + margin),+ },
+                }
+            }
+
+            /// Returns the smallest box containing both this box and the passed box.
+            #[inline]
+            pub fn union(&self, other: &Self) -> Self {
+                self.expand_to(other.min).expand_to(other.max)
+            }
+
+            /// Returns the box where this box and the passed box overlap, or None if they
+            /// do not overlap. Boxes that only touch overlap in a box of zero size.
+            #[inline]
+            pub fn intersection(&self, other: &Self) -> Option<Self> {
+                let min = $vector { $($c: self.min.$c.max(other.min.$c)),+ };
+                let max = $vector { $($c: self.max.$c.min(other.max.$c)),+ };
+                if $(min.$c > max.$c)||+ {
+                    return None;
+                }
+                Some(Self { min, max })
+            }
+
+            /// Returns true if this box and the passed box overlap or touch.
+            #[inline]
+            pub fn intersects(&self, other: &Self) -> bool {
+                $(self.min.$c <= other.max.$c && other.min.$c <= self.max.$c)&&+
+            }
+
+            /// Returns true if the passed point is inside this box or on its boundary.
+            #[inline]
+            pub fn contains(&self, p: $vector) -> bool {
+                $(self.min.$c <= p.$c && p.$c <= self.max.$c)&&+
+            }
+
+            /// Returns true if the passed box lies entirely inside this box.
+            #[inline]
+            pub fn contains_aabb(&self, other: &Self) -> bool {
+                self.contains(other.min) && self.contains(other.max)
+            }
+
+            /// Returns the point of this box closest to the passed point, which is the point
+            /// itself if it is inside the box.
+            #[inline]
+            pub fn clamp(&self, p: $vector) -> $vector {
+                $vector { $($c: p.$c.clamp(self.min.$c, self.max.$c)),+ }
+            }
+        }
+    };
+}
+
+aabb!(
+    /// Represents an axis-aligned bounding box in the plane.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::aabb::Aabb2D;
+    /// use i_mth::vector2d::Vector2D;
+    ///
+    /// let a = Aabb2D::new(Vector2D::new(0.0, 0.0), Vector2D::new(2.0, 1.0));
+    /// let b = Aabb2D::new(Vector2D::new(1.0, 0.5), Vector2D::new(3.0, 3.0));
+    ///
+    /// assert!(a.intersects(&b));
+    /// assert_eq!(Some(Aabb2D::new(Vector2D::new(1.0, 0.5), Vector2D::new(2.0, 1.0))), a.intersection(&b));
+    /// assert_eq!(Vector2D::new(1.5, 1.5), a.union(&b).center());
+    /// ```
+    Aabb2D,
+    Vector2D,
+    x,
+    y
+);
+
+aabb!(
+    /// Represents an axis-aligned bounding box in space.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::aabb::Aabb3D;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let points = [Vector3D::new(1.0, -1.0, 0.0), Vector3D::new(-2.0, 3.0, 1.0), Vector3D::k()];
+    /// let domain = Aabb3D::from_points(&points).unwrap();
+    ///
+    /// assert_eq!(Vector3D::new(3.0, 4.0, 1.0), domain.extent());
+    /// assert!(domain.contains(Vector3D::origin()));
+    /// assert_eq!(Vector3D::new(1.0, 0.0, 1.0), domain.clamp(Vector3D::new(5.0, 0.0, 2.0)));
+    /// ```
+    Aabb3D,
+    Vector3D,
+    x,
+    y,
+    z
+);
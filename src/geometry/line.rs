@@ -0,0 +1,103 @@
+use crate::vector3d::Vector3D;
+
+/// Represents an infinite straight line in space, like the line of action of a force or
+/// an axis of rotation, by a point on it and a unit direction.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Line3D {
+    /// A point on the line.
+    pub point: Vector3D,
+    /// Unit vector along the line.
+    pub direction: Vector3D,
+}
+
+/// The closest points between two lines and the distance between them.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ClosestPoints {
+    /// Point on the first line closest to the second line.
+    pub on_first: Vector3D,
+    /// Point on the second line closest to the first line.
+    pub on_second: Vector3D,
+    /// Shortest distance between the lines, zero if they intersect.
+    pub distance: f64,
+}
+
+impl Line3D {
+    /// Returns the line through `point` along `direction`, or None if the direction is the
+    /// zero vector.
+    #[inline]
+    pub fn new(point: Vector3D, direction: Vector3D) -> Option<Self> {
+        Some(Self {
+            point,
+            direction: direction.normalized()?,
+        })
+    }
+
+    /// Returns the line through the two passed points, or None if they are the same.
+    #[inline]
+    pub fn through(a: Vector3D, b: Vector3D) -> Option<Self> {
+        Self::new(a, b - a)
+    }
+
+    /// Returns the point of this line at the signed distance `t` from its reference point.
+    #[inline]
+    pub fn at(&self, t: f64) -> Vector3D {
+        self.point + self.direction.scale(t)
+    }
+
+    /// Returns the point of this line closest to the passed point.
+    #[inline]
+    pub fn project(&self, p: Vector3D) -> Vector3D {
+        self.at((p - self.point).dot(self.direction))
+    }
+
+    /// Returns the shortest distance from this line to the passed point.
+    #[inline]
+    pub fn distance_to_point(&self, p: Vector3D) -> f64 {
+        (p - self.project(p)).magnitude()
+    }
+
+    /// Returns the closest points between this line and the passed line and the distance
+    /// between them. Parallel lines have no unique closest points, so the reference point
+    /// of this line and its projection onto the other line are returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::line::Line3D;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // a force acting along y at x = 2, z = 1 and the shaft along the z axis
+    /// let action = Line3D::new(Vector3D::new(2.0, 0.0, 1.0), Vector3D::j()).unwrap();
+    /// let shaft = Line3D::new(Vector3D::origin(), Vector3D::k()).unwrap();
+    /// let closest = action.closest_points(&shaft);
+    ///
+    /// assert_eq!(2.0, closest.distance);
+    /// assert_eq!(Vector3D::new(2.0, 0.0, 1.0), closest.on_first);
+    /// assert_eq!(Vector3D::new(0.0, 0.0, 1.0), closest.on_second);
+    /// ```
+    pub fn closest_points(&self, other: &Self) -> ClosestPoints {
+        let w = self.point - other.point;
+        let b = self.direction.dot(other.direction);
+        let d = self.direction.dot(w);
+        let e = other.direction.dot(w);
+        let denominator = 1.0 - b * b;
+
+        let (on_first, on_second) = if denominator <= 64.0 * f64::EPSILON {
+            (self.point, other.project(self.point))
+        } else {
+            let s = (b * e - d) / denominator;
+            let t = (e - b * d) / denominator;
+            (self.at(s), other.at(t))
+        };
+        ClosestPoints {
+            on_first,
+            on_second,
+            distance: (on_second - on_first).magnitude(),
+        }
+    }
+
+    /// Returns the shortest distance between this line and the passed line.
+    #[inline]
+    pub fn distance_to_line(&self, other: &Self) -> f64 {
+        self.closest_points(other).distance
+    }
+}
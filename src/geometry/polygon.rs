@@ -0,0 +1,214 @@
+use crate::vector2d::Vector2D;
+
+/// Represents a simple polygon in the plane by its vertices in order, like the outline of
+/// a cross-section. The last vertex connects back to the first.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Polygon2D {
+    pub vertices: Vec<Vector2D>,
+}
+
+/// Second moments of area of a plane shape about axes through its centroid.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct SecondMoments {
+    /// Second moment of area about the centroidal x axis, ∫ y<sup>2</sup> dA.
+    pub ixx: f64,
+    /// Second moment of area about the centroidal y axis, ∫ x<sup>2</sup> dA.
+    pub iyy: f64,
+    /// Product of area about the centroidal axes, ∫ xy dA.
+    pub ixy: f64,
+}
+
+impl SecondMoments {
+    /// Returns the polar second moment of area about the centroid, I<sub>xx</sub> + I<sub>yy</sub>.
+    #[inline]
+    pub fn polar(&self) -> f64 {
+        self.ixx + self.iyy
+    }
+
+    /// Returns the principal second moments of area as (maximum, minimum).
+    #[inline]
+    pub fn principal(&self) -> (f64, f64) {
+        let mean = 0.5 * (self.ixx + self.iyy);
+        let radius = (0.25 * (self.ixx - self.iyy).powi(2) + self.ixy * self.ixy).sqrt();
+        (mean + radius, mean - radius)
+    }
+}
+
+impl Polygon2D {
+    /// Returns a new polygon with the passed vertices.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::polygon::Polygon2D;
+    /// use i_mth::vector2d::Vector2D;
+    ///
+    /// // a 0.2 m wide by 0.3 m deep rectangular section
+    /// let section = Polygon2D::new(vec![
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(0.2, 0.0),
+    ///     Vector2D::new(0.2, 0.3),
+    ///     Vector2D::new(0.0, 0.3),
+    /// ]);
+    /// let moments = section.second_moments().unwrap();
+    ///
+    /// assert!((section.area() - 0.06).abs() < 1e-12);
+    /// assert!((section.centroid().unwrap() - Vector2D::new(0.1, 0.15)).magnitude() < 1e-12);
+    /// assert!((moments.ixx - 0.2 * 0.3f64.powi(3) / 12.0).abs() < 1e-12);
+    /// assert!(section.is_convex());
+    /// assert!(section.contains(Vector2D::new(0.1, 0.1)));
+    /// ```
+    #[inline]
+    pub fn new(vertices: Vec<Vector2D>) -> Self {
+        Self { vertices }
+    }
+
+    /// Returns the convex hull of the passed points, the smallest convex polygon containing
+    /// all of them, found with Andrew's monotone chain algorithm. The vertices run
+    /// anticlockwise starting from the leftmost point, and points lying on the hull
+    /// edges are left out.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::polygon::Polygon2D;
+    /// use i_mth::vector2d::Vector2D;
+    ///
+    /// // contact points of a stack of boxes on the ground
+    /// let contacts = [
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(1.0, 0.5),
+    ///     Vector2D::new(2.0, 0.0),
+    ///     Vector2D::new(2.0, 1.0),
+    ///     Vector2D::new(1.0, 1.0),
+    ///     Vector2D::new(0.0, 1.0),
+    /// ];
+    /// let support = Polygon2D::convex_hull(&contacts);
+    ///
+    /// assert_eq!(4, support.vertices.len());
+    /// assert_eq!(2.0, support.area());
+    /// ```
+    pub fn convex_hull(points: &[Vector2D]) -> Self {
+        let mut points = points.to_vec();
+        points.sort_by(|p, q| p.x.total_cmp(&q.x).then(p.y.total_cmp(&q.y)));
+        points.dedup();
+        if points.len() < 3 {
+            return Self::new(points);
+        }
+
+        let mut hull: Vec<Vector2D> = Vec::with_capacity(2 * points.len());
+        let push = |hull: &mut Vec<Vector2D>, p: Vector2D, floor: usize| {
+            while hull.len() >= floor + 2 {
+                let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+                if (b - a).cross(p - b) > 0.0 {
+                    break;
+                }
+                hull.pop();
+            }
+            hull.push(p);
+        };
+        for p in &points {
+            push(&mut hull, *p, 0);
+        }
+        let lower = hull.len() - 1;
+        for p in points.iter().rev().skip(1) {
+            push(&mut hull, *p, lower);
+        }
+        hull.pop();
+        Self::new(hull)
+    }
+
+    /// Returns the pairs of consecutive vertices making up the edges of this polygon.
+    fn edges(&self) -> impl Iterator<Item = (Vector2D, Vector2D)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// Returns the area of this polygon by the shoelace formula, positive if the vertices
+    /// run anticlockwise and negative if they run clockwise.
+    #[inline]
+    pub fn signed_area(&self) -> f64 {
+        0.5 * self.edges().map(|(p, q)| p.cross(q)).sum::<f64>()
+    }
+
+    /// Returns the area of this polygon.
+    #[inline]
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    /// Returns the perimeter of this polygon.
+    #[inline]
+    pub fn perimeter(&self) -> f64 {
+        self.edges().map(|(p, q)| (q - p).magnitude()).sum()
+    }
+
+    /// Returns the centroid of the area of this polygon, or None if its area is zero.
+    pub fn centroid(&self) -> Option<Vector2D> {
+        let area = self.signed_area();
+        if area == 0.0 {
+            return None;
+        }
+        let sum = self.edges().fold(Vector2D::origin(), |sum, (p, q)| {
+            sum + (p + q).scale(p.cross(q))
+        });
+        Some(sum.scale(1.0 / (6.0 * area)))
+    }
+
+    /// Returns the second moments of area of this polygon about axes through its centroid,
+    /// parallel to the x and y axes, or None if its area is zero.
+    pub fn second_moments(&self) -> Option<SecondMoments> {
+        let area = self.signed_area();
+        let centroid = self.centroid()?;
+        let (mut ixx, mut iyy, mut ixy) = (0.0, 0.0, 0.0);
+        for (p, q) in self.edges() {
+            let (p, q) = (p - centroid, q - centroid);
+            let a = p.cross(q);
+            ixx += a * (p.y * p.y + p.y * q.y + q.y * q.y);
+            iyy += a * (p.x * p.x + p.x * q.x + q.x * q.x);
+            ixy += a * (p.x * q.y + 2.0 * p.x * p.y + 2.0 * q.x * q.y + q.x * p.y);
+        }
+        // the sums carry the sign of the area, so dividing by it keeps them positive
+        let sign = area.signum();
+        Some(SecondMoments {
+            ixx: sign * ixx / 12.0,
+            iyy: sign * iyy / 12.0,
+            ixy: sign * ixy / 24.0,
+        })
+    }
+
+    /// Returns true if every interior angle of this polygon is at most 180 degrees.
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        if n < 3 {
+            return false;
+        }
+        let mut sign = 0.0;
+        for i in 0..n {
+            let a = self.vertices[i];
+            let b = self.vertices[(i + 1) % n];
+            let c = self.vertices[(i + 2) % n];
+            let turn = (b - a).cross(c - b);
+            if turn != 0.0 {
+                if sign != 0.0 && turn.signum() != sign {
+                    return false;
+                }
+                sign = turn.signum();
+            }
+        }
+        sign != 0.0
+    }
+
+    /// Returns true if the passed point is inside this polygon, found by counting how many
+    /// edges a ray from the point crosses. Points exactly on an edge may go either way.
+    pub fn contains(&self, point: Vector2D) -> bool {
+        let mut inside = false;
+        for (p, q) in self.edges() {
+            if (p.y > point.y) != (q.y > point.y) {
+                let x = p.x + (point.y - p.y) / (q.y - p.y) * (q.x - p.x);
+                if point.x < x {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
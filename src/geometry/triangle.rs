@@ -0,0 +1,256 @@
+use crate::calculus::combine;
+use crate::integrators::StateVector;
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+/// Represents a triangle in the plane by its three vertices.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Triangle2D {
+    pub a: Vector2D,
+    pub b: Vector2D,
+    pub c: Vector2D,
+}
+
+impl Triangle2D {
+    /// Returns a new triangle with the passed vertices.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::triangle::Triangle2D;
+    /// use i_mth::vector2d::Vector2D;
+    ///
+    /// let t = Triangle2D::new(Vector2D::origin(), Vector2D::new(4.0, 0.0), Vector2D::new(0.0, 3.0));
+    ///
+    /// assert_eq!(6.0, t.area());
+    /// assert_eq!(12.0, t.perimeter());
+    /// assert!(t.contains(Vector2D::new(1.0, 1.0)));
+    /// assert_eq!(Some((Vector2D::new(2.0, 1.5), 2.5)), t.circumcircle());
+    /// assert_eq!(Some((Vector2D::new(1.0, 1.0), 1.0)), t.incircle());
+    /// ```
+    #[inline]
+    pub fn new(a: Vector2D, b: Vector2D, c: Vector2D) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Returns the area of this triangle, positive if the vertices run anticlockwise
+    /// and negative if they run clockwise.
+    #[inline]
+    pub fn signed_area(&self) -> f64 {
+        0.5 * (self.b - self.a).cross(self.c - self.a)
+    }
+
+    /// Returns the area of this triangle.
+    #[inline]
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    /// Returns the centroid of this triangle, the average of its vertices.
+    #[inline]
+    pub fn centroid(&self) -> Vector2D {
+        (self.a + self.b + self.c).scale(1.0 / 3.0)
+    }
+
+    /// Returns the lengths of the sides opposite to the vertices a, b and c.
+    #[inline]
+    pub fn side_lengths(&self) -> [f64; 3] {
+        [
+            (self.c - self.b).magnitude(),
+            (self.a - self.c).magnitude(),
+            (self.b - self.a).magnitude(),
+        ]
+    }
+
+    /// Returns the perimeter of this triangle.
+    #[inline]
+    pub fn perimeter(&self) -> f64 {
+        self.side_lengths().iter().sum()
+    }
+
+    /// Returns true if the passed point is inside this triangle or on its edges.
+    pub fn contains(&self, p: Vector2D) -> bool {
+        let d1 = (self.b - self.a).cross(p - self.a);
+        let d2 = (self.c - self.b).cross(p - self.b);
+        let d3 = (self.a - self.c).cross(p - self.c);
+        let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+        let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+        !(has_negative && has_positive)
+    }
+
+    /// Returns the barycentric coordinates (u, v, w) of the passed point, the weights
+    /// with p = u a + v b + w c and u + v + w = 1, or None if the vertices are collinear.
+    /// The point is inside the triangle when all three are between zero and one.
+    pub fn barycentric(&self, p: Vector2D) -> Option<[f64; 3]> {
+        let area = (self.b - self.a).cross(self.c - self.a);
+        if area == 0.0 {
+            return None;
+        }
+        let u = (self.b - p).cross(self.c - p) / area;
+        let v = (self.c - p).cross(self.a - p) / area;
+        Some([u, v, 1.0 - u - v])
+    }
+
+    /// Returns the value at the passed point of the quantity that varies linearly over
+    /// this triangle and takes the passed values at the vertices a, b and c, or None if
+    /// the vertices are collinear.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::triangle::Triangle2D;
+    /// use i_mth::vector2d::Vector2D;
+    ///
+    /// // pressure in kPa measured at the corners of a plate element
+    /// let t = Triangle2D::new(Vector2D::origin(), Vector2D::new(1.0, 0.0), Vector2D::new(0.0, 1.0));
+    /// let pressure = t.interpolate(Vector2D::new(0.25, 0.5), &[10.0, 14.0, 18.0]).unwrap();
+    ///
+    /// assert_eq!(Some([0.25, 0.25, 0.5]), t.barycentric(Vector2D::new(0.25, 0.5)));
+    /// assert!((pressure - 15.0).abs() < 1e-12);
+    /// ```
+    pub fn interpolate<S: StateVector>(&self, p: Vector2D, values: &[S; 3]) -> Option<S> {
+        let [u, v, w] = self.barycentric(p)?;
+        Some(combine(&[
+            (&values[0], u),
+            (&values[1], v),
+            (&values[2], w),
+        ]))
+    }
+
+    /// Returns the centre and radius of the circle through the three vertices of this
+    /// triangle, or None if the vertices are collinear.
+    pub fn circumcircle(&self) -> Option<(Vector2D, f64)> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let d = 2.0 * ab.cross(ac);
+        if d == 0.0 {
+            return None;
+        }
+        let offset = Vector2D::new(
+            ac.y * ab.squared_magnitude() - ab.y * ac.squared_magnitude(),
+            ab.x * ac.squared_magnitude() - ac.x * ab.squared_magnitude(),
+        )
+        .scale(1.0 / d);
+        Some((self.a + offset, offset.magnitude()))
+    }
+
+    /// Returns the centre and radius of the largest circle inside this triangle,
+    /// or None if the vertices are collinear.
+    pub fn incircle(&self) -> Option<(Vector2D, f64)> {
+        let [la, lb, lc] = self.side_lengths();
+        let perimeter = la + lb + lc;
+        let area = self.area();
+        if area == 0.0 {
+            return None;
+        }
+        let centre =
+            (self.a.scale(la) + self.b.scale(lb) + self.c.scale(lc)).scale(1.0 / perimeter);
+        Some((centre, 2.0 * area / perimeter))
+    }
+}
+
+/// Represents a triangle in space by its three vertices.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Triangle3D {
+    pub a: Vector3D,
+    pub b: Vector3D,
+    pub c: Vector3D,
+}
+
+impl Triangle3D {
+    /// Returns a new triangle with the passed vertices.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::triangle::Triangle3D;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let t = Triangle3D::new(Vector3D::i(), Vector3D::j(), Vector3D::k());
+    ///
+    /// assert!((t.area() - 3f64.sqrt() / 2.0).abs() < 1e-12);
+    /// assert!(t.contains(Vector3D::set(1.0 / 3.0)));
+    /// assert!(!t.contains(Vector3D::origin()));
+    /// ```
+    #[inline]
+    pub fn new(a: Vector3D, b: Vector3D, c: Vector3D) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Returns the area vector of this triangle, normal to its plane following the right
+    /// hand rule over a, b, c, with a magnitude equal to its area.
+    #[inline]
+    pub fn area_vector(&self) -> Vector3D {
+        (self.b - self.a).cross(self.c - self.a).scale(0.5)
+    }
+
+    /// Returns the area of this triangle.
+    #[inline]
+    pub fn area(&self) -> f64 {
+        self.area_vector().magnitude()
+    }
+
+    /// Returns the unit normal of this triangle following the right hand rule over
+    /// a, b, c, or None if the vertices are collinear.
+    #[inline]
+    pub fn normal(&self) -> Option<Vector3D> {
+        self.area_vector().normalized()
+    }
+
+    /// Returns the centroid of this triangle, the average of its vertices.
+    #[inline]
+    pub fn centroid(&self) -> Vector3D {
+        (self.a + self.b + self.c).scale(1.0 / 3.0)
+    }
+
+    /// Returns the perimeter of this triangle.
+    #[inline]
+    pub fn perimeter(&self) -> f64 {
+        (self.b - self.a).magnitude()
+            + (self.c - self.b).magnitude()
+            + (self.a - self.c).magnitude()
+    }
+
+    /// Returns the barycentric coordinates (u, v, w) of the passed point projected onto the
+    /// plane of this triangle, the weights with p = u a + v b + w c and u + v + w = 1,
+    /// or None if the vertices are collinear.
+    pub fn barycentric(&self, p: Vector3D) -> Option<[f64; 3]> {
+        let n = (self.b - self.a).cross(self.c - self.a);
+        let n2 = n.squared_magnitude();
+        if n2 == 0.0 {
+            return None;
+        }
+        let u = (self.b - p).cross(self.c - p).dot(n) / n2;
+        let v = (self.c - p).cross(self.a - p).dot(n) / n2;
+        Some([u, v, 1.0 - u - v])
+    }
+
+    /// Returns the value at the passed point, projected onto the plane of this triangle, of
+    /// the quantity that varies linearly over the triangle and takes the passed values at
+    /// the vertices a, b and c, or None if the vertices are collinear.
+    pub fn interpolate<S: StateVector>(&self, p: Vector3D, values: &[S; 3]) -> Option<S> {
+        let [u, v, w] = self.barycentric(p)?;
+        Some(combine(&[
+            (&values[0], u),
+            (&values[1], v),
+            (&values[2], w),
+        ]))
+    }
+
+    /// Returns true if the passed point lies in the plane of this triangle, to within
+    /// rounding, and inside the triangle or on its edges. Always false if the vertices
+    /// are collinear.
+    pub fn contains(&self, p: Vector3D) -> bool {
+        let n = self.area_vector();
+        if n.squared_magnitude() == 0.0 {
+            return false;
+        }
+        let scale = (self.b - self.a)
+            .squared_magnitude()
+            .max((self.c - self.a).squared_magnitude());
+        if n.dot(p - self.a).abs() > 64.0 * f64::EPSILON * scale * scale.sqrt() {
+            return false;
+        }
+        let d1 = (self.b - self.a).cross(p - self.a).dot(n);
+        let d2 = (self.c - self.b).cross(p - self.b).dot(n);
+        let d3 = (self.a - self.c).cross(p - self.c).dot(n);
+        d1 >= 0.0 && d2 >= 0.0 && d3 >= 0.0
+    }
+}
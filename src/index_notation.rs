@@ -0,0 +1,208 @@
+//! # Index notation
+//!
+//! The permutation symbol ε<sub>ijk</sub>, the Kronecker delta δ<sub>ij</sub> and a small
+//! Einstein summation for checking hand derived index notation results, like
+//! (**a** × **b**)<sub>i</sub> = ε<sub>ijk</sub>a<sub>j</sub>b<sub>k</sub>, against the
+//! vector and matrix operations of the crate.
+//!
+//! Indices count from 0. Tensors are passed to [`einsum`] as flat slices in row major
+//! order, so a vector is `[v.x, v.y, v.z]` and a matrix is `m.rows.concat()`.
+//!
+//! # Example
+//! ```rust
+//! use i_mth::index_notation::{einsum, kronecker_delta, LEVI_CIVITA};
+//! use i_mth::vector3d::Vector3D;
+//!
+//! let (a, b) = (Vector3D::new(1.0, -2.0, 0.5), Vector3D::new(3.0, 1.0, 2.0));
+//! let cross = einsum("ijk,j,k->i", 3, &[&LEVI_CIVITA, &[a.x, a.y, a.z], &[b.x, b.y, b.z]]).unwrap();
+//! let c = a.cross(b);
+//! assert_eq!(vec![c.x, c.y, c.z], cross);
+//!
+//! // ε_ijk ε_imn = δ_jm δ_kn - δ_jn δ_km
+//! let lhs = einsum("ijk,imn->jkmn", 3, &[&LEVI_CIVITA, &LEVI_CIVITA]).unwrap();
+//! for (n, value) in lhs.iter().enumerate() {
+//!     let (j, k, m, n) = (n / 27, n / 9 % 3, n / 3 % 3, n % 3);
+//!     let rhs = kronecker_delta(j, m) * kronecker_delta(k, n)
+//!         - kronecker_delta(j, n) * kronecker_delta(k, m);
+//!     assert_eq!(rhs, *value);
+//! }
+//! ```
+
+use crate::error::ImthError;
+
+/// The permutation symbol ε<sub>ijk</sub> as a flat 3 by 3 by 3 tensor, entry
+/// `9i + 3j + k`.
+pub const LEVI_CIVITA: [f64; 27] = {
+    let mut e = [0.0; 27];
+    let mut n = 0;
+    while n < 27 {
+        e[n] = levi_civita(n / 9, n / 3 % 3, n % 3);
+        n += 1;
+    }
+    e
+};
+
+/// The Kronecker delta δ<sub>ij</sub> as a flat 3 by 3 tensor, the identity matrix.
+pub const KRONECKER_DELTA: [f64; 9] = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+
+/// Most distinct indices a single [`einsum`] sums over, which caps the loop at
+/// 3<sup>8</sup> = 6561 terms in three dimensions.
+const MAX_INDICES: usize = 8;
+
+/// Returns the permutation symbol ε<sub>ijk</sub>: 1 for an even permutation of (0, 1, 2),
+/// -1 for an odd one and 0 if an index repeats or is out of range.
+///
+/// # Example
+/// ```rust
+/// use i_mth::index_notation::levi_civita;
+///
+/// assert_eq!(1.0, levi_civita(1, 2, 0));
+/// assert_eq!(-1.0, levi_civita(2, 1, 0));
+/// assert_eq!(0.0, levi_civita(0, 0, 2));
+/// ```
+#[inline]
+pub const fn levi_civita(i: usize, j: usize, k: usize) -> f64 {
+    match (i, j, k) {
+        (0, 1, 2) | (1, 2, 0) | (2, 0, 1) => 1.0,
+        (0, 2, 1) | (2, 1, 0) | (1, 0, 2) => -1.0,
+        _ => 0.0,
+    }
+}
+
+/// Returns the Kronecker delta δ<sub>ij</sub>, 1 if the indices are equal and 0 otherwise.
+#[inline]
+pub const fn kronecker_delta(i: usize, j: usize) -> f64 {
+    if i == j {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+/// Returns the Einstein summation described by `spec` over the passed tensors, every index
+/// running from 0 to `dim - 1`, as a flat tensor in row major order.
+///
+/// The spec names the indices of each operand with lowercase letters, separated by commas,
+/// and those of the result after `->`, like `"ij,jk->ik"` for a matrix product. Indices
+/// missing from the result are summed over. Without `->` the result keeps the indices that
+/// appear once, in alphabetical order, so `"ii"` is the trace and `"i,i"` a dot product.
+///
+/// Fails if the spec is malformed, it does not name one operand per tensor, a tensor does
+/// not have `dim` entries along each of its indices, or it uses more than eight indices.
+///
+/// # Example
+/// ```rust
+/// use i_mth::index_notation::einsum;
+/// use i_mth::matrix3::Matrix3;
+///
+/// let a = Matrix3::new([[1.0, 2.0, 0.0], [0.0, 1.0, 3.0], [4.0, 0.0, 1.0]]);
+/// let b = Matrix3::rotation_z(0.3);
+/// let product = einsum("ij,jk->ik", 3, &[&a.rows.concat(), &b.rows.concat()]).unwrap();
+/// assert_eq!((a * b).rows.concat(), product);
+///
+/// assert_eq!(vec![3.0], einsum("ii", 3, &[&a.rows.concat()]).unwrap());
+/// assert!(einsum("ij->k", 3, &[&a.rows.concat()]).is_err());
+///
+/// // four free indices of size 2^16 need more than 2^64 combinations
+/// let v = vec![1.0; 1 << 16];
+/// assert!(einsum("i,j,k,l->", 1 << 16, &[&v, &v, &v, &v]).is_err());
+/// ```
+pub fn einsum(spec: &str, dim: usize, operands: &[&[f64]]) -> Result<Vec<f64>, ImthError> {
+    let (inputs, output) = match spec.split_once("->") {
+        Some((inputs, output)) => (inputs, Some(output.trim())),
+        None => (spec, None),
+    };
+    let inputs: Vec<&str> = inputs.split(',').map(str::trim).collect();
+    if inputs.len() != operands.len() {
+        return Err(ImthError::InvalidArgument(
+            "einsum needs one index group per operand",
+        ));
+    }
+    if inputs
+        .iter()
+        .chain(&output)
+        .any(|group| !group.chars().all(|c| c.is_ascii_lowercase()))
+    {
+        return Err(ImthError::InvalidArgument(
+            "einsum indices must be lowercase letters",
+        ));
+    }
+
+    // every distinct index gets a slot in the counter that runs over all their values
+    let mut indices: Vec<char> = inputs.iter().flat_map(|g| g.chars()).collect();
+    indices.sort_unstable();
+    indices.dedup();
+    let output: Vec<char> = match output {
+        Some(output) => output.chars().collect(),
+        None => indices
+            .iter()
+            .copied()
+            .filter(|&c| {
+                inputs
+                    .iter()
+                    .flat_map(|g| g.chars())
+                    .filter(|&d| d == c)
+                    .count()
+                    == 1
+            })
+            .collect(),
+    };
+    if output.iter().any(|c| !indices.contains(c))
+        || (1..output.len()).any(|n| output[..n].contains(&output[n]))
+    {
+        return Err(ImthError::InvalidArgument(
+            "einsum output indices must be distinct and appear in an operand",
+        ));
+    }
+    if indices.len() > MAX_INDICES {
+        return Err(ImthError::InvalidArgument("einsum uses too many indices"));
+    }
+    for (group, operand) in inputs.iter().zip(operands) {
+        let size = u32::try_from(group.len())
+            .ok()
+            .and_then(|rank| dim.checked_pow(rank));
+        if size != Some(operand.len()) {
+            return Err(ImthError::InvalidArgument(
+                "einsum operand size does not match its indices",
+            ));
+        }
+    }
+
+    let slot = |c: char| indices.iter().position(|&d| d == c).unwrap_or(0);
+    let offset = |group: &[usize], values: &[usize]| {
+        group.iter().fold(0, |offset, &s| offset * dim + values[s])
+    };
+    let groups: Vec<Vec<usize>> = inputs
+        .iter()
+        .map(|g| g.chars().map(slot).collect())
+        .collect();
+    let output: Vec<usize> = output.into_iter().map(slot).collect();
+
+    let combinations = |rank: usize| {
+        u32::try_from(rank)
+            .ok()
+            .and_then(|rank| dim.checked_pow(rank))
+            .ok_or(ImthError::InvalidArgument(
+                "einsum is too large to evaluate",
+            ))
+    };
+    let mut result = vec![0.0; combinations(output.len())?];
+    let mut values = vec![0; indices.len()];
+    for _ in 0..combinations(indices.len())? {
+        let term: f64 = groups
+            .iter()
+            .zip(operands)
+            .map(|(group, operand)| operand[offset(group, &values)])
+            .product();
+        result[offset(&output, &values)] += term;
+        // step to the next combination of index values, the last index fastest
+        for value in values.iter_mut().rev() {
+            *value += 1;
+            if *value < dim {
+                break;
+            }
+            *value = 0;
+        }
+    }
+    Ok(result)
+}
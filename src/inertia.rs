@@ -0,0 +1,153 @@
+//! # Inertia
+//!
+//! Mass and inertia tensors of rigid bodies.
+//!
+//! The inertia tensor about the center of mass is the symmetric matrix\
+//! **I** = ∫ (|**r**|<sup>2</sup>**E** - **r** **r**<sup>T</sup>) dm\
+//! whose diagonal holds the moments of inertia and whose off-diagonal entries are the
+//! negated products of inertia. It turns with the body like any other rank-2 tensor, so
+//! turning, principal axes and the isotropic part use the tensor operations of
+//! [`Matrix3`] shared with the stress and strain types.
+//!
+//! # Example
+//! ```rust
+//! use i_mth::inertia::RigidBodyInertia;
+//! use i_mth::vector3d::Vector3D;
+//!
+//! // a 2 kg rod 1 m long along z, spun end over end about x
+//! let rod = RigidBodyInertia::solid_cylinder(2.0, 0.01, 1.0);
+//! let omega = Vector3D::new(3.0, 0.0, 0.0);
+//!
+//! assert!((rod.moment_about(Vector3D::i()).unwrap() - (2.0 / 12.0 + 0.5 * 1e-4)).abs() < 1e-12);
+//! assert!((rod.kinetic_energy(omega) - 0.5 * 9.0 * rod.moment_about(Vector3D::i()).unwrap()).abs() < 1e-12);
+//!
+//! // about one end the parallel axis theorem adds m(L/2)²
+//! let end = rod.about_point(Vector3D::new(0.0, 0.0, -0.5));
+//! assert!((end[(0, 0)] - rod.tensor[(0, 0)] - 0.5).abs() < 1e-12);
+//! ```
+
+use crate::matrix3::Matrix3;
+use crate::vector3d::Vector3D;
+
+/// The mass of a rigid body and its inertia tensor about its center of mass.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct RigidBodyInertia {
+    /// Mass in kg.
+    pub mass: f64,
+    /// Inertia tensor about the center of mass in kg.m<sup>2</sup>.
+    pub tensor: Matrix3,
+}
+
+impl RigidBodyInertia {
+    /// Returns the body with the passed mass and inertia tensor about its center of mass.
+    #[inline]
+    pub fn new(mass: f64, tensor: Matrix3) -> Self {
+        Self { mass, tensor }
+    }
+
+    /// Returns the body with the passed principal moments of inertia about x, y and z.
+    #[inline]
+    pub fn from_principal(mass: f64, moments: Vector3D) -> Self {
+        Self::new(mass, Matrix3::diagonal(moments))
+    }
+
+    /// Returns a uniform solid sphere of mass `mass` and radius `radius`,
+    /// I = 2mr<sup>2</sup> / 5 about every axis.
+    #[inline]
+    pub fn solid_sphere(mass: f64, radius: f64) -> Self {
+        let i = 0.4 * mass * radius * radius;
+        Self::from_principal(mass, Vector3D::new(i, i, i))
+    }
+
+    /// Returns a uniform solid cylinder of mass `mass`, radius `radius` and length `length`
+    /// along z, I<sub>z</sub> = mr<sup>2</sup> / 2 and
+    /// I<sub>x</sub> = I<sub>y</sub> = m(3r<sup>2</sup> + L<sup>2</sup>) / 12.
+    #[inline]
+    pub fn solid_cylinder(mass: f64, radius: f64, length: f64) -> Self {
+        let transverse = mass * (3.0 * radius * radius + length * length) / 12.0;
+        Self::from_principal(
+            mass,
+            Vector3D::new(transverse, transverse, 0.5 * mass * radius * radius),
+        )
+    }
+
+    /// Returns a uniform solid cuboid of mass `mass` with sides `size` along x, y and z,
+    /// I<sub>x</sub> = m(b<sup>2</sup> + c<sup>2</sup>) / 12 and so on.
+    #[inline]
+    pub fn solid_cuboid(mass: f64, size: Vector3D) -> Self {
+        let (a2, b2, c2) = (size.x * size.x, size.y * size.y, size.z * size.z);
+        Self::from_principal(
+            mass,
+            Vector3D::new(b2 + c2, c2 + a2, a2 + b2).scale(mass / 12.0),
+        )
+    }
+
+    /// Returns this body turned by the passed rotation, see [`Matrix3::rotated`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::constants::PI;
+    /// use i_mth::inertia::RigidBodyInertia;
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // a plate turned 45° about z picks up a product of inertia
+    /// let plate = RigidBodyInertia::solid_cuboid(6.0, Vector3D::new(2.0, 1.0, 0.0));
+    /// let turned = plate.rotated(&Matrix3::rotation_z(PI / 4.0));
+    ///
+    /// assert!(turned.tensor[(0, 1)].abs() > 0.1);
+    /// let (moments, _) = turned.principal().unwrap();
+    /// assert!((moments[0] - 2.5).abs() < 1e-12 && (moments[2] - 0.5).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn rotated(&self, rotation: &Matrix3) -> Self {
+        Self::new(self.mass, self.tensor.rotated(rotation))
+    }
+
+    /// Returns the inertia tensor about the point at `offset` from the center of mass, by
+    /// the parallel axis theorem **I** + m(|**d**|<sup>2</sup>**E** - **d** **d**<sup>T</sup>).
+    pub fn about_point(&self, offset: Vector3D) -> Matrix3 {
+        let d = offset;
+        let outer = Matrix3::from_row_vectors(d.scale(d.x), d.scale(d.y), d.scale(d.z));
+        self.tensor + (Matrix3::IDENTITY.scale(d.squared_magnitude()) - outer).scale(self.mass)
+    }
+
+    /// Returns the moment of inertia about the axis through the center of mass along
+    /// `axis`, or None if `axis` has no length.
+    #[inline]
+    pub fn moment_about(&self, axis: Vector3D) -> Option<f64> {
+        let n = axis.normalized()?;
+        Some(n.dot(self.tensor * n))
+    }
+
+    /// Returns the principal moments of inertia in decreasing order with the principal axes
+    /// as the columns of an orthogonal matrix, or None if the tensor is not symmetric.
+    #[inline]
+    pub fn principal(&self) -> Option<([f64; 3], Matrix3)> {
+        self.tensor.symmetric_eigen()
+    }
+
+    /// Returns true if the body has the same moment of inertia about every axis through
+    /// its center of mass, like a sphere or a cube, so that the deviatoric part of its
+    /// tensor vanishes within `tol`.
+    #[inline]
+    pub fn is_isotropic(&self, tol: f64) -> bool {
+        self.tensor
+            .deviatoric_part()
+            .is_approx_equal_to(&Matrix3::zeros(), tol)
+    }
+
+    /// Returns the angular momentum **Iω** in kg.m<sup>2</sup>s<sup>-1</sup> of the body
+    /// turning at `omega` about its center of mass.
+    #[inline]
+    pub fn angular_momentum(&self, omega: Vector3D) -> Vector3D {
+        self.tensor * omega
+    }
+
+    /// Returns the rotational kinetic energy ω · **Iω** / 2 in J of the body turning at
+    /// `omega` about its center of mass.
+    #[inline]
+    pub fn kinetic_energy(&self, omega: Vector3D) -> f64 {
+        0.5 * omega.dot(self.angular_momentum(omega))
+    }
+}
@@ -0,0 +1,1060 @@
+//! # Integrators
+//!
+//! Fixed step numerical integrators for simulating dynamics problems that have no
+//! closed-form solution.
+//!
+//! First order systems are written as dy/dt = f(t, y) and advanced through the [`Integrator`]
+//! trait. Second order systems (the usual F = ma problem) are written as
+//! d<sup>2</sup>x/dt<sup>2</sup> = a(t, x, v) and advanced through the [`SecondOrderIntegrator`] trait.
+//!
+//! The state can be anything implementing [`StateVector`]: `f64`, the vector types,
+//! a `Vec` of states or a pair of states.
+//!
+//! [`Rk45`] additionally adapts its step size to meet an error tolerance.
+//!
+//! Integrations can watch [`Event`]s, scalar functions of the state whose zero crossings
+//! are located precisely and can stop the integration.
+
+use std::cell::Cell;
+
+use crate::error::ImthError;
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+/// A quantity that can be advanced by an integrator.
+pub trait StateVector: Clone {
+    /// Returns this state plus the passed state scaled by `value`.
+    fn add_scaled(&self, other: &Self, value: f64) -> Self;
+
+    /// Returns this state scaled by `value`.
+    fn scale(&self, value: f64) -> Self;
+
+    /// Returns a state of the same shape as this one with every component zero.
+    fn zero_like(&self) -> Self;
+
+    /// Returns the largest absolute value among the components of this state.
+    /// Used by adaptive integrators to measure errors.
+    fn max_norm(&self) -> f64;
+
+    /// Returns true if this state and `other` have the same number of components, so
+    /// that [`StateVector::add_scaled`] can combine them. Always true for states of a
+    /// fixed size.
+    #[inline]
+    fn same_shape(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl StateVector for f64 {
+    #[inline]
+    fn add_scaled(&self, other: &Self, value: f64) -> Self {
+        self + other * value
+    }
+
+    #[inline]
+    fn scale(&self, value: f64) -> Self {
+        self * value
+    }
+
+    #[inline]
+    fn zero_like(&self) -> Self {
+        0.0
+    }
+
+    #[inline]
+    fn max_norm(&self) -> f64 {
+        self.abs()
+    }
+}
+
+impl StateVector for Vector2D {
+    #[inline]
+    fn add_scaled(&self, other: &Self, value: f64) -> Self {
+        Vector2D::add_scaled(*self, *other, value)
+    }
+
+    #[inline]
+    fn scale(&self, value: f64) -> Self {
+        Vector2D::scale(self, value)
+    }
+
+    #[inline]
+    fn zero_like(&self) -> Self {
+        Vector2D::origin()
+    }
+
+    #[inline]
+    fn max_norm(&self) -> f64 {
+        self.x.abs().max(self.y.abs())
+    }
+}
+
+impl StateVector for Vector3D {
+    #[inline]
+    fn add_scaled(&self, other: &Self, value: f64) -> Self {
+        Vector3D::add_scaled(*self, *other, value)
+    }
+
+    #[inline]
+    fn scale(&self, value: f64) -> Self {
+        Vector3D::scale(self, value)
+    }
+
+    #[inline]
+    fn zero_like(&self) -> Self {
+        Vector3D::origin()
+    }
+
+    #[inline]
+    fn max_norm(&self) -> f64 {
+        self.x.abs().max(self.y.abs()).max(self.z.abs())
+    }
+}
+
+/// A state of any number of components. Combining two states of different lengths panics,
+/// so the integration functions and [`Rk45`] return an error when a derivative does not
+/// match the state, while stepping an [`Integrator`] directly panics.
+impl<S: StateVector> StateVector for Vec<S> {
+    #[inline]
+    fn add_scaled(&self, other: &Self, value: f64) -> Self {
+        assert_eq!(self.len(), other.len(), "states must have the same length");
+        self.iter()
+            .zip(other)
+            .map(|(a, b)| a.add_scaled(b, value))
+            .collect()
+    }
+
+    #[inline]
+    fn scale(&self, value: f64) -> Self {
+        self.iter().map(|s| s.scale(value)).collect()
+    }
+
+    #[inline]
+    fn zero_like(&self) -> Self {
+        self.iter().map(StateVector::zero_like).collect()
+    }
+
+    #[inline]
+    fn max_norm(&self) -> f64 {
+        self.iter().fold(0.0, |acc, s| acc.max(s.max_norm()))
+    }
+
+    #[inline]
+    fn same_shape(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.same_shape(b))
+    }
+}
+
+impl<A: StateVector, B: StateVector> StateVector for (A, B) {
+    #[inline]
+    fn add_scaled(&self, other: &Self, value: f64) -> Self {
+        (
+            self.0.add_scaled(&other.0, value),
+            self.1.add_scaled(&other.1, value),
+        )
+    }
+
+    #[inline]
+    fn scale(&self, value: f64) -> Self {
+        (self.0.scale(value), self.1.scale(value))
+    }
+
+    #[inline]
+    fn zero_like(&self) -> Self {
+        (self.0.zero_like(), self.1.zero_like())
+    }
+
+    #[inline]
+    fn max_norm(&self) -> f64 {
+        self.0.max_norm().max(self.1.max_norm())
+    }
+
+    #[inline]
+    fn same_shape(&self, other: &Self) -> bool {
+        self.0.same_shape(&other.0) && self.1.same_shape(&other.1)
+    }
+}
+
+/// A scheme advancing a first order system dy/dt = f(t, y).
+pub trait Integrator {
+    /// Returns the state at time `t + dt` given the state `y` at time `t`.
+    fn step<S, F>(&self, f: F, t: f64, y: &S, dt: f64) -> S
+    where
+        S: StateVector,
+        F: Fn(f64, &S) -> S;
+}
+
+/// A scheme advancing a second order system d<sup>2</sup>x/dt<sup>2</sup> = a(t, x, v).
+pub trait SecondOrderIntegrator {
+    /// Returns the position and velocity at time `t + dt` given the position `x`
+    /// and velocity `v` at time `t`.
+    fn step<S, A>(&self, a: A, t: f64, x: &S, v: &S, dt: f64) -> (S, S)
+    where
+        S: StateVector,
+        A: Fn(f64, &S, &S) -> S;
+}
+
+/// Explicit (forward) Euler. First order and not energy conserving, mostly useful as a
+/// baseline to compare other schemes against.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct ExplicitEuler;
+
+impl Integrator for ExplicitEuler {
+    #[inline]
+    fn step<S, F>(&self, f: F, t: f64, y: &S, dt: f64) -> S
+    where
+        S: StateVector,
+        F: Fn(f64, &S) -> S,
+    {
+        y.add_scaled(&f(t, y), dt)
+    }
+}
+
+impl SecondOrderIntegrator for ExplicitEuler {
+    #[inline]
+    fn step<S, A>(&self, a: A, t: f64, x: &S, v: &S, dt: f64) -> (S, S)
+    where
+        S: StateVector,
+        A: Fn(f64, &S, &S) -> S,
+    {
+        (x.add_scaled(v, dt), v.add_scaled(&a(t, x, v), dt))
+    }
+}
+
+/// Semi-implicit (symplectic) Euler: the velocity is updated first and the new velocity is
+/// used to update the position. First order but with a bounded energy error, which makes it
+/// the usual choice for game-style particle simulations.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct SemiImplicitEuler;
+
+impl SecondOrderIntegrator for SemiImplicitEuler {
+    #[inline]
+    fn step<S, A>(&self, a: A, t: f64, x: &S, v: &S, dt: f64) -> (S, S)
+    where
+        S: StateVector,
+        A: Fn(f64, &S, &S) -> S,
+    {
+        let v_new = v.add_scaled(&a(t, x, v), dt);
+        (x.add_scaled(&v_new, dt), v_new)
+    }
+}
+
+/// Velocity Verlet. Second order, time reversible and symplectic for forces that only depend
+/// on position, which makes it a good fit for orbits, springs and N-body problems.\
+/// Velocity dependent accelerations are evaluated at the end of the step with an Euler
+/// estimate of the new velocity, which loses the symplectic property.
+///
+/// # Example
+/// ```rust
+/// use i_mth::integrators::{integrate_second_order, VelocityVerlet};
+///
+/// // unit mass on a unit spring, energy stays put over many periods
+/// let trajectory = integrate_second_order(&VelocityVerlet, |_, x: &f64, _: &f64| -x, 0.0, 1.0, 0.0, 0.01, 10_000).unwrap();
+///
+/// for (_, x, v) in trajectory {
+///     assert!((0.5 * (x * x + v * v) - 0.5).abs() < 1e-4);
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct VelocityVerlet;
+
+impl SecondOrderIntegrator for VelocityVerlet {
+    fn step<S, A>(&self, a: A, t: f64, x: &S, v: &S, dt: f64) -> (S, S)
+    where
+        S: StateVector,
+        A: Fn(f64, &S, &S) -> S,
+    {
+        let a_start = a(t, x, v);
+        let x_new = x.add_scaled(v, dt).add_scaled(&a_start, 0.5 * dt * dt);
+        let a_end = a(t + dt, &x_new, &v.add_scaled(&a_start, dt));
+        let v_new = v
+            .add_scaled(&a_start, 0.5 * dt)
+            .add_scaled(&a_end, 0.5 * dt);
+        (x_new, v_new)
+    }
+}
+
+/// Drift-kick-drift leapfrog. Second order, time reversible and symplectic like
+/// [`VelocityVerlet`] but needing a single acceleration evaluation per step.\
+/// Velocity dependent accelerations are evaluated with the velocity at the start of the step.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Leapfrog;
+
+impl SecondOrderIntegrator for Leapfrog {
+    fn step<S, A>(&self, a: A, t: f64, x: &S, v: &S, dt: f64) -> (S, S)
+    where
+        S: StateVector,
+        A: Fn(f64, &S, &S) -> S,
+    {
+        let half_dt = 0.5 * dt;
+        let x_half = x.add_scaled(v, half_dt);
+        let v_new = v.add_scaled(&a(t + half_dt, &x_half, v), dt);
+        (x_half.add_scaled(&v_new, half_dt), v_new)
+    }
+}
+
+/// The classic fourth order Runge-Kutta method.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Rk4;
+
+impl Integrator for Rk4 {
+    fn step<S, F>(&self, f: F, t: f64, y: &S, dt: f64) -> S
+    where
+        S: StateVector,
+        F: Fn(f64, &S) -> S,
+    {
+        let half_dt = 0.5 * dt;
+        let k1 = f(t, y);
+        let k2 = f(t + half_dt, &y.add_scaled(&k1, half_dt));
+        let k3 = f(t + half_dt, &y.add_scaled(&k2, half_dt));
+        let k4 = f(t + dt, &y.add_scaled(&k3, dt));
+
+        y.add_scaled(&k1, dt / 6.0)
+            .add_scaled(&k2, dt / 3.0)
+            .add_scaled(&k3, dt / 3.0)
+            .add_scaled(&k4, dt / 6.0)
+    }
+}
+
+impl SecondOrderIntegrator for Rk4 {
+    #[inline]
+    fn step<S, A>(&self, a: A, t: f64, x: &S, v: &S, dt: f64) -> (S, S)
+    where
+        S: StateVector,
+        A: Fn(f64, &S, &S) -> S,
+    {
+        let state = (x.clone(), v.clone());
+        Integrator::step(
+            self,
+            |t, (x, v): &(S, S)| (v.clone(), a(t, x, v)),
+            t,
+            &state,
+            dt,
+        )
+    }
+}
+
+/// Integrates the first order system dy/dt = f(t, y) from `(t0, y0)` for `steps` steps of
+/// size `dt` and returns every `(t, y)` pair including the initial one.
+///
+/// Fails when `f` returns a derivative of a different shape than the state.
+///
+/// # Example
+/// ```rust
+/// use i_mth::integrators::{integrate, Rk4};
+///
+/// // exponential decay dy/dt = -y
+/// let trajectory = integrate(&Rk4, |_, y: &f64| -y, 0.0, 1.0, 0.1, 10).unwrap();
+/// let (t, y) = trajectory[10];
+///
+/// assert!((t - 1.0).abs() < 1e-12);
+/// assert!((y - (-1.0f64).exp()).abs() < 1e-6);
+///
+/// // a derivative with a component missing is an error, not a panic
+/// let truncated = integrate(&Rk4, |_, y: &Vec<f64>| vec![-y[0]], 0.0, vec![1.0, 2.0], 0.1, 10);
+/// assert!(truncated.is_err());
+/// ```
+pub fn integrate<I, S, F>(
+    integrator: &I,
+    f: F,
+    t0: f64,
+    y0: S,
+    dt: f64,
+    steps: usize,
+) -> Result<Vec<(f64, S)>, ImthError>
+where
+    I: Integrator,
+    S: StateVector,
+    F: Fn(f64, &S) -> S,
+{
+    let mismatch = Cell::new(false);
+    let f = checked_derivative(&f, &mismatch);
+    let mut trajectory = Vec::with_capacity(steps + 1);
+    let mut t = t0;
+    let mut y = y0;
+    for i in 0..steps {
+        let next = integrator.step(&f, t, &y, dt);
+        if mismatch.get() {
+            return Err(shape_error());
+        }
+        trajectory.push((t, y));
+        y = next;
+        t = t0 + (i + 1) as f64 * dt;
+    }
+    trajectory.push((t, y));
+    Ok(trajectory)
+}
+
+/// Integrates the second order system d<sup>2</sup>x/dt<sup>2</sup> = a(t, x, v) from `(t0, x0, v0)`
+/// for `steps` steps of size `dt` and returns every `(t, x, v)` triple including the initial one.
+///
+/// Fails when `x0` and `v0` or the acceleration returned by `a` differ in shape.
+pub fn integrate_second_order<I, S, A>(
+    integrator: &I,
+    a: A,
+    t0: f64,
+    x0: S,
+    v0: S,
+    dt: f64,
+    steps: usize,
+) -> Result<Vec<(f64, S, S)>, ImthError>
+where
+    I: SecondOrderIntegrator,
+    S: StateVector,
+    A: Fn(f64, &S, &S) -> S,
+{
+    if !x0.same_shape(&v0) {
+        return Err(shape_error());
+    }
+    let mismatch = Cell::new(false);
+    let a = |t: f64, x: &S, v: &S| {
+        let acceleration = a(t, x, v);
+        if acceleration.same_shape(x) && v.same_shape(x) {
+            acceleration
+        } else {
+            mismatch.set(true);
+            x.zero_like()
+        }
+    };
+    let mut trajectory = Vec::with_capacity(steps + 1);
+    let mut t = t0;
+    let mut x = x0;
+    let mut v = v0;
+    for i in 0..steps {
+        let (x_next, v_next) = integrator.step(a, t, &x, &v, dt);
+        if mismatch.get() {
+            return Err(shape_error());
+        }
+        trajectory.push((t, x, v));
+        x = x_next;
+        v = v_next;
+        t = t0 + (i + 1) as f64 * dt;
+    }
+    trajectory.push((t, x, v));
+    Ok(trajectory)
+}
+
+/// Returns `f` checking that every derivative it returns has the shape of the state, which
+/// sets `mismatch` and gives the state a zero derivative when it does not, so that the step
+/// can finish without panicking and be thrown away.
+fn checked_derivative<'a, S, F>(f: &'a F, mismatch: &'a Cell<bool>) -> impl Fn(f64, &S) -> S + 'a
+where
+    S: StateVector,
+    F: Fn(f64, &S) -> S,
+{
+    move |t, y| {
+        let derivative = f(t, y);
+        if derivative.same_shape(y) {
+            derivative
+        } else {
+            mismatch.set(true);
+            y.zero_like()
+        }
+    }
+}
+
+/// The error of a derivative that does not match the state.
+#[inline]
+fn shape_error() -> ImthError {
+    ImthError::InvalidArgument("f must return a derivative of the same shape as the state")
+}
+
+/// The kind of zero crossing of an event function that triggers the event.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EventDirection {
+    /// The event function goes from negative to zero or positive.
+    Rising,
+    /// The event function goes from positive to zero or negative.
+    Falling,
+    /// Any of the two.
+    Either,
+}
+
+impl EventDirection {
+    /// Returns true if going from `before` to `after` is a crossing in this direction.
+    #[inline]
+    fn is_crossing(self, before: f64, after: f64) -> bool {
+        let rising = before < 0.0 && after >= 0.0;
+        let falling = before > 0.0 && after <= 0.0;
+        match self {
+            EventDirection::Rising => rising,
+            EventDirection::Falling => falling,
+            EventDirection::Either => rising || falling,
+        }
+    }
+}
+
+/// A scalar function of the state whose zero crossings are watched during an integration,
+/// like the altitude of a projectile.
+pub struct Event<'a, S> {
+    /// The event function g(t, y). The event happens when it crosses zero.
+    pub function: &'a dyn Fn(f64, &S) -> f64,
+    /// Which crossings of zero trigger the event.
+    pub direction: EventDirection,
+    /// Stops the integration when the event happens.
+    pub terminal: bool,
+}
+
+impl<'a, S> Event<'a, S> {
+    /// Returns a new event watching the passed function.
+    #[inline]
+    pub fn new(
+        function: &'a dyn Fn(f64, &S) -> f64,
+        direction: EventDirection,
+        terminal: bool,
+    ) -> Self {
+        Self {
+            function,
+            direction,
+            terminal,
+        }
+    }
+}
+
+/// An event that happened during an integration.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EventOccurrence<S> {
+    /// Index of the event in the slice of events that was passed in.
+    pub event: usize,
+    /// Time at which the event function crossed zero.
+    pub t: f64,
+    /// State at the time of the crossing.
+    pub y: S,
+}
+
+/// The `(t, y)` pairs of an integration together with the event occurrences along it.
+pub type EventTrajectory<S> = (Vec<(f64, S)>, Vec<EventOccurrence<S>>);
+
+/// Integrates the first order system dy/dt = f(t, y) from `(t0, y0)` with fixed steps of
+/// size `dt` while watching the passed events, for at most `max_steps` steps.
+///
+/// The time of every zero crossing is refined by bisecting the step it happened in, re-running
+/// the integrator from the start of the step. The integration stops at the first terminal event,
+/// which then becomes the last point of the returned trajectory.
+///
+/// Fails when `f` returns a derivative of a different shape than the state.
+pub fn integrate_with_events<I, S, F>(
+    integrator: &I,
+    f: F,
+    t0: f64,
+    y0: S,
+    dt: f64,
+    max_steps: usize,
+    events: &[Event<S>],
+) -> Result<EventTrajectory<S>, ImthError>
+where
+    I: Integrator,
+    S: StateVector,
+    F: Fn(f64, &S) -> S,
+{
+    let mismatch = Cell::new(false);
+    let f = checked_derivative(&f, &mismatch);
+    let mut trajectory = vec![(t0, y0.clone())];
+    let mut occurred = Vec::new();
+    let mut t = t0;
+    let mut y = y0;
+    for i in 0..max_steps {
+        let t_new = t0 + (i + 1) as f64 * dt;
+        let y_new = integrator.step(&f, t, &y, dt);
+
+        let mut occurrences = find_events(
+            events,
+            |s| integrator.step(&f, t, &y, s - t),
+            t,
+            &y,
+            t_new,
+            &y_new,
+        );
+        if mismatch.get() {
+            return Err(shape_error());
+        }
+        if let Some(stop) = occurrences.iter().position(|o| events[o.event].terminal) {
+            occurrences.truncate(stop + 1);
+            trajectory.push((occurrences[stop].t, occurrences[stop].y.clone()));
+            occurred.append(&mut occurrences);
+            break;
+        }
+        occurred.append(&mut occurrences);
+
+        trajectory.push((t_new, y_new.clone()));
+        t = t_new;
+        y = y_new;
+    }
+    Ok((trajectory, occurred))
+}
+
+/// Returns the events crossing zero between `(t0, y0)` and `(t1, y1)` sorted by time,
+/// with `state_at` giving the state at any time in between.
+fn find_events<S, P>(
+    events: &[Event<S>],
+    state_at: P,
+    t0: f64,
+    y0: &S,
+    t1: f64,
+    y1: &S,
+) -> Vec<EventOccurrence<S>>
+where
+    S: StateVector,
+    P: Fn(f64) -> S,
+{
+    let mut occurrences = Vec::new();
+    for (index, event) in events.iter().enumerate() {
+        let g0 = (event.function)(t0, y0);
+        let g1 = (event.function)(t1, y1);
+        if !event.direction.is_crossing(g0, g1) {
+            continue;
+        }
+
+        let (mut a, mut b) = (t0, t1);
+        let mut ga = g0;
+        for _ in 0..EVENT_BISECTIONS {
+            let mid = 0.5 * (a + b);
+            if mid <= a || mid >= b {
+                break;
+            }
+            let g_mid = (event.function)(mid, &state_at(mid));
+            if event.direction.is_crossing(ga, g_mid) {
+                b = mid;
+            } else {
+                a = mid;
+                ga = g_mid;
+            }
+        }
+        let y = if b == t1 { y1.clone() } else { state_at(b) };
+        occurrences.push(EventOccurrence {
+            event: index,
+            t: b,
+            y,
+        });
+    }
+    occurrences.sort_by(|a, b| a.t.total_cmp(&b.t));
+    occurrences
+}
+
+/// Number of bisections used to locate an event inside a step, enough to reach f64 resolution.
+const EVENT_BISECTIONS: usize = 64;
+
+/// Adaptive step Dormand-Prince Runge-Kutta 5(4) integrator.
+///
+/// Every step is taken with a fifth order method and an embedded fourth order method, and
+/// the difference between the two is used as an estimate of the local error. Steps with an
+/// error larger than `atol + rtol * |y|` are rejected and retried with a smaller step.
+///
+/// Used through the [`Integrator`] trait it takes a single fifth order step of the passed size.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Rk45 {
+    /// Relative error tolerance per step.
+    pub rtol: f64,
+    /// Absolute error tolerance per step.
+    pub atol: f64,
+    /// Smallest step size allowed before giving up.
+    pub min_step: f64,
+    /// Largest step size allowed.
+    pub max_step: f64,
+    /// Maximum number of accepted and rejected steps before giving up.
+    pub max_steps: usize,
+    /// Keeps the data needed to evaluate the solution between the steps.
+    pub dense_output: bool,
+}
+
+impl Default for Rk45 {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            rtol: 1e-6,
+            atol: 1e-9,
+            min_step: 1e-12,
+            max_step: f64::INFINITY,
+            max_steps: 100_000,
+            dense_output: false,
+        }
+    }
+}
+
+/// One accepted step of an [`Rk45`] solution, able to evaluate the solution anywhere
+/// inside the step by cubic Hermite interpolation.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DenseSegment<S> {
+    /// Time at the start of the step.
+    pub t0: f64,
+    /// Time at the end of the step.
+    pub t1: f64,
+    /// State at the start of the step.
+    pub y0: S,
+    /// State at the end of the step.
+    pub y1: S,
+    /// Derivative at the start of the step.
+    pub f0: S,
+    /// Derivative at the end of the step.
+    pub f1: S,
+}
+
+impl<S: StateVector> DenseSegment<S> {
+    /// Returns the interpolated state at time `t`, which should lie within `[t0, t1]`.
+    pub fn evaluate(&self, t: f64) -> S {
+        let h = self.t1 - self.t0;
+        let s = (t - self.t0) / h;
+        let s2 = s * s;
+        let s3 = s2 * s;
+        let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+        let h10 = s3 - 2.0 * s2 + s;
+        let h01 = -2.0 * s3 + 3.0 * s2;
+        let h11 = s3 - s2;
+
+        self.y0
+            .scale(h00)
+            .add_scaled(&self.f0, h10 * h)
+            .add_scaled(&self.y1, h01)
+            .add_scaled(&self.f1, h11 * h)
+    }
+}
+
+/// The result of an adaptive [`Rk45`] integration.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Rk45Solution<S> {
+    /// Every accepted `(t, y)` pair including the initial one.
+    pub points: Vec<(f64, S)>,
+    /// The normalized error estimate of every accepted step. Values at or below one
+    /// met the tolerance.
+    pub errors: Vec<f64>,
+    /// Number of steps that were rejected and retried with a smaller step size.
+    pub rejected_steps: usize,
+    /// The interpolation data of every accepted step, empty unless `dense_output` was set.
+    pub segments: Vec<DenseSegment<S>>,
+    /// Every event occurrence in chronological order.
+    pub events: Vec<EventOccurrence<S>>,
+}
+
+impl<S: StateVector> Rk45Solution<S> {
+    /// Returns the final `(t, y)` pair of the solution.
+    #[inline]
+    pub fn last(&self) -> &(f64, S) {
+        &self.points[self.points.len() - 1]
+    }
+
+    /// Returns the interpolated state at time `t`, or None if dense output was not
+    /// requested or `t` is outside of the integrated interval.
+    pub fn evaluate(&self, t: f64) -> Option<S> {
+        let index = self.segments.partition_point(|segment| segment.t1 < t);
+        let segment = self.segments.get(index)?;
+        if t < segment.t0 {
+            return None;
+        }
+        Some(segment.evaluate(t))
+    }
+}
+
+// Dormand-Prince 5(4) tableau.
+const C: [f64; 6] = [1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+const A2: [f64; 1] = [1.0 / 5.0];
+const A3: [f64; 2] = [3.0 / 40.0, 9.0 / 40.0];
+const A4: [f64; 3] = [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0];
+const A5: [f64; 4] = [
+    19372.0 / 6561.0,
+    -25360.0 / 2187.0,
+    64448.0 / 6561.0,
+    -212.0 / 729.0,
+];
+const A6: [f64; 5] = [
+    9017.0 / 3168.0,
+    -355.0 / 33.0,
+    46732.0 / 5247.0,
+    49.0 / 176.0,
+    -5103.0 / 18656.0,
+];
+const B5: [f64; 6] = [
+    35.0 / 384.0,
+    0.0,
+    500.0 / 1113.0,
+    125.0 / 192.0,
+    -2187.0 / 6784.0,
+    11.0 / 84.0,
+];
+const E: [f64; 7] = [
+    71.0 / 57600.0,
+    0.0,
+    -71.0 / 16695.0,
+    71.0 / 1920.0,
+    -17253.0 / 339200.0,
+    22.0 / 525.0,
+    -1.0 / 40.0,
+];
+
+impl Rk45 {
+    /// Returns an integrator with the passed relative and absolute tolerances
+    /// and default step limits.
+    #[inline]
+    pub fn new(rtol: f64, atol: f64) -> Self {
+        Self {
+            rtol,
+            atol,
+            ..Self::default()
+        }
+    }
+
+    /// Integrates the first order system dy/dt = f(t, y) from `(t0, y0)` up to `t_end`,
+    /// adapting the step size to meet the tolerances.
+    ///
+    /// Fails when `t_end` is before `t0`, when `min_step` is not positive and finite or is
+    /// larger than `max_step`, when `f` returns a derivative of a different length than the
+    /// state, when the step size has to drop below `min_step` or when more than `max_steps`
+    /// steps are needed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::integrators::Rk45;
+    ///
+    /// // exponential decay dy/dt = -y
+    /// let solver = Rk45 { dense_output: true, ..Rk45::new(1e-8, 1e-10) };
+    /// let solution = solver.solve(|_, y: &f64| -y, 0.0, 1.0, 5.0).unwrap();
+    ///
+    /// let (t, y) = *solution.last();
+    /// assert_eq!(5.0, t);
+    /// assert!((y - (-5.0f64).exp()).abs() < 1e-8);
+    ///
+    /// let y_mid = solution.evaluate(2.5).unwrap();
+    /// assert!((y_mid - (-2.5f64).exp()).abs() < 1e-6);
+    ///
+    /// // a constant derivative is integrated exactly even with tight tolerances
+    /// let constant = Rk45::new(1e-9, 1e-15).solve(|_, _: &f64| 1e6, 0.0, 0.0, 1e-9).unwrap();
+    /// assert!((constant.last().1 - 1e-3).abs() < 1e-15);
+    ///
+    /// let inconsistent = Rk45 { min_step: 1.0, max_step: 0.1, ..Rk45::default() };
+    /// assert!(inconsistent.solve(|_, y: &f64| -y, 0.0, 1.0, 5.0).is_err());
+    ///
+    /// // a derivative with a component missing is an error, not a shorter state
+    /// let truncated = Rk45::default().solve(|_, y: &Vec<f64>| vec![-y[0]], 0.0, vec![1.0, 2.0], 1.0);
+    /// assert!(truncated.is_err());
+    ///
+    /// // also when it only goes missing inside a step
+    /// let inside = Rk45::default()
+    ///     .solve(|t, y: &Vec<f64>| if t > 0.0 { vec![-y[0]] } else { vec![0.0; 2] }, 0.0, vec![1.0, 2.0], 1.0);
+    /// assert!(inside.is_err());
+    /// ```
+    #[inline]
+    pub fn solve<S, F>(
+        &self,
+        f: F,
+        t0: f64,
+        y0: S,
+        t_end: f64,
+    ) -> Result<Rk45Solution<S>, ImthError>
+    where
+        S: StateVector,
+        F: Fn(f64, &S) -> S,
+    {
+        self.solve_with_events(f, t0, y0, t_end, &[])
+    }
+
+    /// Same as [`Rk45::solve`] but also watches the passed events. Every zero crossing is
+    /// located by refining the root on the interpolated solution of the step it happened in
+    /// and recorded in `events`. The integration stops at the first terminal event, which
+    /// then becomes the last point of the solution.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::integrators::{Event, EventDirection, Rk45};
+    /// use i_mth::vector2d::Vector2D;
+    ///
+    /// // projectile launched at 20 m/s and 45 degrees, stopping when it hits the ground
+    /// let v0 = 20.0 / 2f64.sqrt();
+    /// let state = (Vector2D::origin(), Vector2D::new(v0, v0));
+    /// let gravity = Vector2D::new(0.0, -9.81);
+    /// let ground = |_: f64, (x, _): &(Vector2D, Vector2D)| x.y;
+    ///
+    /// let solution = Rk45::default()
+    ///     .solve_with_events(
+    ///         |_, (_, v): &(Vector2D, Vector2D)| (*v, gravity),
+    ///         0.0,
+    ///         state,
+    ///         100.0,
+    ///         &[Event::new(&ground, EventDirection::Falling, true)],
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let impact = &solution.events[0];
+    /// assert!((impact.t - 2.0 * v0 / 9.81).abs() < 1e-9);
+    /// assert!((impact.y.0.x - 400.0 / 9.81).abs() < 1e-6);
+    /// assert_eq!(impact.t, solution.last().0);
+    /// ```
+    pub fn solve_with_events<S, F>(
+        &self,
+        f: F,
+        t0: f64,
+        y0: S,
+        t_end: f64,
+        events: &[Event<S>],
+    ) -> Result<Rk45Solution<S>, ImthError>
+    where
+        S: StateVector,
+        F: Fn(f64, &S) -> S,
+    {
+        if t_end < t0 {
+            return Err(ImthError::InvalidArgument("t_end must not be before t0"));
+        }
+        if !(self.min_step > 0.0 && self.min_step <= self.max_step) || self.min_step.is_infinite() {
+            return Err(ImthError::InvalidArgument(
+                "min_step must be positive, finite and no larger than max_step",
+            ));
+        }
+
+        let mut solution = Rk45Solution {
+            points: vec![(t0, y0.clone())],
+            errors: Vec::new(),
+            rejected_steps: 0,
+            segments: Vec::new(),
+            events: Vec::new(),
+        };
+        let mismatch = Cell::new(false);
+        let f = checked_derivative(&f, &mismatch);
+        let mut t = t0;
+        let mut y = y0;
+        let mut k1 = f(t, &y);
+        if mismatch.get() {
+            return Err(shape_error());
+        }
+        let mut h = ((t_end - t0) / 100.0).clamp(self.min_step, self.max_step);
+        let mut steps = 0;
+
+        while t < t_end {
+            if steps >= self.max_steps {
+                return Err(ImthError::MaxIterationsExceeded(steps));
+            }
+            steps += 1;
+
+            let last = t + h >= t_end;
+            if last {
+                h = t_end - t;
+            }
+
+            let (y_new, k7, err) = self.dormand_prince(&f, t, &y, &k1, h);
+            if mismatch.get() {
+                return Err(shape_error());
+            }
+            if err <= 1.0 {
+                let t_new = if last { t_end } else { t + h };
+                let mut segment = DenseSegment {
+                    t0: t,
+                    t1: t_new,
+                    y0: y,
+                    y1: y_new,
+                    f0: k1,
+                    f1: k7,
+                };
+
+                let mut occurrences = find_events(
+                    events,
+                    |s| segment.evaluate(s),
+                    segment.t0,
+                    &segment.y0,
+                    t_new,
+                    &segment.y1,
+                );
+                solution.errors.push(err);
+                if let Some(stop) = occurrences.iter().position(|o| events[o.event].terminal) {
+                    occurrences.truncate(stop + 1);
+                    let occurrence = &occurrences[stop];
+                    segment.t1 = occurrence.t;
+                    segment.y1 = occurrence.y.clone();
+                    segment.f1 = f(occurrence.t, &occurrence.y);
+                    if mismatch.get() {
+                        return Err(shape_error());
+                    }
+                    solution.points.push((occurrence.t, occurrence.y.clone()));
+                    solution.events.append(&mut occurrences);
+                    if self.dense_output {
+                        solution.segments.push(segment);
+                    }
+                    return Ok(solution);
+                }
+                solution.events.append(&mut occurrences);
+
+                t = t_new;
+                y = segment.y1.clone();
+                k1 = segment.f1.clone();
+                solution.points.push((t, y.clone()));
+                if self.dense_output {
+                    solution.segments.push(segment);
+                }
+            } else {
+                if h <= self.min_step {
+                    return Err(ImthError::StepSizeUnderflow { t, step: h });
+                }
+                solution.rejected_steps += 1;
+            }
+
+            let factor = if err == 0.0 {
+                5.0
+            } else {
+                (0.9 * err.powf(-0.2)).clamp(0.2, 5.0)
+            };
+            h = (h * factor).clamp(self.min_step, self.max_step);
+        }
+        Ok(solution)
+    }
+
+    /// Takes one Dormand-Prince step and returns the fifth order state, the derivative at the
+    /// end of the step and the normalized error estimate.
+    fn dormand_prince<S, F>(&self, f: &F, t: f64, y: &S, k1: &S, h: f64) -> (S, S, f64)
+    where
+        S: StateVector,
+        F: Fn(f64, &S) -> S,
+    {
+        let k2 = f(t + C[0] * h, &y.add_scaled(k1, A2[0] * h));
+        let k3 = f(
+            t + C[1] * h,
+            &y.add_scaled(k1, A3[0] * h).add_scaled(&k2, A3[1] * h),
+        );
+        let k4 = f(
+            t + C[2] * h,
+            &y.add_scaled(k1, A4[0] * h)
+                .add_scaled(&k2, A4[1] * h)
+                .add_scaled(&k3, A4[2] * h),
+        );
+        let k5 = f(
+            t + C[3] * h,
+            &y.add_scaled(k1, A5[0] * h)
+                .add_scaled(&k2, A5[1] * h)
+                .add_scaled(&k3, A5[2] * h)
+                .add_scaled(&k4, A5[3] * h),
+        );
+        let k6 = f(
+            t + C[4] * h,
+            &y.add_scaled(k1, A6[0] * h)
+                .add_scaled(&k2, A6[1] * h)
+                .add_scaled(&k3, A6[2] * h)
+                .add_scaled(&k4, A6[3] * h)
+                .add_scaled(&k5, A6[4] * h),
+        );
+        let y_new = y
+            .add_scaled(k1, B5[0] * h)
+            .add_scaled(&k3, B5[2] * h)
+            .add_scaled(&k4, B5[3] * h)
+            .add_scaled(&k5, B5[4] * h)
+            .add_scaled(&k6, B5[5] * h);
+        let k7 = f(t + C[5] * h, &y_new);
+
+        let error = k1
+            .scale(E[0] * h)
+            .add_scaled(&k3, E[2] * h)
+            .add_scaled(&k4, E[3] * h)
+            .add_scaled(&k5, E[4] * h)
+            .add_scaled(&k6, E[5] * h)
+            .add_scaled(&k7, E[6] * h);
+        let scale = self.atol + self.rtol * y.max_norm().max(y_new.max_norm());
+
+        (y_new, k7, error.max_norm() / scale)
+    }
+}
+
+impl Integrator for Rk45 {
+    #[inline]
+    fn step<S, F>(&self, f: F, t: f64, y: &S, dt: f64) -> S
+    where
+        S: StateVector,
+        F: Fn(f64, &S) -> S,
+    {
+        let k1 = f(t, y);
+        self.dormand_prince(&f, t, y, &k1, dt).0
+    }
+}
@@ -0,0 +1,167 @@
+//! # Interpolation
+//!
+//! Querying sampled `(t, value)` data at arbitrary points, like sparse simulation output or
+//! tabulated material properties.
+//!
+//! The samples must be sorted by strictly increasing `t`. The values can be anything
+//! implementing [`StateVector`], so `f64`, `Vector2D` and `Vector3D` samples all work.
+//! Queries outside of the sampled range return None rather than extrapolating.
+
+use crate::calculus::combine;
+use crate::integrators::{DenseSegment, StateVector};
+
+/// Returns the value at `t` by linear interpolation between the two samples around it,
+/// or None if `t` is outside of the sampled range.
+///
+/// # Example
+/// ```rust
+/// use i_mth::interpolation::linear;
+///
+/// // yield strength of a steel in MPa against temperature in °C
+/// let table = [(20.0, 250.0), (200.0, 230.0), (400.0, 180.0)];
+///
+/// assert_eq!(Some(205.0), linear(&table, 300.0));
+/// assert_eq!(Some(230.0), linear(&table, 200.0));
+/// assert_eq!(Some(180.0), linear(&table, 400.0));
+/// assert_eq!(None, linear(&table, 500.0));
+/// ```
+pub fn linear<S: StateVector>(samples: &[(f64, S)], t: f64) -> Option<S> {
+    let k = interval(samples, |(t, _)| *t, t)?;
+    let (ta, ya) = &samples[k];
+    let (tb, yb) = &samples[k + 1];
+    let s = (t - ta) / (tb - ta);
+    Some(combine(&[(ya, 1.0 - s), (yb, s)]))
+}
+
+/// Returns the value at `t` by cubic Hermite interpolation of `(t, value, derivative)`
+/// samples, like positions and velocities recorded together, or None if `t` is outside
+/// of the sampled range.
+pub fn hermite<S: StateVector>(samples: &[(f64, S, S)], t: f64) -> Option<S> {
+    let k = interval(samples, |(t, _, _)| *t, t)?;
+    let (t0, y0, f0) = &samples[k];
+    let (t1, y1, f1) = &samples[k + 1];
+    let segment = DenseSegment {
+        t0: *t0,
+        t1: *t1,
+        y0: y0.clone(),
+        y1: y1.clone(),
+        f0: f0.clone(),
+        f1: f1.clone(),
+    };
+    Some(segment.evaluate(t))
+}
+
+/// A twice continuously differentiable piecewise cubic curve through a set of samples.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CubicSpline<S> {
+    times: Vec<f64>,
+    values: Vec<S>,
+    second_derivatives: Vec<S>,
+}
+
+impl<S: StateVector> CubicSpline<S> {
+    /// Returns the natural cubic spline (zero second derivative at both ends) through the
+    /// passed samples, or None if there are less than two samples or the times are not
+    /// strictly increasing.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::interpolation::CubicSpline;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // a trajectory stored every 0.5 s
+    /// let samples: Vec<(f64, Vector3D)> = (0..=8)
+    ///     .map(|i| i as f64 * 0.5)
+    ///     .map(|t| (t, Vector3D::new(t.sin(), t.cos(), 0.0)))
+    ///     .collect();
+    /// let spline = CubicSpline::natural(&samples).unwrap();
+    ///
+    /// let p = spline.evaluate(1.75).unwrap();
+    /// assert!((p - Vector3D::new(1.75f64.sin(), 1.75f64.cos(), 0.0)).magnitude() < 1e-3);
+    /// ```
+    pub fn natural(samples: &[(f64, S)]) -> Option<Self> {
+        let n = samples.len();
+        if n < 2 || samples.windows(2).any(|w| w[1].0 <= w[0].0) {
+            return None;
+        }
+        let times: Vec<f64> = samples.iter().map(|(t, _)| *t).collect();
+        let values: Vec<S> = samples.iter().map(|(_, y)| y.clone()).collect();
+        let zero = values[0].zero_like();
+        let mut second_derivatives = vec![zero.clone(); n];
+
+        // Thomas algorithm on the tridiagonal system for the interior second derivatives.
+        let mut upper = vec![0.0; n];
+        let mut rhs = vec![zero; n];
+        for i in 1..n - 1 {
+            let h0 = times[i] - times[i - 1];
+            let h1 = times[i + 1] - times[i];
+            let slope_change = combine(&[
+                (&values[i + 1], 6.0 / h1),
+                (&values[i], -6.0 / h1 - 6.0 / h0),
+                (&values[i - 1], 6.0 / h0),
+            ]);
+            let diagonal = 2.0 * (h0 + h1) - h0 * upper[i - 1];
+            upper[i] = h1 / diagonal;
+            rhs[i] = combine(&[
+                (&slope_change, 1.0 / diagonal),
+                (&rhs[i - 1], -h0 / diagonal),
+            ]);
+        }
+        for i in (1..n - 1).rev() {
+            second_derivatives[i] = rhs[i].add_scaled(&second_derivatives[i + 1], -upper[i]);
+        }
+
+        Some(Self {
+            times,
+            values,
+            second_derivatives,
+        })
+    }
+
+    /// Returns the value of the spline at `t`, or None if `t` is outside of the sampled range.
+    pub fn evaluate(&self, t: f64) -> Option<S> {
+        let k = interval(&self.times, |t| *t, t)?;
+        let h = self.times[k + 1] - self.times[k];
+        let a = (self.times[k + 1] - t) / h;
+        let b = 1.0 - a;
+        Some(combine(&[
+            (&self.values[k], a),
+            (&self.values[k + 1], b),
+            (&self.second_derivatives[k], (a * a * a - a) * h * h / 6.0),
+            (
+                &self.second_derivatives[k + 1],
+                (b * b * b - b) * h * h / 6.0,
+            ),
+        ]))
+    }
+
+    /// Returns the first derivative of the spline at `t`, or None if `t` is outside of the
+    /// sampled range.
+    pub fn derivative(&self, t: f64) -> Option<S> {
+        let k = interval(&self.times, |t| *t, t)?;
+        let h = self.times[k + 1] - self.times[k];
+        let a = (self.times[k + 1] - t) / h;
+        let b = 1.0 - a;
+        Some(combine(&[
+            (&self.values[k], -1.0 / h),
+            (&self.values[k + 1], 1.0 / h),
+            (&self.second_derivatives[k], -(3.0 * a * a - 1.0) * h / 6.0),
+            (
+                &self.second_derivatives[k + 1],
+                (3.0 * b * b - 1.0) * h / 6.0,
+            ),
+        ]))
+    }
+}
+
+/// Returns the index `k` of the sample interval `[t_k, t_k+1]` containing `t`, found by
+/// binary search over the sorted times given by `time`, or None if `t` is outside of the
+/// sampled range.
+fn interval<T>(samples: &[T], time: impl Fn(&T) -> f64, t: f64) -> Option<usize> {
+    let (first, last) = (samples.first()?, samples.last()?);
+    if samples.len() < 2 || !(time(first)..=time(last)).contains(&t) {
+        return None;
+    }
+    let after = samples.partition_point(|s| time(s) <= t);
+    Some(after.clamp(1, samples.len() - 1) - 1)
+}
@@ -0,0 +1,567 @@
+//! # Intervals
+//!
+//! Closed ranges of values with arithmetic that gives a range containing every possible
+//! result, for worst-case bounds on forces when the loads are only known within ranges.
+//!
+//! The arithmetic treats every occurrence of an interval as independent, so a result can be
+//! wider than the true range when a quantity appears more than once in a formula, like
+//! x - x. Responses that are linear in the loads, like the reactions and member forces of
+//! a truss, are bounded exactly by [`linear_response_bounds`] instead.
+//!
+//! # Example
+//! ```rust
+//! use i_mth::interval::{Interval, IntervalVector3D};
+//! use i_mth::vector3d::Vector3D;
+//!
+//! // moment of a force known to within ±5 N per component about the origin
+//! let force = IntervalVector3D::new(
+//!     Interval::new(95.0, 105.0),
+//!     Interval::new(-5.0, 5.0),
+//!     Interval::new(-5.0, 5.0),
+//! );
+//! let moment = IntervalVector3D::from(Vector3D::new(0.0, 2.0, 0.0)).cross(force);
+//!
+//! assert_eq!(Interval::new(-10.0, 10.0), moment.x);
+//! assert_eq!(Interval::new(-210.0, -190.0), moment.z);
+//! assert!(moment.contains(Vector3D::new(0.0, 2.0, 0.0).cross(Vector3D::new(100.0, 1.0, -3.0))));
+//! ```
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::error::ImthError;
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+/// The closed range of values from `lo` to `hi`.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Interval {
+    /// The smallest value of the range.
+    pub lo: f64,
+    /// The largest value of the range.
+    pub hi: f64,
+}
+
+impl Interval {
+    /// The range of all values, the result of dividing by an interval containing zero.
+    pub const ENTIRE: Interval = Interval {
+        lo: f64::NEG_INFINITY,
+        hi: f64::INFINITY,
+    };
+
+    /// Returns the range between the two passed values, in either order.
+    #[inline]
+    pub fn new(a: f64, b: f64) -> Self {
+        Self {
+            lo: a.min(b),
+            hi: a.max(b),
+        }
+    }
+
+    /// Returns the range holding only the passed value.
+    #[inline]
+    pub const fn point(value: f64) -> Self {
+        Self {
+            lo: value,
+            hi: value,
+        }
+    }
+
+    /// Returns the range `value` ± `radius`.
+    #[inline]
+    pub fn around(value: f64, radius: f64) -> Self {
+        Self::new(value - radius, value + radius)
+    }
+
+    /// Returns the smallest range containing all the passed values, or None if there are
+    /// none.
+    pub fn hull_of(values: &[f64]) -> Option<Self> {
+        let first = Self::point(*values.first()?);
+        Some(
+            values
+                .iter()
+                .fold(first, |hull, &v| hull.hull(Self::point(v))),
+        )
+    }
+
+    /// Returns the middle of the range.
+    #[inline]
+    pub fn midpoint(&self) -> f64 {
+        0.5 * (self.lo + self.hi)
+    }
+
+    /// Returns the width of the range, `hi - lo`.
+    #[inline]
+    pub fn width(&self) -> f64 {
+        self.hi - self.lo
+    }
+
+    /// Returns half the width of the range.
+    #[inline]
+    pub fn radius(&self) -> f64 {
+        0.5 * self.width()
+    }
+
+    /// Returns the largest size of a value in the range, the worst case of a force known
+    /// within it.
+    #[inline]
+    pub fn magnitude(&self) -> f64 {
+        self.lo.abs().max(self.hi.abs())
+    }
+
+    /// Returns the smallest size of a value in the range, zero if it contains zero.
+    #[inline]
+    pub fn min_magnitude(&self) -> f64 {
+        if self.contains(0.0) {
+            0.0
+        } else {
+            self.lo.abs().min(self.hi.abs())
+        }
+    }
+
+    /// Returns true if the passed value lies in the range.
+    #[inline]
+    pub fn contains(&self, value: f64) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    /// Returns true if the passed range lies entirely in this one.
+    #[inline]
+    pub fn encloses(&self, other: Interval) -> bool {
+        self.lo <= other.lo && other.hi <= self.hi
+    }
+
+    /// Returns the smallest range containing both this and the passed range.
+    #[inline]
+    pub fn hull(&self, other: Interval) -> Self {
+        Self {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+
+    /// Returns the values common to this and the passed range, or None if they do not
+    /// overlap.
+    #[inline]
+    pub fn intersection(&self, other: Interval) -> Option<Self> {
+        let (lo, hi) = (self.lo.max(other.lo), self.hi.min(other.hi));
+        (lo <= hi).then_some(Self { lo, hi })
+    }
+
+    /// Returns the range of the squares of the values in the range, which unlike
+    /// `x * x` never includes negative values.
+    #[inline]
+    pub fn sqr(&self) -> Self {
+        let (a, b) = (self.lo * self.lo, self.hi * self.hi);
+        if self.contains(0.0) {
+            Self {
+                lo: 0.0,
+                hi: a.max(b),
+            }
+        } else {
+            Self::new(a, b)
+        }
+    }
+
+    /// Returns the range of the values raised to the integer power `n`.
+    pub fn powi(&self, n: i32) -> Self {
+        match n {
+            0 => Self::point(1.0),
+            n if n < 0 => Self::point(1.0) / self.powi(-n),
+            n if n % 2 == 0 => {
+                let m = self.sqr();
+                Self::new(m.lo.powi(n / 2), m.hi.powi(n / 2))
+            }
+            n => Self::new(self.lo.powi(n), self.hi.powi(n)),
+        }
+    }
+
+    /// Returns the range of the square roots of the non-negative values in the range, or
+    /// None if it has none.
+    #[inline]
+    pub fn sqrt(&self) -> Option<Self> {
+        (self.hi >= 0.0).then(|| Self {
+            lo: self.lo.max(0.0).sqrt(),
+            hi: self.hi.sqrt(),
+        })
+    }
+
+    /// Returns the range of the sizes of the values in the range.
+    #[inline]
+    pub fn abs(&self) -> Self {
+        Self {
+            lo: self.min_magnitude(),
+            hi: self.magnitude(),
+        }
+    }
+}
+
+impl From<f64> for Interval {
+    /// Returns the range holding only the passed value.
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self::point(value)
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            lo: self.lo + rhs.lo,
+            hi: self.hi + rhs.hi,
+        }
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            lo: self.lo - rhs.hi,
+            hi: self.hi - rhs.lo,
+        }
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let products = [
+            self.lo * rhs.lo,
+            self.lo * rhs.hi,
+            self.hi * rhs.lo,
+            self.hi * rhs.hi,
+        ];
+        Self {
+            lo: products.iter().copied().fold(f64::INFINITY, f64::min),
+            hi: products.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+}
+
+/// Dividing by a range that contains zero gives [`Interval::ENTIRE`].
+impl Div for Interval {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        if rhs.contains(0.0) {
+            return Self::ENTIRE;
+        }
+        self * Self::new(1.0 / rhs.lo, 1.0 / rhs.hi)
+    }
+}
+
+impl Neg for Interval {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Add<f64> for Interval {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: f64) -> Self {
+        self + Self::point(rhs)
+    }
+}
+
+impl Sub<f64> for Interval {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: f64) -> Self {
+        self - Self::point(rhs)
+    }
+}
+
+impl Mul<f64> for Interval {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(self.lo * rhs, self.hi * rhs)
+    }
+}
+
+impl Div<f64> for Interval {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f64) -> Self {
+        self / Self::point(rhs)
+    }
+}
+
+impl Mul<Interval> for f64 {
+    type Output = Interval;
+    #[inline]
+    fn mul(self, rhs: Interval) -> Interval {
+        rhs * self
+    }
+}
+
+/// Formats as `[lo, hi]`, passing the precision on to both.
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "[{:.*}, {:.*}]", p, self.lo, p, self.hi),
+            None => write!(f, "[{}, {}]", self.lo, self.hi),
+        }
+    }
+}
+
+/// A vector in the plane with components known within ranges.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct IntervalVector2D {
+    pub x: Interval,
+    pub y: Interval,
+}
+
+impl IntervalVector2D {
+    /// Returns the vector with the passed component ranges.
+    #[inline]
+    pub const fn new(x: Interval, y: Interval) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the vector whose components range between those of the two passed vectors.
+    #[inline]
+    pub fn between(a: Vector2D, b: Vector2D) -> Self {
+        Self::new(Interval::new(a.x, b.x), Interval::new(a.y, b.y))
+    }
+
+    /// Returns the vector of the component midpoints.
+    #[inline]
+    pub fn midpoint(&self) -> Vector2D {
+        Vector2D::new(self.x.midpoint(), self.y.midpoint())
+    }
+
+    /// Returns true if every component of the passed vector lies in its range.
+    #[inline]
+    pub fn contains(&self, v: Vector2D) -> bool {
+        self.x.contains(v.x) && self.y.contains(v.y)
+    }
+
+    /// Returns the vector scaled by the passed range.
+    #[inline]
+    pub fn scale(&self, s: Interval) -> Self {
+        Self::new(self.x * s, self.y * s)
+    }
+
+    /// Returns the range of the dot product.
+    #[inline]
+    pub fn dot(&self, other: Self) -> Interval {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the range of the z component of the cross product, like the moment of a
+    /// force in the plane.
+    #[inline]
+    pub fn cross(&self, other: Self) -> Interval {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Returns the range of the magnitude.
+    #[inline]
+    pub fn magnitude(&self) -> Interval {
+        let squared = self.x.sqr() + self.y.sqr();
+        Interval::new(squared.lo.sqrt(), squared.hi.sqrt())
+    }
+}
+
+impl From<Vector2D> for IntervalVector2D {
+    /// Returns the vector with exactly known components.
+    #[inline]
+    fn from(v: Vector2D) -> Self {
+        Self::new(Interval::point(v.x), Interval::point(v.y))
+    }
+}
+
+impl Add for IntervalVector2D {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for IntervalVector2D {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// A vector in space with components known within ranges.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct IntervalVector3D {
+    pub x: Interval,
+    pub y: Interval,
+    pub z: Interval,
+}
+
+impl IntervalVector3D {
+    /// Returns the vector with the passed component ranges.
+    #[inline]
+    pub const fn new(x: Interval, y: Interval, z: Interval) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the vector whose components range between those of the two passed vectors.
+    #[inline]
+    pub fn between(a: Vector3D, b: Vector3D) -> Self {
+        Self::new(
+            Interval::new(a.x, b.x),
+            Interval::new(a.y, b.y),
+            Interval::new(a.z, b.z),
+        )
+    }
+
+    /// Returns the vector of the component midpoints.
+    #[inline]
+    pub fn midpoint(&self) -> Vector3D {
+        Vector3D::new(self.x.midpoint(), self.y.midpoint(), self.z.midpoint())
+    }
+
+    /// Returns true if every component of the passed vector lies in its range.
+    #[inline]
+    pub fn contains(&self, v: Vector3D) -> bool {
+        self.x.contains(v.x) && self.y.contains(v.y) && self.z.contains(v.z)
+    }
+
+    /// Returns the vector scaled by the passed range.
+    #[inline]
+    pub fn scale(&self, s: Interval) -> Self {
+        Self::new(self.x * s, self.y * s, self.z * s)
+    }
+
+    /// Returns the range of the dot product.
+    #[inline]
+    pub fn dot(&self, other: Self) -> Interval {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the ranges of the components of the cross product.
+    #[inline]
+    pub fn cross(&self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Returns the range of the magnitude.
+    #[inline]
+    pub fn magnitude(&self) -> Interval {
+        let squared = self.x.sqr() + self.y.sqr() + self.z.sqr();
+        Interval::new(squared.lo.sqrt(), squared.hi.sqrt())
+    }
+}
+
+impl From<Vector3D> for IntervalVector3D {
+    /// Returns the vector with exactly known components.
+    #[inline]
+    fn from(v: Vector3D) -> Self {
+        Self::new(
+            Interval::point(v.x),
+            Interval::point(v.y),
+            Interval::point(v.z),
+        )
+    }
+}
+
+impl Add for IntervalVector3D {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for IntervalVector3D {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+/// Returns the exact range of every output of `response` when each of its inputs varies
+/// independently over the passed ranges, for a response that is linear in its inputs,
+/// like the reactions and member forces of a structure as functions of its loads.
+///
+/// The response is evaluated once at the midpoints and once more per input, and the
+/// worst case of each output adds up the size of its change from every input. Fails with
+/// the first error of `response`, or if its outputs change in number.
+///
+/// # Example
+/// ```rust
+/// use i_mth::interval::{linear_response_bounds, Interval};
+/// use i_mth::truss::{Support2D, Truss2D};
+/// use i_mth::vector2d::Vector2D;
+///
+/// // the apex of a triangular truss carries 10 to 14 kN down and up to 1 kN sideways
+/// let truss = Truss2D::new(vec![
+///     Vector2D::new(0.0, 0.0),
+///     Vector2D::new(4.0, 0.0),
+///     Vector2D::new(2.0, 3.0),
+/// ])
+/// .with_member(0, 1)
+/// .with_member(0, 2)
+/// .with_member(1, 2)
+/// .with_support(0, Support2D::Pin)
+/// .with_support(1, Support2D::Roller(Vector2D::j()));
+/// let loads = [Interval::new(-1e3, 1e3), Interval::new(-14e3, -10e3)];
+/// let forces = linear_response_bounds(
+///     |p| {
+///         let loaded = truss.clone().with_load(2, Vector2D::new(p[0], p[1]));
+///         Ok(loaded.solve()?.member_forces)
+///     },
+///     &loads,
+/// )
+/// .unwrap();
+///
+/// // the bottom chord carries a third of the vertical load and half the horizontal one
+/// assert!((forces[0].lo - (10e3 / 3.0 - 500.0)).abs() < 1e-6);
+/// assert!((forces[0].hi - (14e3 / 3.0 + 500.0)).abs() < 1e-6);
+/// ```
+pub fn linear_response_bounds<F>(
+    response: F,
+    ranges: &[Interval],
+) -> Result<Vec<Interval>, ImthError>
+where
+    F: Fn(&[f64]) -> Result<Vec<f64>, ImthError>,
+{
+    let mut point: Vec<f64> = ranges.iter().map(Interval::midpoint).collect();
+    let middle = response(&point)?;
+    let mut spread = vec![0.0; middle.len()];
+    for (i, range) in ranges.iter().enumerate() {
+        if range.radius() == 0.0 {
+            continue;
+        }
+        point[i] = range.hi;
+        let shifted = response(&point)?;
+        point[i] = range.midpoint();
+        if shifted.len() != middle.len() {
+            return Err(ImthError::InvalidArgument(
+                "response changed its number of outputs",
+            ));
+        }
+        for ((s, m), r) in spread.iter_mut().zip(&middle).zip(&shifted) {
+            *s += (r - m).abs();
+        }
+    }
+    Ok(middle
+        .iter()
+        .zip(&spread)
+        .map(|(&m, &s)| Interval::around(m, s))
+        .collect())
+}
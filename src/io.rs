@@ -0,0 +1,211 @@
+//! # IO
+//!
+//! Reading and writing vectors and simulation results as CSV, so they can be opened in a
+//! spreadsheet or plotted without any serialization code. Enabled with the `io` feature.
+//!
+//! The first line of a file is a header naming the columns, and every following line is
+//! one record. Numbers are written in the shortest form that reads back to the same f64.
+//!
+//! # Example
+//! ```rust
+//! use i_mth::integrators::{integrate, Rk4};
+//! use i_mth::io::{read_trajectory, write_trajectory};
+//! use i_mth::vector2d::Vector2D;
+//!
+//! // x'' = -x as the state (x, v)
+//! let trajectory = integrate(&Rk4, |_, s: &Vector2D| Vector2D::new(s.y, -s.x), 0.0, Vector2D::new(1.0, 0.0), 0.1, 10).unwrap();
+//!
+//! let mut csv = Vec::new();
+//! write_trajectory(&mut csv, &trajectory).unwrap();
+//! assert!(String::from_utf8_lossy(&csv).starts_with("t,x,y\n0,1,0\n"));
+//!
+//! let read: Vec<(f64, Vector2D)> = read_trajectory(csv.as_slice()).unwrap();
+//! assert_eq!(trajectory, read);
+//! ```
+
+use std::io::{self, BufRead, Write};
+
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+/// A value that is stored as a fixed number of f64 columns in a CSV record.
+pub trait CsvRecord: Sized {
+    /// Returns the names of the columns of this type, each starting with `prefix`.
+    fn columns(prefix: &str) -> Vec<String>;
+
+    /// Appends the column values of this value to `fields`.
+    fn write_fields(&self, fields: &mut Vec<f64>);
+
+    /// Returns the value read from the next columns of `fields`, or None if there are too
+    /// few of them.
+    fn read_fields(fields: &mut dyn Iterator<Item = f64>) -> Option<Self>;
+}
+
+impl CsvRecord for f64 {
+    fn columns(prefix: &str) -> Vec<String> {
+        match prefix.trim_end_matches('.') {
+            "" => vec![String::from("value")],
+            name => vec![String::from(name)],
+        }
+    }
+
+    fn write_fields(&self, fields: &mut Vec<f64>) {
+        fields.push(*self);
+    }
+
+    fn read_fields(fields: &mut dyn Iterator<Item = f64>) -> Option<Self> {
+        fields.next()
+    }
+}
+
+impl CsvRecord for Vector2D {
+    fn columns(prefix: &str) -> Vec<String> {
+        vec![format!("{prefix}x"), format!("{prefix}y")]
+    }
+
+    fn write_fields(&self, fields: &mut Vec<f64>) {
+        fields.extend([self.x, self.y]);
+    }
+
+    fn read_fields(fields: &mut dyn Iterator<Item = f64>) -> Option<Self> {
+        Some(Vector2D::new(fields.next()?, fields.next()?))
+    }
+}
+
+impl CsvRecord for Vector3D {
+    fn columns(prefix: &str) -> Vec<String> {
+        vec![
+            format!("{prefix}x"),
+            format!("{prefix}y"),
+            format!("{prefix}z"),
+        ]
+    }
+
+    fn write_fields(&self, fields: &mut Vec<f64>) {
+        fields.extend([self.x, self.y, self.z]);
+    }
+
+    fn read_fields(fields: &mut dyn Iterator<Item = f64>) -> Option<Self> {
+        Some(Vector3D::new(
+            fields.next()?,
+            fields.next()?,
+            fields.next()?,
+        ))
+    }
+}
+
+/// The columns of a pair are those of the first value prefixed with `0.` followed by
+/// those of the second prefixed with `1.`, like `0.x,0.y,1.x,1.y` for a position and
+/// velocity.
+impl<A: CsvRecord, B: CsvRecord> CsvRecord for (A, B) {
+    fn columns(prefix: &str) -> Vec<String> {
+        let mut columns = A::columns(&format!("{prefix}0."));
+        columns.extend(B::columns(&format!("{prefix}1.")));
+        columns
+    }
+
+    fn write_fields(&self, fields: &mut Vec<f64>) {
+        self.0.write_fields(fields);
+        self.1.write_fields(fields);
+    }
+
+    fn read_fields(fields: &mut dyn Iterator<Item = f64>) -> Option<Self> {
+        Some((A::read_fields(fields)?, B::read_fields(fields)?))
+    }
+}
+
+/// Writes the passed values as CSV, one record per line after the header.
+///
+/// # Example
+/// ```rust
+/// use i_mth::io::{read_csv, write_csv};
+/// use i_mth::vector3d::Vector3D;
+///
+/// let loads = vec![Vector3D::new(0.0, -2.5, 0.0), Vector3D::new(1.5e3, 0.0, -0.125)];
+///
+/// let mut csv = Vec::new();
+/// write_csv(&mut csv, &loads).unwrap();
+/// assert_eq!("x,y,z\n0,-2.5,0\n1500,0,-0.125\n", String::from_utf8(csv.clone()).unwrap());
+/// assert_eq!(loads, read_csv::<_, Vector3D>(csv.as_slice()).unwrap());
+/// ```
+pub fn write_csv<W: Write, V: CsvRecord>(writer: W, values: &[V]) -> io::Result<()> {
+    write_rows(writer, V::columns(""), values.iter().map(|v| (None, v)))
+}
+
+/// Reads values written by [`write_csv`], skipping the header and any blank lines.
+pub fn read_csv<R: BufRead, V: CsvRecord>(reader: R) -> io::Result<Vec<V>> {
+    let rows = read_rows(reader, V::columns("").len())?;
+    Ok(rows
+        .into_iter()
+        .map(|fields| V::read_fields(&mut fields.into_iter()).expect("field count checked"))
+        .collect())
+}
+
+/// Writes `(t, state)` records, like the output of [`crate::integrators::integrate`], as
+/// CSV with the time in the first column.
+pub fn write_trajectory<W: Write, S: CsvRecord>(writer: W, records: &[(f64, S)]) -> io::Result<()> {
+    let mut columns = vec![String::from("t")];
+    columns.extend(S::columns(""));
+    write_rows(writer, columns, records.iter().map(|(t, s)| (Some(*t), s)))
+}
+
+/// Reads `(t, state)` records written by [`write_trajectory`], skipping the header and any
+/// blank lines.
+pub fn read_trajectory<R: BufRead, S: CsvRecord>(reader: R) -> io::Result<Vec<(f64, S)>> {
+    let rows = read_rows(reader, S::columns("").len() + 1)?;
+    Ok(rows
+        .into_iter()
+        .map(|fields| {
+            let mut fields = fields.into_iter();
+            let t = fields.next().expect("field count checked");
+            (t, S::read_fields(&mut fields).expect("field count checked"))
+        })
+        .collect())
+}
+
+fn write_rows<'a, W: Write, V: CsvRecord + 'a>(
+    mut writer: W,
+    columns: Vec<String>,
+    rows: impl Iterator<Item = (Option<f64>, &'a V)>,
+) -> io::Result<()> {
+    writeln!(writer, "{}", columns.join(","))?;
+    let mut fields = Vec::with_capacity(columns.len());
+    for (t, value) in rows {
+        fields.clear();
+        fields.extend(t);
+        value.write_fields(&mut fields);
+        let line: Vec<String> = fields.iter().map(|f| f.to_string()).collect();
+        writeln!(writer, "{}", line.join(","))?;
+    }
+    writer.flush()
+}
+
+/// Returns the fields of every record after the header, failing if a record does not have
+/// exactly `width` numbers.
+fn read_rows<R: BufRead>(reader: R, width: usize) -> io::Result<Vec<Vec<f64>>> {
+    let mut rows = Vec::new();
+    for (number, line) in reader.lines().enumerate().skip(1) {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields = line
+            .split(',')
+            .map(|f| f.trim().parse::<f64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| invalid(format!("line {}: {e}", number + 1)))?;
+        if fields.len() != width {
+            return Err(invalid(format!(
+                "line {}: expected {width} fields, found {}",
+                number + 1,
+                fields.len()
+            )));
+        }
+        rows.push(fields);
+    }
+    Ok(rows)
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
@@ -0,0 +1,701 @@
+//! # Kinematics
+//!
+//! Velocity and acceleration of points on rigid bodies: relative motion of two points of
+//! one body, wheels and discs rolling on a surface, and points moving relative to a turning
+//! frame of reference. Motion along a line and rotation about a fixed axis with constant
+//! acceleration are solved by [`LinearMotion`] and [`AngularMotion`].
+//!
+//! A body rolling without slipping turns about its contact point, which has no velocity at
+//! that instant. With **r** the position of the center relative to the contact point the
+//! center moves at **v**<sub>G</sub> = **ω** × **r** and, on a flat surface,
+//! **a**<sub>G</sub> = **α** × **r**.
+
+use crate::conversions::radians_to_revolutions;
+use crate::error::ImthError;
+use crate::polynomial::Polynomial;
+use crate::utils::ke;
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+/// Returns the velocity of the center of a wheel rolling without slipping with angular
+/// velocity `omega`, where `contact_to_center` goes from the contact point to the center.
+///
+/// # Example
+/// ```rust
+/// use i_mth::kinematics::{rolling_angular_velocity, rolling_center_velocity};
+/// use i_mth::vector3d::Vector3D;
+///
+/// // a 0.3 m wheel turning clockwise at 10 rad/s rolls to the right at 3 m/s
+/// let r = Vector3D::new(0.0, 0.3, 0.0);
+/// let v = rolling_center_velocity(Vector3D::new(0.0, 0.0, -10.0), r);
+///
+/// assert!((v - Vector3D::new(3.0, 0.0, 0.0)).magnitude() < 1e-12);
+/// assert!((rolling_angular_velocity(v, r).unwrap().z + 10.0).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn rolling_center_velocity(omega: Vector3D, contact_to_center: Vector3D) -> Vector3D {
+    omega.cross(contact_to_center)
+}
+
+/// Returns the angular velocity of a wheel rolling without slipping whose center moves
+/// with velocity `v_center`, where `contact_to_center` goes from the contact point to the
+/// center, or None if the radius is zero.
+#[inline]
+pub fn rolling_angular_velocity(
+    v_center: Vector3D,
+    contact_to_center: Vector3D,
+) -> Option<Vector3D> {
+    let r2 = contact_to_center.squared_magnitude();
+    (r2 > 0.0).then(|| contact_to_center.cross(v_center).scale(1.0 / r2))
+}
+
+/// Returns the acceleration of the center of a wheel rolling without slipping on a flat
+/// surface with angular acceleration `alpha`.
+#[inline]
+pub fn rolling_center_acceleration(alpha: Vector3D, contact_to_center: Vector3D) -> Vector3D {
+    alpha.cross(contact_to_center)
+}
+
+/// Returns the angular acceleration of a wheel rolling without slipping on a flat surface
+/// whose center accelerates at `a_center`, or None if the radius is zero.
+#[inline]
+pub fn rolling_angular_acceleration(
+    a_center: Vector3D,
+    contact_to_center: Vector3D,
+) -> Option<Vector3D> {
+    rolling_angular_velocity(a_center, contact_to_center)
+}
+
+/// Returns the velocity of a point of a wheel rolling without slipping with angular
+/// velocity `omega`, where `center_to_point` goes from the center to the point.
+/// The wheel turns about its contact point, so the point moves at
+/// **ω** × (**r** + **ρ**).
+///
+/// # Example
+/// ```rust
+/// use i_mth::kinematics::rolling_point_velocity;
+/// use i_mth::vector3d::Vector3D;
+///
+/// let r = Vector3D::new(0.0, 0.3, 0.0);
+/// let omega = Vector3D::new(0.0, 0.0, -10.0);
+///
+/// // the top of the wheel moves at twice the speed of the center and the contact point is still
+/// let top = rolling_point_velocity(omega, r, r);
+/// assert!((top - Vector3D::new(6.0, 0.0, 0.0)).magnitude() < 1e-12);
+/// assert!(rolling_point_velocity(omega, r, r.scale(-1.0)).magnitude() < 1e-12);
+/// ```
+#[inline]
+pub fn rolling_point_velocity(
+    omega: Vector3D,
+    contact_to_center: Vector3D,
+    center_to_point: Vector3D,
+) -> Vector3D {
+    omega.cross(contact_to_center + center_to_point)
+}
+
+/// Returns the acceleration of a point of a wheel rolling without slipping on a flat
+/// surface, **a**<sub>G</sub> + **α** × **ρ** - ω<sup>2</sup>**ρ**, where `center_to_point`
+/// lies in the plane of the wheel.\
+/// The contact point itself accelerates towards the center at ω<sup>2</sup>r.
+#[inline]
+pub fn rolling_point_acceleration(
+    omega: Vector3D,
+    alpha: Vector3D,
+    contact_to_center: Vector3D,
+    center_to_point: Vector3D,
+) -> Vector3D {
+    rolling_center_acceleration(alpha, contact_to_center) + alpha.cross(center_to_point)
+        - center_to_point.scale(omega.squared_magnitude())
+}
+
+/// Returns the velocity of point B of a rigid body turning with angular velocity `omega`,
+/// where A moves at `v_ref` and `r_rel` goes from A to B,\
+/// **v**<sub>B</sub> = **v**<sub>A</sub> + **ω** × **r**
+#[inline]
+pub fn velocity_of_point(v_ref: Vector3D, omega: Vector3D, r_rel: Vector3D) -> Vector3D {
+    v_ref + omega.cross(r_rel)
+}
+
+/// Returns the acceleration of point B of a rigid body turning with angular velocity
+/// `omega` and angular acceleration `alpha`, where A accelerates at `a_ref` and `r_rel`
+/// goes from A to B,\
+/// **a**<sub>B</sub> = **a**<sub>A</sub> + **α** × **r** + **ω** × (**ω** × **r**)
+///
+/// # Example
+/// ```rust
+/// use i_mth::kinematics::acceleration_of_point;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // the end of a 2 m bar turning about a fixed pin at 3 rad/s and speeding up at 4 rad/s²
+/// let a = acceleration_of_point(
+///     Vector3D::origin(),
+///     Vector3D::new(0.0, 0.0, 4.0),
+///     Vector3D::new(0.0, 0.0, 3.0),
+///     Vector3D::new(2.0, 0.0, 0.0),
+/// );
+///
+/// // 8 m/s² tangential and 18 m/s² towards the pin
+/// assert!((a - Vector3D::new(-18.0, 8.0, 0.0)).magnitude() < 1e-12);
+/// ```
+#[inline]
+pub fn acceleration_of_point(
+    a_ref: Vector3D,
+    alpha: Vector3D,
+    omega: Vector3D,
+    r_rel: Vector3D,
+) -> Vector3D {
+    a_ref + alpha.cross(r_rel) + omega.cross(omega.cross(r_rel))
+}
+
+/// Returns the velocity of point B of a body in plane motion turning anticlockwise at
+/// `omega` rad/s, where A moves at `v_ref` and `r_rel` goes from A to B,\
+/// **v**<sub>B</sub> = **v**<sub>A</sub> + ω**k** × **r**
+#[inline]
+pub fn velocity_of_point_2d(v_ref: Vector2D, omega: f64, r_rel: Vector2D) -> Vector2D {
+    v_ref + Vector2D::new(-r_rel.y, r_rel.x).scale(omega)
+}
+
+/// Returns the acceleration of point B of a body in plane motion turning anticlockwise at
+/// `omega` rad/s with angular acceleration `alpha`, where A accelerates at `a_ref` and
+/// `r_rel` goes from A to B,\
+/// **a**<sub>B</sub> = **a**<sub>A</sub> + α**k** × **r** - ω<sup>2</sup>**r**
+///
+/// # Example
+/// ```rust
+/// use i_mth::kinematics::acceleration_of_point_2d;
+/// use i_mth::vector2d::Vector2D;
+///
+/// let a = acceleration_of_point_2d(Vector2D::origin(), 4.0, 3.0, Vector2D::new(2.0, 0.0));
+///
+/// assert!((a - Vector2D::new(-18.0, 8.0)).magnitude() < 1e-12);
+/// ```
+#[inline]
+pub fn acceleration_of_point_2d(
+    a_ref: Vector2D,
+    alpha: f64,
+    omega: f64,
+    r_rel: Vector2D,
+) -> Vector2D {
+    a_ref + Vector2D::new(-r_rel.y, r_rel.x).scale(alpha) - r_rel.scale(omega * omega)
+}
+
+/// The motion of a wheel on a flat surface found by [`Wheel::motion`].
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct RollingMotion {
+    /// Acceleration of the center along the surface in ms<sup>-2</sup>.
+    pub acceleration: f64,
+    /// Angular acceleration of the wheel in rad s<sup>-2</sup>, positive when it turns in
+    /// the direction that rolls it forwards.
+    pub angular_acceleration: f64,
+    /// Friction force at the contact point in N, positive forwards.
+    pub friction: f64,
+    /// True if static friction cannot hold the contact point still, so the wheel slips.
+    pub slipping: bool,
+}
+
+/// A round body, like a wheel, disc, cylinder or sphere, that can roll on a surface.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Wheel {
+    /// Mass of the wheel in kg.
+    pub mass: f64,
+    /// Moment of inertia of the wheel about its center in kg.m<sup>2</sup>.
+    pub inertia: f64,
+    /// Rolling radius of the wheel in m.
+    pub radius: f64,
+}
+
+impl Wheel {
+    /// Returns the wheel with the passed mass, moment of inertia about its center and radius.
+    #[inline]
+    pub fn new(mass: f64, inertia: f64, radius: f64) -> Self {
+        Self {
+            mass,
+            inertia,
+            radius,
+        }
+    }
+
+    /// Returns a uniform solid disc or cylinder with the passed mass and radius.
+    #[inline]
+    pub fn solid_disc(mass: f64, radius: f64) -> Self {
+        Self::new(mass, 0.5 * mass * radius * radius, radius)
+    }
+
+    /// Returns a thin ring or hoop with the passed mass and radius.
+    #[inline]
+    pub fn ring(mass: f64, radius: f64) -> Self {
+        Self::new(mass, mass * radius * radius, radius)
+    }
+
+    /// Returns a uniform solid sphere with the passed mass and radius.
+    #[inline]
+    pub fn solid_sphere(mass: f64, radius: f64) -> Self {
+        Self::new(mass, 0.4 * mass * radius * radius, radius)
+    }
+
+    /// Returns the kinetic energy of this wheel rolling without slipping with its center
+    /// moving at `speed`, the sum of its translational and rotational kinetic energy.
+    #[inline]
+    pub fn rolling_ke(&self, speed: f64) -> f64 {
+        ke(
+            self.mass,
+            Vector3D::new(speed, 0.0, 0.0),
+            self.inertia,
+            speed / self.radius,
+        )
+    }
+
+    /// Returns the friction force in N, positive forwards, needed for this wheel to roll
+    /// without slipping under a force `force` along the surface through its center and a
+    /// torque `torque` that turns it forwards.\
+    /// f = (mrT - IF) / (I + mr<sup>2</sup>)
+    #[inline]
+    pub fn friction_required(&self, force: f64, torque: f64) -> f64 {
+        let (m, i, r) = (self.mass, self.inertia, self.radius);
+        (m * r * torque - i * force) / (i + m * r * r)
+    }
+
+    /// Returns the motion of this wheel on a flat surface, starting from rolling, under a
+    /// force `force` along the surface through its center and a torque `torque` that turns it
+    /// forwards. The wheel rolls if the friction it needs is within μ<sub>s</sub>N, and
+    /// otherwise slips with kinetic friction μ<sub>k</sub>N acting the same way.
+    ///
+    /// On an incline, pass the component of the weight down the slope as `force` and the
+    /// normal reaction as `normal_force`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::kinematics::Wheel;
+    ///
+    /// // a 2 kg solid cylinder of radius 0.1 m on a 30° slope
+    /// let g = 9.81;
+    /// let theta = 30f64.to_radians();
+    /// let cylinder = Wheel::solid_disc(2.0, 0.1);
+    /// let down_slope = 2.0 * g * theta.sin();
+    /// let normal = 2.0 * g * theta.cos();
+    ///
+    /// let rough = cylinder.motion(down_slope, 0.0, normal, 0.5, 0.4);
+    /// assert!(!rough.slipping);
+    /// assert!((rough.acceleration - 2.0 / 3.0 * g * theta.sin()).abs() < 1e-12);
+    ///
+    /// let icy = cylinder.motion(down_slope, 0.0, normal, 0.1, 0.05);
+    /// assert!(icy.slipping);
+    /// assert!((icy.friction + 0.05 * normal).abs() < 1e-12);
+    /// ```
+    pub fn motion(
+        &self,
+        force: f64,
+        torque: f64,
+        normal_force: f64,
+        mu_s: f64,
+        mu_k: f64,
+    ) -> RollingMotion {
+        let required = self.friction_required(force, torque);
+        let slipping = required.abs() > mu_s * normal_force;
+        let friction = if slipping {
+            mu_k * normal_force * required.signum()
+        } else {
+            required
+        };
+        RollingMotion {
+            acceleration: (force + friction) / self.mass,
+            angular_acceleration: (torque - friction * self.radius) / self.inertia,
+            friction,
+            slipping,
+        }
+    }
+
+    /// Returns true if this wheel slips under the passed loads with a coefficient of
+    /// static friction `mu_s`.
+    #[inline]
+    pub fn is_slipping(&self, force: f64, torque: f64, normal_force: f64, mu_s: f64) -> bool {
+        self.friction_required(force, torque).abs() > mu_s * normal_force
+    }
+}
+
+/// A reference frame whose origin moves and whose axes turn, used to describe motion
+/// measured by an observer riding on a moving body, like a collar sliding along a
+/// turning rod.
+///
+/// All vectors, including the position, velocity and acceleration relative to the frame,
+/// are given in fixed axes that line up with the frame at the instant considered.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct RotatingFrame {
+    /// Position of the origin of the frame in m.
+    pub origin: Vector3D,
+    /// Velocity of the origin of the frame in ms<sup>-1</sup>.
+    pub velocity: Vector3D,
+    /// Acceleration of the origin of the frame in ms<sup>-2</sup>.
+    pub acceleration: Vector3D,
+    /// Angular velocity of the frame in rad s<sup>-1</sup>.
+    pub omega: Vector3D,
+    /// Angular acceleration of the frame in rad s<sup>-2</sup>.
+    pub alpha: Vector3D,
+}
+
+impl RotatingFrame {
+    /// Returns a frame turning about its fixed origin with the passed angular velocity and
+    /// angular acceleration.
+    #[inline]
+    pub fn new(omega: Vector3D, alpha: Vector3D) -> Self {
+        Self {
+            omega,
+            alpha,
+            ..Self::default()
+        }
+    }
+
+    /// Returns this frame with its origin at `origin`, moving with `velocity` and
+    /// `acceleration`.
+    #[inline]
+    pub fn with_origin(self, origin: Vector3D, velocity: Vector3D, acceleration: Vector3D) -> Self {
+        Self {
+            origin,
+            velocity,
+            acceleration,
+            ..self
+        }
+    }
+
+    /// Returns the absolute position of the point at `r_rel` from the origin of this frame.
+    #[inline]
+    pub fn absolute_position(&self, r_rel: Vector3D) -> Vector3D {
+        self.origin + r_rel
+    }
+
+    /// Returns the absolute velocity of a point at `r_rel` from the origin of this frame
+    /// moving at `v_rel` relative to it,\
+    /// **v** = **v**<sub>O</sub> + **ω** × **r** + **v**<sub>rel</sub>
+    #[inline]
+    pub fn absolute_velocity(&self, r_rel: Vector3D, v_rel: Vector3D) -> Vector3D {
+        self.velocity + self.omega.cross(r_rel) + v_rel
+    }
+
+    /// Returns the Coriolis acceleration 2**ω** × **v**<sub>rel</sub> of a point moving at
+    /// `v_rel` relative to this frame.
+    #[inline]
+    pub fn coriolis(&self, v_rel: Vector3D) -> Vector3D {
+        self.omega.cross(v_rel).scale(2.0)
+    }
+
+    /// Returns the centripetal acceleration **ω** × (**ω** × **r**) of the point of this
+    /// frame at `r_rel` from its origin.
+    #[inline]
+    pub fn centripetal(&self, r_rel: Vector3D) -> Vector3D {
+        self.omega.cross(self.omega.cross(r_rel))
+    }
+
+    /// Returns the absolute acceleration of a point at `r_rel` from the origin of this frame
+    /// moving at `v_rel` and accelerating at `a_rel` relative to it,\
+    /// **a** = **a**<sub>O</sub> + **α** × **r** + **ω** × (**ω** × **r**) + 2**ω** × **v**<sub>rel</sub> + **a**<sub>rel</sub>
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::kinematics::RotatingFrame;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // a collar 0.5 m along a rod turning at 2 rad/s, sliding outwards at 1 m/s
+    /// let rod = RotatingFrame::new(Vector3D::new(0.0, 0.0, 2.0), Vector3D::origin());
+    /// let r = Vector3D::new(0.5, 0.0, 0.0);
+    /// let v_rel = Vector3D::new(1.0, 0.0, 0.0);
+    ///
+    /// let v = rod.absolute_velocity(r, v_rel);
+    /// let a = rod.absolute_acceleration(r, v_rel, Vector3D::origin());
+    ///
+    /// assert!((v - Vector3D::new(1.0, 1.0, 0.0)).magnitude() < 1e-12);
+    /// // centripetal -ω²r along the rod and Coriolis 2ωv across it
+    /// assert!((a - Vector3D::new(-2.0, 4.0, 0.0)).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn absolute_acceleration(
+        &self,
+        r_rel: Vector3D,
+        v_rel: Vector3D,
+        a_rel: Vector3D,
+    ) -> Vector3D {
+        self.acceleration
+            + self.alpha.cross(r_rel)
+            + self.centripetal(r_rel)
+            + self.coriolis(v_rel)
+            + a_rel
+    }
+
+    /// Returns the velocity relative to this frame of a point at `r_rel` from its origin
+    /// moving with absolute velocity `v`.
+    #[inline]
+    pub fn relative_velocity(&self, r_rel: Vector3D, v: Vector3D) -> Vector3D {
+        v - self.velocity - self.omega.cross(r_rel)
+    }
+
+    /// Returns the acceleration relative to this frame of a point at `r_rel` from its origin
+    /// moving at `v_rel` relative to it with absolute acceleration `a`.
+    #[inline]
+    pub fn relative_acceleration(&self, r_rel: Vector3D, v_rel: Vector3D, a: Vector3D) -> Vector3D {
+        a - self.absolute_acceleration(r_rel, v_rel, Vector3D::origin())
+    }
+}
+
+/// Motion along a straight line with constant acceleration, described by the constant
+/// acceleration (SUVAT) equations\
+/// v = u + at\
+/// s = ut + at<sup>2</sup> / 2\
+/// v<sup>2</sup> = u<sup>2</sup> + 2as
+///
+/// # Example
+/// ```rust
+/// use i_mth::kinematics::LinearMotion;
+///
+/// // a car braking from 25 m/s at 5 m/s² stops in 62.5 m
+/// let stop = LinearMotion::solve(None, Some(25.0), Some(0.0), Some(-5.0), None).unwrap();
+/// assert!((stop.displacement - 62.5).abs() < 1e-12);
+/// assert!((stop.time - 5.0).abs() < 1e-12);
+///
+/// // and takes the first 40 m of that in 2 s
+/// let first = LinearMotion::solve(Some(40.0), Some(25.0), None, Some(-5.0), None).unwrap();
+/// assert!((first.time - 2.0).abs() < 1e-12);
+/// assert!(LinearMotion::solve(Some(40.0), None, None, None, Some(2.0)).is_err());
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct LinearMotion {
+    /// Displacement along the line in m.
+    pub displacement: f64,
+    /// Velocity at the start in m s<sup>-1</sup>.
+    pub initial_velocity: f64,
+    /// Velocity at the end in m s<sup>-1</sup>.
+    pub final_velocity: f64,
+    /// Constant acceleration in m s<sup>-2</sup>.
+    pub acceleration: f64,
+    /// Duration of the motion in s.
+    pub time: f64,
+}
+
+impl LinearMotion {
+    /// Returns the motion with the passed displacement, initial and final velocities,
+    /// acceleration and time.
+    #[inline]
+    pub fn new(
+        displacement: f64,
+        initial_velocity: f64,
+        final_velocity: f64,
+        acceleration: f64,
+        time: f64,
+    ) -> Self {
+        Self {
+            displacement,
+            initial_velocity,
+            final_velocity,
+            acceleration,
+            time,
+        }
+    }
+
+    /// Returns the motion with the two unknown quantities found from the three known ones.
+    /// When the time is unknown the earliest non-negative time that fits is taken.
+    ///
+    /// Fails if other than three quantities are known, the time is negative, or no motion
+    /// fits the known quantities.
+    pub fn solve(
+        displacement: Option<f64>,
+        initial_velocity: Option<f64>,
+        final_velocity: Option<f64>,
+        acceleration: Option<f64>,
+        time: Option<f64>,
+    ) -> Result<Self, ImthError> {
+        let [s, u, v, a, t] = solve_suvat([
+            displacement,
+            initial_velocity,
+            final_velocity,
+            acceleration,
+            time,
+        ])?;
+        Ok(Self::new(s, u, v, a, t))
+    }
+
+    /// Returns the average velocity s / t over the motion.
+    #[inline]
+    pub fn average_velocity(&self) -> f64 {
+        0.5 * (self.initial_velocity + self.final_velocity)
+    }
+
+    /// Returns the angular motion of a wheel of radius `radius` rolling without slipping
+    /// through this motion, or None if the radius is not positive.
+    #[inline]
+    pub fn rolling(&self, radius: f64) -> Option<AngularMotion> {
+        (radius > 0.0).then(|| {
+            AngularMotion::new(
+                self.displacement / radius,
+                self.initial_velocity / radius,
+                self.final_velocity / radius,
+                self.acceleration / radius,
+                self.time,
+            )
+        })
+    }
+}
+
+/// Rotation about a fixed axis with constant angular acceleration, the angular form of the
+/// constant acceleration (SUVAT) equations of [`LinearMotion`]\
+/// ω = ω<sub>0</sub> + αt\
+/// θ = ω<sub>0</sub>t + αt<sup>2</sup> / 2\
+/// ω<sup>2</sup> = ω<sub>0</sub><sup>2</sup> + 2αθ
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct AngularMotion {
+    /// Angle turned through in rad.
+    pub angle: f64,
+    /// Angular velocity at the start in rad s<sup>-1</sup>.
+    pub initial_velocity: f64,
+    /// Angular velocity at the end in rad s<sup>-1</sup>.
+    pub final_velocity: f64,
+    /// Constant angular acceleration in rad s<sup>-2</sup>.
+    pub acceleration: f64,
+    /// Duration of the motion in s.
+    pub time: f64,
+}
+
+impl AngularMotion {
+    /// Returns the motion with the passed angle, initial and final angular velocities,
+    /// angular acceleration and time.
+    #[inline]
+    pub fn new(
+        angle: f64,
+        initial_velocity: f64,
+        final_velocity: f64,
+        acceleration: f64,
+        time: f64,
+    ) -> Self {
+        Self {
+            angle,
+            initial_velocity,
+            final_velocity,
+            acceleration,
+            time,
+        }
+    }
+
+    /// Returns the motion with the two unknown quantities found from the three known ones.
+    /// When the time is unknown the earliest non-negative time that fits is taken.
+    ///
+    /// Fails if other than three quantities are known, the time is negative, or no motion
+    /// fits the known quantities.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::conversions::{radians_to_revolutions, rpm_to_rad_per_sec};
+    /// use i_mth::kinematics::AngularMotion;
+    ///
+    /// // a grinding wheel at 3000 rpm coasts to rest in 40 s
+    /// let w0 = rpm_to_rad_per_sec(3000.0);
+    /// let motion = AngularMotion::solve(None, Some(w0), Some(0.0), None, Some(40.0)).unwrap();
+    ///
+    /// assert!((motion.acceleration + w0 / 40.0).abs() < 1e-12);
+    /// assert!((radians_to_revolutions(motion.angle) - 1000.0).abs() < 1e-9);
+    ///
+    /// // the time to turn the first 500 revolutions of that
+    /// let alpha = motion.acceleration;
+    /// let half = AngularMotion::solve(Some(motion.angle / 2.0), Some(w0), None, Some(alpha), None)
+    ///     .unwrap();
+    /// assert!((half.time - 40.0 * (1.0 - 0.5f64.sqrt())).abs() < 1e-9);
+    /// assert!((half.final_velocity - w0 * 0.5f64.sqrt()).abs() < 1e-9);
+    /// ```
+    pub fn solve(
+        angle: Option<f64>,
+        initial_velocity: Option<f64>,
+        final_velocity: Option<f64>,
+        acceleration: Option<f64>,
+        time: Option<f64>,
+    ) -> Result<Self, ImthError> {
+        let [theta, w0, w, a, t] =
+            solve_suvat([angle, initial_velocity, final_velocity, acceleration, time])?;
+        Ok(Self::new(theta, w0, w, a, t))
+    }
+
+    /// Returns the average angular velocity θ / t over the motion.
+    #[inline]
+    pub fn average_velocity(&self) -> f64 {
+        0.5 * (self.initial_velocity + self.final_velocity)
+    }
+
+    /// Returns the angle turned through in revolutions.
+    #[inline]
+    pub fn revolutions(&self) -> f64 {
+        radians_to_revolutions(self.angle)
+    }
+}
+
+/// Returns the displacement, initial and final velocities, acceleration and time of a
+/// motion with constant acceleration, with the two unknown quantities found from the three
+/// known ones, shared by [`LinearMotion`] and [`AngularMotion`].
+fn solve_suvat(known: [Option<f64>; 5]) -> Result<[f64; 5], ImthError> {
+    let [displacement, initial_velocity, final_velocity, acceleration, time] = known;
+    if time.is_some_and(|t| t < 0.0) {
+        return Err(ImthError::InvalidArgument("time must not be negative"));
+    }
+    let no_motion = || ImthError::InvalidArgument("no motion fits the known quantities");
+    // the earliest non-negative root of a + bt + ct²
+    let earliest = |a: f64, b: f64, c: f64| {
+        Polynomial::new(vec![a, b, c])
+            .real_roots()
+            .into_iter()
+            .find(|t| *t >= 0.0)
+            .ok_or_else(no_motion)
+    };
+    let positive = |t: f64| if t > 0.0 { Ok(t) } else { Err(no_motion()) };
+
+    let solved = match (
+        displacement,
+        initial_velocity,
+        final_velocity,
+        acceleration,
+        time,
+    ) {
+        (None, Some(u), None, Some(a), Some(t)) => [u * t + 0.5 * a * t * t, u, u + a * t, a, t],
+        (None, None, Some(v), Some(a), Some(t)) => [v * t - 0.5 * a * t * t, v - a * t, v, a, t],
+        (None, Some(u), Some(v), None, Some(t)) => {
+            let t = positive(t)?;
+            [0.5 * (u + v) * t, u, v, (v - u) / t, t]
+        }
+        (None, Some(u), Some(v), Some(a), None) => {
+            if a == 0.0 {
+                return Err(no_motion());
+            }
+            let t = (v - u) / a;
+            if t < 0.0 {
+                return Err(no_motion());
+            }
+            [(v * v - u * u) / (2.0 * a), u, v, a, t]
+        }
+        (Some(s), None, None, Some(a), Some(t)) => {
+            let t = positive(t)?;
+            let average = s / t;
+            [s, average - 0.5 * a * t, average + 0.5 * a * t, a, t]
+        }
+        (Some(s), Some(u), None, None, Some(t)) => {
+            let t = positive(t)?;
+            let a = 2.0 * (s - u * t) / (t * t);
+            [s, u, 2.0 * s / t - u, a, t]
+        }
+        (Some(s), Some(u), None, Some(a), None) => {
+            let t = earliest(-s, u, 0.5 * a)?;
+            [s, u, u + a * t, a, t]
+        }
+        (Some(s), None, Some(v), None, Some(t)) => {
+            let t = positive(t)?;
+            let a = 2.0 * (v * t - s) / (t * t);
+            [s, 2.0 * s / t - v, v, a, t]
+        }
+        (Some(s), None, Some(v), Some(a), None) => {
+            let t = earliest(-s, v, -0.5 * a)?;
+            [s, v - a * t, v, a, t]
+        }
+        (Some(s), Some(u), Some(v), None, None) => {
+            let t = positive(2.0 * s / (u + v))?;
+            [s, u, v, (v - u) / t, t]
+        }
+        _ => {
+            return Err(ImthError::InvalidArgument(
+                "exactly three of the five quantities must be known",
+            ))
+        }
+    };
+    Ok(solved)
+}
@@ -33,60 +33,52 @@
 //! 1. Creating 2D and 3D vectors with i_mth
 //!
 //! ```rust
-//!    use i_mth::vector3d::Vector3D;
+//! use i_mth::vector3d::Vector3D;
 //!
-//!    fn main() {
-//!       // there are several available methods to create vectors
-//!        let v3d_0 = Vector3D::new(1.0, 1.0, 1.0);
-//!        let v3d_1 = Vector3D::set(1.0);
+//! // there are several available methods to create vectors
+//! let v3d_0 = Vector3D::new(1.0, 1.0, 1.0);
+//! let v3d_1 = Vector3D::set(1.0);
 //!
-//!        assert_eq!(v3d_0, v3d_1);
-//!    }
+//! assert_eq!(v3d_0, v3d_1);
 //! ```
 //!
 //! 2. Moment of a force About a **O**: The moment of a force **F** about  **O** can be defined as the vector product (cross product) of **r** and **F**.
-//! Where **r** is the position vector between the point of application of the force to the fixed reference point **O**.
-//! ie **Moment** = **r x F**
+//!    Where **r** is the position vector between the point of application of the force to the fixed reference point **O**.
+//!    ie **Moment** = **r x F**
 //!
 //! ```rust
-//!    use i_mth::vector3d::Vector3D;
+//! use i_mth::vector3d::Vector3D;
 //!
-//!    fn main() {
-//!        let f = Vector3D::new(400.0, 693.0, 0.0);
-//!        let r = Vector3D::new(-0.2, 0.16, 0.0);
+//! let f = Vector3D::new(400.0, 693.0, 0.0);
+//! let r = Vector3D::new(-0.2, 0.16, 0.0);
 //!
-//!        let moment = r.cross(f);
-//!        assert_eq!(-202.6, moment.z);
-//!    }
+//! let moment = r.cross(f);
+//! assert_eq!(-202.6, moment.z);
 //! ```
 //! 3. Using the utils to calculate [escape velocity](https://en.wikipedia.org/wiki/Escape_velocity#:~:text=More%20generally%2C%20escape%20velocity%20is,orbit%20(of%20any%20radius).): Escape velocity or escape speed is the minimum speed needed for a free, non-propelled object to escape from the gravitational influence of a primary body, thus reaching an infinite distance from it.
 //!
 //! ```rust
-//!     use i_mth::utils::calc_escape_velocity;
-//! 
-//!     fn main() {
-//!         let mass_of_moon = 7.342e22;
-//!         let radius_of_moon = 1737.4e3;
-//! 
-//!         let escape_vel_of_moon = calc_escape_velocity(mass_of_moon, radius_of_moon);
-//! 
-//!         println!("{} km/s", escape_vel_of_moon / 1000.0);
-//!     }
+//! use i_mth::utils::calc_escape_velocity;
+//!
+//! let mass_of_moon = 7.342e22;
+//! let radius_of_moon = 1737.4e3;
+//!
+//! let escape_vel_of_moon = calc_escape_velocity(mass_of_moon, radius_of_moon);
+//!
+//! println!("{} km/s", escape_vel_of_moon / 1000.0);
 //! ```
 //! 
 //! ### Supports fmt
 //! 
 //! ```rust
-//!     use i_mth::vector3d::Vector3D;
-//! 
-//!     fn main() {
-//!         let unit_i = Vector3D::i();
-//!         let unit_j = Vector3D::j();
-//! 
-//!         let unit_k = unit_i.cross(unit_j);
-//! 
-//!         println!("{}", unit_k);
-//!     }
+//! use i_mth::vector3d::Vector3D;
+//!
+//! let unit_i = Vector3D::i();
+//! let unit_j = Vector3D::j();
+//!
+//! let unit_k = unit_i.cross(unit_j);
+//!
+//! println!("{}", unit_k);
 //! ```
 //! 
 
@@ -94,6 +86,72 @@
 pub mod utils;
 pub mod constants;
 
+pub mod beam;
+pub mod buckling;
+pub mod calculus;
+pub mod conversions;
+pub mod drives;
+pub mod dynamics;
+pub mod electromagnetism;
+pub mod energy;
+pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fitting;
+pub mod fluids;
+pub mod frame;
+pub mod index_notation;
+pub mod inertia;
+pub mod integrators;
+pub mod interpolation;
+pub mod interval;
+#[cfg(feature = "io")]
+pub mod io;
+pub mod kinematics;
+mod macros;
+pub mod measurement;
+pub mod monte_carlo;
+pub mod nbody;
+pub mod orbital;
+pub mod particles;
+pub mod polynomial;
+#[cfg(feature = "precision")]
+pub mod precision;
+pub mod projectile;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod roots;
+pub mod rotations;
+pub mod scalar;
+pub mod screw;
+pub mod series;
+pub mod state_space;
+pub mod statistics;
+pub mod strain;
+pub mod stress;
+pub mod torsion;
+pub mod transform;
+pub mod truss;
+pub mod units;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 mod vectors;
+pub use vectors::bulk;
+pub use vectors::ordering;
 pub use vectors::vector2d;
-pub use vectors::vector3d;
\ No newline at end of file
+pub use vectors::vector3d;
+
+mod matrices;
+pub use matrices::matrix3;
+pub use matrices::matrixn;
+
+mod geometry;
+pub use geometry::aabb;
+pub use geometry::line;
+pub use geometry::polygon;
+pub use geometry::triangle;
+
+/// The default absolute tolerance used when comparing the results of floating point
+/// computations, such as checking that a rotated vector is perpendicular to its axis.
+pub const EPSILON: f64 = 1e-9;
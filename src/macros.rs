@@ -0,0 +1,49 @@
+//! # Macros
+//!
+//! Shorthand for building vectors.
+
+/// Creates a [`Vector2D`](crate::vector2d::Vector2D) from its components, or with both
+/// components set to one value.
+///
+/// # Example
+/// ```rust
+/// use i_mth::vec2;
+/// use i_mth::vector2d::Vector2D;
+///
+/// assert_eq!(Vector2D::new(1.0, 2.0), vec2!(1.0, 2.0));
+/// assert_eq!(Vector2D::set(3.0), vec2!(3.0));
+/// ```
+#[macro_export]
+macro_rules! vec2 {
+    ($x:expr, $y:expr $(,)?) => {
+        $crate::vector2d::Vector2D::new($x, $y)
+    };
+    ($value:expr) => {
+        $crate::vector2d::Vector2D::set($value)
+    };
+}
+
+/// Creates a [`Vector3D`](crate::vector3d::Vector3D) from its components, or with all
+/// components set to one value.
+///
+/// # Example
+/// ```rust
+/// use i_mth::vec3;
+/// use i_mth::vector3d::Vector3D;
+///
+/// let f = vec3!(400.0, 693.0, 0.0);
+/// let r = vec3!(-0.2, 0.16, 0.0);
+///
+/// assert_eq!(Vector3D::new(1.0, 2.0, 3.0), vec3!(1.0, 2.0, 3.0));
+/// assert_eq!(Vector3D::set(1.0), vec3!(1.0));
+/// assert_eq!(-202.6, r.cross(f).z);
+/// ```
+#[macro_export]
+macro_rules! vec3 {
+    ($x:expr, $y:expr, $z:expr $(,)?) => {
+        $crate::vector3d::Vector3D::new($x, $y, $z)
+    };
+    ($value:expr) => {
+        $crate::vector3d::Vector3D::set($value)
+    };
+}
@@ -0,0 +1,2 @@
+pub mod matrix3;
+pub mod matrixn;
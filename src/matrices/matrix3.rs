@@ -0,0 +1,759 @@
+use std::fmt;
+use std::ops::*;
+
+use crate::error::ImthError;
+use crate::vector3d::Vector3D;
+
+/// Maximum number of sweeps of the Jacobi eigenvalue method, which converges
+/// quadratically and needs far fewer for a 3 by 3 matrix.
+const JACOBI_SWEEPS: usize = 50;
+
+/// Represents a 3 by 3 matrix, stored row by row, like a rotation or an inertia tensor.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[repr(C)]
+pub struct Matrix3 {
+    pub rows: [[f64; 3]; 3],
+}
+
+impl Matrix3 {
+    /// Returns a matrix with the passed rows.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let m = Matrix3::new([[2.0, 0.0, 0.0], [0.0, 3.0, 1.0], [0.0, 0.0, 4.0]]);
+    ///
+    /// assert_eq!(24.0, m.determinant());
+    /// assert_eq!(Vector3D::new(2.0, 4.0, 4.0), m * Vector3D::set(1.0));
+    /// assert_eq!(Matrix3::identity(), m * m.inverse().unwrap());
+    /// ```
+    #[inline]
+    pub const fn new(rows: [[f64; 3]; 3]) -> Self {
+        Self { rows }
+    }
+
+    /// The default tolerance for [`Matrix3::is_approx_equal_to`], [`crate::EPSILON`].
+    pub const DEFAULT_TOL: f64 = crate::EPSILON;
+
+    /// The identity matrix.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// const GRAVITY: Vector3D = Vector3D::new(0.0, 0.0, -9.81);
+    /// const SWAP_XY: Matrix3 = Matrix3::from_row_vectors(Vector3D::j(), Vector3D::i(), Vector3D::k());
+    ///
+    /// assert_eq!(GRAVITY, Matrix3::IDENTITY * GRAVITY);
+    /// assert_eq!(Matrix3::IDENTITY, SWAP_XY * SWAP_XY);
+    /// ```
+    pub const IDENTITY: Self = Self::identity();
+
+    /// Returns the matrix with every entry set to zero.
+    #[inline]
+    pub const fn zeros() -> Self {
+        Self::new([[0.0; 3]; 3])
+    }
+
+    /// Returns the identity matrix.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self::diagonal(Vector3D::set(1.0))
+    }
+
+    /// Returns the matrix with the components of the passed vector on its diagonal and
+    /// zero elsewhere.
+    #[inline]
+    pub const fn diagonal(d: Vector3D) -> Self {
+        Self::new([[d.x, 0.0, 0.0], [0.0, d.y, 0.0], [0.0, 0.0, d.z]])
+    }
+
+    /// Returns the matrix with the passed vectors as its rows.
+    #[inline]
+    pub const fn from_row_vectors(r0: Vector3D, r1: Vector3D, r2: Vector3D) -> Self {
+        Self::new([[r0.x, r0.y, r0.z], [r1.x, r1.y, r1.z], [r2.x, r2.y, r2.z]])
+    }
+
+    /// Returns the matrix with the passed vectors as its columns.
+    #[inline]
+    pub fn from_column_vectors(c0: Vector3D, c1: Vector3D, c2: Vector3D) -> Self {
+        Self::from_row_vectors(c0, c1, c2).transpose()
+    }
+
+    /// Returns the rotation by `angle` radians anticlockwise about the x axis.
+    #[inline]
+    pub fn rotation_x(angle: f64) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::new([[1.0, 0.0, 0.0], [0.0, c, -s], [0.0, s, c]])
+    }
+
+    /// Returns the rotation by `angle` radians anticlockwise about the y axis.
+    #[inline]
+    pub fn rotation_y(angle: f64) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::new([[c, 0.0, s], [0.0, 1.0, 0.0], [-s, 0.0, c]])
+    }
+
+    /// Returns the rotation by `angle` radians anticlockwise about the z axis.
+    #[inline]
+    pub fn rotation_z(angle: f64) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::new([[c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    /// Returns the rotation by `angle` radians anticlockwise about the passed axis
+    /// (Rodrigues' formula), or None if the axis is the zero vector.
+    pub fn from_axis_angle(axis: Vector3D, angle: f64) -> Option<Self> {
+        let u = axis.normalized()?;
+        let (s, c) = angle.sin_cos();
+        let t = 1.0 - c;
+        Some(Self::new([
+            [
+                t * u.x * u.x + c,
+                t * u.x * u.y - s * u.z,
+                t * u.x * u.z + s * u.y,
+            ],
+            [
+                t * u.x * u.y + s * u.z,
+                t * u.y * u.y + c,
+                t * u.y * u.z - s * u.x,
+            ],
+            [
+                t * u.x * u.z - s * u.y,
+                t * u.y * u.z + s * u.x,
+                t * u.z * u.z + c,
+            ],
+        ]))
+    }
+
+    /// Returns the rotation by the magnitude of `w` in radians anticlockwise about its
+    /// direction, the identity for the zero vector.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let r = Matrix3::from_rotation_vector(Vector3D::new(0.0, 0.0, 0.5));
+    /// assert!(r.is_approx_equal_to(&Matrix3::rotation_z(0.5), 1e-15));
+    /// assert_eq!(Matrix3::IDENTITY, Matrix3::from_rotation_vector(Vector3D::origin()));
+    /// ```
+    pub fn from_rotation_vector(w: Vector3D) -> Self {
+        let theta2 = w.squared_magnitude();
+        let k = w.hat();
+        // sinθ/θ and (1 - cosθ)/θ² lose their precision for tiny angles, where their
+        // series are exact to f64 resolution
+        let (a, b) = if theta2 < 1e-8 {
+            (1.0 - theta2 / 6.0, 0.5 - theta2 / 24.0)
+        } else {
+            let theta = theta2.sqrt();
+            (theta.sin() / theta, (1.0 - theta.cos()) / theta2)
+        };
+        Self::IDENTITY + k.scale(a) + (k * k).scale(b)
+    }
+
+    /// Returns the passed row as a vector.
+    #[inline]
+    pub fn row(&self, row: usize) -> Vector3D {
+        let r = self.rows[row];
+        Vector3D::new(r[0], r[1], r[2])
+    }
+
+    /// Returns the passed column as a vector.
+    #[inline]
+    pub fn column(&self, col: usize) -> Vector3D {
+        Vector3D::new(self.rows[0][col], self.rows[1][col], self.rows[2][col])
+    }
+
+    /// Returns the vector of the skew-symmetric part of this matrix, the inverse of
+    /// [`Vector3D::hat`] for a cross product matrix.
+    #[inline]
+    pub fn vee(&self) -> Vector3D {
+        let m = &self.rows;
+        Vector3D::new(m[2][1] - m[1][2], m[0][2] - m[2][0], m[1][0] - m[0][1]).scale(0.5)
+    }
+
+    /// Returns the transpose of this matrix.
+    #[inline]
+    pub const fn transpose(&self) -> Self {
+        let m = &self.rows;
+        Self::new([
+            [m[0][0], m[1][0], m[2][0]],
+            [m[0][1], m[1][1], m[2][1]],
+            [m[0][2], m[1][2], m[2][2]],
+        ])
+    }
+
+    /// Returns true if this matrix equals its transpose, every entry within `tol` of its
+    /// mirror image across the diagonal, like an inertia tensor or a stress tensor.
+    #[inline]
+    pub fn is_symmetric(&self, tol: f64) -> bool {
+        [(0, 1), (0, 2), (1, 2)]
+            .iter()
+            .all(|&(i, j)| (self.rows[i][j] - self.rows[j][i]).abs() <= tol)
+    }
+
+    /// Returns true if the transpose of this matrix is its inverse within `tol`, so its
+    /// columns are orthonormal.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let r = Matrix3::from_axis_angle(Vector3D::new(1.0, 2.0, 2.0), 0.7).unwrap();
+    /// assert!(r.is_orthogonal(1e-12) && r.is_rotation(1e-12));
+    ///
+    /// // a reflection is orthogonal but not a rotation
+    /// let mirror = Matrix3::diagonal(Vector3D::new(1.0, 1.0, -1.0));
+    /// assert!(mirror.is_orthogonal(1e-12) && !mirror.is_rotation(1e-12));
+    ///
+    /// assert!(!r.scale(2.0).is_orthogonal(1e-12));
+    /// ```
+    #[inline]
+    pub fn is_orthogonal(&self, tol: f64) -> bool {
+        (self.transpose() * *self).is_approx_equal_to(&Self::IDENTITY, tol)
+    }
+
+    /// Returns true if this matrix is a proper rotation, orthogonal within `tol` with a
+    /// determinant of +1 rather than a reflection.
+    #[inline]
+    pub fn is_rotation(&self, tol: f64) -> bool {
+        self.is_orthogonal(tol) && self.determinant() > 0.0
+    }
+
+    /// Returns true if this matrix is the negative of its transpose within `tol`, like the
+    /// cross product matrix of an angular velocity.
+    #[inline]
+    pub fn is_skew_symmetric(&self, tol: f64) -> bool {
+        (*self + self.transpose()).is_approx_equal_to(&Self::zeros(), 2.0 * tol)
+    }
+
+    /// Returns the matrix exponential of this skew-symmetric matrix, the rotation by the
+    /// angle and about the axis of its vector [`Matrix3::vee`], or None if it is not
+    /// skew-symmetric within [`Matrix3::DEFAULT_TOL`] relative to its largest entry.\
+    /// Turning an orientation R by a constant angular velocity ω for a time t gives
+    /// e<sup>[ωt]</sup>R.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // spin at 2 rad/s about (1, 1, 0) in 100 steps of 0.01 s
+    /// let omega = Vector3D::new(1.0, 1.0, 0.0).normalize_or_zero().scale(2.0);
+    /// let step = omega.scale(0.01).hat().exp().unwrap();
+    /// let orientation = (0..100).fold(Matrix3::IDENTITY, |r, _| step * r);
+    ///
+    /// let expected = Matrix3::from_axis_angle(omega, 2.0).unwrap();
+    /// assert!(orientation.is_approx_equal_to(&expected, 1e-12));
+    /// assert!(Matrix3::IDENTITY.exp().is_none());
+    /// ```
+    pub fn exp(&self) -> Option<Self> {
+        let scale = self
+            .rows
+            .iter()
+            .flatten()
+            .fold(0.0f64, |m, e| m.max(e.abs()));
+        if !self.is_skew_symmetric(Self::DEFAULT_TOL * scale) {
+            return None;
+        }
+        Some(Self::from_rotation_vector(self.vee()))
+    }
+
+    /// Returns the rotation vector of this rotation, along its axis with a magnitude of its
+    /// angle in radians from 0 to π, or None if this matrix is not a rotation within
+    /// [`Matrix3::DEFAULT_TOL`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let w = Vector3D::new(0.3, -0.2, 0.9);
+    /// let r = Matrix3::from_rotation_vector(w);
+    /// assert!(r.rotation_vector().unwrap().is_approx_equal_to(w, 1e-15));
+    ///
+    /// // a half turn still gives its axis
+    /// let half = Matrix3::from_axis_angle(Vector3D::new(2.0, 1.0, 2.0), std::f64::consts::PI);
+    /// let w = half.unwrap().rotation_vector().unwrap();
+    /// assert!((w.magnitude() - std::f64::consts::PI).abs() < 1e-12);
+    /// assert!(w.is_parallel_to(Vector3D::new(2.0, 1.0, 2.0), 1e-12));
+    /// ```
+    pub fn rotation_vector(&self) -> Option<Vector3D> {
+        if !self.is_rotation(Self::DEFAULT_TOL) {
+            return None;
+        }
+        let v = self.vee();
+        let (s, c) = (v.magnitude(), 0.5 * (self.trace() - 1.0));
+        let angle = s.atan2(c);
+        if c > 0.0 {
+            // within a quarter turn the skew part holds the axis scaled by sinθ
+            return Some(if s > 0.0 { v.scale(angle / s) } else { v });
+        }
+        // the skew part vanishes towards a half turn, while the symmetric part
+        // (1 - cosθ)uuᵀ + cosθ I holds the axis as its largest column
+        let b = (*self + self.transpose()).scale(0.5) - Self::IDENTITY.scale(c);
+        let k = (0..3).max_by(|&i, &j| b.rows[i][i].total_cmp(&b.rows[j][j]))?;
+        let u = b.column(k).normalized()?;
+        let u = if u.dot(v) < 0.0 { u.scale(-1.0) } else { u };
+        Some(u.scale(angle))
+    }
+
+    /// Returns the unit axis and angle in radians from 0 to π of this rotation, the axis
+    /// being i for the identity, or None if this matrix is not a rotation within
+    /// [`Matrix3::DEFAULT_TOL`].
+    #[inline]
+    pub fn to_axis_angle(&self) -> Option<(Vector3D, f64)> {
+        let w = self.rotation_vector()?;
+        Some((w.normalize_or(Vector3D::i()), w.magnitude()))
+    }
+
+    /// Returns the matrix logarithm of this rotation, the skew-symmetric cross product
+    /// matrix of [`Matrix3::rotation_vector`], or None if this matrix is not a rotation.\
+    /// Interpolating between orientations R<sub>0</sub> and R<sub>1</sub> as
+    /// e<sup>s log(R<sub>1</sub>R<sub>0</sub><sup>T</sup>)</sup>R<sub>0</sub> turns at a
+    /// constant rate about a fixed axis.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrix3::Matrix3;
+    ///
+    /// let (r0, r1) = (Matrix3::rotation_x(0.4), Matrix3::rotation_y(1.1));
+    /// let delta = (r1 * r0.transpose()).log().unwrap();
+    /// let halfway = delta.scale(0.5).exp().unwrap() * r0;
+    ///
+    /// assert!(halfway.is_rotation(1e-12));
+    /// let end = delta.exp().unwrap() * r0;
+    /// assert!(end.is_approx_equal_to(&r1, 1e-12));
+    /// ```
+    #[inline]
+    pub fn log(&self) -> Option<Self> {
+        Some(self.rotation_vector()?.hat())
+    }
+
+    /// Returns the sum of the diagonal entries of this matrix.
+    #[inline]
+    pub fn trace(&self) -> f64 {
+        self.rows[0][0] + self.rows[1][1] + self.rows[2][2]
+    }
+
+    /// Returns the determinant of this matrix.
+    #[inline]
+    pub fn determinant(&self) -> f64 {
+        self.row(0).triple_scalar_prod(self.row(1), self.row(2))
+    }
+
+    /// Returns the inverse of this matrix, or None if it is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0.0 {
+            return None;
+        }
+        let (r0, r1, r2) = (self.row(0), self.row(1), self.row(2));
+        Some(Self::from_column_vectors(r1.cross(r2), r2.cross(r0), r0.cross(r1)).scale(1.0 / det))
+    }
+
+    /// Returns the inverse of this matrix, or a division by zero error if it is singular.
+    #[inline]
+    pub fn try_inverse(&self) -> Result<Self, ImthError> {
+        self.inverse().ok_or(ImthError::DivisionByZero)
+    }
+
+    /// Returns the entry at the passed row and column, or an error if either is larger
+    /// than 2, where indexing panics.
+    #[inline]
+    pub fn try_index(&self, row: usize, col: usize) -> Result<f64, ImthError> {
+        self.rows
+            .get(row)
+            .and_then(|r| r.get(col))
+            .copied()
+            .ok_or(ImthError::InvalidArgument("matrix index out of bounds"))
+    }
+
+    /// Returns true if every entry of this matrix is within `tol` of the matching entry of
+    /// the passed matrix.
+    #[inline]
+    pub fn is_approx_equal_to(&self, other: &Matrix3, tol: f64) -> bool {
+        (0..3).all(|i| (0..3).all(|j| (self.rows[i][j] - other.rows[i][j]).abs() <= tol))
+    }
+
+    /// Returns the double contraction **A** : **B**, the sum of the products of matching
+    /// entries of this and the passed tensor, like the strain energy density ½**σ** : **ε**.
+    #[inline]
+    pub fn double_contraction(&self, other: &Matrix3) -> f64 {
+        (0..3)
+            .map(|i| {
+                (0..3)
+                    .map(|j| self.rows[i][j] * other.rows[i][j])
+                    .sum::<f64>()
+            })
+            .sum()
+    }
+
+    /// Returns the spherical part of this tensor, the identity scaled by a third of its
+    /// trace, like the hydrostatic part of a stress.
+    #[inline]
+    pub fn spherical_part(&self) -> Self {
+        Self::IDENTITY.scale(self.trace() / 3.0)
+    }
+
+    /// Returns the deviatoric part of this tensor, what is left once the spherical part is
+    /// taken off, which has no trace.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::stress::StressState3D;
+    ///
+    /// let stress = StressState3D::new(80.0, -20.0, 10.0, 30.0, -15.0, 5.0);
+    /// let s = stress.to_matrix().deviatoric_part();
+    ///
+    /// assert!(s.trace().abs() < 1e-12);
+    /// assert!((s + stress.to_matrix().spherical_part()).is_approx_equal_to(&stress.to_matrix(), 1e-12));
+    /// // the von Mises stress is √(3/2 s:s)
+    /// assert!(((1.5 * s.double_contraction(&s)).sqrt() - stress.von_mises()).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn deviatoric_part(&self) -> Self {
+        *self - self.spherical_part()
+    }
+
+    /// Returns this tensor turned by the passed rotation, **RTR**<sup>T</sup>.\
+    /// The components of a tensor in a frame whose axes are the columns of **Q** are
+    /// `t.rotated(&q.transpose())`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::constants::PI;
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // a uniaxial stress along x seen from axes turned by 45° about z
+    /// let t = Matrix3::diagonal(Vector3D::new(100.0, 0.0, 0.0));
+    /// let axes = Matrix3::rotation_z(PI / 4.0);
+    /// let local = t.rotated(&axes.transpose());
+    ///
+    /// assert!((local[(0, 0)] - 50.0).abs() < 1e-12 && (local[(1, 1)] - 50.0).abs() < 1e-12);
+    /// assert!((local[(0, 1)] + 50.0).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn rotated(&self, rotation: &Matrix3) -> Self {
+        *rotation * *self * rotation.transpose()
+    }
+
+    /// Returns a matrix with every entry of this matrix scaled by the passed value.
+    #[inline]
+    pub fn scale(&self, value: f64) -> Self {
+        Self::new(self.rows.map(|r| r.map(|e| e * value)))
+    }
+
+    /// Returns the eigenvalues of this symmetric matrix in decreasing order with the matching
+    /// unit eigenvectors as the columns of an orthogonal matrix, found with the cyclic Jacobi
+    /// method. Returns None if the matrix is not symmetric within [`Matrix3::DEFAULT_TOL`]
+    /// relative to its largest entry.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrix3::Matrix3;
+    ///
+    /// let m = Matrix3::new([[2.0, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 5.0]]);
+    /// let (values, vectors) = m.symmetric_eigen().unwrap();
+    ///
+    /// assert!((values[0] - 5.0).abs() < 1e-12);
+    /// assert!((values[1] - 3.0).abs() < 1e-12);
+    /// assert!((values[2] - 1.0).abs() < 1e-12);
+    /// for i in 0..3 {
+    ///     let v = vectors.column(i);
+    ///     assert!((m * v - v.scale(values[i])).magnitude() < 1e-12);
+    /// }
+    /// ```
+    pub fn symmetric_eigen(&self) -> Option<([f64; 3], Matrix3)> {
+        let scale = self
+            .rows
+            .iter()
+            .flatten()
+            .fold(0.0f64, |m, e| m.max(e.abs()));
+        if !self.is_symmetric(Self::DEFAULT_TOL * scale) {
+            return None;
+        }
+
+        let mut a = self.rows;
+        let mut v = Self::IDENTITY.rows;
+        for _ in 0..JACOBI_SWEEPS {
+            let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+            if off <= (f64::EPSILON * scale).powi(2) {
+                break;
+            }
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + theta.hypot(1.0));
+                let c = 1.0 / t.hypot(1.0);
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (rp, rq) = (a[p], a[q]);
+                a[p] = [0, 1, 2].map(|k| c * rp[k] - s * rq[k]);
+                a[q] = [0, 1, 2].map(|k| s * rp[k] + c * rq[k]);
+                for row in v.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+            }
+        }
+
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| a[j][j].total_cmp(&a[i][i]));
+        let values = order.map(|i| a[i][i]);
+        let vectors = Self::new(v.map(|row| order.map(|i| row[i])));
+        Some((values, vectors))
+    }
+
+    /// Returns the singular value decomposition `(U, σ, V)` of this matrix, such that
+    /// `A = U diag(σ) Vᵀ` with `U` and `V` orthogonal and the singular values non-negative
+    /// in decreasing order, computed with the one-sided Jacobi method.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let m = Matrix3::new([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0], [0.0, 0.0, 0.0]]);
+    /// let (u, sigma, v) = m.svd();
+    ///
+    /// assert!((sigma[0] - 5.0).abs() < 1e-12);
+    /// assert!((sigma[1] - 3.0).abs() < 1e-12);
+    /// assert!(sigma[2].abs() < 1e-12);
+    /// assert!(u.is_orthogonal(1e-12) && v.is_orthogonal(1e-12));
+    /// let diag = Matrix3::diagonal(Vector3D::new(sigma[0], sigma[1], sigma[2]));
+    /// assert!((u * diag * v.transpose()).is_approx_equal_to(&m, 1e-12));
+    /// ```
+    pub fn svd(&self) -> (Matrix3, [f64; 3], Matrix3) {
+        // the columns of A are rotated in pairs until they are orthogonal, the same
+        // rotations applied to the identity give V, stored here column by column
+        let mut a = self.transpose().rows;
+        let mut v = Self::IDENTITY.rows;
+        let dot = |x: &[f64; 3], y: &[f64; 3]| x[0] * y[0] + x[1] * y[1] + x[2] * y[2];
+        for _ in 0..JACOBI_SWEEPS {
+            let mut rotated = false;
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                let (alpha, beta) = (dot(&a[p], &a[p]), dot(&a[q], &a[q]));
+                let gamma = dot(&a[p], &a[q]);
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + zeta.hypot(1.0));
+                let c = 1.0 / t.hypot(1.0);
+                let s = t * c;
+                for m in [&mut a, &mut v] {
+                    let (mp, mq) = (m[p], m[q]);
+                    m[p] = [0, 1, 2].map(|k| c * mp[k] - s * mq[k]);
+                    m[q] = [0, 1, 2].map(|k| s * mp[k] + c * mq[k]);
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let columns = a.map(|c| Vector3D::new(c[0], c[1], c[2]));
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| columns[j].magnitude().total_cmp(&columns[i].magnitude()));
+        let sigma = order.map(|i| columns[i].magnitude());
+        let [v0, v1, v2] = order.map(|i| Vector3D::new(v[i][0], v[i][1], v[i][2]));
+
+        // the columns of U for zero singular values only have to complete the basis
+        let tol = 3.0 * f64::EPSILON * sigma[0];
+        let unit = |k: usize| columns[order[k]].scale(1.0 / sigma[k]);
+        let (u0, u1, u2) = if sigma[1] > tol {
+            let (u0, u1) = (unit(0), unit(1));
+            (
+                u0,
+                u1,
+                if sigma[2] > tol {
+                    unit(2)
+                } else {
+                    u0.cross(u1)
+                },
+            )
+        } else {
+            Vector3D::orthonormal_basis_from(columns[order[0]]).unwrap_or((
+                Vector3D::i(),
+                Vector3D::j(),
+                Vector3D::k(),
+            ))
+        };
+        (
+            Self::from_column_vectors(u0, u1, u2),
+            sigma,
+            Self::from_column_vectors(v0, v1, v2),
+        )
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse of this matrix, treating singular values
+    /// below `tol` times the largest one as zero.\
+    /// It is the inverse of an invertible matrix and gives the least squares solution of
+    /// smallest norm of a singular system.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // two equations in three unknowns, x + y = 2 and z = 1
+    /// let m = Matrix3::new([[1.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]);
+    /// let x = m.pseudo_inverse(1e-12) * Vector3D::new(2.0, 1.0, 0.0);
+    /// assert!(x.is_approx_equal_to(Vector3D::new(1.0, 1.0, 1.0), 1e-12));
+    ///
+    /// let r = Matrix3::rotation_z(0.3);
+    /// assert!(r.pseudo_inverse(1e-12).is_approx_equal_to(&r.transpose(), 1e-12));
+    /// ```
+    pub fn pseudo_inverse(&self, tol: f64) -> Matrix3 {
+        let (u, sigma, v) = self.svd();
+        let inv = sigma.map(|s| if s > tol * sigma[0] { 1.0 / s } else { 0.0 });
+        v * Self::diagonal(Vector3D::new(inv[0], inv[1], inv[2])) * u.transpose()
+    }
+
+    /// Returns the rotation `R` and translation `t` that best map the points `from` onto
+    /// the matching points `to` as `R p + t`, in the least squares sense, found with the
+    /// Kabsch algorithm.\
+    /// Returns None if the point sets are empty or differ in length. The rotation is
+    /// proper, never a reflection, but is not unique if the points are collinear.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let r = Matrix3::from_axis_angle(Vector3D::new(1.0, 2.0, 2.0), 0.8).unwrap();
+    /// let t = Vector3D::new(0.5, -1.0, 2.0);
+    /// let from = [
+    ///     Vector3D::new(0.0, 0.0, 0.0),
+    ///     Vector3D::new(1.0, 0.0, 0.0),
+    ///     Vector3D::new(0.0, 2.0, 0.0),
+    ///     Vector3D::new(1.0, 1.0, 3.0),
+    /// ];
+    /// let to = from.map(|p| r * p + t);
+    ///
+    /// let (fit, offset) = Matrix3::best_fit_transform(&from, &to).unwrap();
+    /// assert!(fit.is_approx_equal_to(&r, 1e-12));
+    /// assert!(offset.is_approx_equal_to(t, 1e-12));
+    /// ```
+    pub fn best_fit_transform(from: &[Vector3D], to: &[Vector3D]) -> Option<(Matrix3, Vector3D)> {
+        if from.is_empty() || from.len() != to.len() {
+            return None;
+        }
+        let n = from.len() as f64;
+        let centroid = |points: &[Vector3D]| {
+            points
+                .iter()
+                .fold(Vector3D::origin(), |sum, p| sum + *p)
+                .scale(1.0 / n)
+        };
+        let (from_centre, to_centre) = (centroid(from), centroid(to));
+        let covariance = from.iter().zip(to).fold(Self::zeros(), |h, (p, q)| {
+            let (p, q) = (*p - from_centre, *q - to_centre);
+            h + Self::from_row_vectors(q.scale(p.x), q.scale(p.y), q.scale(p.z))
+        });
+        let (u, _, v) = covariance.svd();
+        // flipping the last axis turns a best fit reflection into the best fit rotation
+        let d = (v * u.transpose()).determinant().signum();
+        let rotation = v * Self::diagonal(Vector3D::new(1.0, 1.0, d)) * u.transpose();
+        Some((rotation, to_centre - rotation * from_centre))
+    }
+
+    /// Returns the product of this matrix and the passed column vector.
+    #[inline]
+    pub fn mul_vec(&self, v: Vector3D) -> Vector3D {
+        Vector3D::new(self.row(0).dot(v), self.row(1).dot(v), self.row(2).dot(v))
+    }
+}
+
+/// Panics if the row or column is larger than 2, see [`Matrix3::try_index`].
+impl Index<(usize, usize)> for Matrix3 {
+    type Output = f64;
+    #[inline]
+    fn index(&self, (row, col): (usize, usize)) -> &f64 {
+        &self.rows[row][col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix3 {
+    #[inline]
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        &mut self.rows[row][col]
+    }
+}
+
+impl Add for Matrix3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let mut result = self;
+        for i in 0..3 {
+            for j in 0..3 {
+                result.rows[i][j] += rhs.rows[i][j];
+            }
+        }
+        result
+    }
+}
+
+impl Sub for Matrix3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + rhs.scale(-1.0)
+    }
+}
+
+impl Neg for Matrix3 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self.scale(-1.0)
+    }
+}
+
+impl Mul for Matrix3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let mut result = Self::zeros();
+        for i in 0..3 {
+            for j in 0..3 {
+                result.rows[i][j] = self.row(i).dot(rhs.column(j));
+            }
+        }
+        result
+    }
+}
+
+impl Mul<Vector3D> for Matrix3 {
+    type Output = Vector3D;
+    #[inline]
+    fn mul(self, rhs: Vector3D) -> Vector3D {
+        self.mul_vec(rhs)
+    }
+}
+
+impl fmt::Display for Matrix3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for r in &self.rows {
+            writeln!(f, "[{}, {}, {}]", r[0], r[1], r[2])?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,892 @@
+use std::fmt;
+use std::ops::*;
+
+use crate::error::ImthError;
+
+/// Represents a dense matrix of any size, stored row by row.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct MatrixN {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl MatrixN {
+    /// Returns a matrix with the passed dimensions whose entries are taken row by row
+    /// from `data`, or None if `data` does not hold exactly `rows * cols` entries.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// let m = MatrixN::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+    ///
+    /// assert_eq!(6.0, m[(1, 2)]);
+    /// assert!(MatrixN::from_vec(2, 2, vec![1.0]).is_none());
+    /// ```
+    #[inline]
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<f64>) -> Option<Self> {
+        if data.len() != rows * cols {
+            return None;
+        }
+        Some(Self { rows, cols, data })
+    }
+
+    /// Returns a matrix built from the passed rows, or None if the rows do not all
+    /// have the same length.
+    pub fn from_rows<R: AsRef<[f64]>>(rows: &[R]) -> Option<Self> {
+        let cols = rows.first().map_or(0, |r| r.as_ref().len());
+        let mut data = Vec::with_capacity(rows.len() * cols);
+        for row in rows {
+            if row.as_ref().len() != cols {
+                return None;
+            }
+            data.extend_from_slice(row.as_ref());
+        }
+        Some(Self {
+            rows: rows.len(),
+            cols,
+            data,
+        })
+    }
+
+    /// Returns a matrix with the passed dimensions and every entry set to zero.
+    #[inline]
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![0.0; rows * cols],
+        }
+    }
+
+    /// Returns the n by n identity matrix.
+    #[inline]
+    pub fn identity(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m.data[i * n + i] = 1.0;
+        }
+        m
+    }
+
+    /// Returns a column matrix holding the passed values.
+    #[inline]
+    pub fn column(values: &[f64]) -> Self {
+        Self {
+            rows: values.len(),
+            cols: 1,
+            data: values.to_vec(),
+        }
+    }
+
+    /// Returns the number of rows of this matrix.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns of this matrix.
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns true if this matrix has as many rows as columns.
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Returns the entries of this matrix row by row.
+    #[inline]
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// Returns the entries of the passed row.
+    #[inline]
+    pub fn row(&self, row: usize) -> &[f64] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Returns the entry at the passed row and column, or None if it is out of bounds.
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        if row < self.rows && col < self.cols {
+            return Some(self.data[row * self.cols + col]);
+        }
+        None
+    }
+
+    /// Returns the transpose of this matrix.
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        let mut t = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                t.data[j * self.rows + i] = self.data[i * self.cols + j];
+            }
+        }
+        t
+    }
+
+    /// Returns true if this matrix is square and equals its transpose, every entry within
+    /// `tol` of its mirror image across the diagonal.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// let a = MatrixN::from_rows(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]).unwrap();
+    /// let t = a.transpose();
+    ///
+    /// assert_eq!((3, 2), (t.rows(), t.cols()));
+    /// assert_eq!(6.0, t[(2, 1)]);
+    /// assert!(!a.is_symmetric(1e-12));
+    /// assert!((&a * &t).is_symmetric(1e-12));
+    /// ```
+    #[inline]
+    pub fn is_symmetric(&self, tol: f64) -> bool {
+        self.is_square()
+            && (0..self.rows)
+                .all(|i| (i + 1..self.cols).all(|j| (self[(i, j)] - self[(j, i)]).abs() <= tol))
+    }
+
+    /// Returns true if this matrix is square and its transpose is its inverse within `tol`,
+    /// so its columns are orthonormal.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// let (c, s) = (0.6, 0.8);
+    /// let q = MatrixN::from_rows(&[[c, -s], [s, c]]).unwrap();
+    ///
+    /// assert!(q.is_orthogonal(1e-12));
+    /// assert!(!q.scale(2.0).is_orthogonal(1e-12));
+    /// ```
+    #[inline]
+    pub fn is_orthogonal(&self, tol: f64) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+        let product = &self.transpose() * self;
+        let identity = Self::identity(self.rows);
+        product
+            .data
+            .iter()
+            .zip(&identity.data)
+            .all(|(a, b)| (a - b).abs() <= tol)
+    }
+
+    /// Returns a matrix with every entry of this matrix scaled by the passed value.
+    #[inline]
+    pub fn scale(&self, value: f64) -> Self {
+        Self {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(|e| e * value).collect(),
+        }
+    }
+
+    /// Returns the product of this matrix and the passed column vector.
+    ///
+    /// # Panics
+    /// If the length of `v` differs from the number of columns of this matrix.
+    pub fn mul_vec(&self, v: &[f64]) -> Vec<f64> {
+        assert_eq!(
+            self.cols,
+            v.len(),
+            "matrix and vector dimensions do not match"
+        );
+        (0..self.rows)
+            .map(|i| self.row(i).iter().zip(v).map(|(a, b)| a * b).sum())
+            .collect()
+    }
+
+    /// Returns the entry at the passed row and column, or an error if it is out of bounds.
+    #[inline]
+    pub fn try_index(&self, row: usize, col: usize) -> Result<f64, ImthError> {
+        self.get(row, col)
+            .ok_or(ImthError::InvalidArgument("matrix index out of bounds"))
+    }
+
+    /// Returns the sum of this and the passed matrix, or an error if their dimensions
+    /// differ, where `+` panics.
+    pub fn try_add(&self, rhs: &MatrixN) -> Result<Self, ImthError> {
+        self.check_same_dimensions(rhs)?;
+        Ok(self + rhs)
+    }
+
+    /// Returns the difference of this and the passed matrix, or an error if their
+    /// dimensions differ, where `-` panics.
+    pub fn try_sub(&self, rhs: &MatrixN) -> Result<Self, ImthError> {
+        self.check_same_dimensions(rhs)?;
+        Ok(self - rhs)
+    }
+
+    /// Returns the product of this and the passed matrix, or an error if the columns of
+    /// this matrix do not match the rows of the other, where `*` panics.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::error::ImthError;
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// let a = MatrixN::from_rows(&[[1.0, 2.0, 3.0]]).unwrap();
+    /// let b = MatrixN::column(&[1.0, 0.0, -1.0]);
+    ///
+    /// assert_eq!(Ok(-2.0), a.try_mul(&b).and_then(|p| p.try_index(0, 0)));
+    /// assert!(matches!(a.try_mul(&a), Err(ImthError::InvalidArgument(_))));
+    ///
+    /// // zeros do not hide infinite or undefined entries of the other matrix
+    /// let c = MatrixN::column(&[f64::INFINITY, 0.0, 0.0]);
+    /// assert!(MatrixN::from_rows(&[[0.0, 1.0, 1.0]]).unwrap().try_mul(&c).unwrap()[(0, 0)].is_nan());
+    /// ```
+    pub fn try_mul(&self, rhs: &MatrixN) -> Result<Self, ImthError> {
+        if self.cols != rhs.rows {
+            return Err(ImthError::InvalidArgument("matrix dimensions do not match"));
+        }
+        Ok(self * rhs)
+    }
+
+    /// Returns the product of this matrix and the passed column vector, or an error if its
+    /// length differs from the number of columns, where [`MatrixN::mul_vec`] panics.
+    pub fn try_mul_vec(&self, v: &[f64]) -> Result<Vec<f64>, ImthError> {
+        if self.cols != v.len() {
+            return Err(ImthError::InvalidArgument(
+                "matrix and vector dimensions do not match",
+            ));
+        }
+        Ok(self.mul_vec(v))
+    }
+
+    /// Returns the solution x of **A**x = b like [`MatrixN::solve`], but telling a size
+    /// mismatch, an invalid argument, apart from a singular matrix, a division by zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::error::ImthError;
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// let singular = MatrixN::from_rows(&[[1.0, 2.0], [2.0, 4.0]]).unwrap();
+    ///
+    /// assert_eq!(Err(ImthError::DivisionByZero), singular.try_solve(&[1.0, 2.0]));
+    /// assert!(matches!(singular.try_solve(&[1.0]), Err(ImthError::InvalidArgument(_))));
+    /// ```
+    pub fn try_solve(&self, b: &[f64]) -> Result<Vec<f64>, ImthError> {
+        if !self.is_square() || b.len() != self.rows {
+            return Err(ImthError::InvalidArgument(
+                "matrix must be square and match the length of b",
+            ));
+        }
+        self.solve(b).ok_or(ImthError::DivisionByZero)
+    }
+
+    fn check_same_dimensions(&self, other: &MatrixN) -> Result<(), ImthError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(ImthError::InvalidArgument("matrix dimensions do not match"));
+        }
+        Ok(())
+    }
+
+    /// Returns the largest absolute row sum of this matrix (the infinity norm).
+    #[inline]
+    pub fn norm_inf(&self) -> f64 {
+        (0..self.rows)
+            .map(|i| self.row(i).iter().map(|e| e.abs()).sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+
+    /// Returns the matrix built by placing the passed blocks side by side and on top of each
+    /// other as `[[top_left, top_right], [bottom_left, bottom_right]]`, or None if the block
+    /// dimensions do not line up.
+    pub fn from_blocks(
+        top_left: &Self,
+        top_right: &Self,
+        bottom_left: &Self,
+        bottom_right: &Self,
+    ) -> Option<Self> {
+        if top_left.rows != top_right.rows
+            || bottom_left.rows != bottom_right.rows
+            || top_left.cols != bottom_left.cols
+            || top_right.cols != bottom_right.cols
+        {
+            return None;
+        }
+        let rows = top_left.rows + bottom_left.rows;
+        let cols = top_left.cols + top_right.cols;
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..top_left.rows {
+            data.extend_from_slice(top_left.row(i));
+            data.extend_from_slice(top_right.row(i));
+        }
+        for i in 0..bottom_left.rows {
+            data.extend_from_slice(bottom_left.row(i));
+            data.extend_from_slice(bottom_right.row(i));
+        }
+        Some(Self { rows, cols, data })
+    }
+
+    /// Returns the sub-matrix with `rows` rows and `cols` columns whose top left entry is at
+    /// `(row, col)` in this matrix, or None if it does not fit inside this matrix.
+    pub fn block(&self, row: usize, col: usize, rows: usize, cols: usize) -> Option<Self> {
+        if row + rows > self.rows || col + cols > self.cols {
+            return None;
+        }
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in row..row + rows {
+            data.extend_from_slice(&self.row(i)[col..col + cols]);
+        }
+        Some(Self { rows, cols, data })
+    }
+
+    /// Returns the solution x of **A**x = b, with this matrix as **A**, found by Gaussian
+    /// elimination with partial pivoting, or None if this matrix is not square, its size
+    /// differs from the length of `b` or it is singular.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// let a = MatrixN::from_rows(&[[2.0, 1.0], [1.0, 3.0]]).unwrap();
+    /// let x = a.solve(&[3.0, 5.0]).unwrap();
+    ///
+    /// assert!((x[0] - 0.8).abs() < 1e-12 && (x[1] - 1.4).abs() < 1e-12);
+    ///
+    /// // equations of very different scales are not singular
+    /// let stiff = MatrixN::from_rows(&[[1e17, 0.0], [0.0, 1.0]]).unwrap();
+    /// assert_eq!(Some(vec![2.0, 3.0]), stiff.solve(&[2e17, 3.0]));
+    /// ```
+    pub fn solve(&self, b: &[f64]) -> Option<Vec<f64>> {
+        if b.len() != self.rows {
+            return None;
+        }
+        let (lu, perm) = self.lu()?;
+        Some(Self::lu_solve(&lu, &perm, b))
+    }
+
+    /// Returns the solution x of **A**x = b like [`MatrixN::solve`], together with
+    /// diagnostics of how far it can be trusted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// let healthy = MatrixN::from_rows(&[[2.0, 1.0], [1.0, 3.0]]).unwrap();
+    /// let (_, diagnostics) = healthy.solve_with_diagnostics(&[3.0, 5.0]).unwrap();
+    /// assert!((diagnostics.condition_number - 3.2).abs() < 1e-12);
+    /// assert!(diagnostics.residual_norm < 1e-12);
+    ///
+    /// // two nearly parallel equations only just pin the solution down
+    /// let shaky = MatrixN::from_rows(&[[1.0, 1.0], [1.0, 1.0 + 1e-9]]).unwrap();
+    /// let (x, diagnostics) = shaky.solve_with_diagnostics(&[2.0, 2.0]).unwrap();
+    /// assert!((x[0] - 2.0).abs() < 1e-6 && x[1].abs() < 1e-6);
+    /// assert!(diagnostics.condition_number > 1e9);
+    /// assert!(diagnostics.digits_lost() > 9.0);
+    /// ```
+    pub fn solve_with_diagnostics(&self, b: &[f64]) -> Option<(Vec<f64>, SolveDiagnostics)> {
+        if b.len() != self.rows {
+            return None;
+        }
+        let (lu, perm) = self.lu()?;
+        let x = Self::lu_solve(&lu, &perm, b);
+        let n = self.rows;
+        let pivots = (0..n).map(|k| lu[k * n + k].abs());
+        let residual_norm = self
+            .mul_vec(&x)
+            .iter()
+            .zip(b)
+            .fold(0.0f64, |m, (ax, b)| m.max((ax - b).abs()));
+        let diagnostics = SolveDiagnostics {
+            min_pivot: pivots.clone().fold(f64::INFINITY, f64::min),
+            max_pivot: pivots.fold(0.0, f64::max),
+            condition_number: self.norm_inf() * Self::lu_inverse_norm(&lu, &perm),
+            residual_norm,
+        };
+        Some((x, diagnostics))
+    }
+
+    /// Returns the condition number κ = ||**A**|| ||**A**<sup>-1</sup>|| of this matrix in
+    /// the infinity norm, infinite if it is singular, or None if it is not square.\
+    /// Solving **A**x = b can lose up to log<sub>10</sub>κ significant digits of x.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// assert_eq!(Some(1.0), MatrixN::identity(3).condition_number());
+    /// let a = MatrixN::from_rows(&[[1.0, 2.0], [3.0, 4.0]]).unwrap();
+    /// assert!((a.condition_number().unwrap() - 21.0).abs() < 1e-12);
+    /// let singular = MatrixN::from_rows(&[[1.0, 2.0], [2.0, 4.0]]).unwrap();
+    /// assert_eq!(Some(f64::INFINITY), singular.condition_number());
+    /// ```
+    pub fn condition_number(&self) -> Option<f64> {
+        if !self.is_square() {
+            return None;
+        }
+        Some(match self.lu() {
+            Some((lu, perm)) => self.norm_inf() * Self::lu_inverse_norm(&lu, &perm),
+            None => f64::INFINITY,
+        })
+    }
+
+    /// Returns the determinant of this matrix from its LU decomposition, zero if it is
+    /// singular, or None if it is not square.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// let a = MatrixN::from_rows(&[[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]]).unwrap();
+    /// assert!((a.determinant().unwrap() + 5.0).abs() < 1e-12);
+    /// assert_eq!(Some(0.0), MatrixN::zeros(2, 2).determinant());
+    /// ```
+    pub fn determinant(&self) -> Option<f64> {
+        if !self.is_square() {
+            return None;
+        }
+        let Some((lu, perm)) = self.lu() else {
+            return Some(0.0);
+        };
+        let n = self.rows;
+        // every cycle of length k in the row permutation takes k - 1 swaps
+        let mut visited = vec![false; n];
+        let mut sign = 1.0;
+        for start in 0..n {
+            let mut i = start;
+            while !visited[i] {
+                visited[i] = true;
+                i = perm[i];
+                if i != start {
+                    sign = -sign;
+                }
+            }
+        }
+        Some((0..n).fold(sign, |det, k| det * lu[k * n + k]))
+    }
+
+    /// Returns the LU factors of this matrix found by Gaussian elimination with partial
+    /// pivoting, packed row by row into one square array with the unit diagonal of L left
+    /// out, and the row of this matrix each row of the factors came from, or None if this
+    /// matrix is not square or it is singular.
+    fn lu(&self) -> Option<(Vec<f64>, Vec<usize>)> {
+        let n = self.rows;
+        if !self.is_square() {
+            return None;
+        }
+        let mut a = self.data.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        // pivots are chosen and judged relative to the largest entry of their own row, so
+        // that badly scaled but well posed equations are not taken for singular ones
+        let mut row_scales: Vec<f64> = (0..n)
+            .map(|i| self.row(i).iter().fold(0.0f64, |m, e| m.max(e.abs())))
+            .collect();
+        for k in 0..n {
+            let scaled = |i: usize| a[i * n + k].abs() / row_scales[i];
+            let pivot = (k..n).max_by(|&i, &j| scaled(i).total_cmp(&scaled(j)))?;
+            if a[pivot * n + k].abs() <= n as f64 * f64::EPSILON * row_scales[pivot] {
+                return None;
+            }
+            if pivot != k {
+                for j in 0..n {
+                    a.swap(k * n + j, pivot * n + j);
+                }
+                perm.swap(k, pivot);
+                row_scales.swap(k, pivot);
+            }
+            for i in k + 1..n {
+                let factor = a[i * n + k] / a[k * n + k];
+                a[i * n + k] = factor;
+                for j in k + 1..n {
+                    a[i * n + j] -= factor * a[k * n + j];
+                }
+            }
+        }
+        Some((a, perm))
+    }
+
+    /// Returns the solution of **A**x = b from the factors of [`MatrixN::lu`].
+    fn lu_solve(lu: &[f64], perm: &[usize], b: &[f64]) -> Vec<f64> {
+        let n = perm.len();
+        let mut x: Vec<f64> = perm.iter().map(|&i| b[i]).collect();
+        for i in 0..n {
+            let sum: f64 = (0..i).map(|j| lu[i * n + j] * x[j]).sum();
+            x[i] -= sum;
+        }
+        for i in (0..n).rev() {
+            let sum: f64 = (i + 1..n).map(|j| lu[i * n + j] * x[j]).sum();
+            x[i] = (x[i] - sum) / lu[i * n + i];
+        }
+        x
+    }
+
+    /// Returns the infinity norm of **A**<sup>-1</sup> from the factors of [`MatrixN::lu`],
+    /// solving for one column of the inverse at a time.
+    fn lu_inverse_norm(lu: &[f64], perm: &[usize]) -> f64 {
+        let n = perm.len();
+        let mut row_sums = vec![0.0; n];
+        let mut e = vec![0.0; n];
+        for j in 0..n {
+            e[j] = 1.0;
+            let column = Self::lu_solve(lu, perm, &e);
+            e[j] = 0.0;
+            row_sums
+                .iter_mut()
+                .zip(column)
+                .for_each(|(sum, c)| *sum += c.abs());
+        }
+        row_sums.into_iter().fold(0.0, f64::max)
+    }
+
+    /// Returns the lower triangular **L** with a positive diagonal and **A** = **LL**<sup>T</sup>,
+    /// with this matrix as **A**, or None if this matrix is not symmetric and positive
+    /// definite, like a stiffness matrix of a structure that is not fully supported.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// let k = MatrixN::from_rows(&[[4.0, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]])
+    ///     .unwrap();
+    /// let l = k.cholesky().unwrap();
+    ///
+    /// assert_eq!(&[2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0], l.as_slice());
+    /// assert!(MatrixN::from_rows(&[[1.0, 2.0], [2.0, 1.0]]).unwrap().cholesky().is_none());
+    ///
+    /// let stiff = MatrixN::from_rows(&[[1e17, 0.0], [0.0, 1.0]]).unwrap();
+    /// assert_eq!(&[1e17f64.sqrt(), 0.0, 0.0, 1.0], stiff.cholesky().unwrap().as_slice());
+    /// ```
+    pub fn cholesky(&self) -> Option<Self> {
+        let n = self.rows;
+        let scale = self.norm_inf();
+        if !self.is_square() || !self.is_symmetric(n as f64 * f64::EPSILON * scale) {
+            return None;
+        }
+        let mut l = Self::zeros(n, n);
+        for j in 0..n {
+            let d = self[(j, j)] - (0..j).map(|k| l[(j, k)].powi(2)).sum::<f64>();
+            // judged against its own diagonal entry, so badly scaled matrices still factor
+            if d <= n as f64 * f64::EPSILON * self[(j, j)] {
+                return None;
+            }
+            l[(j, j)] = d.sqrt();
+            for i in j + 1..n {
+                let sum: f64 = (0..j).map(|k| l[(i, k)] * l[(j, k)]).sum();
+                l[(i, j)] = (self[(i, j)] - sum) / l[(j, j)];
+            }
+        }
+        Some(l)
+    }
+
+    /// Returns the solution x of **A**x = b, with this matrix as **A**, found from its
+    /// Cholesky factor in about half the work of [`MatrixN::solve`], or None if this matrix
+    /// is not symmetric and positive definite or its size differs from the length of `b`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// // two springs of 100 N/m and 50 N/m in series, pulled by 10 N at the free end
+    /// let k = MatrixN::from_rows(&[[150.0, -50.0], [-50.0, 50.0]]).unwrap();
+    /// let x = k.solve_cholesky(&[0.0, 10.0]).unwrap();
+    ///
+    /// assert!((x[0] - 0.1).abs() < 1e-12 && (x[1] - 0.3).abs() < 1e-12);
+    /// ```
+    pub fn solve_cholesky(&self, b: &[f64]) -> Option<Vec<f64>> {
+        if b.len() != self.rows {
+            return None;
+        }
+        let l = self.cholesky()?;
+        let n = self.rows;
+        let mut x = b.to_vec();
+        for i in 0..n {
+            let sum: f64 = (0..i).map(|k| l[(i, k)] * x[k]).sum();
+            x[i] = (x[i] - sum) / l[(i, i)];
+        }
+        for i in (0..n).rev() {
+            let sum: f64 = (i + 1..n).map(|k| l[(k, i)] * x[k]).sum();
+            x[i] = (x[i] - sum) / l[(i, i)];
+        }
+        Some(x)
+    }
+
+    /// Returns the thin QR decomposition `(Q, R)` of this matrix found with Householder
+    /// reflections, where `Q` has orthonormal columns, `R` is square and upper triangular
+    /// with a non-negative diagonal and **A** = `QR`, or None if this matrix has more
+    /// columns than rows.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// let a = MatrixN::from_rows(&[[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0]])
+    ///     .unwrap();
+    /// let (q, r) = a.qr().unwrap();
+    ///
+    /// assert!(q.is_orthogonal(1e-12));
+    /// assert!((r[(0, 0)] - 14.0).abs() < 1e-12 && (r[(1, 1)] - 175.0).abs() < 1e-12);
+    /// assert!(r[(1, 0)] == 0.0 && r[(2, 0)] == 0.0 && r[(2, 1)] == 0.0);
+    /// let qr = &q * &r;
+    /// assert!(qr.as_slice().iter().zip(a.as_slice()).all(|(x, y)| (x - y).abs() < 1e-12));
+    /// ```
+    pub fn qr(&self) -> Option<(Self, Self)> {
+        let (m, n) = (self.rows, self.cols);
+        if n > m {
+            return None;
+        }
+        let mut r = self.clone();
+        let mut q = Self::identity(m);
+        for k in 0..n {
+            // the reflection maps column k below the diagonal onto the axis, choosing the
+            // sign that avoids cancellation
+            let norm = (k..m).map(|i| r[(i, k)].powi(2)).sum::<f64>().sqrt();
+            let alpha = if r[(k, k)] > 0.0 { -norm } else { norm };
+            let mut v: Vec<f64> = (k..m).map(|i| r[(i, k)]).collect();
+            v[0] -= alpha;
+            let v_norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if v_norm == 0.0 {
+                continue;
+            }
+            v.iter_mut().for_each(|x| *x /= v_norm);
+            for j in k..n {
+                let dot: f64 = (k..m).map(|i| v[i - k] * r[(i, j)]).sum();
+                (k..m).for_each(|i| r[(i, j)] -= 2.0 * v[i - k] * dot);
+            }
+            for i in 0..m {
+                let dot: f64 = (k..m).map(|j| q[(i, j)] * v[j - k]).sum();
+                (k..m).for_each(|j| q[(i, j)] -= 2.0 * dot * v[j - k]);
+            }
+            (k + 1..m).for_each(|i| r[(i, k)] = 0.0);
+        }
+        for k in 0..n {
+            if r[(k, k)] < 0.0 {
+                (k..n).for_each(|j| r[(k, j)] = -r[(k, j)]);
+                (0..m).for_each(|i| q[(i, k)] = -q[(i, k)]);
+            }
+        }
+        Some((q.block(0, 0, m, n)?, r.block(0, 0, n, n)?))
+    }
+
+    /// Returns the x minimizing the length of **A**x - b, with this matrix as **A**, found
+    /// from its QR decomposition without forming the normal equations, or None if this
+    /// matrix has more columns than rows, its row count differs from the length of `b` or
+    /// its columns are linearly dependent.\
+    /// It is the exact solution when **A** is square and invertible.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// // a straight line y = c0 + c1 t through four points
+    /// let a = MatrixN::from_rows(&[[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]).unwrap();
+    /// let c = a.solve_least_squares(&[1.0, 2.9, 5.1, 7.0]).unwrap();
+    ///
+    /// assert!((c[0] - 0.97).abs() < 1e-12 && (c[1] - 2.02).abs() < 1e-12);
+    ///
+    /// // columns of very different magnitudes are still independent
+    /// let a = MatrixN::from_rows(&[[1e10, 1e-10], [1e10, 2e-10], [1e10, 3e-10]]).unwrap();
+    /// let c = a.solve_least_squares(&[0.0, 1e-10, 2e-10]).unwrap();
+    /// assert!((c[0] + 1e-20).abs() < 1e-30 && (c[1] - 1.0).abs() < 1e-9);
+    ///
+    /// let dependent = MatrixN::from_rows(&[[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]).unwrap();
+    /// assert_eq!(None, dependent.solve_least_squares(&[1.0, 2.0, 3.0]));
+    /// ```
+    pub fn solve_least_squares(&self, b: &[f64]) -> Option<Vec<f64>> {
+        if b.len() != self.rows {
+            return None;
+        }
+        let (q, r) = self.qr()?;
+        let n = self.cols;
+        let mut x = q.transpose().mul_vec(b);
+        // each diagonal entry of R is judged against the length of its own column, so that
+        // columns of very different scales are not taken for dependent ones
+        let eps = self.rows.max(n) as f64 * f64::EPSILON;
+        for k in (0..n).rev() {
+            let column_norm = (0..self.rows)
+                .map(|i| self[(i, k)].powi(2))
+                .sum::<f64>()
+                .sqrt();
+            if r[(k, k)] <= eps * column_norm {
+                return None;
+            }
+            let sum: f64 = (k + 1..n).map(|j| r[(k, j)] * x[j]).sum();
+            x[k] = (x[k] - sum) / r[(k, k)];
+        }
+        Some(x)
+    }
+
+    /// Returns the matrix exponential e<sup>A</sup> of this matrix computed by scaling
+    /// and squaring, or None if this matrix is not square or has an entry that is not
+    /// finite.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrixn::MatrixN;
+    ///
+    /// // the exponential of a rotation generator is a rotation
+    /// let generator = MatrixN::from_rows(&[[0.0, -1.0], [1.0, 0.0]]).unwrap();
+    /// let rotation = generator.scale(0.5).exp().unwrap();
+    /// assert!((rotation[(0, 0)] - 0.5f64.cos()).abs() < 1e-12);
+    /// assert!((rotation[(1, 0)] - 0.5f64.sin()).abs() < 1e-12);
+    ///
+    /// assert_eq!(None, MatrixN::from_rows(&[[f64::NAN]]).unwrap().exp());
+    /// ```
+    pub fn exp(&self) -> Option<Self> {
+        if !self.is_square() || !self.data.iter().all(|e| e.is_finite()) {
+            return None;
+        }
+        let norm = self.norm_inf();
+        let squarings = if norm > 0.5 {
+            (norm / 0.5).log2().ceil() as i32
+        } else {
+            0
+        };
+        let scaled = self.scale(0.5f64.powi(squarings));
+
+        let mut result = Self::identity(self.rows);
+        let mut term = Self::identity(self.rows);
+        for k in 1..=EXP_TAYLOR_TERMS {
+            term = (&term * &scaled).scale(1.0 / k as f64);
+            result = &result + &term;
+        }
+        for _ in 0..squarings {
+            result = &result * &result;
+        }
+        Some(result)
+    }
+}
+
+/// Diagnostics of the solution of a linear system, telling a healthy system apart from
+/// one that is close to singular, like a truss that is nearly a mechanism.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct SolveDiagnostics {
+    /// Magnitude of the smallest pivot of the elimination.
+    pub min_pivot: f64,
+    /// Magnitude of the largest pivot of the elimination.
+    pub max_pivot: f64,
+    /// Condition number of the matrix in the infinity norm, see
+    /// [`MatrixN::condition_number`].
+    pub condition_number: f64,
+    /// Largest entry of the residual **A**x - b.
+    pub residual_norm: f64,
+}
+
+impl SolveDiagnostics {
+    /// Returns roughly how many significant digits of the solution may be lost to
+    /// rounding, log<sub>10</sub> of the condition number.
+    #[inline]
+    pub fn digits_lost(&self) -> f64 {
+        self.condition_number.log10().max(0.0)
+    }
+
+    /// Returns true if the condition number exceeds `limit`, like 1e12 for a system whose
+    /// solution keeps only a few significant digits in f64.
+    #[inline]
+    pub fn is_ill_conditioned(&self, limit: f64) -> bool {
+        self.condition_number > limit
+    }
+}
+
+/// Number of Taylor terms used by [`MatrixN::exp`] once the matrix is scaled down to a norm
+/// of at most one half, which keeps the truncation error below f64 resolution.
+const EXP_TAYLOR_TERMS: usize = 18;
+
+/// Panics if the entry is out of bounds, see [`MatrixN::get`] and [`MatrixN::try_index`].
+impl Index<(usize, usize)> for MatrixN {
+    type Output = f64;
+    #[inline]
+    fn index(&self, (row, col): (usize, usize)) -> &f64 {
+        assert!(row < self.rows && col < self.cols, "Index out of bounds");
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for MatrixN {
+    #[inline]
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        assert!(row < self.rows && col < self.cols, "Index out of bounds");
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+/// Panics if the dimensions differ, see [`MatrixN::try_add`].
+impl Add for &MatrixN {
+    type Output = MatrixN;
+    #[inline]
+    fn add(self, rhs: &MatrixN) -> MatrixN {
+        assert!(
+            self.rows == rhs.rows && self.cols == rhs.cols,
+            "matrix dimensions do not match"
+        );
+        MatrixN {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(&rhs.data)
+                .map(|(a, b)| a + b)
+                .collect(),
+        }
+    }
+}
+
+/// Panics if the dimensions differ, see [`MatrixN::try_sub`].
+impl Sub for &MatrixN {
+    type Output = MatrixN;
+    #[inline]
+    fn sub(self, rhs: &MatrixN) -> MatrixN {
+        assert!(
+            self.rows == rhs.rows && self.cols == rhs.cols,
+            "matrix dimensions do not match"
+        );
+        MatrixN {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(&rhs.data)
+                .map(|(a, b)| a - b)
+                .collect(),
+        }
+    }
+}
+
+/// Panics if the dimensions do not match, see [`MatrixN::try_mul`].
+impl Mul for &MatrixN {
+    type Output = MatrixN;
+    fn mul(self, rhs: &MatrixN) -> MatrixN {
+        assert_eq!(self.cols, rhs.rows, "matrix dimensions do not match");
+        let mut result = MatrixN::zeros(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self.data[i * self.cols + k];
+                for j in 0..rhs.cols {
+                    result.data[i * rhs.cols + j] += a * rhs.data[k * rhs.cols + j];
+                }
+            }
+        }
+        result
+    }
+}
+
+impl Neg for &MatrixN {
+    type Output = MatrixN;
+    #[inline]
+    fn neg(self) -> MatrixN {
+        self.scale(-1.0)
+    }
+}
+
+impl fmt::Display for MatrixN {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..self.rows {
+            let row: Vec<String> = self.row(i).iter().map(|e| e.to_string()).collect();
+            writeln!(f, "[{}]", row.join(", "))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,333 @@
+//! # Measurements
+//!
+//! Measured values with a standard uncertainty, whose arithmetic carries the uncertainty
+//! through a calculation, so a result comes out with its error bar.
+//!
+//! Uncertainties are propagated to first order assuming the quantities are independent:
+//! for z = f(x, y) the uncertainty is
+//! σ<sub>z</sub> = √((∂f/∂x σ<sub>x</sub>)<sup>2</sup> + (∂f/∂y σ<sub>y</sub>)<sup>2</sup>),
+//! so the uncertainties of a sum add in quadrature. A quantity used twice in one formula,
+//! like x in x - x, is not independent of itself and gets an uncertainty that is too large,
+//! so such formulas are best written with each measurement appearing once.
+//!
+//! # Example
+//! ```rust
+//! use i_mth::constants::PI;
+//! use i_mth::measurement::Measurement;
+//!
+//! // g from a pendulum of length L = 1.000 ± 0.002 m timed at T = 2.006 ± 0.005 s
+//! let length = Measurement::new(1.0, 0.002);
+//! let period = Measurement::new(2.006, 0.005);
+//! let g = length * (4.0 * PI * PI) / period.powi(2);
+//!
+//! assert!((g.value - 9.8106).abs() < 1e-4);
+//! // the relative uncertainty of L and twice that of T in quadrature
+//! let relative = 0.002f64.hypot(2.0 * 0.005 / 2.006);
+//! assert!((g.relative_uncertainty() - relative).abs() < 1e-12);
+//! assert!(g.agrees_with(Measurement::exact(9.81), 1.0));
+//! assert_eq!("9.811 ± 0.053", format!("{:.3}", g));
+//! ```
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::scalar::Real;
+
+/// A measured value with its standard uncertainty. It is a [`Real`] scalar, so the
+/// formulas generic over it, like those of the utils module, take measurements.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+pub struct Measurement {
+    /// The best estimate of the quantity.
+    pub value: f64,
+    /// The standard uncertainty, one standard deviation, never negative.
+    pub uncertainty: f64,
+}
+
+impl Measurement {
+    /// Returns the measurement of `value` with the passed standard uncertainty, whose sign
+    /// is dropped.
+    #[inline]
+    pub fn new(value: f64, uncertainty: f64) -> Self {
+        Self {
+            value,
+            uncertainty: uncertainty.abs(),
+        }
+    }
+
+    /// Returns an exactly known value, like a defined constant.
+    #[inline]
+    pub const fn exact(value: f64) -> Self {
+        Self {
+            value,
+            uncertainty: 0.0,
+        }
+    }
+
+    /// Returns the measurement of `value` with an uncertainty of `relative` times its size,
+    /// like 0.01 for 1 %.
+    #[inline]
+    pub fn from_relative(value: f64, relative: f64) -> Self {
+        Self::new(value, value * relative)
+    }
+
+    /// Returns the uncertainty as a fraction of the size of the value, infinite for a zero
+    /// value with an uncertainty.
+    #[inline]
+    pub fn relative_uncertainty(&self) -> f64 {
+        if self.uncertainty == 0.0 {
+            return 0.0;
+        }
+        self.uncertainty / self.value.abs()
+    }
+
+    /// Returns true if this and the passed measurement differ by at most `coverage` times
+    /// the uncertainty of their difference, like 2.0 for roughly 95 % confidence.
+    #[inline]
+    pub fn agrees_with(&self, other: Measurement, coverage: f64) -> bool {
+        let difference = *self - other;
+        difference.value.abs() <= coverage * difference.uncertainty
+    }
+
+    /// Returns `f` of this measurement, with the uncertainty scaled by the size of its
+    /// derivative `df` at the value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::measurement::Measurement;
+    ///
+    /// let x = Measurement::new(2.0, 0.1);
+    /// let y = x.apply(f64::cbrt, |x| x.powf(-2.0 / 3.0) / 3.0);
+    ///
+    /// assert_eq!(2f64.cbrt(), y.value);
+    /// assert!((y.uncertainty - 0.1 / (3.0 * 4f64.cbrt())).abs() < 1e-15);
+    /// ```
+    #[inline]
+    pub fn apply<F, D>(&self, f: F, df: D) -> Self
+    where
+        F: Fn(f64) -> f64,
+        D: Fn(f64) -> f64,
+    {
+        Self::new(f(self.value), df(self.value) * self.uncertainty)
+    }
+
+    /// Returns this measurement raised to the integer power `n`.
+    #[inline]
+    pub fn powi(&self, n: i32) -> Self {
+        self.apply(|x| x.powi(n), |x| n as f64 * x.powi(n - 1))
+    }
+
+    /// Returns this measurement raised to the power `n`.
+    #[inline]
+    pub fn powf(&self, n: f64) -> Self {
+        self.apply(|x| x.powf(n), |x| n * x.powf(n - 1.0))
+    }
+
+    /// Returns the square root of this measurement.
+    #[inline]
+    pub fn sqrt(&self) -> Self {
+        self.apply(f64::sqrt, |x| 0.5 / x.sqrt())
+    }
+
+    /// Returns the sine of this measurement of an angle in radians.
+    #[inline]
+    pub fn sin(&self) -> Self {
+        self.apply(f64::sin, f64::cos)
+    }
+
+    /// Returns the cosine of this measurement of an angle in radians.
+    #[inline]
+    pub fn cos(&self) -> Self {
+        self.apply(f64::cos, f64::sin)
+    }
+
+    /// Returns the tangent of this measurement of an angle in radians.
+    #[inline]
+    pub fn tan(&self) -> Self {
+        self.apply(f64::tan, |x| 1.0 / x.cos().powi(2))
+    }
+
+    /// Returns e raised to this measurement.
+    #[inline]
+    pub fn exp(&self) -> Self {
+        self.apply(f64::exp, f64::exp)
+    }
+
+    /// Returns the natural logarithm of this measurement.
+    #[inline]
+    pub fn ln(&self) -> Self {
+        self.apply(f64::ln, |x| 1.0 / x)
+    }
+
+    /// Returns the absolute value of this measurement, with the same uncertainty.
+    #[inline]
+    pub fn abs(&self) -> Self {
+        Self::new(self.value.abs(), self.uncertainty)
+    }
+
+    /// Returns the angle in radians of the point (x, y) with this measurement as y, like
+    /// the direction of a measured force.
+    #[inline]
+    pub fn atan2(&self, x: Measurement) -> Self {
+        let r2 = self.value * self.value + x.value * x.value;
+        Self::new(
+            self.value.atan2(x.value),
+            (x.value * self.uncertainty).hypot(self.value * x.uncertainty) / r2,
+        )
+    }
+}
+
+impl From<f64> for Measurement {
+    /// Returns the exactly known value.
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self::exact(value)
+    }
+}
+
+impl Add for Measurement {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.value + rhs.value,
+            self.uncertainty.hypot(rhs.uncertainty),
+        )
+    }
+}
+
+impl Sub for Measurement {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            self.value - rhs.value,
+            self.uncertainty.hypot(rhs.uncertainty),
+        )
+    }
+}
+
+impl Mul for Measurement {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.value * rhs.value,
+            (rhs.value * self.uncertainty).hypot(self.value * rhs.uncertainty),
+        )
+    }
+}
+
+impl Div for Measurement {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let value = self.value / rhs.value;
+        Self::new(
+            value,
+            (self.uncertainty / rhs.value).hypot(value * rhs.uncertainty / rhs.value),
+        )
+    }
+}
+
+impl Neg for Measurement {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.value, self.uncertainty)
+    }
+}
+
+impl AddAssign for Measurement {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Measurement {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add<f64> for Measurement {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: f64) -> Self {
+        Self::new(self.value + rhs, self.uncertainty)
+    }
+}
+
+impl Sub<f64> for Measurement {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: f64) -> Self {
+        Self::new(self.value - rhs, self.uncertainty)
+    }
+}
+
+impl Mul<f64> for Measurement {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(self.value * rhs, self.uncertainty * rhs)
+    }
+}
+
+impl Div<f64> for Measurement {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f64) -> Self {
+        Self::new(self.value / rhs, self.uncertainty / rhs)
+    }
+}
+
+impl Mul<Measurement> for f64 {
+    type Output = Measurement;
+    #[inline]
+    fn mul(self, rhs: Measurement) -> Measurement {
+        rhs * self
+    }
+}
+
+impl Div<Measurement> for f64 {
+    type Output = Measurement;
+    #[inline]
+    fn div(self, rhs: Measurement) -> Measurement {
+        Measurement::exact(self) / rhs
+    }
+}
+
+impl Real for Measurement {
+    /// Returns the value, dropping the uncertainty.
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.value
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        Measurement::abs(&self)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        Measurement::sqrt(&self)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        Measurement::powi(&self, n)
+    }
+}
+
+/// Formats as `value ± uncertainty`, passing the precision on to both.
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "{:.*} ± {:.*}", p, self.value, p, self.uncertainty),
+            None => write!(f, "{} ± {}", self.value, self.uncertainty),
+        }
+    }
+}
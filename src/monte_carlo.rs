@@ -0,0 +1,299 @@
+//! # Monte Carlo
+//!
+//! Integration by averaging a function over random points, which works the same in any
+//! number of dimensions and over regions only known through an indicator function, like
+//! the volume, centroid and inertia of an irregular solid.
+//!
+//! The error of an estimate shrinks like 1/√N with the number of samples N. Every estimate
+//! reports its standard error, found from the spread of the means of 32 equal batches of
+//! samples so that it works for vector integrands too.
+//!
+//! Sampling is reproducible: the same seed gives the same points and the same estimate.
+
+use crate::calculus::combine;
+use crate::integrators::StateVector;
+use crate::matrix3::Matrix3;
+use crate::matrixn::MatrixN;
+use crate::vector3d::Vector3D;
+
+/// Number of batches the samples of an estimate are split into to find its standard error.
+const BATCHES: usize = 32;
+
+/// A small, fast pseudo-random number generator (SplitMix64) for sampling. Not suitable for
+/// cryptography.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Returns a generator started from the passed seed.
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next uniformly distributed 64 bit integer.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns the next uniformly distributed number in [0, 1).
+    #[inline]
+    pub fn next_f64(&mut self) -> f64 {
+        // the top 53 bits fill the mantissa exactly
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Returns a uniformly distributed number in [a, b).
+    #[inline]
+    pub fn uniform(&mut self, a: f64, b: f64) -> f64 {
+        a + (b - a) * self.next_f64()
+    }
+
+    /// Returns a uniformly distributed point in the box with the passed opposite corners.
+    #[inline]
+    pub fn in_box(&mut self, min: Vector3D, max: Vector3D) -> Vector3D {
+        Vector3D::new(
+            self.uniform(min.x, max.x),
+            self.uniform(min.y, max.y),
+            self.uniform(min.z, max.z),
+        )
+    }
+
+    /// Returns uniformly distributed barycentric coordinates of a point in a simplex with
+    /// `count` vertices, non-negative and summing to one.
+    pub fn barycentric(&mut self, count: usize) -> Vec<f64> {
+        // normalized exponential variables are uniform on the simplex
+        let weights: Vec<f64> = (0..count).map(|_| -(1.0 - self.next_f64()).ln()).collect();
+        let total: f64 = weights.iter().sum();
+        weights.into_iter().map(|w| w / total).collect()
+    }
+}
+
+/// A Monte Carlo estimate of an integral.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct MonteCarloEstimate<S> {
+    /// The estimated integral.
+    pub value: S,
+    /// Standard error of the estimate. For a vector integrand it is a conservative bound on
+    /// the standard error of every component, taken from the largest deviation of each batch
+    /// in whichever component it is.
+    pub standard_error: f64,
+    /// The number of points the integrand was evaluated at.
+    pub samples: usize,
+}
+
+/// Returns the integral of `f` over the box between the corners `min` and `max`, in as many
+/// dimensions as they have, estimated from `samples` random points, or None if the corners
+/// differ in length or are empty or there are fewer samples than batches.
+///
+/// # Example
+/// ```rust
+/// use i_mth::monte_carlo::{integrate_box, SplitMix64};
+///
+/// // the integral of xy over the unit square is 1/4
+/// let mut rng = SplitMix64::new(7);
+/// let estimate = integrate_box(|p| p[0] * p[1], &[0.0, 0.0], &[1.0, 1.0], 100_000, &mut rng).unwrap();
+///
+/// assert!((estimate.value - 0.25).abs() < 4.0 * estimate.standard_error);
+/// assert!(estimate.standard_error < 1e-3);
+/// ```
+pub fn integrate_box<S, F>(
+    f: F,
+    min: &[f64],
+    max: &[f64],
+    samples: usize,
+    rng: &mut SplitMix64,
+) -> Option<MonteCarloEstimate<S>>
+where
+    S: StateVector,
+    F: Fn(&[f64]) -> S,
+{
+    if min.is_empty() || min.len() != max.len() {
+        return None;
+    }
+    let volume: f64 = min.iter().zip(max).map(|(a, b)| b - a).product();
+    let mut point = vec![0.0; min.len()];
+    estimate(samples, volume.abs(), || {
+        for ((p, a), b) in point.iter_mut().zip(min).zip(max) {
+            *p = rng.uniform(*a, *b);
+        }
+        f(&point)
+    })
+}
+
+/// Returns the integral of `f` over the simplex with the passed vertices, a triangle in
+/// the plane or a tetrahedron in space, one more vertex than it has dimensions, estimated
+/// from `samples` random points, or None if the vertices do not span a simplex or there are
+/// fewer samples than batches.
+///
+/// # Example
+/// ```rust
+/// use i_mth::monte_carlo::{integrate_simplex, SplitMix64};
+///
+/// // the first moment of area of the triangle (0, 0), (1, 0), (0, 1) about the y axis is 1/6
+/// let mut rng = SplitMix64::new(1);
+/// let triangle = [vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]];
+/// let estimate = integrate_simplex(|p| p[0], &triangle, 100_000, &mut rng).unwrap();
+///
+/// assert!((estimate.value - 1.0 / 6.0).abs() < 4.0 * estimate.standard_error);
+/// ```
+pub fn integrate_simplex<S, F>(
+    f: F,
+    vertices: &[Vec<f64>],
+    samples: usize,
+    rng: &mut SplitMix64,
+) -> Option<MonteCarloEstimate<S>>
+where
+    S: StateVector,
+    F: Fn(&[f64]) -> S,
+{
+    let n = vertices.len().checked_sub(1)?;
+    if n == 0 || vertices.iter().any(|v| v.len() != n) {
+        return None;
+    }
+    let edges = (1..=n).flat_map(|i| (0..n).map(move |k| vertices[i][k] - vertices[0][k]));
+    let det = MatrixN::from_vec(n, n, edges.collect())?.determinant()?;
+    let volume = det.abs() / (1..=n).map(|k| k as f64).product::<f64>();
+    if volume == 0.0 {
+        return None;
+    }
+    let mut point = vec![0.0; n];
+    estimate(samples, volume, || {
+        let weights = rng.barycentric(n + 1);
+        for (k, p) in point.iter_mut().enumerate() {
+            *p = vertices.iter().zip(&weights).map(|(v, w)| w * v[k]).sum();
+        }
+        f(&point)
+    })
+}
+
+/// The volume, centroid and inertia tensor of a solid of unit density.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct RegionProperties {
+    /// Volume in m<sup>3</sup>.
+    pub volume: f64,
+    /// Centroid of the volume.
+    pub centroid: Vector3D,
+    /// Inertia tensor about the centroid per unit density, in m<sup>5</sup>. Multiplying by
+    /// the density gives it in kg.m<sup>2</sup>.
+    pub inertia: Matrix3,
+    /// Standard error of the volume.
+    pub volume_error: f64,
+}
+
+/// Returns the volume, centroid and inertia of the region for which `inside` is true,
+/// which must lie within the box between `min` and `max`, estimated from `samples` random
+/// points, or None if the region was not hit or there are fewer samples than batches.
+///
+/// # Example
+/// ```rust
+/// use i_mth::constants::PI;
+/// use i_mth::monte_carlo::{region_properties, SplitMix64};
+/// use i_mth::vector3d::Vector3D;
+///
+/// // a unit sphere centred at (1, 2, 3)
+/// let centre = Vector3D::new(1.0, 2.0, 3.0);
+/// let inside = |p: Vector3D| (p - centre).magnitude() <= 1.0;
+/// let mut rng = SplitMix64::new(42);
+/// let (min, max) = (centre - Vector3D::set(1.0), centre + Vector3D::set(1.0));
+/// let sphere = region_properties(inside, min, max, 200_000, &mut rng).unwrap();
+///
+/// let volume = 4.0 / 3.0 * PI;
+/// assert!((sphere.volume - volume).abs() < 4.0 * sphere.volume_error);
+/// assert!((sphere.centroid - centre).magnitude() < 0.01);
+/// // I = 2/5 m r² about every axis through the centre
+/// assert!((sphere.inertia[(0, 0)] / (0.4 * volume) - 1.0).abs() < 0.02);
+/// assert!(sphere.inertia[(0, 1)].abs() < 0.01);
+/// ```
+pub fn region_properties<F>(
+    inside: F,
+    min: Vector3D,
+    max: Vector3D,
+    samples: usize,
+    rng: &mut SplitMix64,
+) -> Option<RegionProperties>
+where
+    F: Fn(Vector3D) -> bool,
+{
+    // moments are taken about the middle of the box to keep them well scaled
+    let middle = (min + max).scale(0.5);
+    let volume = ((max.x - min.x) * (max.y - min.y) * (max.z - min.z)).abs();
+    let moments = estimate(samples, volume, || {
+        let p = rng.in_box(min, max);
+        if !inside(p) {
+            return vec![0.0; 10];
+        }
+        let Vector3D { x, y, z } = p - middle;
+        vec![1.0, x, y, z, x * x, y * y, z * z, x * y, y * z, z * x]
+    })?;
+    let m = &moments.value;
+    if m[0] == 0.0 {
+        return None;
+    }
+    let c = Vector3D::new(m[1], m[2], m[3]).scale(1.0 / m[0]);
+    let about_middle = Matrix3::new([
+        [m[5] + m[6], -m[7], -m[9]],
+        [-m[7], m[4] + m[6], -m[8]],
+        [-m[9], -m[8], m[4] + m[5]],
+    ]);
+    // the parallel axis theorem moves the inertia to the centroid
+    let shift = Matrix3::IDENTITY.scale(c.squared_magnitude())
+        - Matrix3::from_row_vectors(c.scale(c.x), c.scale(c.y), c.scale(c.z));
+    Some(RegionProperties {
+        volume: m[0],
+        centroid: middle + c,
+        inertia: about_middle - shift.scale(m[0]),
+        volume_error: indicator_error(samples, volume, m[0]),
+    })
+}
+
+/// Returns the standard error of the mean of a 0 or 1 valued indicator with the passed
+/// integral over a region of the passed volume.
+#[inline]
+fn indicator_error(samples: usize, volume: f64, integral: f64) -> f64 {
+    let p = integral / volume;
+    volume * (p * (1.0 - p) / samples as f64).sqrt()
+}
+
+/// Returns the estimate `volume` times the mean of `samples` values drawn from `sample`,
+/// with its standard error from the means of [`BATCHES`] batches weighted by their sizes,
+/// which differ by at most one sample, or None if there are fewer samples than batches.
+fn estimate<S, G>(samples: usize, volume: f64, mut sample: G) -> Option<MonteCarloEstimate<S>>
+where
+    S: StateVector,
+    G: FnMut() -> S,
+{
+    if samples < BATCHES {
+        return None;
+    }
+    let mut means = Vec::with_capacity(BATCHES);
+    let mut weights = Vec::with_capacity(BATCHES);
+    for b in 0..BATCHES {
+        let size = (b + 1) * samples / BATCHES - b * samples / BATCHES;
+        let mut sum = sample();
+        for _ in 1..size {
+            sum = sum.add_scaled(&sample(), 1.0);
+        }
+        means.push(combine(&[(&sum, volume / size as f64)]));
+        weights.push(size as f64 / samples as f64);
+    }
+    let terms: Vec<(&S, f64)> = means.iter().zip(&weights).map(|(m, w)| (m, *w)).collect();
+    let value = combine(&terms);
+    let spread: f64 = means
+        .iter()
+        .zip(&weights)
+        .map(|(m, w)| w * m.add_scaled(&value, -1.0).max_norm().powi(2))
+        .sum();
+    Some(MonteCarloEstimate {
+        value,
+        standard_error: (spread / (BATCHES - 1) as f64).sqrt(),
+        samples,
+    })
+}
@@ -0,0 +1,345 @@
+//! # N-Body
+//!
+//! Gravitational simulation of a set of point masses attracting each other through
+//! Newton's law of universal gravitation.
+//!
+//! Accelerations are accumulated by direct pairwise summation which is O(n<sup>2</sup>) in the
+//! number of bodies. The integration scheme is pluggable through the [`NBodyIntegrator`] trait,
+//! which is implemented for every [`SecondOrderIntegrator`].
+
+use crate::constants::G;
+use crate::error::ImthError;
+use crate::integrators::SecondOrderIntegrator;
+use crate::vector3d::Vector3D;
+
+/// A point mass taking part in an N-body simulation.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Body {
+    /// Mass of the body in kg.
+    pub mass: f64,
+    /// Position of the body in m.
+    pub position: Vector3D,
+    /// Velocity of the body in ms<sup>-1</sup>.
+    pub velocity: Vector3D,
+}
+
+impl Body {
+    /// Returns a new body with the passed mass, position and velocity.
+    #[inline]
+    pub fn new(mass: f64, position: Vector3D, velocity: Vector3D) -> Self {
+        Self {
+            mass,
+            position,
+            velocity,
+        }
+    }
+
+    /// Returns the linear momentum of this body.
+    #[inline]
+    pub fn momentum(&self) -> Vector3D {
+        self.velocity.scale(self.mass)
+    }
+
+    /// Returns the kinetic energy of this body.
+    #[inline]
+    pub fn kinetic_energy(&self) -> f64 {
+        0.5 * self.mass * self.velocity.squared_magnitude()
+    }
+}
+
+/// A snapshot of the conserved quantities of an [`NBodySystem`].
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Diagnostics {
+    /// Total (kinetic + potential) energy in J.
+    pub energy: f64,
+    /// Total linear momentum in kg.ms<sup>-1</sup>.
+    pub momentum: Vector3D,
+    /// Total angular momentum about the origin in kg.m<sup>2</sup>s<sup>-1</sup>.
+    pub angular_momentum: Vector3D,
+}
+
+impl Diagnostics {
+    /// Returns the relative change in total energy since the passed initial snapshot.
+    /// This is the usual measure of how well an integrator is doing.
+    #[inline]
+    pub fn energy_drift(&self, initial: &Diagnostics) -> f64 {
+        (self.energy - initial.energy) / initial.energy.abs()
+    }
+
+    /// Returns the magnitude of the change in linear momentum since the passed initial snapshot.
+    #[inline]
+    pub fn momentum_drift(&self, initial: &Diagnostics) -> f64 {
+        (self.momentum - initial.momentum).magnitude()
+    }
+
+    /// Returns the magnitude of the change in angular momentum since the passed initial snapshot.
+    #[inline]
+    pub fn angular_momentum_drift(&self, initial: &Diagnostics) -> f64 {
+        (self.angular_momentum - initial.angular_momentum).magnitude()
+    }
+}
+
+/// A set of bodies interacting gravitationally.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct NBodySystem {
+    /// The bodies in the system.
+    pub bodies: Vec<Body>,
+    /// Softening length ε in m avoiding the singularity of close encounters, which puts
+    /// every separation r at √(r<sup>2</sup> + ε<sup>2</sup>). Zero gives plain Newtonian gravity.
+    pub softening: f64,
+    /// Simulation time in s.
+    pub time: f64,
+}
+
+impl NBodySystem {
+    /// Returns an empty system with no softening.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns an empty system with the passed softening length.
+    #[inline]
+    pub fn with_softening(softening: f64) -> Self {
+        Self {
+            softening,
+            ..Self::default()
+        }
+    }
+
+    /// Adds a body to the system and returns its index.
+    #[inline]
+    pub fn add_body(&mut self, body: Body) -> usize {
+        self.bodies.push(body);
+        self.bodies.len() - 1
+    }
+
+    /// Returns the gravitational acceleration of every body due to all the others,
+    /// in the same order as `bodies`.
+    #[inline]
+    pub fn accelerations(&self) -> Vec<Vector3D> {
+        let positions: Vec<Vector3D> = self.bodies.iter().map(|b| b.position).collect();
+        self.accelerations_at(&positions)
+    }
+
+    /// Returns the gravitational acceleration of every body if the bodies were at the passed
+    /// positions instead of their current ones, or an error if there is not one position
+    /// for every body, where [`NBodySystem::accelerations_at`] panics.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::nbody::{Body, NBodySystem};
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let mut system = NBodySystem::new();
+    /// system.add_body(Body::new(1.0, Vector3D::origin(), Vector3D::origin()));
+    /// system.add_body(Body::new(1.0, Vector3D::i(), Vector3D::origin()));
+    ///
+    /// assert!(system.try_accelerations_at(&[Vector3D::origin()]).is_err());
+    /// let acc = system.try_accelerations_at(&[Vector3D::origin(), Vector3D::i()]).unwrap();
+    /// assert_eq!(acc, system.accelerations());
+    /// ```
+    #[inline]
+    pub fn try_accelerations_at(&self, positions: &[Vector3D]) -> Result<Vec<Vector3D>, ImthError> {
+        if positions.len() != self.bodies.len() {
+            return Err(ImthError::InvalidArgument(
+                "there must be one position for every body",
+            ));
+        }
+        Ok(self.accelerations_at(positions))
+    }
+
+    /// Returns the gravitational acceleration of every body if the bodies were at the passed
+    /// positions instead of their current ones.
+    ///
+    /// # Panics
+    /// If the number of positions differs from the number of bodies.
+    #[cfg(not(feature = "rayon"))]
+    pub fn accelerations_at(&self, positions: &[Vector3D]) -> Vec<Vector3D> {
+        let n = self.bodies.len();
+        assert_eq!(
+            n,
+            positions.len(),
+            "there must be one position for every body"
+        );
+        let eps2 = self.softening * self.softening;
+        let mut acc = vec![Vector3D::origin(); n];
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let r = positions[j] - positions[i];
+                let dist2 = r.squared_magnitude() + eps2;
+                if dist2 == 0.0 {
+                    continue;
+                }
+                let inv_dist3 = 1.0 / (dist2 * dist2.sqrt());
+                acc[i] += r.scale(G * self.bodies[j].mass * inv_dist3);
+                acc[j] -= r.scale(G * self.bodies[i].mass * inv_dist3);
+            }
+        }
+        acc
+    }
+
+    /// Returns the gravitational acceleration of every body if the bodies were at the passed
+    /// positions instead of their current ones.
+    ///
+    /// With the `rayon` feature enabled the acceleration of each body is summed on its own
+    /// thread, which does twice the pairwise work but scales with the number of cores.
+    ///
+    /// # Panics
+    /// If the number of positions differs from the number of bodies.
+    #[cfg(feature = "rayon")]
+    pub fn accelerations_at(&self, positions: &[Vector3D]) -> Vec<Vector3D> {
+        use rayon::prelude::*;
+
+        assert_eq!(
+            self.bodies.len(),
+            positions.len(),
+            "there must be one position for every body"
+        );
+        let eps2 = self.softening * self.softening;
+        positions
+            .par_iter()
+            .map(|&p| {
+                positions
+                    .iter()
+                    .zip(&self.bodies)
+                    .fold(Vector3D::origin(), |acc, (&q, body)| {
+                        let r = q - p;
+                        let dist2 = r.squared_magnitude() + eps2;
+                        if dist2 == 0.0 {
+                            return acc;
+                        }
+                        let inv_dist3 = 1.0 / (dist2 * dist2.sqrt());
+                        acc + r.scale(G * body.mass * inv_dist3)
+                    })
+            })
+            .collect()
+    }
+
+    /// Advances the system by `dt` seconds with the passed integrator.
+    #[inline]
+    pub fn step<I: NBodyIntegrator>(&mut self, integrator: &I, dt: f64) {
+        integrator.advance(self, dt);
+        self.time += dt;
+    }
+
+    /// Returns the total mass of the system.
+    #[inline]
+    pub fn total_mass(&self) -> f64 {
+        self.bodies.iter().map(|b| b.mass).sum()
+    }
+
+    /// Returns the position of the center of mass of the system,
+    /// or None if the system has no mass.
+    pub fn center_of_mass(&self) -> Option<Vector3D> {
+        let total_mass = self.total_mass();
+        if total_mass > 0.0 {
+            let weighted = self.bodies.iter().fold(Vector3D::origin(), |acc, b| {
+                acc.add_scaled(b.position, b.mass)
+            });
+            return Some(weighted.scale(1.0 / total_mass));
+        }
+        None
+    }
+
+    /// Returns the total kinetic energy of the system.
+    #[inline]
+    pub fn kinetic_energy(&self) -> f64 {
+        self.bodies.iter().map(Body::kinetic_energy).sum()
+    }
+
+    /// Returns the total gravitational potential energy of the system.
+    pub fn potential_energy(&self) -> f64 {
+        let eps2 = self.softening * self.softening;
+        let mut energy = 0.0;
+        for (i, a) in self.bodies.iter().enumerate() {
+            for b in &self.bodies[(i + 1)..] {
+                let dist = ((b.position - a.position).squared_magnitude() + eps2).sqrt();
+                if dist > 0.0 {
+                    energy -= G * a.mass * b.mass / dist;
+                }
+            }
+        }
+        energy
+    }
+
+    /// Returns the total kinetic and potential energy of the system.
+    #[inline]
+    pub fn total_energy(&self) -> f64 {
+        self.kinetic_energy() + self.potential_energy()
+    }
+
+    /// Returns the total linear momentum of the system.
+    #[inline]
+    pub fn total_momentum(&self) -> Vector3D {
+        self.bodies
+            .iter()
+            .fold(Vector3D::origin(), |acc, b| acc + b.momentum())
+    }
+
+    /// Returns the total angular momentum of the system about the origin.
+    #[inline]
+    pub fn angular_momentum(&self) -> Vector3D {
+        self.bodies.iter().fold(Vector3D::origin(), |acc, b| {
+            acc + b.position.cross(b.momentum())
+        })
+    }
+
+    /// Returns a snapshot of the conserved quantities of the system, which can be compared
+    /// against a later snapshot to measure integration drift.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::integrators::Leapfrog;
+    /// use i_mth::nbody::{Body, NBodySystem};
+    /// use i_mth::vector3d::Vector3D;
+    /// use i_mth::utils::circular_orbit_velocity;
+    ///
+    /// let mut system = NBodySystem::new();
+    /// system.add_body(Body::new(5.972e24, Vector3D::origin(), Vector3D::origin()));
+    /// let v = circular_orbit_velocity(5.972e24, 384_400e3);
+    /// system.add_body(Body::new(7.342e22, Vector3D::new(384_400e3, 0.0, 0.0), Vector3D::new(0.0, v, 0.0)));
+    ///
+    /// let initial = system.diagnostics();
+    /// for _ in 0..1000 {
+    ///     system.step(&Leapfrog, 600.0);
+    /// }
+    ///
+    /// assert!(system.diagnostics().energy_drift(&initial).abs() < 1e-6);
+    /// ```
+    #[inline]
+    pub fn diagnostics(&self) -> Diagnostics {
+        Diagnostics {
+            energy: self.total_energy(),
+            momentum: self.total_momentum(),
+            angular_momentum: self.angular_momentum(),
+        }
+    }
+}
+
+/// An integration scheme able to advance an [`NBodySystem`] in time.
+pub trait NBodyIntegrator {
+    /// Advances the positions and velocities of the bodies by `dt` seconds.
+    /// The simulation time is updated by [`NBodySystem::step`].
+    fn advance(&self, system: &mut NBodySystem, dt: f64);
+}
+
+impl<I: SecondOrderIntegrator> NBodyIntegrator for I {
+    fn advance(&self, system: &mut NBodySystem, dt: f64) {
+        let positions: Vec<Vector3D> = system.bodies.iter().map(|b| b.position).collect();
+        let velocities: Vec<Vector3D> = system.bodies.iter().map(|b| b.velocity).collect();
+        let (positions, velocities) = SecondOrderIntegrator::step(
+            self,
+            |_, x: &Vec<Vector3D>, _: &Vec<Vector3D>| system.accelerations_at(x),
+            system.time,
+            &positions,
+            &velocities,
+            dt,
+        );
+        for ((body, x), v) in system.bodies.iter_mut().zip(positions).zip(velocities) {
+            body.position = x;
+            body.velocity = v;
+        }
+    }
+}
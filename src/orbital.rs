@@ -0,0 +1,170 @@
+//! # Orbital Mechanics
+//!
+//! Maneuver calculators for transfers between circular orbits around a celestial body, and
+//! the rocket equation relating the velocity changes to the propellant they burn.
+//!
+//! All functions take the mass of the central celestial body in kg and radii measured from
+//! its center in m, the same way the functions in [`crate::utils`] do. Angles are in radians.
+
+use crate::constants::{EARTH_GRAVITY, G, PI};
+use crate::utils::circular_orbit_velocity;
+
+/// The velocity changes and time of flight of a Hohmann transfer between two
+/// coplanar circular orbits.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct HohmannTransfer {
+    /// Velocity change at the departure (periapsis or apoapsis) burn in ms<sup>-1</sup>.
+    /// Negative when the burn slows the vehicle down (transfer to a lower orbit).
+    pub dv_departure: f64,
+    /// Velocity change at the arrival burn in ms<sup>-1</sup>.
+    /// Negative when the burn slows the vehicle down (transfer to a lower orbit).
+    pub dv_arrival: f64,
+    /// Semi-major axis of the transfer ellipse in m.
+    pub semi_major_axis: f64,
+    /// Time taken to travel half of the transfer ellipse in s.
+    pub transfer_time: f64,
+}
+
+impl HohmannTransfer {
+    /// Returns the sum of the magnitudes of both burns.
+    #[inline]
+    pub fn total_dv(&self) -> f64 {
+        self.dv_departure.abs() + self.dv_arrival.abs()
+    }
+}
+
+/// Returns the Hohmann transfer from a circular orbit of radius `r_departure` to a
+/// circular orbit of radius `r_arrival` around the celestial body with the passed mass.\
+/// [More Info](https://en.wikipedia.org/wiki/Hohmann_transfer_orbit)
+///
+/// # Example
+/// ```rust
+/// use i_mth::constants::{EARTH_MASS, EARTH_RADIUS};
+/// use i_mth::orbital::hohmann_transfer;
+///
+/// // low earth orbit to geostationary orbit
+/// let transfer = hohmann_transfer(EARTH_MASS, EARTH_RADIUS + 300e3, 42_164e3);
+///
+/// assert!((transfer.total_dv() - 3_893.0).abs() < 5.0);
+/// assert!((transfer.transfer_time / 3600.0 - 5.28).abs() < 0.01);
+/// ```
+pub fn hohmann_transfer(
+    mass_of_celestial_body: f64,
+    r_departure: f64,
+    r_arrival: f64,
+) -> HohmannTransfer {
+    let mu = G * mass_of_celestial_body;
+    let semi_major_axis = (r_departure + r_arrival) / 2.0;
+
+    let v_departure = circular_orbit_velocity(mass_of_celestial_body, r_departure);
+    let v_arrival = circular_orbit_velocity(mass_of_celestial_body, r_arrival);
+    let v_transfer_departure = vis_viva(mu, r_departure, semi_major_axis);
+    let v_transfer_arrival = vis_viva(mu, r_arrival, semi_major_axis);
+
+    HohmannTransfer {
+        dv_departure: v_transfer_departure - v_departure,
+        dv_arrival: v_arrival - v_transfer_arrival,
+        semi_major_axis,
+        transfer_time: PI * (semi_major_axis.powi(3) / mu).sqrt(),
+    }
+}
+
+/// Returns the velocity change needed to rotate the orbital plane by `delta_inclination`
+/// without changing the speed `velocity` of the vehicle.
+#[inline]
+pub fn plane_change_dv(velocity: f64, delta_inclination: f64) -> f64 {
+    2.0 * velocity * (delta_inclination / 2.0).sin()
+}
+
+/// Returns the velocity change needed to go from speed `v_initial` to speed `v_final`
+/// while rotating the orbital plane by `delta_inclination` in a single burn.
+#[inline]
+pub fn combined_maneuver_dv(v_initial: f64, v_final: f64, delta_inclination: f64) -> f64 {
+    (v_initial * v_initial + v_final * v_final
+        - 2.0 * v_initial * v_final * delta_inclination.cos())
+    .sqrt()
+}
+
+/// Returns a Hohmann transfer where the plane change of `delta_inclination` is combined
+/// with the arrival burn, which is cheaper than doing it as a separate maneuver
+/// when the arrival orbit is the higher one.\
+/// The returned `dv_arrival` is always positive as it is the magnitude of the combined burn.
+pub fn hohmann_with_plane_change(
+    mass_of_celestial_body: f64,
+    r_departure: f64,
+    r_arrival: f64,
+    delta_inclination: f64,
+) -> HohmannTransfer {
+    let mu = G * mass_of_celestial_body;
+    let mut transfer = hohmann_transfer(mass_of_celestial_body, r_departure, r_arrival);
+
+    let v_arrival = circular_orbit_velocity(mass_of_celestial_body, r_arrival);
+    let v_transfer_arrival = vis_viva(mu, r_arrival, transfer.semi_major_axis);
+    transfer.dv_arrival = combined_maneuver_dv(v_transfer_arrival, v_arrival, delta_inclination);
+    transfer
+}
+
+/// Returns the velocity change a rocket gets from burning propellant with effective exhaust
+/// velocity `exhaust_velocity` until its mass drops from `initial_mass` to `final_mass`,
+/// given by the Tsiolkovsky rocket equation Δv = v<sub>e</sub> ln(m<sub>0</sub> / m<sub>f</sub>).\
+/// [More Info](https://en.wikipedia.org/wiki/Tsiolkovsky_rocket_equation)
+#[inline]
+pub fn rocket_delta_v(exhaust_velocity: f64, initial_mass: f64, final_mass: f64) -> f64 {
+    exhaust_velocity * (initial_mass / final_mass).ln()
+}
+
+/// Returns the mass ratio m<sub>0</sub> / m<sub>f</sub> a rocket with effective exhaust
+/// velocity `exhaust_velocity` needs to gain the velocity change `delta_v`.
+#[inline]
+pub fn mass_ratio(exhaust_velocity: f64, delta_v: f64) -> f64 {
+    (delta_v / exhaust_velocity).exp()
+}
+
+/// Returns the mass of propellant a rocket with effective exhaust velocity
+/// `exhaust_velocity` burns to gain the velocity change `delta_v`, leaving it with
+/// `final_mass` once the burn is over.
+///
+/// # Example
+/// ```rust
+/// use i_mth::constants::{EARTH_MASS, EARTH_RADIUS};
+/// use i_mth::orbital::{exhaust_velocity_from_isp, hohmann_transfer, propellant_mass, rocket_delta_v};
+///
+/// // a 2000 kg satellite raised from low earth orbit to geostationary orbit by a 320 s engine
+/// let transfer = hohmann_transfer(EARTH_MASS, EARTH_RADIUS + 300e3, 42_164e3);
+/// let ve = exhaust_velocity_from_isp(320.0);
+/// let propellant = propellant_mass(ve, transfer.total_dv(), 2000.0);
+///
+/// assert!((propellant - 4915.0).abs() < 15.0);
+/// assert!((rocket_delta_v(ve, 2000.0 + propellant, 2000.0) - transfer.total_dv()).abs() < 1e-9);
+/// ```
+#[inline]
+pub fn propellant_mass(exhaust_velocity: f64, delta_v: f64, final_mass: f64) -> f64 {
+    final_mass * (mass_ratio(exhaust_velocity, delta_v) - 1.0)
+}
+
+/// Returns the effective exhaust velocity of an engine with specific impulse `isp` in s,
+/// I<sub>sp</sub>g<sub>0</sub> with g<sub>0</sub> the standard acceleration of gravity.
+#[inline]
+pub fn exhaust_velocity_from_isp(isp: f64) -> f64 {
+    // EARTH_GRAVITY points down, g0 is its magnitude
+    isp * EARTH_GRAVITY.abs()
+}
+
+/// Returns the thrust of a rocket engine expelling `mass_flow_rate` kg/s of propellant at
+/// effective exhaust velocity `exhaust_velocity`, F = ṁv<sub>e</sub>.
+#[inline]
+pub fn thrust(mass_flow_rate: f64, exhaust_velocity: f64) -> f64 {
+    mass_flow_rate * exhaust_velocity
+}
+
+/// Returns the time in s needed to burn `propellant` kg at a steady `mass_flow_rate` kg/s.
+#[inline]
+pub fn burn_time(propellant: f64, mass_flow_rate: f64) -> f64 {
+    propellant / mass_flow_rate
+}
+
+/// Speed on an orbit with the passed semi-major axis at distance `r` from the central body.
+#[inline]
+fn vis_viva(mu: f64, r: f64, semi_major_axis: f64) -> f64 {
+    (mu * (2.0 / r - 1.0 / semi_major_axis)).sqrt()
+}
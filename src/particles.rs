@@ -0,0 +1,561 @@
+//! # Particles
+//!
+//! A small mass-aggregate physics engine in the style of cyclone-physics.
+//!
+//! A [`ParticleWorld`] owns a list of [`Particle`]s and a list of [`ForceGenerator`]s.
+//! Every call to [`ParticleWorld::step`] asks each generator for its forces, accumulates
+//! them per particle and integrates the particles forward in time. Any
+//! [`SecondOrderIntegrator`] can be used instead through [`ParticleWorld::step_with`].
+
+use crate::error::ImthError;
+use crate::integrators::SecondOrderIntegrator;
+use crate::utils::linear_drag;
+use crate::vector3d::Vector3D;
+
+/// A point mass with no orientation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Particle {
+    /// Position of the particle in m.
+    pub position: Vector3D,
+    /// Velocity of the particle in ms<sup>-1</sup>.
+    pub velocity: Vector3D,
+    /// One over the mass of the particle. Zero means infinite mass (an immovable particle).
+    pub inverse_mass: f64,
+    /// Fraction of the velocity kept after one second, used to bleed off numerical energy.
+    /// One means no damping.
+    pub damping: f64,
+}
+
+impl Default for Particle {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            position: Vector3D::origin(),
+            velocity: Vector3D::origin(),
+            inverse_mass: 1.0,
+            damping: 1.0,
+        }
+    }
+}
+
+impl Particle {
+    /// Returns a new undamped particle with the passed mass, position and velocity.
+    /// A mass that is not greater than zero gives an immovable particle.
+    #[inline]
+    pub fn new(mass: f64, position: Vector3D, velocity: Vector3D) -> Self {
+        let inverse_mass = if mass > 0.0 { 1.0 / mass } else { 0.0 };
+        Self {
+            position,
+            velocity,
+            inverse_mass,
+            damping: 1.0,
+        }
+    }
+
+    /// Returns an immovable particle at the passed position.
+    #[inline]
+    pub fn fixed(position: Vector3D) -> Self {
+        Self {
+            position,
+            velocity: Vector3D::origin(),
+            inverse_mass: 0.0,
+            damping: 1.0,
+        }
+    }
+
+    /// Returns the mass of the particle, which is infinite for immovable particles.
+    #[inline]
+    pub fn mass(&self) -> f64 {
+        if self.inverse_mass > 0.0 {
+            return 1.0 / self.inverse_mass;
+        }
+        f64::INFINITY
+    }
+
+    /// Returns true if the particle can be moved by forces.
+    #[inline]
+    pub fn has_finite_mass(&self) -> bool {
+        self.inverse_mass > 0.0
+    }
+
+    /// Returns the kinetic energy of the particle.
+    #[inline]
+    pub fn kinetic_energy(&self) -> f64 {
+        if self.has_finite_mass() {
+            return 0.5 * self.mass() * self.velocity.squared_magnitude();
+        }
+        0.0
+    }
+
+    /// Returns the linear momentum of the particle, which is zero for immovable particles.
+    #[inline]
+    pub fn momentum(&self) -> Vector3D {
+        if self.has_finite_mass() {
+            return self.velocity.scale(self.mass());
+        }
+        Vector3D::origin()
+    }
+}
+
+/// Something that applies forces to the particles of a [`ParticleWorld`].
+pub trait ForceGenerator {
+    /// Adds the forces this generator applies into `forces`, which is indexed
+    /// the same way as `particles`.
+    fn accumulate(&self, particles: &[Particle], forces: &mut [Vector3D]);
+
+    /// Returns an error if this generator cannot act on the passed particles, like a
+    /// spring attached to a particle that does not exist.
+    #[inline]
+    fn validate(&self, _particles: &[Particle]) -> Result<(), ImthError> {
+        Ok(())
+    }
+}
+
+/// Returns an error unless every one of `indices` is the index of one of the particles.
+#[inline]
+fn check_indices(particles: &[Particle], indices: &[usize]) -> Result<(), ImthError> {
+    if indices.iter().all(|&i| i < particles.len()) {
+        return Ok(());
+    }
+    Err(ImthError::InvalidArgument(
+        "spring is attached to a particle that does not exist",
+    ))
+}
+
+/// Applies a uniform gravitational field to every particle with finite mass.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct GravityGenerator {
+    /// Acceleration due to gravity in ms<sup>-2</sup>.
+    pub gravity: Vector3D,
+}
+
+impl GravityGenerator {
+    /// Returns a generator for the passed gravitational acceleration.
+    #[inline]
+    pub fn new(gravity: Vector3D) -> Self {
+        Self { gravity }
+    }
+}
+
+impl ForceGenerator for GravityGenerator {
+    fn accumulate(&self, particles: &[Particle], forces: &mut [Vector3D]) {
+        for (particle, force) in particles.iter().zip(forces.iter_mut()) {
+            if particle.has_finite_mass() {
+                *force += self.gravity.scale(particle.mass());
+            }
+        }
+    }
+}
+
+/// Applies a drag force of magnitude `k1 * |v| + k2 * |v|²` against the velocity
+/// of every particle.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct DragGenerator {
+    /// Linear drag coefficient in kg.s<sup>-1</sup>.
+    pub k1: f64,
+    /// Quadratic drag coefficient in kg.m<sup>-1</sup>.
+    pub k2: f64,
+}
+
+impl DragGenerator {
+    /// Returns a drag generator with the passed linear and quadratic coefficients.
+    #[inline]
+    pub fn new(k1: f64, k2: f64) -> Self {
+        Self { k1, k2 }
+    }
+
+    /// Returns a drag generator applying only linear drag with the coefficient `b`,
+    /// see [`linear_drag`].
+    #[inline]
+    pub fn linear(b: f64) -> Self {
+        Self { k1: b, k2: 0.0 }
+    }
+
+    /// Returns a drag generator applying only the quadratic drag of a body with drag
+    /// coefficient `cd` and frontal area `area` in a fluid of density `rho`,
+    /// see [`quadratic_drag`](crate::utils::quadratic_drag).
+    #[inline]
+    pub fn quadratic(rho: f64, cd: f64, area: f64) -> Self {
+        Self {
+            k1: 0.0,
+            k2: 0.5 * rho * cd * area,
+        }
+    }
+}
+
+impl ForceGenerator for DragGenerator {
+    fn accumulate(&self, particles: &[Particle], forces: &mut [Vector3D]) {
+        for (particle, force) in particles.iter().zip(forces.iter_mut()) {
+            let v = particle.velocity;
+            *force += linear_drag(self.k1, v) + v.scale(-self.k2 * v.magnitude());
+        }
+    }
+}
+
+/// A Hookean spring connecting two particles of the world.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct SpringGenerator {
+    /// Index of the particle at one end of the spring.
+    pub a: usize,
+    /// Index of the particle at the other end of the spring.
+    pub b: usize,
+    /// Spring constant in Nm<sup>-1</sup>.
+    pub spring_constant: f64,
+    /// Length at which the spring exerts no force in m.
+    pub rest_length: f64,
+}
+
+impl SpringGenerator {
+    /// Returns a spring between the particles at indices `a` and `b`.
+    #[inline]
+    pub fn new(a: usize, b: usize, spring_constant: f64, rest_length: f64) -> Self {
+        Self {
+            a,
+            b,
+            spring_constant,
+            rest_length,
+        }
+    }
+}
+
+impl ForceGenerator for SpringGenerator {
+    fn accumulate(&self, particles: &[Particle], forces: &mut [Vector3D]) {
+        if check_indices(particles, &[self.a, self.b]).is_err() {
+            return;
+        }
+        let d = particles[self.a].position - particles[self.b].position;
+        if let Some(direction) = d.normalized() {
+            let force = direction.scale(-self.spring_constant * (d.magnitude() - self.rest_length));
+            forces[self.a] += force;
+            forces[self.b] -= force;
+        }
+    }
+
+    #[inline]
+    fn validate(&self, particles: &[Particle]) -> Result<(), ImthError> {
+        check_indices(particles, &[self.a, self.b])
+    }
+}
+
+/// A Hookean spring connecting one particle of the world to a fixed point in space.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct AnchoredSpringGenerator {
+    /// Index of the particle attached to the spring.
+    pub particle: usize,
+    /// The fixed end of the spring in m.
+    pub anchor: Vector3D,
+    /// Spring constant in Nm<sup>-1</sup>.
+    pub spring_constant: f64,
+    /// Length at which the spring exerts no force in m.
+    pub rest_length: f64,
+}
+
+impl AnchoredSpringGenerator {
+    /// Returns a spring between the particle at index `particle` and the passed anchor point.
+    #[inline]
+    pub fn new(particle: usize, anchor: Vector3D, spring_constant: f64, rest_length: f64) -> Self {
+        Self {
+            particle,
+            anchor,
+            spring_constant,
+            rest_length,
+        }
+    }
+}
+
+impl ForceGenerator for AnchoredSpringGenerator {
+    fn accumulate(&self, particles: &[Particle], forces: &mut [Vector3D]) {
+        if check_indices(particles, &[self.particle]).is_err() {
+            return;
+        }
+        let d = particles[self.particle].position - self.anchor;
+        if let Some(direction) = d.normalized() {
+            forces[self.particle] +=
+                direction.scale(-self.spring_constant * (d.magnitude() - self.rest_length));
+        }
+    }
+
+    #[inline]
+    fn validate(&self, particles: &[Particle]) -> Result<(), ImthError> {
+        check_indices(particles, &[self.particle])
+    }
+}
+
+/// A collection of particles and the force generators acting on them.
+#[derive(Default)]
+pub struct ParticleWorld {
+    /// The particles in the world.
+    pub particles: Vec<Particle>,
+    /// Simulation time in s.
+    pub time: f64,
+    generators: Vec<Box<dyn ForceGenerator>>,
+}
+
+impl ParticleWorld {
+    /// Returns an empty world.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a particle to the world and returns its index.
+    #[inline]
+    pub fn add_particle(&mut self, particle: Particle) -> usize {
+        self.particles.push(particle);
+        self.particles.len() - 1
+    }
+
+    /// Adds a force generator to the world, or returns an error if it cannot act on the
+    /// particles of the world, like a spring attached to a particle that has not been added
+    /// yet. A generator left referring to a particle removed from `particles` afterwards
+    /// applies no force.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::particles::{Particle, ParticleWorld, SpringGenerator};
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let mut world = ParticleWorld::new();
+    /// let a = world.add_particle(Particle::new(1.0, Vector3D::origin(), Vector3D::origin()));
+    /// let b = world.add_particle(Particle::new(1.0, Vector3D::i(), Vector3D::origin()));
+    ///
+    /// assert!(world.add_generator(SpringGenerator::new(a, b, 10.0, 0.5)).is_ok());
+    /// assert!(world.add_generator(SpringGenerator::new(a, 2, 10.0, 0.5)).is_err());
+    /// ```
+    #[inline]
+    pub fn add_generator<F: ForceGenerator + 'static>(
+        &mut self,
+        generator: F,
+    ) -> Result<(), ImthError> {
+        generator.validate(&self.particles)?;
+        self.generators.push(Box::new(generator));
+        Ok(())
+    }
+
+    /// Returns the total force on every particle from all the generators,
+    /// in the same order as `particles`.
+    pub fn forces(&self) -> Vec<Vector3D> {
+        let mut forces = vec![Vector3D::origin(); self.particles.len()];
+        for generator in &self.generators {
+            generator.accumulate(&self.particles, &mut forces);
+        }
+        forces
+    }
+
+    /// Returns the total kinetic energy of the particles.
+    #[inline]
+    pub fn kinetic_energy(&self) -> f64 {
+        self.particles.iter().map(Particle::kinetic_energy).sum()
+    }
+
+    /// Returns the total mass of the particles that can move.
+    #[inline]
+    pub fn total_mass(&self) -> f64 {
+        self.particles
+            .iter()
+            .filter(|p| p.has_finite_mass())
+            .map(Particle::mass)
+            .sum()
+    }
+
+    /// Returns the total linear momentum of the particles.
+    #[inline]
+    pub fn total_momentum(&self) -> Vector3D {
+        self.particles
+            .iter()
+            .fold(Vector3D::origin(), |acc, p| acc + p.momentum())
+    }
+
+    /// Returns the position of the center of mass of the particles that can move,
+    /// or None if there are none.
+    pub fn center_of_mass(&self) -> Option<Vector3D> {
+        let total_mass = self.total_mass();
+        if total_mass > 0.0 {
+            let weighted = self
+                .particles
+                .iter()
+                .filter(|p| p.has_finite_mass())
+                .fold(Vector3D::origin(), |acc, p| {
+                    acc.add_scaled(p.position, p.mass())
+                });
+            return Some(weighted.scale(1.0 / total_mass));
+        }
+        None
+    }
+
+    /// Returns the velocity of the center of mass of the particles that can move, the total
+    /// momentum over the total mass, or None if there are none.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::particles::{Particle, ParticleWorld};
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let mut world = ParticleWorld::new();
+    /// world.add_particle(Particle::new(2.0, Vector3D::origin(), Vector3D::new(3.0, 0.0, 0.0)));
+    /// world.add_particle(Particle::new(1.0, Vector3D::new(3.0, 0.0, 0.0), Vector3D::new(0.0, 6.0, 0.0)));
+    ///
+    /// assert_eq!(Vector3D::new(6.0, 6.0, 0.0), world.total_momentum());
+    /// assert_eq!(Some(Vector3D::new(2.0, 2.0, 0.0)), world.center_of_mass_velocity());
+    /// assert_eq!(Some(Vector3D::new(1.0, 0.0, 0.0)), world.center_of_mass());
+    /// ```
+    #[inline]
+    pub fn center_of_mass_velocity(&self) -> Option<Vector3D> {
+        let total_mass = self.total_mass();
+        if total_mass > 0.0 {
+            return Some(self.total_momentum().scale(1.0 / total_mass));
+        }
+        None
+    }
+
+    /// Accumulates the forces on every particle and advances the world by `dt` seconds
+    /// using semi-implicit Euler integration.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::particles::{GravityGenerator, Particle, ParticleWorld};
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let mut world = ParticleWorld::new();
+    /// let ball = world.add_particle(Particle::new(1.0, Vector3D::origin(), Vector3D::origin()));
+    /// world.add_generator(GravityGenerator::new(Vector3D::new(0.0, -9.81, 0.0))).unwrap();
+    ///
+    /// for _ in 0..100 {
+    ///     world.step(0.01);
+    /// }
+    ///
+    /// assert!((world.particles[ball].velocity.y + 9.81).abs() < 1e-9);
+    /// ```
+    pub fn step(&mut self, dt: f64) {
+        let forces = self.forces();
+        for (particle, force) in self.particles.iter_mut().zip(forces) {
+            if !particle.has_finite_mass() {
+                continue;
+            }
+            particle.velocity = particle
+                .velocity
+                .add_scaled(force, particle.inverse_mass * dt);
+            particle.velocity = particle.velocity.scale(particle.damping.powf(dt));
+            particle.position = particle.position.add_scaled(particle.velocity, dt);
+        }
+        self.time += dt;
+    }
+
+    /// Accumulates the forces on every particle and advances the world by `dt` seconds
+    /// using the passed integrator. Forces are re-evaluated at every stage the integrator needs.
+    pub fn step_with<I: SecondOrderIntegrator>(&mut self, integrator: &I, dt: f64) {
+        let positions: Vec<Vector3D> = self.particles.iter().map(|p| p.position).collect();
+        let velocities: Vec<Vector3D> = self.particles.iter().map(|p| p.velocity).collect();
+        let (positions, velocities) = integrator.step(
+            |_, x: &Vec<Vector3D>, v: &Vec<Vector3D>| self.accelerations_at(x, v),
+            self.time,
+            &positions,
+            &velocities,
+            dt,
+        );
+        for ((particle, x), v) in self.particles.iter_mut().zip(positions).zip(velocities) {
+            if particle.has_finite_mass() {
+                particle.position = x;
+                particle.velocity = v.scale(particle.damping.powf(dt));
+            }
+        }
+        self.time += dt;
+    }
+
+    /// Returns the acceleration of every particle if the particles had the passed
+    /// positions and velocities instead of their current ones.
+    fn accelerations_at(&self, positions: &[Vector3D], velocities: &[Vector3D]) -> Vec<Vector3D> {
+        let particles: Vec<Particle> = self
+            .particles
+            .iter()
+            .zip(positions.iter().zip(velocities))
+            .map(|(p, (x, v))| Particle {
+                position: *x,
+                velocity: *v,
+                ..*p
+            })
+            .collect();
+        let mut forces = vec![Vector3D::origin(); particles.len()];
+        for generator in &self.generators {
+            generator.accumulate(&particles, &mut forces);
+        }
+        particles
+            .iter()
+            .zip(forces)
+            .map(|(p, f)| f.scale(p.inverse_mass))
+            .collect()
+    }
+}
+
+/// Returns the velocity of the last fragment of a body of mass `total_mass` moving at
+/// `velocity` that splits apart, given the `(mass, velocity)` of every other fragment.
+/// The last fragment takes the rest of the mass and, since the forces of the separation
+/// are internal, the rest of the momentum.\
+/// Returns None if the other fragments take up all the mass.
+///
+/// # Example
+/// ```rust
+/// use i_mth::particles::last_fragment_velocity;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // a 12 kg shell moving at 50 m/s bursts into three, two of the pieces are tracked
+/// let v = last_fragment_velocity(
+///     12.0,
+///     Vector3D::new(50.0, 0.0, 0.0),
+///     &[(4.0, Vector3D::new(0.0, 60.0, 0.0)), (5.0, Vector3D::new(80.0, 0.0, 0.0))],
+/// )
+/// .unwrap();
+///
+/// assert!((v - Vector3D::new(200.0 / 3.0, -80.0, 0.0)).magnitude() < 1e-12);
+/// ```
+pub fn last_fragment_velocity(
+    total_mass: f64,
+    velocity: Vector3D,
+    fragments: &[(f64, Vector3D)],
+) -> Option<Vector3D> {
+    let (known_mass, known_momentum) = fragments
+        .iter()
+        .fold((0.0, Vector3D::origin()), |(m, p), &(mi, vi)| {
+            (m + mi, p.add_scaled(vi, mi))
+        });
+    let remaining = total_mass - known_mass;
+    if remaining > 0.0 {
+        return Some((velocity.scale(total_mass) - known_momentum).scale(1.0 / remaining));
+    }
+    None
+}
+
+/// Returns the velocities of two bodies of mass `m1` and `m2`, moving together at
+/// `velocity`, after an explosion between them releases `energy` J of kinetic energy and
+/// pushes them apart along `direction`, with the first body going along `direction`.\
+/// Returns None if either mass is not positive, the energy is negative or the direction is zero.
+///
+/// # Example
+/// ```rust
+/// use i_mth::particles::separation_velocities;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // a 3 kg and a 1 kg block at rest are pushed apart by a spring storing 24 J
+/// let (v1, v2) = separation_velocities(3.0, 1.0, Vector3D::origin(), 24.0, Vector3D::i()).unwrap();
+///
+/// assert!((v1 - Vector3D::new(2.0, 0.0, 0.0)).magnitude() < 1e-12);
+/// assert!((v2 - Vector3D::new(-6.0, 0.0, 0.0)).magnitude() < 1e-12);
+/// ```
+pub fn separation_velocities(
+    m1: f64,
+    m2: f64,
+    velocity: Vector3D,
+    energy: f64,
+    direction: Vector3D,
+) -> Option<(Vector3D, Vector3D)> {
+    if m1 <= 0.0 || m2 <= 0.0 || energy < 0.0 {
+        return None;
+    }
+    let direction = direction.normalized()?;
+    // in the center of mass frame the momenta are equal and opposite, p = √(2Em₁m₂ / (m₁ + m₂))
+    let p = (2.0 * energy * m1 * m2 / (m1 + m2)).sqrt();
+    Some((
+        velocity.add_scaled(direction, p / m1),
+        velocity.add_scaled(direction, -p / m2),
+    ))
+}
@@ -0,0 +1,571 @@
+//! # Polynomial
+//!
+//! A real polynomial type with evaluation, arithmetic, calculus and real root finding,
+//! handy for beam shear and moment equations and fitted trajectories.
+
+use std::fmt;
+use std::ops::*;
+
+use crate::roots::brent;
+use crate::series::DataSeries;
+
+/// Represents a polynomial c<sub>0</sub> + c<sub>1</sub>x + c<sub>2</sub>x<sup>2</sup> + ...
+/// by its coefficients in increasing powers of x.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Polynomial {
+    /// The coefficients in increasing powers of x, so `coefficients[i]` multiplies x<sup>i</sup>.
+    pub coefficients: Vec<f64>,
+}
+
+impl Polynomial {
+    /// Returns a polynomial with the passed coefficients in increasing powers of x.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::polynomial::Polynomial;
+    ///
+    /// // 1 + 2x + 3x²
+    /// let p = Polynomial::new(vec![1.0, 2.0, 3.0]);
+    ///
+    /// assert_eq!(17.0, p.evaluate(2.0));
+    /// assert_eq!(Polynomial::new(vec![2.0, 6.0]), p.derivative());
+    /// ```
+    #[inline]
+    pub fn new(coefficients: Vec<f64>) -> Self {
+        Self { coefficients }
+    }
+
+    /// Returns the polynomial that is constant at the passed value.
+    #[inline]
+    pub fn constant(value: f64) -> Self {
+        Self {
+            coefficients: vec![value],
+        }
+    }
+
+    /// Returns the polynomial with the passed real roots and a leading coefficient of one,
+    /// (x - r<sub>0</sub>)(x - r<sub>1</sub>)...
+    pub fn from_roots(roots: &[f64]) -> Self {
+        roots
+            .iter()
+            .fold(Self::constant(1.0), |p, r| &p * &Self::new(vec![-r, 1.0]))
+    }
+
+    /// Returns the degree of the polynomial, ignoring zero leading coefficients.
+    /// The zero polynomial has degree zero.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.coefficients
+            .iter()
+            .rposition(|c| *c != 0.0)
+            .unwrap_or(0)
+    }
+
+    /// Returns a copy of the polynomial without zero leading coefficients.
+    #[inline]
+    pub fn trimmed(&self) -> Self {
+        let len = self
+            .coefficients
+            .iter()
+            .rposition(|c| *c != 0.0)
+            .map_or(0, |i| i + 1);
+        Self {
+            coefficients: self.coefficients[..len].to_vec(),
+        }
+    }
+
+    /// Returns the value of the polynomial at `x`, evaluated with Horner's method.
+    #[inline]
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |acc, c| acc * x + c)
+    }
+
+    /// Returns the polynomial with every coefficient scaled by the passed value.
+    #[inline]
+    pub fn scale(&self, value: f64) -> Self {
+        Self {
+            coefficients: self.coefficients.iter().map(|c| c * value).collect(),
+        }
+    }
+
+    /// Returns the derivative of the polynomial.
+    pub fn derivative(&self) -> Self {
+        if self.coefficients.len() <= 1 {
+            return Self::constant(0.0);
+        }
+        Self {
+            coefficients: self
+                .coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| c * i as f64)
+                .collect(),
+        }
+    }
+
+    /// Returns the antiderivative of the polynomial whose value at zero is `constant`.
+    pub fn antiderivative(&self, constant: f64) -> Self {
+        let mut coefficients = Vec::with_capacity(self.coefficients.len() + 1);
+        coefficients.push(constant);
+        coefficients.extend(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .map(|(i, c)| c / (i + 1) as f64),
+        );
+        Self { coefficients }
+    }
+
+    /// Returns the definite integral of the polynomial from `a` to `b`.
+    #[inline]
+    pub fn integrate(&self, a: f64, b: f64) -> f64 {
+        let antiderivative = self.antiderivative(0.0);
+        antiderivative.evaluate(b) - antiderivative.evaluate(a)
+    }
+
+    /// Returns the distinct real roots of the polynomial in increasing order.
+    ///
+    /// Roots are isolated between the real roots of the derivative, where the polynomial is
+    /// monotonic, and refined with Brent's method. Repeated roots are reported once.
+    /// The zero polynomial returns no roots.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from_roots(&[-2.0, 0.5, 0.5, 3.0]);
+    /// let roots = p.real_roots();
+    ///
+    /// assert_eq!(3, roots.len());
+    /// for (root, expected) in roots.iter().zip([-2.0, 0.5, 3.0]) {
+    ///     assert!((root - expected).abs() < 1e-6);
+    /// }
+    /// ```
+    pub fn real_roots(&self) -> Vec<f64> {
+        let p = self.trimmed();
+        let n = p.coefficients.len();
+        if n <= 1 {
+            return Vec::new();
+        }
+        let c = &p.coefficients;
+        if n == 2 {
+            return vec![-c[0] / c[1]];
+        }
+        if n == 3 {
+            return quadratic_roots(c[2], c[1], c[0]);
+        }
+
+        let lead = c[n - 1];
+        let bound = 1.0
+            + c[..n - 1]
+                .iter()
+                .map(|a| (a / lead).abs())
+                .fold(0.0, f64::max);
+        let mut points = vec![-bound];
+        points.extend(
+            p.derivative()
+                .real_roots()
+                .into_iter()
+                .filter(|x| x.abs() < bound),
+        );
+        points.push(bound);
+
+        let mut roots: Vec<f64> = Vec::new();
+        let push = |x: f64, roots: &mut Vec<f64>| {
+            if roots
+                .last()
+                .is_none_or(|last| (x - last).abs() > 1e-12 * x.abs().max(1.0))
+            {
+                roots.push(x);
+            }
+        };
+        for window in points.windows(2) {
+            let (a, b) = (window[0], window[1]);
+            let fa = p.evaluate(a);
+            let fb = p.evaluate(b);
+            if p.is_negligible_at(a) {
+                push(a, &mut roots);
+            } else if !p.is_negligible_at(b) && fa.signum() != fb.signum() {
+                if let Ok(root) = brent(|x| p.evaluate(x), a, b, 1e-15 * bound, 200) {
+                    push(root.root, &mut roots);
+                }
+            }
+        }
+        if p.is_negligible_at(bound) {
+            push(bound, &mut roots);
+        }
+        roots
+    }
+
+    /// Returns true if the value at `x` is indistinguishable from zero given the rounding
+    /// error of evaluating the polynomial there.
+    fn is_negligible_at(&self, x: f64) -> bool {
+        let magnitude = self
+            .coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |acc, c| acc * x.abs() + c.abs());
+        self.evaluate(x).abs() <= 64.0 * f64::EPSILON * magnitude
+    }
+}
+
+/// Returns the distinct real roots of ax² + bx + c in increasing order, with a non-zero `a`.
+fn quadratic_roots(a: f64, b: f64, c: f64) -> Vec<f64> {
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < -64.0 * f64::EPSILON * b * b {
+        return Vec::new();
+    }
+    if discriminant <= 64.0 * f64::EPSILON * b * b {
+        return vec![-b / (2.0 * a)];
+    }
+    let q = -0.5 * (b + discriminant.sqrt().copysign(b));
+    let (r1, r2) = (q / a, c / q);
+    vec![r1.min(r2), r1.max(r2)]
+}
+
+impl Add for &Polynomial {
+    type Output = Polynomial;
+    fn add(self, rhs: &Polynomial) -> Polynomial {
+        let n = self.coefficients.len().max(rhs.coefficients.len());
+        Polynomial {
+            coefficients: (0..n)
+                .map(|i| {
+                    self.coefficients.get(i).unwrap_or(&0.0)
+                        + rhs.coefficients.get(i).unwrap_or(&0.0)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Add for Polynomial {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        &self + &rhs
+    }
+}
+
+impl Sub for &Polynomial {
+    type Output = Polynomial;
+    #[inline]
+    fn sub(self, rhs: &Polynomial) -> Polynomial {
+        self + &rhs.scale(-1.0)
+    }
+}
+
+impl Sub for Polynomial {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        &self - &rhs
+    }
+}
+
+impl Mul for &Polynomial {
+    type Output = Polynomial;
+    fn mul(self, rhs: &Polynomial) -> Polynomial {
+        if self.coefficients.is_empty() || rhs.coefficients.is_empty() {
+            return Polynomial::constant(0.0);
+        }
+        let mut coefficients = vec![0.0; self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                coefficients[i + j] += a * b;
+            }
+        }
+        Polynomial { coefficients }
+    }
+}
+
+impl Mul for Polynomial {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        &self * &rhs
+    }
+}
+
+impl Neg for Polynomial {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self.scale(-1.0)
+    }
+}
+
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms: Vec<String> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| **c != 0.0)
+            .map(|(i, c)| match i {
+                0 => format!("{c}"),
+                1 => format!("{c}x"),
+                _ => format!("{c}x^{i}"),
+            })
+            .collect();
+        if terms.is_empty() {
+            return write!(f, "0");
+        }
+        write!(f, "{}", terms.join(" + "))
+    }
+}
+
+/// A polynomial that holds over the interval `[start, end]`.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PolynomialSegment {
+    /// Start of the interval.
+    pub start: f64,
+    /// End of the interval.
+    pub end: f64,
+    /// The polynomial in x, not in the distance from `start`.
+    pub polynomial: Polynomial,
+}
+
+impl PolynomialSegment {
+    /// Returns the segment of the passed polynomial over `[start, end]`.
+    #[inline]
+    pub fn new(start: f64, end: f64, polynomial: Polynomial) -> Self {
+        Self {
+            start,
+            end,
+            polynomial,
+        }
+    }
+
+    /// Returns the value of the polynomial at x.
+    #[inline]
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.polynomial.evaluate(x)
+    }
+}
+
+/// A function made of polynomial segments over consecutive intervals, like the shear force
+/// or bending moment along a beam. It may jump where two segments meet.
+///
+/// # Example
+/// ```rust
+/// use i_mth::polynomial::{PiecewisePolynomial, Polynomial, PolynomialSegment};
+///
+/// // shear of a 4 m simply supported beam with 10 kN at 1 m
+/// let shear = PiecewisePolynomial::new(vec![
+///     PolynomialSegment::new(0.0, 1.0, Polynomial::constant(7.5)),
+///     PolynomialSegment::new(1.0, 4.0, Polynomial::constant(-2.5)),
+/// ]);
+/// let moment = shear.antiderivative(0.0);
+///
+/// assert_eq!(Some(-2.5), shear.evaluate(1.0));
+/// assert_eq!(Some(7.5), shear.evaluate_left(1.0));
+/// assert_eq!(vec![1.0], shear.roots());
+/// assert_eq!(Some((1.0, 7.5)), moment.max());
+/// assert!(moment.evaluate(4.0).unwrap().abs() < 1e-12);
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PiecewisePolynomial {
+    /// The segments in increasing x, each starting where the previous one ends.
+    pub segments: Vec<PolynomialSegment>,
+}
+
+impl PiecewisePolynomial {
+    /// Returns the function made of the passed segments, which must be consecutive.
+    #[inline]
+    pub fn new(segments: Vec<PolynomialSegment>) -> Self {
+        Self { segments }
+    }
+
+    /// Returns the start of the first segment, or None if there are no segments.
+    #[inline]
+    pub fn start(&self) -> Option<f64> {
+        Some(self.segments.first()?.start)
+    }
+
+    /// Returns the end of the last segment, or None if there are no segments.
+    #[inline]
+    pub fn end(&self) -> Option<f64> {
+        Some(self.segments.last()?.end)
+    }
+
+    /// Returns the segment that holds just to the right of x, or the last segment at its
+    /// end, or None if x is outside of the segments.
+    pub fn segment_at(&self, x: f64) -> Option<&PolynomialSegment> {
+        if x < self.start()? || x > self.end()? {
+            return None;
+        }
+        let index = self.segments.partition_point(|s| s.end <= x);
+        self.segments.get(index).or(self.segments.last())
+    }
+
+    /// Returns the value at x, taking the value just to the right of x where the function
+    /// jumps, or None if x is outside of the segments.
+    #[inline]
+    pub fn evaluate(&self, x: f64) -> Option<f64> {
+        Some(self.segment_at(x)?.evaluate(x))
+    }
+
+    /// Returns the value just to the left of x where the function jumps, or None if x is
+    /// outside of the segments.
+    pub fn evaluate_left(&self, x: f64) -> Option<f64> {
+        if x < self.start()? || x > self.end()? {
+            return None;
+        }
+        let index = self.segments.partition_point(|s| s.end < x);
+        Some(self.segments.get(index)?.evaluate(x))
+    }
+
+    /// Returns the segment-wise derivative.
+    pub fn derivative(&self) -> Self {
+        self.map(|p| p.derivative())
+    }
+
+    /// Returns the continuous antiderivative whose value at the start is `initial`.
+    pub fn antiderivative(&self, initial: f64) -> Self {
+        let mut value = initial;
+        let segments = self
+            .segments
+            .iter()
+            .map(|s| {
+                let mut p = s.polynomial.antiderivative(0.0);
+                p.coefficients[0] = value - p.evaluate(s.start);
+                value = p.evaluate(s.end);
+                PolynomialSegment::new(s.start, s.end, p)
+            })
+            .collect();
+        Self::new(segments)
+    }
+
+    /// Returns the integral from the start to the end of the segments.
+    pub fn integrate(&self) -> f64 {
+        self.segments
+            .iter()
+            .map(|s| s.polynomial.integrate(s.start, s.end))
+            .sum()
+    }
+
+    /// Returns the function with `value` added to every segment.
+    pub fn add_constant(&self, value: f64) -> Self {
+        self.map(|p| p + &Polynomial::constant(value))
+    }
+
+    /// Returns the function with every segment scaled by `value`.
+    pub fn scale(&self, value: f64) -> Self {
+        self.map(|p| p.scale(value))
+    }
+
+    /// Returns the function with `polynomial` added to every segment.
+    pub fn add_polynomial(&self, polynomial: &Polynomial) -> Self {
+        self.map(|p| p + polynomial)
+    }
+
+    /// Returns the x values in increasing order where the function is zero or changes sign
+    /// across a jump. Segments that are zero throughout are skipped.
+    pub fn roots(&self) -> Vec<f64> {
+        let mut roots: Vec<f64> = Vec::new();
+        let mut push = |x: f64| {
+            if roots
+                .last()
+                .is_none_or(|last| (x - last).abs() > 1e-12 * x.abs().max(1.0))
+            {
+                roots.push(x);
+            }
+        };
+        let mut previous: Option<f64> = None;
+        for s in &self.segments {
+            let left = s.evaluate(s.start);
+            if previous.is_some_and(|p| p * left < 0.0) {
+                push(s.start);
+            }
+            for root in s.polynomial.real_roots() {
+                if root >= s.start && root <= s.end {
+                    push(root);
+                }
+            }
+            previous = Some(s.evaluate(s.end));
+        }
+        roots
+    }
+
+    /// Returns the point with the smallest value, including the values on both sides of
+    /// every jump, or None if there are no segments.
+    pub fn min(&self) -> Option<(f64, f64)> {
+        self.candidates().min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Returns the point with the largest value, including the values on both sides of
+    /// every jump, or None if there are no segments.
+    pub fn max(&self) -> Option<(f64, f64)> {
+        self.candidates().max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Returns the point with the largest magnitude, or None if there are no segments.
+    pub fn abs_max(&self) -> Option<(f64, f64)> {
+        self.candidates()
+            .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+    }
+
+    /// Returns the function sampled at about `samples` points spread over the segments,
+    /// with the ends of every segment included so jumps are drawn as vertical lines. With
+    /// the `rayon` feature the points are evaluated in parallel.
+    pub fn sample(&self, name: &str, samples: usize) -> DataSeries {
+        let (Some(start), Some(end)) = (self.start(), self.end()) else {
+            return DataSeries::new(name, Vec::new());
+        };
+        let width = (end - start).max(f64::MIN_POSITIVE);
+        let mut xs = Vec::with_capacity(samples + 2 * self.segments.len());
+        for s in &self.segments {
+            let n = ((samples as f64 * (s.end - s.start) / width).round() as usize).max(2);
+            let step = (s.end - s.start) / (n - 1) as f64;
+            xs.extend((0..n).map(|i| {
+                let x = if i == n - 1 {
+                    s.end
+                } else {
+                    s.start + step * i as f64
+                };
+                (s, x)
+            }));
+        }
+        let evaluate = |&(s, x): &(&PolynomialSegment, f64)| (x, s.evaluate(x));
+        #[cfg(feature = "rayon")]
+        let points = {
+            use rayon::prelude::*;
+            xs.par_iter()
+                .with_min_len(crate::bulk::BLOCK)
+                .map(evaluate)
+                .collect()
+        };
+        #[cfg(not(feature = "rayon"))]
+        let points = xs.iter().map(evaluate).collect();
+        DataSeries::new(name, points)
+    }
+
+    /// Returns the ends of every segment and the stationary points inside them.
+    fn candidates(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.segments.iter().flat_map(|s| {
+            let mut xs = vec![s.start, s.end];
+            xs.extend(
+                s.polynomial
+                    .derivative()
+                    .real_roots()
+                    .into_iter()
+                    .filter(|x| *x > s.start && *x < s.end),
+            );
+            xs.into_iter().map(move |x| (x, s.evaluate(x)))
+        })
+    }
+
+    fn map<F: Fn(&Polynomial) -> Polynomial>(&self, f: F) -> Self {
+        Self::new(
+            self.segments
+                .iter()
+                .map(|s| PolynomialSegment::new(s.start, s.end, f(&s.polynomial)))
+                .collect(),
+        )
+    }
+}
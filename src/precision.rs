@@ -0,0 +1,437 @@
+//! # Extended precision
+//!
+//! A double-double number type and solvers generic over the scalar they compute in, for
+//! checking f64 results that may have lost digits, like long orbital propagations and
+//! the solves of trusses that are nearly mechanisms. Enabled with the `precision` feature.
+//!
+//! A [`DoubleDouble`] holds a value as the unevaluated sum of two f64, the second being
+//! the rounding error of the first, which gives about 32 significant digits with the
+//! exponent range of f64. Its arithmetic is built on error-free transformations of f64
+//! operations, so it needs no dependencies and runs at roughly a tenth of the speed of
+//! f64. Running the same calculation through the [`Real`] trait with f64 and with
+//! [`DoubleDouble`] and comparing the results shows how many digits of the f64 result can
+//! be trusted.
+//!
+//! [`solve`] and [`propagate_two_body`] are reference implementations kept apart from the
+//! f64 solvers of the crate, not generic versions of them: [`MatrixN::solve`] factors
+//! into f64 storage and the [`crate::integrators`] step f64 state vectors. They follow
+//! the textbook Gaussian elimination and classic Runge-Kutta method so that only the
+//! scalar differs between two runs, and their f64 results can differ slightly from the
+//! solvers they check.
+//! # Example
+//! ```rust
+//! use i_mth::precision::DoubleDouble;
+//!
+//! let tenth = DoubleDouble::from(1.0) / DoubleDouble::from(10.0);
+//! let mut sum = DoubleDouble::from(0.0);
+//! let mut naive = 0.0;
+//! for _ in 0..1_000_000 {
+//!     sum += tenth;
+//!     naive += 0.1;
+//! }
+//!
+//! assert!((sum.to_f64() - 1e5).abs() < 1e-20);
+//! assert!((naive - 1e5f64).abs() > 1e-7);
+//! ```
+//!
+//! Checking the member forces of a very flat truss, which carries large forces from a
+//! small load:
+//! ```rust
+//! use i_mth::precision::{solve, DoubleDouble};
+//! use i_mth::truss::{Support2D, Truss2D};
+//! use i_mth::vector2d::Vector2D;
+//!
+//! let truss = Truss2D::new(vec![
+//!     Vector2D::new(0.0, 0.0),
+//!     Vector2D::new(2.0, 0.0),
+//!     Vector2D::new(1.0, 1e-6),
+//! ])
+//!     .with_member(0, 1)
+//!     .with_member(1, 2)
+//!     .with_member(0, 2)
+//!     .with_support(0, Support2D::Pin)
+//!     .with_support(1, Support2D::Roller(Vector2D::j()))
+//!     .with_load(2, Vector2D::new(0.0, -1.0));
+//!
+//! let forces = truss.solve().unwrap().member_forces;
+//! let (a, b) = truss.equilibrium_equations().unwrap();
+//! let extended: Vec<DoubleDouble> = solve(&a, &b).unwrap();
+//! for (f, e) in forces.iter().zip(&extended) {
+//!     assert!((f - e.to_f64()).abs() <= 1e-9 * f.abs().max(1.0));
+//! }
+//! ```
+
+use std::fmt;
+use std::ops::*;
+
+use crate::matrixn::MatrixN;
+use crate::vector3d::Vector3D;
+
+pub use crate::scalar::Real;
+
+/// Represents a real number as the unevaluated sum `hi + lo` of two f64 with
+/// |lo| ≤ ulp(hi) / 2, giving about 106 bits of significand.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+pub struct DoubleDouble {
+    /// The value rounded to the nearest f64.
+    pub hi: f64,
+    /// The rounding error of `hi`.
+    pub lo: f64,
+}
+
+impl DoubleDouble {
+    /// Returns the double-double `hi + lo`, renormalized so `hi` is the sum rounded to f64.
+    #[inline]
+    pub fn new(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = two_sum(hi, lo);
+        Self { hi, lo }
+    }
+
+    /// Returns the exact sum of two f64.
+    #[inline]
+    pub fn sum(a: f64, b: f64) -> Self {
+        Self::new(a, b)
+    }
+
+    /// Returns the exact product of two f64.
+    #[inline]
+    pub fn product(a: f64, b: f64) -> Self {
+        let p = a * b;
+        Self {
+            hi: p,
+            lo: a.mul_add(b, -p),
+        }
+    }
+
+    /// Returns this value rounded to the nearest f64.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.hi + self.lo
+    }
+
+    /// Returns the absolute value of this value.
+    #[inline]
+    pub fn abs(self) -> Self {
+        if self.hi < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Returns the square root of this value, NaN if it is negative.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::precision::DoubleDouble;
+    ///
+    /// let root = DoubleDouble::from(2.0).sqrt();
+    /// let error = root * root - DoubleDouble::from(2.0);
+    /// assert!(error.abs().to_f64() < 1e-30);
+    /// ```
+    pub fn sqrt(self) -> Self {
+        if self.hi <= 0.0 {
+            return Self::from(self.hi.sqrt());
+        }
+        // one Newton step from the f64 root doubles its correct digits
+        let q = self.hi.sqrt();
+        let residual = self - Self::product(q, q);
+        Self::sum(q, residual.hi / (2.0 * q))
+    }
+
+    /// Returns this value raised to the integer power `n`, by repeated squaring.
+    pub fn powi(self, n: i32) -> Self {
+        let mut result = Self::from(1.0);
+        let mut base = self;
+        let mut e = n.unsigned_abs();
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        if n < 0 {
+            Self::from(1.0) / result
+        } else {
+            result
+        }
+    }
+}
+
+/// Returns `a + b` and its rounding error.
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let b_virtual = s - a;
+    (s, (a - (s - b_virtual)) + (b - b_virtual))
+}
+
+/// Returns `a + b` and its rounding error, for |a| ≥ |b|.
+#[inline]
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+impl From<f64> for DoubleDouble {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self { hi: value, lo: 0.0 }
+    }
+}
+
+impl Real for DoubleDouble {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        DoubleDouble::to_f64(self)
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        DoubleDouble::abs(self)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        DoubleDouble::sqrt(self)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        DoubleDouble::powi(self, n)
+    }
+}
+
+impl fmt::Display for DoubleDouble {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}", self.hi, self.lo)
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (s, e) = two_sum(self.hi, rhs.hi);
+        let (t, f) = two_sum(self.lo, rhs.lo);
+        let (s, e) = quick_two_sum(s, e + t);
+        let (hi, lo) = quick_two_sum(s, e + f);
+        Self { hi, lo }
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let p = Self::product(self.hi, rhs.hi);
+        let e = p.lo + (self.hi * rhs.lo + self.lo * rhs.hi);
+        let (hi, lo) = quick_two_sum(p.hi, e);
+        Self { hi, lo }
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        // long division, one f64 digit at a time
+        let q1 = self.hi / rhs.hi;
+        let r = self - rhs * Self::from(q1);
+        let q2 = r.hi / rhs.hi;
+        let r = r - rhs * Self::from(q2);
+        let q3 = r.hi / rhs.hi;
+        let (hi, lo) = quick_two_sum(q1, q2);
+        Self { hi, lo } + Self::from(q3)
+    }
+}
+
+impl AddAssign for DoubleDouble {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for DoubleDouble {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for DoubleDouble {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for DoubleDouble {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+/// Returns the solution x of **A**x = b computed in the scalar type `R` by Gaussian
+/// elimination with partial pivoting, or None if **A** is not square, its size differs
+/// from the length of `b` or a pivot is exactly zero.\
+/// This is a separate reference implementation of [`MatrixN::solve`] for checking its
+/// results. Unlike it, it does not give up on nearly singular matrices, since finding out
+/// what a wider type makes of them is the point.
+///
+/// # Example
+/// ```rust
+/// use i_mth::matrixn::MatrixN;
+/// use i_mth::precision::{solve, DoubleDouble};
+///
+/// // a Vandermonde matrix on the nodes 1..=12, whose entries and the sums of its rows are
+/// // exact in f64, so the solution is exactly all ones
+/// let n = 12;
+/// let rows: Vec<Vec<f64>> = (1..=n)
+///     .map(|i| (0..n).map(|j| (i as f64).powi(j as i32)).collect())
+///     .collect();
+/// let b: Vec<f64> = rows.iter().map(|row| row.iter().sum()).collect();
+/// let a = MatrixN::from_rows(&rows).unwrap();
+///
+/// let error = |x: &[f64]| x.iter().fold(0.0f64, |m, x| m.max((x - 1.0).abs()));
+/// let single: Vec<f64> = solve::<f64>(&a, &b).unwrap();
+/// let double: Vec<DoubleDouble> = solve(&a, &b).unwrap();
+/// let double: Vec<f64> = double.into_iter().map(DoubleDouble::to_f64).collect();
+///
+/// assert!(error(&single) > 1e-8);
+/// assert!(error(&double) < 1e-15);
+/// ```
+pub fn solve<R: Real>(a: &MatrixN, b: &[f64]) -> Option<Vec<R>> {
+    let n = a.rows();
+    if !a.is_square() || b.len() != n {
+        return None;
+    }
+    let mut m: Vec<Vec<R>> = (0..n)
+        .map(|i| a.row(i).iter().map(|&e| R::from(e)).collect())
+        .collect();
+    let mut x: Vec<R> = b.iter().map(|&e| R::from(e)).collect();
+    for k in 0..n {
+        let pivot = (k..n).max_by(|&i, &j| {
+            m[i][k]
+                .abs()
+                .partial_cmp(&m[j][k].abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+        if m[pivot][k] == R::from(0.0) {
+            return None;
+        }
+        m.swap(k, pivot);
+        x.swap(k, pivot);
+        let (top, bottom) = m.split_at_mut(k + 1);
+        let (pivot_row, x_k) = (&top[k], x[k]);
+        for (row, x_i) in bottom.iter_mut().zip(&mut x[k + 1..]) {
+            let factor = row[k] / pivot_row[k];
+            for (e, &p) in row.iter_mut().zip(pivot_row).skip(k + 1) {
+                *e -= factor * p;
+            }
+            *x_i -= factor * x_k;
+        }
+    }
+    for i in (0..n).rev() {
+        let mut sum = x[i];
+        for (&e, &x_j) in m[i].iter().zip(&x).skip(i + 1) {
+            sum -= e * x_j;
+        }
+        x[i] = sum / m[i][i];
+    }
+    Some(x)
+}
+
+/// Returns the position in m and velocity in m/s, rounded to f64, of a body orbiting a
+/// point mass with gravitational parameter `mu` in m<sup>3</sup>/s<sup>2</sup> after
+/// `steps` fourth order Runge-Kutta steps of `dt` s, computed in the scalar type `R`.\
+/// This is a separate reference implementation of the two-body problem stepped with
+/// [`crate::integrators::Rk4`], for checking f64 propagations.
+/// Comparing the results in f64 and in [`DoubleDouble`] separates the rounding error of a
+/// long propagation from its truncation error, which both share.
+///
+/// # Example
+/// ```rust
+/// use i_mth::precision::{propagate_two_body, DoubleDouble};
+/// use i_mth::vector3d::Vector3D;
+///
+/// let mu = 3.986e14;
+/// let r = 7.0e6;
+/// let position = Vector3D::new(r, 0.0, 0.0);
+/// let velocity = Vector3D::new(0.0, (mu / r).sqrt(), 0.0);
+///
+/// let (p64, _) = propagate_two_body::<f64>(mu, position, velocity, 1.0, 20_000);
+/// let (p128, _) = propagate_two_body::<DoubleDouble>(mu, position, velocity, 1.0, 20_000);
+///
+/// // the f64 propagation drifts from the extended one by well under a millimetre
+/// assert!((p64 - p128).magnitude() < 1e-3);
+/// // and both stay on the circular orbit to within the truncation error of the steps
+/// assert!((p128.magnitude() - r).abs() < 1e-3);
+/// ```
+pub fn propagate_two_body<R: Real>(
+    mu: f64,
+    position: Vector3D,
+    velocity: Vector3D,
+    dt: f64,
+    steps: usize,
+) -> (Vector3D, Vector3D) {
+    let mu = R::from(mu);
+    let derivative = |s: &[R; 6]| -> [R; 6] {
+        let r2 = s[0] * s[0] + s[1] * s[1] + s[2] * s[2];
+        let k = -mu / (r2 * r2.sqrt());
+        [s[3], s[4], s[5], k * s[0], k * s[1], k * s[2]]
+    };
+    let offset = |s: &[R; 6], d: &[R; 6], h: R| -> [R; 6] {
+        let mut out = *s;
+        for (o, &d) in out.iter_mut().zip(d) {
+            *o += h * d;
+        }
+        out
+    };
+
+    let (h, half) = (R::from(dt), R::from(dt / 2.0));
+    let sixth = R::from(1.0) / R::from(6.0);
+    let two = R::from(2.0);
+    let mut state = [
+        position.x, position.y, position.z, velocity.x, velocity.y, velocity.z,
+    ]
+    .map(R::from);
+    for _ in 0..steps {
+        let k1 = derivative(&state);
+        let k2 = derivative(&offset(&state, &k1, half));
+        let k3 = derivative(&offset(&state, &k2, half));
+        let k4 = derivative(&offset(&state, &k3, h));
+        for (i, s) in state.iter_mut().enumerate() {
+            *s += h * sixth * (k1[i] + two * (k2[i] + k3[i]) + k4[i]);
+        }
+    }
+    let s = state.map(R::to_f64);
+    (
+        Vector3D::new(s[0], s[1], s[2]),
+        Vector3D::new(s[3], s[4], s[5]),
+    )
+}
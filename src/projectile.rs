@@ -0,0 +1,287 @@
+//! # Projectiles
+//!
+//! Flight of a projectile through the air under gravity and drag, integrated with the
+//! adaptive [`Rk45`] solver until it comes down to the ground.
+//!
+//! Heights are measured against gravity, so the ground is the plane perpendicular to the
+//! gravity vector at `ground_height` above the origin.
+
+use crate::constants::EARTH_GRAVITY;
+use crate::error::ImthError;
+use crate::integrators::{Event, EventDirection, EventOccurrence, Rk45, Rk45Solution};
+use crate::particles::DragGenerator;
+use crate::vector3d::Vector3D;
+
+/// The state of a projectile where it hits the ground.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Impact {
+    /// Time of flight in s.
+    pub time: f64,
+    /// Position of the impact in m.
+    pub position: Vector3D,
+    /// Velocity at the impact in ms<sup>-1</sup>.
+    pub velocity: Vector3D,
+}
+
+impl Impact {
+    /// Returns the speed at the impact.
+    #[inline]
+    pub fn speed(&self) -> f64 {
+        self.velocity.magnitude()
+    }
+}
+
+/// The flight of a projectile from launch to impact.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProjectileFlight {
+    /// The integrated `(t, (position, velocity))` states with dense output.
+    pub trajectory: Rk45Solution<(Vector3D, Vector3D)>,
+    /// Where the projectile hit the ground, or None if it was still flying at the
+    /// maximum flight time.
+    pub impact: Option<Impact>,
+    /// Unit vector pointing up, against gravity.
+    up: Vector3D,
+}
+
+impl ProjectileFlight {
+    /// Returns the launch position.
+    #[inline]
+    pub fn launch_position(&self) -> Vector3D {
+        self.trajectory.points[0].1 .0
+    }
+
+    /// Returns the horizontal distance from the launch position to the impact, or None if
+    /// the projectile did not land.
+    #[inline]
+    pub fn range(&self) -> Option<f64> {
+        let d = self.impact?.position - self.launch_position();
+        Some(d.add_scaled(self.up, -d.dot(self.up)).magnitude())
+    }
+
+    /// Returns the greatest height reached above the origin, measured against gravity, and
+    /// the time it was reached, checked at every accepted step and refined on the dense
+    /// output around the highest one.
+    pub fn apex(&self) -> (f64, f64) {
+        let height = |p: &Vector3D| p.dot(self.up);
+        let (mut t_max, mut h_max) = self
+            .trajectory
+            .points
+            .iter()
+            .map(|(t, (p, _))| (*t, height(p)))
+            .fold(
+                (0.0, f64::NEG_INFINITY),
+                |a, b| if b.1 > a.1 { b } else { a },
+            );
+        // the vertical velocity is zero at the apex, so it lies inside a step where it
+        // changes sign, which dense output locates
+        for segment in &self.trajectory.segments {
+            let (v0, v1) = (segment.y0.1.dot(self.up), segment.y1.1.dot(self.up));
+            if v0 > 0.0 && v1 <= 0.0 {
+                let s = v0 / (v0 - v1);
+                let t = segment.t0 + s * (segment.t1 - segment.t0);
+                let h = height(&segment.evaluate(t).0);
+                if h > h_max {
+                    (t_max, h_max) = (t, h);
+                }
+            }
+        }
+        (h_max, t_max)
+    }
+
+    /// Returns the interpolated position and velocity at time `t`, or None if `t` is outside
+    /// of the flight.
+    #[inline]
+    pub fn state_at(&self, t: f64) -> Option<(Vector3D, Vector3D)> {
+        self.trajectory.evaluate(t)
+    }
+}
+
+/// A point mass flying under uniform gravity and drag.
+///
+/// # Example
+/// ```rust
+/// use i_mth::particles::DragGenerator;
+/// use i_mth::projectile::ProjectileWithDrag;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // a 45 g golf ball driven at 70 m/s and 12 degrees
+/// let ball = ProjectileWithDrag::new(0.045, DragGenerator::quadratic(1.225, 0.25, 1.43e-3));
+/// let angle = 12f64.to_radians();
+/// let launch = Vector3D::new(70.0 * angle.cos(), 0.0, 70.0 * angle.sin());
+///
+/// let flight = ball.fly(Vector3D::origin(), launch).unwrap();
+/// let vacuum = ProjectileWithDrag::new(0.045, DragGenerator::default())
+///     .fly(Vector3D::origin(), launch)
+///     .unwrap();
+///
+/// // drag shortens the carry and slows the ball down
+/// let impact = flight.impact.unwrap();
+/// assert!(flight.range().unwrap() < vacuum.range().unwrap());
+/// assert!(impact.speed() < 70.0);
+/// assert!(impact.position.z.abs() < 1e-9);
+///
+/// // without drag the range is v²sin(2θ)/g
+/// let g = 9.806_65;
+/// assert!((vacuum.range().unwrap() - 70.0f64.powi(2) * (2.0 * angle).sin() / g).abs() < 1e-6);
+/// assert!((vacuum.apex().0 - (70.0 * angle.sin()).powi(2) / (2.0 * g)).abs() < 1e-6);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ProjectileWithDrag {
+    /// Mass of the projectile in kg.
+    pub mass: f64,
+    /// Drag acting against the velocity of the projectile.
+    pub drag: DragGenerator,
+    /// Acceleration due to gravity in ms<sup>-2</sup>.
+    pub gravity: Vector3D,
+    /// Height of the ground above the origin in m, measured against gravity.
+    pub ground_height: f64,
+    /// Longest flight time integrated in s.
+    pub max_time: f64,
+    /// The integrator used for the flight. Dense output is always turned on.
+    pub solver: Rk45,
+}
+
+impl ProjectileWithDrag {
+    /// Returns a projectile of the passed mass and drag under standard gravity along -z,
+    /// with the ground through the origin.
+    #[inline]
+    pub fn new(mass: f64, drag: DragGenerator) -> Self {
+        Self {
+            mass,
+            drag,
+            gravity: Vector3D::new(0.0, 0.0, EARTH_GRAVITY),
+            ground_height: 0.0,
+            max_time: 3600.0,
+            solver: Rk45::new(1e-10, 1e-10),
+        }
+    }
+
+    /// Returns this projectile under the passed gravity instead.
+    #[inline]
+    pub fn with_gravity(self, gravity: Vector3D) -> Self {
+        Self { gravity, ..self }
+    }
+
+    /// Returns this projectile landing on ground at the passed height instead.
+    #[inline]
+    pub fn with_ground_height(self, ground_height: f64) -> Self {
+        Self {
+            ground_height,
+            ..self
+        }
+    }
+
+    /// Returns this projectile integrated with the passed solver instead.
+    #[inline]
+    pub fn with_solver(self, solver: Rk45) -> Self {
+        Self { solver, ..self }
+    }
+
+    /// Returns the acceleration of the projectile moving at velocity `v`.
+    #[inline]
+    pub fn acceleration(&self, v: Vector3D) -> Vector3D {
+        let drag = v.scale(-(self.drag.k1 + self.drag.k2 * v.magnitude()));
+        self.gravity.add_scaled(drag, 1.0 / self.mass)
+    }
+
+    /// Returns the flight of the projectile launched from `position` with `velocity`, which
+    /// ends when it comes down through the ground or after `max_time`. A projectile launched
+    /// from the ground that does not rise for at least `min_step` lands at the launch.
+    ///
+    /// Fails if the mass is not positive or the integration fails.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::particles::DragGenerator;
+    /// use i_mth::projectile::ProjectileWithDrag;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // thrown all but horizontally off a 1 m ledge, it lands after √(2h/g)
+    /// let stone = ProjectileWithDrag::new(0.1, DragGenerator::default());
+    /// let launch = Vector3D::new(5.0, 0.0, 1e-12);
+    /// let impact = stone.fly(Vector3D::new(0.0, 0.0, 1.0), launch).unwrap().impact.unwrap();
+    /// assert!((impact.time - (2.0 / 9.806_65f64).sqrt()).abs() < 1e-9);
+    ///
+    /// // and from the ground it does not rise far enough to fly at all
+    /// let impact = stone.fly(Vector3D::origin(), launch).unwrap().impact.unwrap();
+    /// assert_eq!(0.0, impact.time);
+    /// assert_eq!(Vector3D::origin(), impact.position);
+    ///
+    /// // nor does one thrown horizontally along the ground
+    /// let flight = stone.fly(Vector3D::origin(), Vector3D::new(5.0, 0.0, 0.0)).unwrap();
+    /// assert_eq!(Some(0.0), flight.impact.map(|impact| impact.time));
+    /// assert_eq!(Some(0.0), flight.range());
+    /// ```
+    pub fn fly(
+        &self,
+        position: Vector3D,
+        velocity: Vector3D,
+    ) -> Result<ProjectileFlight, ImthError> {
+        if self.mass <= 0.0 {
+            return Err(ImthError::InvalidArgument(
+                "projectile mass must be positive",
+            ));
+        }
+        let up = self.gravity.scale(-1.0).normalize_or(Vector3D::k());
+        let ground = |_: f64, (p, _): &(Vector3D, Vector3D)| p.dot(up) - self.ground_height;
+        // a flight launched from the ground starts on the event, so the first steps must
+        // end before it can come back down or the impact is stepped over, unless it comes
+        // down sooner than the smallest step can resolve
+        let rise = velocity.dot(up) / self.gravity.magnitude();
+        let height = position.dot(up) - self.ground_height;
+        if height <= 0.0 && rise <= self.solver.min_step {
+            // launched from the ground without rising above the smallest step, so the
+            // projectile lands where it starts
+            let state = (position, velocity);
+            return Ok(ProjectileFlight {
+                trajectory: Rk45Solution {
+                    points: vec![(0.0, state)],
+                    errors: Vec::new(),
+                    rejected_steps: 0,
+                    segments: Vec::new(),
+                    events: vec![EventOccurrence {
+                        event: 0,
+                        t: 0.0,
+                        y: state,
+                    }],
+                },
+                impact: Some(Impact {
+                    time: 0.0,
+                    position,
+                    velocity,
+                }),
+                up,
+            });
+        }
+        let max_step = if height <= 0.0 {
+            self.solver
+                .max_step
+                .min(0.5 * rise)
+                .max(self.solver.min_step)
+        } else {
+            self.solver.max_step
+        };
+        let solver = Rk45 {
+            dense_output: true,
+            max_step,
+            ..self.solver
+        };
+        let trajectory = solver.solve_with_events(
+            |_, (_, v): &(Vector3D, Vector3D)| (*v, self.acceleration(*v)),
+            0.0,
+            (position, velocity),
+            self.max_time,
+            &[Event::new(&ground, EventDirection::Falling, true)],
+        )?;
+        let impact = trajectory.events.first().map(|e| Impact {
+            time: e.t,
+            position: e.y.0,
+            velocity: e.y.1,
+        });
+        Ok(ProjectileFlight {
+            trajectory,
+            impact,
+            up,
+        })
+    }
+}
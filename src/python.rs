@@ -0,0 +1,736 @@
+//! # Python
+//!
+//! [`pyo3`] bindings exposing the vectors, [`Matrix3`], the truss and beam solvers and the
+//! integrators to Python, for using the crate from scripts and Jupyter notebooks. Enabled
+//! with the `python` feature.
+//!
+//! The extension module is built with [maturin](https://www.maturin.rs), which needs the
+//! `cdylib` crate type: `maturin develop --release --features python,pyo3/extension-module`
+//! with `crate-type = ["cdylib", "rlib"]` under `[lib]`.
+//!
+//! Vectors going into the solvers are plain tuples, so lists and NumPy rows work directly,
+//! and results come back as tuples and lists. Errors are raised as `ValueError`, or
+//! `ZeroDivisionError` for a division by zero, and `RuntimeError` when an iterative method
+//! gives up.
+//!
+//! ```python
+//! import i_mth
+//!
+//! truss = i_mth.Truss2D([(0, 0), (4, 0), (2, 3)])
+//! truss.add_member(0, 1); truss.add_member(0, 2); truss.add_member(1, 2)
+//! truss.add_pin(0); truss.add_roller(1, (0, 1))
+//! truss.add_load(2, (0, -12e3))
+//! print(truss.solve().member_forces)
+//!
+//! ts, ys = i_mth.solve_adaptive(lambda t, y: [y[1], -y[0]], 0.0, [1.0, 0.0], 10.0)
+//! ```
+//!
+//! # Example
+//! ```rust
+//! use i_mth::python::{PyBeam, PyTruss2D};
+//!
+//! let mut truss = PyTruss2D::new(vec![(0.0, 0.0), (4.0, 0.0), (2.0, 3.0)]);
+//! truss.add_member(0, 1);
+//! truss.add_member(0, 2);
+//! truss.add_member(1, 2);
+//! truss.add_pin(0);
+//! truss.add_roller(1, (0.0, 1.0));
+//! truss.add_load(2, (0.0, -12e3));
+//! let solution = truss.solve().unwrap();
+//! assert!((solution.member_forces()[0] - 4e3).abs() < 1e-9);
+//!
+//! let mut beam = PyBeam::simply_supported(6.0);
+//! beam.add_point_load(2.0, 20e3);
+//! let solution = beam.solve().unwrap();
+//! assert!((solution.moment(2.0).unwrap() - 80e3 / 3.0).abs() < 1e-9);
+//! ```
+//!
+//! The integrators stop calling a right hand side once it raises, and raise its error:
+//! ```rust
+//! use i_mth::python::{integrate, solve_adaptive};
+//! use pyo3::prelude::*;
+//! use pyo3::types::PyDict;
+//!
+//! pyo3::prepare_freethreaded_python();
+//! Python::with_gil(|py| {
+//!     let code = c"
+//! calls = []
+//! def f(t, y):
+//!     calls.append(t)
+//!     if t >= 0.5:
+//!         raise ValueError('stop')
+//!     return [1.0]
+//! ";
+//!     let raised = |py: Python<'_>, solve: &dyn Fn(&Bound<'_, PyAny>) -> PyResult<()>| {
+//!         let scope = PyDict::new(py);
+//!         py.run(code, Some(&scope), None).unwrap();
+//!         let f = scope.get_item("f").unwrap().unwrap();
+//!         assert!(solve(&f).unwrap_err().is_instance_of::<pyo3::exceptions::PyValueError>(py));
+//!         let calls: Vec<f64> = scope.get_item("calls").unwrap().unwrap().extract().unwrap();
+//!         calls
+//!     };
+//!
+//!     // RK4 calls f 4 times a step, the last at its end, so it stops in the fifth step
+//!     let calls = raised(py, &|f| integrate(f, 0.0, vec![0.0], 0.1, 100, "rk4").map(|_| ()));
+//!     assert_eq!(5 * 4, calls.len());
+//!
+//!     let calls = raised(py, &|f| solve_adaptive(f, 0.0, vec![0.0], 10.0, 1e-6, 1e-9).map(|_| ()));
+//!     assert_eq!(1, calls.iter().filter(|&&t| t >= 0.5).count());
+//!     assert!(*calls.last().unwrap() >= 0.5);
+//! });
+//! ```
+
+use std::cell::RefCell;
+
+use pyo3::exceptions::{PyRuntimeError, PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+
+use crate::beam::{Beam, BeamSolution, DistributedLoad, Support};
+use crate::error::ImthError;
+use crate::integrators::{ExplicitEuler, Integrator, Rk4, Rk45};
+use crate::matrix3::Matrix3;
+use crate::matrixn::SolveDiagnostics;
+use crate::truss::{Support2D, Support3D, Truss2D, Truss3D, TrussSolution};
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+impl From<ImthError> for PyErr {
+    fn from(error: ImthError) -> Self {
+        let message = error.to_string();
+        match error {
+            ImthError::DivisionByZero => PyZeroDivisionError::new_err(message),
+            ImthError::MaxIterationsExceeded(_) | ImthError::StepSizeUnderflow { .. } => {
+                PyRuntimeError::new_err(message)
+            }
+            ImthError::InvalidArgument(_) | ImthError::InvalidModel(_) => {
+                PyValueError::new_err(message)
+            }
+        }
+    }
+}
+
+/// A [`Vector2D`] for Python.
+#[pyclass(name = "Vector2D", module = "i_mth")]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct PyVector2D(pub Vector2D);
+
+#[pymethods]
+impl PyVector2D {
+    /// Returns a new vector with the passed components.
+    #[new]
+    pub fn new(x: f64, y: f64) -> Self {
+        Self(Vector2D::new(x, y))
+    }
+
+    /// Returns the x component.
+    #[getter]
+    pub fn x(&self) -> f64 {
+        self.0.x
+    }
+
+    /// Returns the y component.
+    #[getter]
+    pub fn y(&self) -> f64 {
+        self.0.y
+    }
+
+    /// Returns the dot product of this and the passed vector.
+    pub fn dot(&self, other: PyVector2D) -> f64 {
+        self.0.dot(other.0)
+    }
+
+    /// Returns the z component of the cross product, like the moment of a force in the
+    /// plane.
+    pub fn cross(&self, other: PyVector2D) -> f64 {
+        self.0.cross(other.0)
+    }
+
+    /// Returns the magnitude of this vector.
+    pub fn magnitude(&self) -> f64 {
+        self.0.magnitude()
+    }
+
+    /// Returns the unit vector along this vector, or None for a zero vector.
+    pub fn normalized(&self) -> Option<PyVector2D> {
+        self.0.normalized().map(Self)
+    }
+
+    /// Returns the components as an `(x, y)` tuple.
+    pub fn to_tuple(&self) -> (f64, f64) {
+        (self.0.x, self.0.y)
+    }
+
+    fn __add__(&self, other: PyVector2D) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    fn __sub__(&self, other: PyVector2D) -> Self {
+        Self(self.0 - other.0)
+    }
+
+    fn __neg__(&self) -> Self {
+        Self(self.0.scale(-1.0))
+    }
+
+    fn __mul__(&self, value: f64) -> Self {
+        Self(self.0.scale(value))
+    }
+
+    fn __rmul__(&self, value: f64) -> Self {
+        Self(self.0.scale(value))
+    }
+
+    fn __eq__(&self, other: PyVector2D) -> bool {
+        self.0 == other.0
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Vector2D({}, {})", self.0.x, self.0.y)
+    }
+}
+
+/// A [`Vector3D`] for Python.
+#[pyclass(name = "Vector3D", module = "i_mth")]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct PyVector3D(pub Vector3D);
+
+#[pymethods]
+impl PyVector3D {
+    /// Returns a new vector with the passed components.
+    #[new]
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self(Vector3D::new(x, y, z))
+    }
+
+    /// Returns the x component.
+    #[getter]
+    pub fn x(&self) -> f64 {
+        self.0.x
+    }
+
+    /// Returns the y component.
+    #[getter]
+    pub fn y(&self) -> f64 {
+        self.0.y
+    }
+
+    /// Returns the z component.
+    #[getter]
+    pub fn z(&self) -> f64 {
+        self.0.z
+    }
+
+    /// Returns the dot product of this and the passed vector.
+    pub fn dot(&self, other: PyVector3D) -> f64 {
+        self.0.dot(other.0)
+    }
+
+    /// Returns the cross product of this and the passed vector, like the moment r × F.
+    pub fn cross(&self, other: PyVector3D) -> Self {
+        Self(self.0.cross(other.0))
+    }
+
+    /// Returns the magnitude of this vector.
+    pub fn magnitude(&self) -> f64 {
+        self.0.magnitude()
+    }
+
+    /// Returns the unit vector along this vector, or None for a zero vector.
+    pub fn normalized(&self) -> Option<PyVector3D> {
+        self.0.normalized().map(Self)
+    }
+
+    /// Returns the components as an `(x, y, z)` tuple.
+    pub fn to_tuple(&self) -> (f64, f64, f64) {
+        (self.0.x, self.0.y, self.0.z)
+    }
+
+    fn __add__(&self, other: PyVector3D) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    fn __sub__(&self, other: PyVector3D) -> Self {
+        Self(self.0 - other.0)
+    }
+
+    fn __neg__(&self) -> Self {
+        Self(self.0.scale(-1.0))
+    }
+
+    fn __mul__(&self, value: f64) -> Self {
+        Self(self.0.scale(value))
+    }
+
+    fn __rmul__(&self, value: f64) -> Self {
+        Self(self.0.scale(value))
+    }
+
+    fn __eq__(&self, other: PyVector3D) -> bool {
+        self.0 == other.0
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Vector3D({}, {}, {})", self.0.x, self.0.y, self.0.z)
+    }
+}
+
+/// A [`Matrix3`] for Python, built from a list of three rows.
+#[pyclass(name = "Matrix3", module = "i_mth")]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct PyMatrix3(pub Matrix3);
+
+#[pymethods]
+impl PyMatrix3 {
+    /// Returns the matrix with the passed rows.
+    #[new]
+    pub fn new(rows: [[f64; 3]; 3]) -> Self {
+        Self(Matrix3::new(rows))
+    }
+
+    /// Returns the identity matrix.
+    #[staticmethod]
+    pub fn identity() -> Self {
+        Self(Matrix3::IDENTITY)
+    }
+
+    /// Returns the rotation about the passed axis by `angle` radians, or None for a zero
+    /// axis.
+    #[staticmethod]
+    pub fn from_axis_angle(axis: PyVector3D, angle: f64) -> Option<Self> {
+        Matrix3::from_axis_angle(axis.0, angle).map(Self)
+    }
+
+    /// Returns the rows of this matrix.
+    #[getter]
+    pub fn rows(&self) -> [[f64; 3]; 3] {
+        self.0.rows
+    }
+
+    /// Returns the transpose of this matrix.
+    pub fn transpose(&self) -> Self {
+        Self(self.0.transpose())
+    }
+
+    /// Returns the determinant of this matrix.
+    pub fn determinant(&self) -> f64 {
+        self.0.determinant()
+    }
+
+    /// Returns the inverse of this matrix, raising `ZeroDivisionError` if it is singular.
+    pub fn inverse(&self) -> PyResult<Self> {
+        Ok(Self(self.0.try_inverse()?))
+    }
+
+    /// Returns the product of this matrix and the passed column vector.
+    pub fn mul_vec(&self, v: PyVector3D) -> PyVector3D {
+        PyVector3D(self.0.mul_vec(v.0))
+    }
+
+    fn __matmul__(&self, other: PyMatrix3) -> Self {
+        Self(self.0 * other.0)
+    }
+
+    fn __add__(&self, other: PyMatrix3) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    fn __sub__(&self, other: PyMatrix3) -> Self {
+        Self(self.0 - other.0)
+    }
+
+    fn __eq__(&self, other: PyMatrix3) -> bool {
+        self.0 == other.0
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Matrix3({:?})", self.0.rows)
+    }
+}
+
+/// The member forces and reactions of a truss solved from Python.
+#[pyclass(name = "TrussSolution", module = "i_mth")]
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PyTrussSolution {
+    member_forces: Vec<f64>,
+    reactions: Vec<(usize, Vec<f64>)>,
+    condition_number: f64,
+}
+
+impl PyTrussSolution {
+    /// Returns the solution with the reactions written out by `components`.
+    fn new<V>(
+        solution: TrussSolution<V>,
+        diagnostics: SolveDiagnostics,
+        components: fn(V) -> Vec<f64>,
+    ) -> Self {
+        Self {
+            member_forces: solution.member_forces,
+            reactions: solution
+                .reactions
+                .into_iter()
+                .map(|(joint, r)| (joint, components(r)))
+                .collect(),
+            condition_number: diagnostics.condition_number,
+        }
+    }
+}
+
+#[pymethods]
+impl PyTrussSolution {
+    /// Returns the axial force in N in each member, positive in tension.
+    #[getter]
+    pub fn member_forces(&self) -> Vec<f64> {
+        self.member_forces.clone()
+    }
+
+    /// Returns the reaction at each support as `(joint, [components])`.
+    #[getter]
+    pub fn reactions(&self) -> Vec<(usize, Vec<f64>)> {
+        self.reactions.clone()
+    }
+
+    /// Returns the condition number of the joint equilibrium equations, large for a truss
+    /// that is nearly a mechanism.
+    #[getter]
+    pub fn condition_number(&self) -> f64 {
+        self.condition_number
+    }
+}
+
+/// A [`Truss2D`] for Python, set up by adding members, supports and loads in place.
+#[pyclass(name = "Truss2D", module = "i_mth")]
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PyTruss2D(pub Truss2D);
+
+#[pymethods]
+impl PyTruss2D {
+    /// Returns a truss with joints at the passed `(x, y)` positions in m.
+    #[new]
+    pub fn new(joints: Vec<(f64, f64)>) -> Self {
+        Self(Truss2D::new(
+            joints
+                .into_iter()
+                .map(|(x, y)| Vector2D::new(x, y))
+                .collect(),
+        ))
+    }
+
+    /// Adds a member joining the passed joints.
+    pub fn add_member(&mut self, start: usize, end: usize) {
+        self.0 = std::mem::take(&mut self.0).with_member(start, end);
+    }
+
+    /// Adds a pin at the passed joint.
+    pub fn add_pin(&mut self, joint: usize) {
+        self.0.supports.push((joint, Support2D::Pin));
+    }
+
+    /// Adds a roller at the passed joint on a surface with the passed `(x, y)` normal.
+    pub fn add_roller(&mut self, joint: usize, normal: (f64, f64)) {
+        let normal = Vector2D::new(normal.0, normal.1);
+        self.0.supports.push((joint, Support2D::Roller(normal)));
+    }
+
+    /// Adds the passed `(x, y)` force in N at the passed joint.
+    pub fn add_load(&mut self, joint: usize, force: (f64, f64)) {
+        self.0.loads.push((joint, Vector2D::new(force.0, force.1)));
+    }
+
+    /// Returns the solved truss, raising `ValueError` if it cannot be solved.
+    pub fn solve(&self) -> PyResult<PyTrussSolution> {
+        let (solution, diagnostics) = self.0.solve_with_diagnostics()?;
+        Ok(PyTrussSolution::new(solution, diagnostics, |r| {
+            vec![r.x, r.y]
+        }))
+    }
+}
+
+/// A [`Truss3D`] for Python, set up by adding members, supports and loads in place.
+#[pyclass(name = "Truss3D", module = "i_mth")]
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PyTruss3D(pub Truss3D);
+
+#[pymethods]
+impl PyTruss3D {
+    /// Returns a truss with joints at the passed `(x, y, z)` positions in m.
+    #[new]
+    pub fn new(joints: Vec<(f64, f64, f64)>) -> Self {
+        Self(Truss3D::new(
+            joints
+                .into_iter()
+                .map(|(x, y, z)| Vector3D::new(x, y, z))
+                .collect(),
+        ))
+    }
+
+    /// Adds a member joining the passed joints.
+    pub fn add_member(&mut self, start: usize, end: usize) {
+        self.0 = std::mem::take(&mut self.0).with_member(start, end);
+    }
+
+    /// Adds a ball and socket at the passed joint.
+    pub fn add_ball_and_socket(&mut self, joint: usize) {
+        self.0.supports.push((joint, Support3D::BallAndSocket));
+    }
+
+    /// Adds a joint sliding along the passed `(x, y, z)` direction.
+    pub fn add_slider(&mut self, joint: usize, direction: (f64, f64, f64)) {
+        let (x, y, z) = direction;
+        let support = Support3D::Slider(Vector3D::new(x, y, z));
+        self.0.supports.push((joint, support));
+    }
+
+    /// Adds a roller at the passed joint on a surface with the passed `(x, y, z)` normal.
+    pub fn add_roller(&mut self, joint: usize, normal: (f64, f64, f64)) {
+        let (x, y, z) = normal;
+        let support = Support3D::Roller(Vector3D::new(x, y, z));
+        self.0.supports.push((joint, support));
+    }
+
+    /// Adds the passed `(x, y, z)` force in N at the passed joint.
+    pub fn add_load(&mut self, joint: usize, force: (f64, f64, f64)) {
+        let (x, y, z) = force;
+        self.0.loads.push((joint, Vector3D::new(x, y, z)));
+    }
+
+    /// Returns the solved truss, raising `ValueError` if it cannot be solved.
+    pub fn solve(&self) -> PyResult<PyTrussSolution> {
+        let (solution, diagnostics) = self.0.solve_with_diagnostics()?;
+        Ok(PyTrussSolution::new(solution, diagnostics, |r| {
+            vec![r.x, r.y, r.z]
+        }))
+    }
+}
+
+/// A [`Beam`] for Python, set up by adding supports and loads in place.
+#[pyclass(name = "Beam", module = "i_mth")]
+#[derive(Debug, Clone, Default)]
+pub struct PyBeam(pub Beam);
+
+#[pymethods]
+impl PyBeam {
+    /// Returns an unsupported and unloaded beam of the passed length in m.
+    #[new]
+    pub fn new(length: f64) -> Self {
+        Self(Beam::new(length))
+    }
+
+    /// Returns a beam with a pin at the left end and a roller at the right end.
+    #[staticmethod]
+    pub fn simply_supported(length: f64) -> Self {
+        Self(Beam::simply_supported(length))
+    }
+
+    /// Returns a beam fixed at the left end and free at the right end.
+    #[staticmethod]
+    pub fn cantilever(length: f64) -> Self {
+        Self(Beam::cantilever(length))
+    }
+
+    /// Adds a pin at the passed position in m.
+    pub fn add_pin(&mut self, x: f64) {
+        self.0.supports.push(Support::Pin(x));
+    }
+
+    /// Adds a roller at the passed position in m.
+    pub fn add_roller(&mut self, x: f64) {
+        self.0.supports.push(Support::Roller(x));
+    }
+
+    /// Adds a fixed support at the passed position in m.
+    pub fn add_fixed(&mut self, x: f64) {
+        self.0.supports.push(Support::Fixed(x));
+    }
+
+    /// Adds a point load of `p` N downwards at `x` m.
+    pub fn add_point_load(&mut self, x: f64, p: f64) {
+        self.0.point_loads.push((x, p));
+    }
+
+    /// Adds a couple of `c` N.m anticlockwise at `x` m.
+    pub fn add_couple(&mut self, x: f64, c: f64) {
+        self.0.couples.push((x, c));
+    }
+
+    /// Adds a uniform load of `w` N/m downwards from `start` to `end` m.
+    pub fn add_uniform_load(&mut self, w: f64, start: f64, end: f64) {
+        let load = DistributedLoad::uniform(w, start, end);
+        self.0.distributed_loads.push(load);
+    }
+
+    /// Adds a load varying linearly from `w_start` N/m at `start` to `w_end` N/m at
+    /// `end`, downwards.
+    pub fn add_trapezoid_load(&mut self, w_start: f64, w_end: f64, start: f64, end: f64) {
+        let load = DistributedLoad::trapezoid(w_start, w_end, start, end);
+        self.0.distributed_loads.push(load);
+    }
+
+    /// Returns the solved beam, raising `ValueError` if it cannot be solved.
+    pub fn solve(&self) -> PyResult<PyBeamSolution> {
+        Ok(PyBeamSolution(self.0.solve()?))
+    }
+}
+
+/// A [`BeamSolution`] for Python.
+#[pyclass(name = "BeamSolution", module = "i_mth")]
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PyBeamSolution(pub BeamSolution);
+
+#[pymethods]
+impl PyBeamSolution {
+    /// Returns the support reactions as `(x, force, moment)` tuples.
+    #[getter]
+    pub fn reactions(&self) -> Vec<(f64, f64, f64)> {
+        self.0
+            .reactions
+            .iter()
+            .map(|r| (r.position, r.force, r.moment))
+            .collect()
+    }
+
+    /// Returns the shear force in N at `x` m, or None off the beam.
+    pub fn shear(&self, x: f64) -> Option<f64> {
+        self.0.shear.evaluate(x)
+    }
+
+    /// Returns the bending moment in N.m at `x` m, or None off the beam.
+    pub fn moment(&self, x: f64) -> Option<f64> {
+        self.0.moment.evaluate(x)
+    }
+
+    /// Returns the positions where the shear force is zero or changes sign.
+    pub fn zero_shear(&self) -> Vec<f64> {
+        self.0.zero_shear()
+    }
+
+    /// Returns the shear force diagram at about `samples` points as `(x, V)` pairs.
+    pub fn shear_diagram(&self, samples: usize) -> Vec<(f64, f64)> {
+        self.0.shear_diagram(samples).points
+    }
+
+    /// Returns the bending moment diagram at about `samples` points as `(x, M)` pairs.
+    pub fn moment_diagram(&self, samples: usize) -> Vec<(f64, f64)> {
+        self.0.moment_diagram(samples).points
+    }
+}
+
+/// Returns the right hand side of dy/dt = f(t, y) evaluated by the Python callable `f`,
+/// failing if it raises or returns the wrong number of values.
+fn call_rhs(f: &Bound<'_, PyAny>, t: f64, y: &[f64]) -> PyResult<Vec<f64>> {
+    let dy: Vec<f64> = f.call1((t, y.to_vec()))?.extract()?;
+    if dy.len() != y.len() {
+        return Err(PyValueError::new_err(
+            "f must return as many values as the state has",
+        ));
+    }
+    Ok(dy)
+}
+
+/// Returns the right hand side of dy/dt = f(t, y) as the integrators take it, which keeps
+/// the first error raised by `f` in `error` and stops calling `f` once there is one.
+/// The integrators cannot fail, so the state is then given a zero derivative.
+fn guarded_rhs<'a>(
+    f: &'a Bound<'_, PyAny>,
+    error: &'a RefCell<Option<PyErr>>,
+) -> impl Fn(f64, &Vec<f64>) -> Vec<f64> + 'a {
+    move |t, y| {
+        if error.borrow().is_some() {
+            return vec![0.0; y.len()];
+        }
+        call_rhs(f, t, y).unwrap_or_else(|e| {
+            *error.borrow_mut() = Some(e);
+            vec![0.0; y.len()]
+        })
+    }
+}
+
+/// Steps dy/dt = f(t, y) like [`integrate`](crate::integrators::integrate), stopping at
+/// the first step in which `f` raises.
+fn integrate_guarded<I: Integrator>(
+    integrator: &I,
+    f: &Bound<'_, PyAny>,
+    t0: f64,
+    y0: Vec<f64>,
+    dt: f64,
+    steps: usize,
+) -> PyResult<Vec<(f64, Vec<f64>)>> {
+    let error = RefCell::new(None);
+    let rhs = guarded_rhs(f, &error);
+    let mut trajectory = Vec::with_capacity(steps + 1);
+    let mut t = t0;
+    let mut y = y0;
+    for i in 0..steps {
+        let next = integrator.step(&rhs, t, &y, dt);
+        if let Some(e) = error.take() {
+            return Err(e);
+        }
+        trajectory.push((t, y));
+        y = next;
+        t = t0 + (i + 1) as f64 * dt;
+    }
+    trajectory.push((t, y));
+    Ok(trajectory)
+}
+
+/// Splits `(t, y)` pairs into a list of times and a list of states.
+fn unzip(points: Vec<(f64, Vec<f64>)>) -> (Vec<f64>, Vec<Vec<f64>>) {
+    points.into_iter().unzip()
+}
+
+/// Integrates dy/dt = f(t, y) from `(t0, y0)` for `steps` steps of size `dt` with the
+/// `"rk4"` or `"euler"` method, see [`integrate`](crate::integrators::integrate), and
+/// returns the times and the states. Stops at the first error raised by `f` and raises it.
+#[pyfunction]
+#[pyo3(signature = (f, t0, y0, dt, steps, method = "rk4"))]
+pub fn integrate(
+    f: &Bound<'_, PyAny>,
+    t0: f64,
+    y0: Vec<f64>,
+    dt: f64,
+    steps: usize,
+    method: &str,
+) -> PyResult<(Vec<f64>, Vec<Vec<f64>>)> {
+    let trajectory = match method {
+        "rk4" => integrate_guarded(&Rk4, f, t0, y0, dt, steps)?,
+        "euler" => integrate_guarded(&ExplicitEuler, f, t0, y0, dt, steps)?,
+        _ => return Err(PyValueError::new_err("method must be \"rk4\" or \"euler\"")),
+    };
+    Ok(unzip(trajectory))
+}
+
+/// Integrates dy/dt = f(t, y) from `(t0, y0)` to `t_end` with the adaptive [`Rk45`]
+/// solver and returns the times and the states of the accepted steps. `f` is not called
+/// again once it raises, and its error is raised.
+#[pyfunction]
+#[pyo3(signature = (f, t0, y0, t_end, rtol = 1e-6, atol = 1e-9))]
+pub fn solve_adaptive(
+    f: &Bound<'_, PyAny>,
+    t0: f64,
+    y0: Vec<f64>,
+    t_end: f64,
+    rtol: f64,
+    atol: f64,
+) -> PyResult<(Vec<f64>, Vec<Vec<f64>>)> {
+    let error = RefCell::new(None);
+    let solution = Rk45::new(rtol, atol).solve(guarded_rhs(f, &error), t0, y0, t_end);
+    if let Some(e) = error.into_inner() {
+        return Err(e);
+    }
+    Ok(unzip(solution?.points))
+}
+
+/// The `i_mth` Python module.
+#[pymodule]
+pub fn i_mth(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyVector2D>()?;
+    m.add_class::<PyVector3D>()?;
+    m.add_class::<PyMatrix3>()?;
+    m.add_class::<PyTruss2D>()?;
+    m.add_class::<PyTruss3D>()?;
+    m.add_class::<PyTrussSolution>()?;
+    m.add_class::<PyBeam>()?;
+    m.add_class::<PyBeamSolution>()?;
+    m.add_function(wrap_pyfunction!(integrate, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_adaptive, m)?)?;
+    Ok(())
+}
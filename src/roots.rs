@@ -0,0 +1,409 @@
+//! # Roots
+//!
+//! Root finding for scalar equations f(x) = 0, like Kepler's equation, the sag of a
+//! catenary or the angle at which a mechanism is in equilibrium, and for systems of
+//! nonlinear equations, like the equilibrium of a linkage with several joints.
+//!
+//! Every method stops once the root is known to within `tol` and fails with
+//! [`ImthError::MaxIterationsExceeded`] if that takes more than `max_iterations` iterations.
+
+use crate::calculus::jacobian;
+use crate::error::ImthError;
+use crate::matrixn::MatrixN;
+
+/// A root found by one of the root finding methods.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Root {
+    /// The value of x at which f(x) is (close to) zero.
+    pub root: f64,
+    /// The value of f at the root.
+    pub residual: f64,
+    /// The number of iterations it took to converge.
+    pub iterations: usize,
+}
+
+/// Finds a root of `f` inside `[a, b]` by repeatedly halving the interval.
+/// Slow but guaranteed to converge once `f(a)` and `f(b)` have opposite signs.
+///
+/// Fails with [`ImthError::InvalidArgument`] if `f(a)` and `f(b)` have the same sign.
+pub fn bisection<F>(
+    f: F,
+    a: f64,
+    b: f64,
+    tol: f64,
+    max_iterations: usize,
+) -> Result<Root, ImthError>
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut a, mut b) = (a, b);
+    let mut fa = f(a);
+    let fb = f(b);
+    if fa == 0.0 {
+        return Ok(Root {
+            root: a,
+            residual: fa,
+            iterations: 0,
+        });
+    }
+    if fb == 0.0 {
+        return Ok(Root {
+            root: b,
+            residual: fb,
+            iterations: 0,
+        });
+    }
+    if fa.signum() == fb.signum() {
+        return Err(ImthError::InvalidArgument(
+            "f(a) and f(b) must have opposite signs",
+        ));
+    }
+
+    for iterations in 1..=max_iterations {
+        let mid = 0.5 * (a + b);
+        let f_mid = f(mid);
+        if f_mid == 0.0 || 0.5 * (b - a).abs() <= tol {
+            return Ok(Root {
+                root: mid,
+                residual: f_mid,
+                iterations,
+            });
+        }
+        if f_mid.signum() == fa.signum() {
+            a = mid;
+            fa = f_mid;
+        } else {
+            b = mid;
+        }
+    }
+    Err(ImthError::MaxIterationsExceeded(max_iterations))
+}
+
+/// Finds a root of `f` starting from the guess `x0` using Newton's method with the
+/// derivative `df`. Converges quadratically close to a simple root but may diverge from a
+/// poor guess.
+///
+/// Fails with [`ImthError::DivisionByZero`] if the derivative vanishes on the way.
+///
+/// # Example
+/// ```rust
+/// use i_mth::roots::newton_raphson;
+///
+/// // Kepler's equation E - e sin(E) = M for the eccentric anomaly E
+/// let (e, m) = (0.3, 1.0);
+/// let root = newton_raphson(|x| x - e * x.sin() - m, |x| 1.0 - e * x.cos(), m, 1e-12, 50).unwrap();
+///
+/// assert!((root.root - e * root.root.sin() - m).abs() < 1e-12);
+/// assert!(root.iterations < 10);
+/// ```
+pub fn newton_raphson<F, D>(
+    f: F,
+    df: D,
+    x0: f64,
+    tol: f64,
+    max_iterations: usize,
+) -> Result<Root, ImthError>
+where
+    F: Fn(f64) -> f64,
+    D: Fn(f64) -> f64,
+{
+    let mut x = x0;
+    for iterations in 1..=max_iterations {
+        let fx = f(x);
+        let dfx = df(x);
+        if dfx == 0.0 {
+            return Err(ImthError::DivisionByZero);
+        }
+        let step = fx / dfx;
+        x -= step;
+        if step.abs() <= tol {
+            return Ok(Root {
+                root: x,
+                residual: f(x),
+                iterations,
+            });
+        }
+    }
+    Err(ImthError::MaxIterationsExceeded(max_iterations))
+}
+
+/// Finds a root of `f` inside `[a, b]` using Brent's method, which combines bisection with
+/// secant and inverse quadratic interpolation steps. As reliable as bisection and usually
+/// almost as fast as Newton's method, without needing the derivative.
+///
+/// Fails with [`ImthError::InvalidArgument`] if `f(a)` and `f(b)` have the same sign.
+///
+/// # Example
+/// ```rust
+/// use i_mth::roots::brent;
+///
+/// // sag parameter a of a catenary cable spanning 100 m with 120 m of cable:
+/// // 2a sinh(50 / a) = 120
+/// let root = brent(|a| 2.0 * a * (50.0 / a).sinh() - 120.0, 10.0, 1000.0, 1e-10, 100).unwrap();
+///
+/// assert!(root.residual.abs() < 1e-8);
+/// ```
+pub fn brent<F>(f: F, a: f64, b: f64, tol: f64, max_iterations: usize) -> Result<Root, ImthError>
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut a, mut b) = (a, b);
+    let mut fa = f(a);
+    let mut fb = f(b);
+    if fa == 0.0 {
+        return Ok(Root {
+            root: a,
+            residual: fa,
+            iterations: 0,
+        });
+    }
+    if fb == 0.0 {
+        return Ok(Root {
+            root: b,
+            residual: fb,
+            iterations: 0,
+        });
+    }
+    if fa.signum() == fb.signum() {
+        return Err(ImthError::InvalidArgument(
+            "f(a) and f(b) must have opposite signs",
+        ));
+    }
+
+    let (mut c, mut fc) = (a, fa);
+    let mut d = b - a;
+    let mut e = d;
+    for iterations in 1..=max_iterations {
+        if fb.signum() == fc.signum() {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tol1 = 2.0 * f64::EPSILON * b.abs() + 0.5 * tol;
+        let xm = 0.5 * (c - b);
+        if xm.abs() <= tol1 || fb == 0.0 {
+            return Ok(Root {
+                root: b,
+                residual: fb,
+                iterations,
+            });
+        }
+
+        if e.abs() >= tol1 && fa.abs() > fb.abs() {
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * xm * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * xm * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+            let min1 = 3.0 * xm * q - (tol1 * q).abs();
+            let min2 = (e * q).abs();
+            if 2.0 * p < min1.min(min2) {
+                e = d;
+                d = p / q;
+            } else {
+                d = xm;
+                e = d;
+            }
+        } else {
+            d = xm;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol1 { d } else { tol1.copysign(xm) };
+        fb = f(b);
+    }
+    Err(ImthError::MaxIterationsExceeded(max_iterations))
+}
+
+/// A root of a system of equations found by [`newton_system`].
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct SystemRoot {
+    /// The values of the unknowns at which every equation is (close to) zero.
+    pub root: Vec<f64>,
+    /// The values of the equations at the root.
+    pub residual: Vec<f64>,
+    /// The number of Newton iterations it took to converge.
+    pub iterations: usize,
+    /// The number of times the line search had to shorten a Newton step.
+    pub backtracks: usize,
+}
+
+impl SystemRoot {
+    /// Returns the largest absolute value among the equations at the root.
+    #[inline]
+    pub fn residual_norm(&self) -> f64 {
+        self.residual.iter().fold(0.0, |m, r| m.max(r.abs()))
+    }
+}
+
+/// Relative step of the central differences [`newton_system`] estimates the Jacobian with,
+/// about the cube root of the f64 epsilon which balances truncation and rounding errors.
+const JACOBIAN_STEP: f64 = 6e-6;
+
+/// Most times the line search halves a Newton step before taking it anyway.
+const MAX_BACKTRACKS: usize = 30;
+
+/// Finds a root of the system of equations `f`, as many as there are unknowns, starting
+/// from the guess `x0` using Newton's method with a Jacobian estimated by central
+/// differences. See [`newton_system_with_jacobian`].
+///
+/// # Example
+/// ```rust
+/// use i_mth::error::ImthError;
+/// use i_mth::roots::newton_system;
+///
+/// // a double pendulum of point masses pushed sideways at its tip by F: the angles of the
+/// // two links from the vertical where the moments about both joints balance
+/// let (m1, m2, l1, l2, f, g) = (2.0, 1.0, 1.0, 0.5, 5.0, 9.81);
+/// let moments = |a: &[f64]| {
+///     let (t1, t2) = (a[0], a[1]);
+///     vec![
+///         f * (l1 * t1.cos() + l2 * t2.cos())
+///             - m1 * g * l1 * t1.sin()
+///             - m2 * g * (l1 * t1.sin() + l2 * t2.sin()),
+///         f * l2 * t2.cos() - m2 * g * l2 * t2.sin(),
+///     ]
+/// };
+/// let root = newton_system(moments, &[0.0, 0.0], 1e-12, 50).unwrap();
+///
+/// assert!((root.root[0] - (f / ((m1 + m2) * g)).atan()).abs() < 1e-10);
+/// assert!((root.root[1] - (f / (m2 * g)).atan()).abs() < 1e-10);
+/// assert!(root.residual_norm() < 1e-10);
+///
+/// // equations that change in number away from the guess are an error, not a panic
+/// let changing = |p: &[f64]| if p[0] == 0.0 { vec![p[0] - 1.0] } else { vec![p[0] - 1.0, 0.0] };
+/// assert!(matches!(newton_system(changing, &[0.0], 1e-12, 50), Err(ImthError::InvalidArgument(_))));
+/// ```
+#[inline]
+pub fn newton_system<F>(
+    f: F,
+    x0: &[f64],
+    tol: f64,
+    max_iterations: usize,
+) -> Result<SystemRoot, ImthError>
+where
+    F: Fn(&[f64]) -> Vec<f64>,
+{
+    // equations changing in number within a difference step have no Jacobian, and the
+    // empty matrix standing in for it fails the size check with an error
+    let jacobian = |x: &[f64]| jacobian(&f, x, JACOBIAN_STEP).unwrap_or_default();
+    newton_system_with_jacobian(&f, jacobian, x0, tol, max_iterations)
+}
+
+/// Finds a root of the system of equations `f`, as many as there are unknowns, starting
+/// from the guess `x0` using Newton's method with the Jacobian `df`.\
+/// Each Newton step solves **J**Δx = -f by LU decomposition and is halved until it
+/// reduces the sum of the squares of the equations enough, which keeps a poor guess from
+/// throwing the iteration far away. It stops once the largest component of a full Newton
+/// step is no larger than `tol`.
+///
+/// Fails with [`ImthError::InvalidArgument`] if the number of equations or the size of the
+/// Jacobian does not match the number of unknowns, and with [`ImthError::DivisionByZero`]
+/// if the Jacobian is singular on the way.
+///
+/// # Example
+/// ```rust
+/// use i_mth::matrixn::MatrixN;
+/// use i_mth::roots::newton_system_with_jacobian;
+///
+/// // where the circle x² + y² = 4 meets the curve y = eˣ
+/// let f = |p: &[f64]| vec![p[0] * p[0] + p[1] * p[1] - 4.0, p[1] - p[0].exp()];
+/// let df = |p: &[f64]| {
+///     MatrixN::from_rows(&[[2.0 * p[0], 2.0 * p[1]], [-p[0].exp(), 1.0]]).unwrap()
+/// };
+/// let root = newton_system_with_jacobian(f, df, &[-3.0, 3.0], 1e-12, 50).unwrap();
+///
+/// let (x, y) = (root.root[0], root.root[1]);
+/// assert!((x * x + y * y - 4.0).abs() < 1e-12 && (y - x.exp()).abs() < 1e-12);
+/// assert!(root.iterations < 10);
+/// ```
+pub fn newton_system_with_jacobian<F, D>(
+    f: F,
+    df: D,
+    x0: &[f64],
+    tol: f64,
+    max_iterations: usize,
+) -> Result<SystemRoot, ImthError>
+where
+    F: Fn(&[f64]) -> Vec<f64>,
+    D: Fn(&[f64]) -> MatrixN,
+{
+    let n = x0.len();
+    let squares = |r: &[f64]| r.iter().map(|r| r * r).sum::<f64>();
+    let mut x = x0.to_vec();
+    let mut fx = f(&x);
+    if fx.len() != n {
+        return Err(ImthError::InvalidArgument(
+            "system needs as many equations as unknowns",
+        ));
+    }
+    let mut backtracks = 0;
+    for iterations in 1..=max_iterations {
+        let j = df(&x);
+        if j.rows() != n || j.cols() != n {
+            return Err(ImthError::InvalidArgument(
+                "jacobian must be square with a row per equation",
+            ));
+        }
+        let rhs: Vec<f64> = fx.iter().map(|r| -r).collect();
+        let step = j.solve(&rhs).ok_or(ImthError::DivisionByZero)?;
+        let converged = step.iter().all(|s| s.abs() <= tol);
+
+        // backtrack until the squared residual drops by a small fraction of what the full
+        // step promises, the Armijo condition
+        let current = squares(&fx);
+        let mut lambda = 1.0;
+        let mut tries = 0;
+        let (next, f_next) = loop {
+            let next: Vec<f64> = x.iter().zip(&step).map(|(x, s)| x + lambda * s).collect();
+            let f_next = f(&next);
+            if f_next.len() != n {
+                return Err(ImthError::InvalidArgument(
+                    "system needs as many equations as unknowns",
+                ));
+            }
+            if converged
+                || squares(&f_next) <= (1.0 - 2e-4 * lambda) * current
+                || tries == MAX_BACKTRACKS
+            {
+                break (next, f_next);
+            }
+            lambda *= 0.5;
+            tries += 1;
+        };
+        backtracks += tries;
+        x = next;
+        fx = f_next;
+        if converged {
+            return Ok(SystemRoot {
+                root: x,
+                residual: fx,
+                iterations,
+                backtracks,
+            });
+        }
+    }
+    Err(ImthError::MaxIterationsExceeded(max_iterations))
+}
@@ -0,0 +1,681 @@
+//! # Rotations
+//!
+//! Conversions between the ways of describing an orientation in space: rotation matrices,
+//! unit quaternions, axis-angle pairs and Euler angles.
+//!
+//! Every rotation is active and anticlockwise about its axis, turning vectors rather than
+//! the coordinate axes, so all of them agree with [`Matrix3::from_axis_angle`].
+//! Converting a rotation to any of the other forms and back reproduces it to within a few
+//! units of f64 rounding. Euler angles within 1e-12 rad of gimbal lock are the exception:
+//! their angles are not unique there and come back as a different set describing the
+//! same rotation to within about 1e-12.
+//!
+//! # Example
+//! ```rust
+//! use i_mth::matrix3::Matrix3;
+//! use i_mth::rotations::{EulerAngles, EulerFrame, EulerOrder, Quaternion};
+//! use i_mth::vector3d::Vector3D;
+//!
+//! let r = Matrix3::from_axis_angle(Vector3D::new(1.0, -2.0, 0.5), 2.5).unwrap();
+//! let q = Quaternion::from_matrix(&r).unwrap();
+//! assert!(q.to_matrix().is_approx_equal_to(&r, 1e-15));
+//!
+//! for order in EulerOrder::ALL {
+//!     for frame in [EulerFrame::Intrinsic, EulerFrame::Extrinsic] {
+//!         let angles = EulerAngles::from_quaternion(q, order, frame);
+//!         assert!(angles.to_matrix().is_approx_equal_to(&r, 1e-14));
+//!         assert!(angles.to_quaternion().is_same_rotation(q, 1e-14));
+//!     }
+//! }
+//! ```
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::constants::PI;
+use crate::matrix3::Matrix3;
+use crate::vector3d::Vector3D;
+
+/// A quaternion w + xi + yj + zk, with Hamilton's convention ij = k.\
+/// The unit quaternion cos(θ/2) + sin(θ/2)**u** rotates by θ about the unit axis **u**,
+/// and so does its negative.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Quaternion {
+    /// Scalar part.
+    pub w: f64,
+    /// i component of the vector part.
+    pub x: f64,
+    /// j component of the vector part.
+    pub y: f64,
+    /// k component of the vector part.
+    pub z: f64,
+}
+
+impl Default for Quaternion {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Quaternion {
+    /// Returns the quaternion with the passed scalar part `w` and vector part (x, y, z).
+    #[inline]
+    pub const fn new(w: f64, x: f64, y: f64, z: f64) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// Returns the unit quaternion of no rotation.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Returns the quaternion with the passed scalar and vector parts.
+    #[inline]
+    pub const fn from_parts(w: f64, v: Vector3D) -> Self {
+        Self::new(w, v.x, v.y, v.z)
+    }
+
+    /// Returns the vector part of this quaternion.
+    #[inline]
+    pub const fn vector(&self) -> Vector3D {
+        Vector3D::new(self.x, self.y, self.z)
+    }
+
+    /// Returns the unit quaternion rotating by `angle` radians anticlockwise about the
+    /// passed axis, or None if the axis is the zero vector.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::rotations::Quaternion;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let q = Quaternion::from_axis_angle(Vector3D::k(), std::f64::consts::FRAC_PI_2).unwrap();
+    /// assert!(q.rotate(Vector3D::i()).is_approx_equal_to(Vector3D::j(), 1e-15));
+    /// ```
+    #[inline]
+    pub fn from_axis_angle(axis: Vector3D, angle: f64) -> Option<Self> {
+        let (s, c) = (0.5 * angle).sin_cos();
+        Some(Self::from_parts(c, axis.normalized()?.scale(s)))
+    }
+
+    /// Returns the unit quaternion rotating by the magnitude of `w` in radians
+    /// anticlockwise about its direction, the identity for the zero vector.
+    #[inline]
+    pub fn from_rotation_vector(w: Vector3D) -> Self {
+        let angle = w.magnitude();
+        Self::from_axis_angle(w, angle).unwrap_or_default()
+    }
+
+    /// Returns the unit axis and the angle in radians from 0 to π of the rotation of this
+    /// quaternion, the axis being i for no rotation, or None if it is the zero quaternion.
+    pub fn to_axis_angle(&self) -> Option<(Vector3D, f64)> {
+        // q and -q are the same rotation, and the one with w >= 0 turns by at most π
+        let q = self.normalized()?;
+        let q = if q.w < 0.0 { -q } else { q };
+        let v = q.vector();
+        let angle = 2.0 * v.magnitude().atan2(q.w);
+        Some((v.normalize_or(Vector3D::i()), angle))
+    }
+
+    /// Returns the rotation vector of this quaternion, along its axis with a magnitude of
+    /// its angle in radians from 0 to π, or None if it is the zero quaternion.
+    #[inline]
+    pub fn to_rotation_vector(&self) -> Option<Vector3D> {
+        let (axis, angle) = self.to_axis_angle()?;
+        Some(axis.scale(angle))
+    }
+
+    /// Returns the unit quaternion with a non-negative scalar part of the passed rotation
+    /// matrix, found with Shepperd's method, or None if it is not a rotation within
+    /// [`Matrix3::DEFAULT_TOL`].
+    pub fn from_matrix(m: &Matrix3) -> Option<Self> {
+        if !m.is_rotation(Matrix3::DEFAULT_TOL) {
+            return None;
+        }
+        let r = &m.rows;
+        // dividing by the largest of the four components keeps the others accurate
+        let diagonal = [m.trace(), r[0][0], r[1][1], r[2][2]];
+        let largest = (0..4).max_by(|&i, &j| diagonal[i].total_cmp(&diagonal[j]))?;
+        let q = match largest {
+            0 => {
+                let s = 2.0 * (1.0 + m.trace()).sqrt();
+                Self::new(
+                    0.25 * s,
+                    (r[2][1] - r[1][2]) / s,
+                    (r[0][2] - r[2][0]) / s,
+                    (r[1][0] - r[0][1]) / s,
+                )
+            }
+            1 => {
+                let s = 2.0 * (1.0 + r[0][0] - r[1][1] - r[2][2]).sqrt();
+                Self::new(
+                    (r[2][1] - r[1][2]) / s,
+                    0.25 * s,
+                    (r[0][1] + r[1][0]) / s,
+                    (r[0][2] + r[2][0]) / s,
+                )
+            }
+            2 => {
+                let s = 2.0 * (1.0 - r[0][0] + r[1][1] - r[2][2]).sqrt();
+                Self::new(
+                    (r[0][2] - r[2][0]) / s,
+                    (r[0][1] + r[1][0]) / s,
+                    0.25 * s,
+                    (r[1][2] + r[2][1]) / s,
+                )
+            }
+            _ => {
+                let s = 2.0 * (1.0 - r[0][0] - r[1][1] + r[2][2]).sqrt();
+                Self::new(
+                    (r[1][0] - r[0][1]) / s,
+                    (r[0][2] + r[2][0]) / s,
+                    (r[1][2] + r[2][1]) / s,
+                    0.25 * s,
+                )
+            }
+        };
+        let q = q.normalized()?;
+        Some(if q.w < 0.0 { -q } else { q })
+    }
+
+    /// Returns the rotation matrix of this quaternion, normalizing it first, or the
+    /// identity for the zero quaternion.
+    pub fn to_matrix(&self) -> Matrix3 {
+        let Some(Self { w, x, y, z }) = self.normalized() else {
+            return Matrix3::IDENTITY;
+        };
+        Matrix3::new([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ])
+    }
+
+    /// Returns the four dimensional dot product of this and the passed quaternion.
+    #[inline]
+    pub fn dot(&self, other: Quaternion) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the squared norm of this quaternion.
+    #[inline]
+    pub fn squared_norm(&self) -> f64 {
+        self.dot(*self)
+    }
+
+    /// Returns the norm of this quaternion.
+    #[inline]
+    pub fn norm(&self) -> f64 {
+        self.squared_norm().sqrt()
+    }
+
+    /// Returns this quaternion with every component scaled by the passed value.
+    #[inline]
+    pub fn scale(&self, value: f64) -> Self {
+        Self::new(
+            self.w * value,
+            self.x * value,
+            self.y * value,
+            self.z * value,
+        )
+    }
+
+    /// Returns this quaternion scaled to unit norm, or None if it is the zero quaternion.
+    #[inline]
+    pub fn normalized(&self) -> Option<Self> {
+        let norm = self.norm();
+        if norm == 0.0 || !norm.is_finite() {
+            return None;
+        }
+        Some(self.scale(1.0 / norm))
+    }
+
+    /// Returns the conjugate w - xi - yj - zk, the inverse rotation of a unit quaternion.
+    #[inline]
+    pub const fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Returns the multiplicative inverse of this quaternion, or None if it is the zero
+    /// quaternion.
+    #[inline]
+    pub fn inverse(&self) -> Option<Self> {
+        let n2 = self.squared_norm();
+        if n2 == 0.0 {
+            return None;
+        }
+        Some(self.conjugate().scale(1.0 / n2))
+    }
+
+    /// Returns the passed vector rotated by this unit quaternion, q**v**q<sup>*</sup>.
+    #[inline]
+    pub fn rotate(&self, v: Vector3D) -> Vector3D {
+        let u = self.vector();
+        let t = u.cross(v).scale(2.0);
+        v.add_scaled(t, self.w) + u.cross(t)
+    }
+
+    /// Returns true if this and the passed unit quaternion describe the same rotation,
+    /// either equal or opposite in every component within `tol`.
+    #[inline]
+    pub fn is_same_rotation(&self, other: Quaternion, tol: f64) -> bool {
+        let close = |q: Quaternion| {
+            [q.w - other.w, q.x - other.x, q.y - other.y, q.z - other.z]
+                .iter()
+                .all(|d| d.abs() <= tol)
+        };
+        close(*self) || close(-*self)
+    }
+
+    /// Returns the angle in radians from 0 to π of the rotation taking this unit quaternion
+    /// to the passed one.
+    #[inline]
+    pub fn angle_to(&self, other: Quaternion) -> f64 {
+        // the quaternions are half the rotation angle apart on the unit sphere
+        let other = if self.dot(other) < 0.0 { -other } else { other };
+        4.0 * (*self - other).norm().atan2((*self + other).norm())
+    }
+
+    /// Returns the exponential e<sup>q</sup> of this quaternion, a unit quaternion rotating
+    /// by twice the magnitude of the vector part for a pure quaternion.
+    pub fn exp(&self) -> Self {
+        let v = self.vector();
+        let angle = v.magnitude();
+        // sin(θ)/θ loses its precision for tiny angles, where its series is exact
+        let sinc = if angle < 1e-4 {
+            1.0 - angle * angle / 6.0
+        } else {
+            angle.sin() / angle
+        };
+        Self::from_parts(angle.cos(), v.scale(sinc)).scale(self.w.exp())
+    }
+
+    /// Returns the natural logarithm of this quaternion, whose vector part is half the
+    /// rotation vector of a unit quaternion, taken about i for a negative real quaternion.
+    /// The scalar part is -∞ for the zero quaternion.
+    pub fn log(&self) -> Self {
+        let v = self.vector();
+        let angle = v.magnitude().atan2(self.w);
+        Self::from_parts(self.norm().ln(), v.normalize_or(Vector3D::i()).scale(angle))
+    }
+
+    /// Returns the spherical linear interpolation from this unit quaternion at `t` = 0 to
+    /// the passed one at `t` = 1, which turns at a constant rate about a fixed axis along
+    /// the shorter of the two ways round.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::rotations::Quaternion;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let a = Quaternion::from_axis_angle(Vector3D::k(), 0.2).unwrap();
+    /// let b = Quaternion::from_axis_angle(Vector3D::k(), 1.4).unwrap();
+    /// let q = a.slerp(b, 0.25);
+    /// assert!(q.is_same_rotation(Quaternion::from_axis_angle(Vector3D::k(), 0.5).unwrap(), 1e-15));
+    ///
+    /// // -b is the same rotation as b, and the path still turns by 1.2 rad rather than 2π - 1.2
+    /// assert!((a.angle_to(-b) - 1.2).abs() < 1e-15);
+    /// let q = a.slerp(-b, 0.25);
+    /// assert!(q.is_same_rotation(Quaternion::from_axis_angle(Vector3D::k(), 0.5).unwrap(), 1e-15));
+    /// ```
+    #[inline]
+    pub fn slerp(&self, other: Quaternion, t: f64) -> Self {
+        let other = if self.dot(other) < 0.0 { -other } else { other };
+        self.slerp_unflipped(other, t)
+    }
+
+    /// Returns the inner control point at the key `q` of a [`Quaternion::squad`] curve
+    /// through the keys `prev`, `q` and `next`, which makes the angular velocity continuous
+    /// through `q`.
+    pub fn squad_control(prev: Quaternion, q: Quaternion, next: Quaternion) -> Self {
+        let prev = if q.dot(prev) < 0.0 { -prev } else { prev };
+        let next = if q.dot(next) < 0.0 { -next } else { next };
+        let inverse = q.conjugate();
+        let tangent = (inverse * next).log() + (inverse * prev).log();
+        q * tangent.scale(-0.25).exp()
+    }
+
+    /// Returns the spherical quadrangle interpolation from this unit quaternion at `t` = 0
+    /// to the passed one at `t` = 1, with the control points `a` at this key and `b` at the
+    /// other one from [`Quaternion::squad_control`].\
+    /// Joining the segments between a series of keys gives an orientation path with a
+    /// continuous angular velocity, unlike joining [`Quaternion::slerp`] segments.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::rotations::Quaternion;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let keys: Vec<Quaternion> = [0.0, 0.4, 1.1, 1.5]
+    ///     .iter()
+    ///     .map(|&angle| Quaternion::from_axis_angle(Vector3D::new(1.0, 2.0, 0.0), angle).unwrap())
+    ///     .collect();
+    /// let a = Quaternion::squad_control(keys[0], keys[1], keys[2]);
+    /// let b = Quaternion::squad_control(keys[1], keys[2], keys[3]);
+    ///
+    /// assert!(keys[1].squad(keys[2], a, b, 0.0).is_same_rotation(keys[1], 1e-15));
+    /// assert!(keys[1].squad(keys[2], a, b, 1.0).is_same_rotation(keys[2], 1e-15));
+    /// // keys about one axis stay on it
+    /// let (axis, _) = keys[1].squad(keys[2], a, b, 0.3).to_axis_angle().unwrap();
+    /// assert!(axis.is_parallel_to(Vector3D::new(1.0, 2.0, 0.0), 1e-12));
+    /// ```
+    #[inline]
+    pub fn squad(&self, other: Quaternion, a: Quaternion, b: Quaternion, t: f64) -> Self {
+        let other = if self.dot(other) < 0.0 { -other } else { other };
+        self.slerp_unflipped(other, t)
+            .slerp_unflipped(a.slerp_unflipped(b, t), 2.0 * t * (1.0 - t))
+    }
+
+    /// Returns the spherical linear interpolation from this unit quaternion to the passed
+    /// one, the long way round if their dot product is negative.
+    fn slerp_unflipped(&self, other: Quaternion, t: f64) -> Self {
+        // the angle from the chord and the sum keeps its precision for close quaternions
+        let angle = 2.0 * (*self - other).norm().atan2((*self + other).norm());
+        let sin = angle.sin();
+        let (a, b) = if sin < 1e-8 {
+            (1.0 - t, t)
+        } else {
+            (((1.0 - t) * angle).sin() / sin, (t * angle).sin() / sin)
+        };
+        let q = self.scale(a) + other.scale(b);
+        q.normalized().unwrap_or(q)
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Self;
+    /// Returns the Hamilton product, the rotation `rhs` followed by `self` for unit
+    /// quaternions.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+impl Add for Quaternion {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.w + rhs.w,
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+        )
+    }
+}
+
+impl Sub for Quaternion {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            self.w - rhs.w,
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+        )
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self.scale(-1.0)
+    }
+}
+
+impl From<Quaternion> for Matrix3 {
+    #[inline]
+    fn from(q: Quaternion) -> Self {
+        q.to_matrix()
+    }
+}
+
+/// The axes of the three elementary rotations of a set of Euler angles, in the order
+/// they are applied.\
+/// The first six are Tait-Bryan orders about three different axes, like yaw, pitch and
+/// roll for ZYX, and the last six are proper Euler orders repeating the first axis.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    #[default]
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerOrder {
+    /// All twelve orders.
+    pub const ALL: [EulerOrder; 12] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+        EulerOrder::XYX,
+        EulerOrder::XZX,
+        EulerOrder::YXY,
+        EulerOrder::YZY,
+        EulerOrder::ZXZ,
+        EulerOrder::ZYZ,
+    ];
+
+    /// Returns the indices of the three axes, 0 for x, 1 for y and 2 for z.
+    pub const fn axes(&self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// Returns true for the proper Euler orders, whose first and last axes are the same.
+    #[inline]
+    pub const fn is_proper(&self) -> bool {
+        let [i, _, k] = self.axes();
+        i == k
+    }
+}
+
+/// Whether the rotations of a set of Euler angles are about the axes of the turning body
+/// or about the fixed axes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum EulerFrame {
+    /// Each rotation is about the axes as moved by the rotations before it, so the
+    /// intrinsic ZYX angles (yaw, pitch, roll) give **R** = **R**<sub>z</sub>**R**<sub>y</sub>**R**<sub>x</sub>.
+    #[default]
+    Intrinsic,
+    /// Each rotation is about the fixed axes, so the extrinsic XYZ angles give the same
+    /// **R** = **R**<sub>z</sub>**R**<sub>y</sub>**R**<sub>x</sub>.
+    Extrinsic,
+}
+
+/// Three angles in radians of successive rotations about coordinate axes.
+///
+/// # Example
+/// ```rust
+/// use i_mth::matrix3::Matrix3;
+/// use i_mth::rotations::{EulerAngles, EulerFrame, EulerOrder};
+///
+/// // an aircraft yawed by 30°, pitched up by 10° and rolled by 5°
+/// let (yaw, pitch, roll) = (30f64.to_radians(), 10f64.to_radians(), 5f64.to_radians());
+/// let attitude = EulerAngles::new([yaw, pitch, roll], EulerOrder::ZYX, EulerFrame::Intrinsic);
+/// let r = Matrix3::rotation_z(yaw) * Matrix3::rotation_y(pitch) * Matrix3::rotation_x(roll);
+/// assert!(attitude.to_matrix().is_approx_equal_to(&r, 1e-15));
+///
+/// // the same rotation about the fixed axes happens in the reverse order
+/// let fixed = EulerAngles::from_matrix(&r, EulerOrder::XYZ, EulerFrame::Extrinsic).unwrap();
+/// assert!((fixed.angles[0] - roll).abs() < 1e-14);
+/// assert!((fixed.angles[2] - yaw).abs() < 1e-14);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct EulerAngles {
+    /// Angles of the three rotations in the order they are applied.
+    pub angles: [f64; 3],
+    /// Axes of the three rotations.
+    pub order: EulerOrder,
+    /// Whether the axes turn with the body or stay fixed.
+    pub frame: EulerFrame,
+}
+
+impl EulerAngles {
+    /// Returns the angles of the passed rotations.
+    #[inline]
+    pub const fn new(angles: [f64; 3], order: EulerOrder, frame: EulerFrame) -> Self {
+        Self {
+            angles,
+            order,
+            frame,
+        }
+    }
+
+    /// Returns the rotation matrix of these angles.
+    pub fn to_matrix(&self) -> Matrix3 {
+        let [r0, r1, r2] = [0, 1, 2].map(|n| {
+            let angle = self.angles[n];
+            match self.order.axes()[n] {
+                0 => Matrix3::rotation_x(angle),
+                1 => Matrix3::rotation_y(angle),
+                _ => Matrix3::rotation_z(angle),
+            }
+        });
+        match self.frame {
+            EulerFrame::Intrinsic => r0 * r1 * r2,
+            EulerFrame::Extrinsic => r2 * r1 * r0,
+        }
+    }
+
+    /// Returns the unit quaternion of these angles.
+    pub fn to_quaternion(&self) -> Quaternion {
+        let [q0, q1, q2] = [0, 1, 2].map(|n| {
+            let (s, c) = (0.5 * self.angles[n]).sin_cos();
+            let mut v = [0.0; 3];
+            v[self.order.axes()[n]] = s;
+            Quaternion::new(c, v[0], v[1], v[2])
+        });
+        match self.frame {
+            EulerFrame::Intrinsic => q0 * q1 * q2,
+            EulerFrame::Extrinsic => q2 * q1 * q0,
+        }
+    }
+
+    /// Returns the angles of the passed rotation matrix in the passed convention, or None
+    /// if it is not a rotation within [`Matrix3::DEFAULT_TOL`].\
+    /// See [`EulerAngles::from_quaternion`] for the ranges of the angles.
+    #[inline]
+    pub fn from_matrix(m: &Matrix3, order: EulerOrder, frame: EulerFrame) -> Option<Self> {
+        Some(Self::from_quaternion(
+            Quaternion::from_matrix(m)?,
+            order,
+            frame,
+        ))
+    }
+
+    /// Returns the angles of the rotation of the passed quaternion in the passed convention,
+    /// found with the method of Bernardes and Viollet (2022).\
+    /// The first and last angles are in [-π, π]. The middle angle is in [0, π] for proper
+    /// Euler orders and in [-π/2, π/2] for Tait-Bryan orders. At gimbal lock, where the
+    /// first and last axes line up, the last angle is taken as zero.
+    pub fn from_quaternion(q: Quaternion, order: EulerOrder, frame: EulerFrame) -> Self {
+        let q = q.normalized().unwrap_or_default();
+        let components = [q.x, q.y, q.z];
+        // the method works on extrinsic rotations, and intrinsic ones are the same
+        // rotations about the axes in the reverse order
+        let [i, j, k] = match frame {
+            EulerFrame::Extrinsic => order.axes(),
+            EulerFrame::Intrinsic => {
+                let [i, j, k] = order.axes();
+                [k, j, i]
+            }
+        };
+        let proper = i == k;
+        let k = if proper { 3 - i - j } else { k };
+        // +1 for an even permutation of the axes and -1 for an odd one
+        let sign =
+            ((i as i32 - j as i32) * (j as i32 - k as i32) * (k as i32 - i as i32) / 2) as f64;
+        let (a, b, c, d) = if proper {
+            (q.w, components[i], components[j], components[k] * sign)
+        } else {
+            (
+                q.w - components[j],
+                components[i] + components[k] * sign,
+                components[j] + q.w,
+                components[k] * sign - components[i],
+            )
+        };
+
+        let (first, third) = match frame {
+            EulerFrame::Extrinsic => (0, 2),
+            EulerFrame::Intrinsic => (2, 0),
+        };
+        let mut angles = [0.0; 3];
+        angles[1] = 2.0 * c.hypot(d).atan2(a.hypot(b));
+        let half_sum = b.atan2(a);
+        let half_diff = d.atan2(c);
+        if angles[1].abs() <= GIMBAL_LOCK_TOL {
+            angles[0] = 2.0 * half_sum;
+        } else if (angles[1] - PI).abs() <= GIMBAL_LOCK_TOL {
+            angles[0] = match frame {
+                EulerFrame::Extrinsic => -2.0 * half_diff,
+                EulerFrame::Intrinsic => 2.0 * half_diff,
+            };
+        } else {
+            angles[first] = half_sum - half_diff;
+            angles[third] = half_sum + half_diff;
+        }
+        if !proper {
+            angles[third] *= sign;
+            angles[1] -= 0.5 * PI;
+        }
+        for angle in angles.iter_mut() {
+            if *angle < -PI {
+                *angle += 2.0 * PI;
+            } else if *angle > PI {
+                *angle -= 2.0 * PI;
+            }
+        }
+        Self::new(angles, order, frame)
+    }
+}
+
+/// Distance in radians of the middle Euler angle from its limits below which the first
+/// and last axes are taken to line up.
+const GIMBAL_LOCK_TOL: f64 = 1e-12;
@@ -0,0 +1,71 @@
+//! # Scalars
+//!
+//! The [`Real`] trait for the formulas and solvers that are generic over the scalar they
+//! compute in. It is implemented for f64 here, for measurements with an uncertainty, and
+//! for the double-double of the precision module when the `precision` feature is enabled.
+//!
+//! A formula written over [`Real`] can be evaluated with measurements to carry their
+//! uncertainties through to the result. Their arithmetic treats every operand as an
+//! independent quantity, so a value used more than once, like v in v * v, is best raised
+//! to a power with [`Real::powi`] instead.
+//!
+//! # Example
+//! ```rust
+//! use i_mth::scalar::Real;
+//!
+//! fn hypot<R: Real>(a: R, b: R) -> R {
+//!     (a * a + b * b).sqrt()
+//! }
+//!
+//! assert_eq!(5.0, hypot(3.0, 4.0));
+//! ```
+
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// A real scalar type the generic formulas and solvers can compute in.
+pub trait Real:
+    Copy
+    + PartialOrd
+    + From<f64>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    /// Returns this value rounded to the nearest f64.
+    fn to_f64(self) -> f64;
+
+    /// Returns the absolute value of this value.
+    fn abs(self) -> Self;
+
+    /// Returns the square root of this value, NaN if it is negative.
+    fn sqrt(self) -> Self;
+
+    /// Returns this value raised to the integer power `n`.
+    fn powi(self, n: i32) -> Self;
+}
+
+impl Real for f64 {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        f64::powi(self, n)
+    }
+}
@@ -0,0 +1,231 @@
+//! # Screws
+//!
+//! Wrenches (force systems), couples and twists (rigid body velocity fields) in space.
+//!
+//! Both pair a vector that is the same everywhere (the resultant force, the angular
+//! velocity) with one that depends on the reference point (the moment, the linear
+//! velocity). Moving a wrench from point A to point B changes the moment by
+//! **M**<sub>B</sub> = **M**<sub>A</sub> + (A - B) × **F**, and moving a twist changes
+//! the velocity by **v**<sub>B</sub> = **v**<sub>A</sub> + **ω** × (B - A).
+
+use std::ops::{Add, Neg};
+
+use crate::error::ImthError;
+use crate::vector3d::Vector3D;
+
+/// A system of forces reduced to a resultant force and a moment about a reference point.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Wrench {
+    /// Resultant force in N.
+    pub force: Vector3D,
+    /// Resultant moment about `point` in N.m.
+    pub moment: Vector3D,
+    /// Reference point the moment is taken about.
+    pub point: Vector3D,
+}
+
+impl Wrench {
+    /// Returns the wrench with the passed force and moment about `point`.
+    #[inline]
+    pub fn new(force: Vector3D, moment: Vector3D, point: Vector3D) -> Self {
+        Self {
+            force,
+            moment,
+            point,
+        }
+    }
+
+    /// Returns the wrench of a single force applied at the passed point.
+    #[inline]
+    pub fn from_force(force: Vector3D, point: Vector3D) -> Self {
+        Self::new(force, Vector3D::origin(), point)
+    }
+
+    /// Returns the same force system with its moment taken about `point` instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::screw::Wrench;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // two forces on a bracket reduced to a single wrench at the bolt in the origin
+    /// let a = Wrench::from_force(Vector3D::new(0.0, -100.0, 0.0), Vector3D::new(0.5, 0.0, 0.0));
+    /// let b = Wrench::from_force(Vector3D::new(50.0, 0.0, 0.0), Vector3D::new(0.0, 0.2, 0.0));
+    /// let at_bolt = (a + b).at(Vector3D::origin());
+    ///
+    /// assert_eq!(Vector3D::new(50.0, -100.0, 0.0), at_bolt.force);
+    /// assert_eq!(Vector3D::new(0.0, 0.0, -60.0), at_bolt.moment);
+    /// ```
+    #[inline]
+    pub fn at(&self, point: Vector3D) -> Self {
+        Self::new(
+            self.force,
+            self.moment + (self.point - point).cross(self.force),
+            point,
+        )
+    }
+
+    /// Returns the power delivered by this wrench to a body moving with the passed twist,
+    /// **F** · **v** + **M** · **ω** with both taken about the same point.
+    #[inline]
+    pub fn power(&self, twist: &Twist) -> f64 {
+        let twist = twist.at(self.point);
+        self.force.dot(twist.linear) + self.moment.dot(twist.angular)
+    }
+
+    /// Returns the pitch of this wrench, the ratio of the moment along the force to the
+    /// force, which is the same about every point. Returns None if the force is zero.
+    #[inline]
+    pub fn pitch(&self) -> Option<f64> {
+        let f2 = self.force.squared_magnitude();
+        if f2 == 0.0 {
+            return None;
+        }
+        Some(self.moment.dot(self.force) / f2)
+    }
+}
+
+impl Add for Wrench {
+    type Output = Self;
+    /// Returns the combined force system, with its moment about the point of `self`.
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let rhs = rhs.at(self.point);
+        Self::new(self.force + rhs.force, self.moment + rhs.moment, self.point)
+    }
+}
+
+impl Neg for Wrench {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(self.force.scale(-1.0), self.moment.scale(-1.0), self.point)
+    }
+}
+
+/// Two equal and opposite forces with separate lines of action, which have no resultant
+/// force and the same moment about every point.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Couple {
+    /// Moment of the couple in N.m.
+    pub moment: Vector3D,
+}
+
+impl Couple {
+    /// Returns the couple with the passed moment.
+    #[inline]
+    pub fn new(moment: Vector3D) -> Self {
+        Self { moment }
+    }
+
+    /// Returns the couple formed by force `f_a` acting at `point_a` and force `f_b` acting
+    /// at `point_b`, with the moment **r**<sub>AB</sub> × **F**<sub>B</sub>. Returns an
+    /// error if the forces are not equal and opposite within [`Vector3D::DEFAULT_TOL`]
+    /// relative to their size.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::screw::Couple;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // hands 0.4 m apart on a wheel brace
+    /// let f = Vector3D::new(0.0, 0.0, 50.0);
+    /// let couple = Couple::from_forces(f, Vector3D::new(-0.2, 0.0, 0.0), f.scale(-1.0), Vector3D::new(0.2, 0.0, 0.0)).unwrap();
+    ///
+    /// assert_eq!(Vector3D::new(0.0, 20.0, 0.0), couple.moment);
+    /// assert!(Couple::from_forces(f, Vector3D::origin(), f, Vector3D::i()).is_err());
+    /// ```
+    pub fn from_forces(
+        f_a: Vector3D,
+        point_a: Vector3D,
+        f_b: Vector3D,
+        point_b: Vector3D,
+    ) -> Result<Self, ImthError> {
+        let size = f_a.magnitude().max(f_b.magnitude()).max(1.0);
+        if !(f_a + f_b).is_zero(Vector3D::DEFAULT_TOL * size) {
+            return Err(ImthError::InvalidArgument(
+                "the forces of a couple must be equal and opposite",
+            ));
+        }
+        Ok(Self::new((point_b - point_a).cross(f_b)))
+    }
+}
+
+impl Add for Couple {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.moment + rhs.moment)
+    }
+}
+
+/// A couple is a wrench with no resultant force, taken about the origin.
+impl From<Couple> for Wrench {
+    #[inline]
+    fn from(couple: Couple) -> Self {
+        Wrench::new(Vector3D::origin(), couple.moment, Vector3D::origin())
+    }
+}
+
+/// The velocity field of a rigid body, given by its angular velocity and the linear
+/// velocity of a reference point.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Twist {
+    /// Angular velocity in rad.s<sup>-1</sup>.
+    pub angular: Vector3D,
+    /// Velocity of the body at `point` in ms<sup>-1</sup>.
+    pub linear: Vector3D,
+    /// Reference point the linear velocity is given at.
+    pub point: Vector3D,
+}
+
+impl Twist {
+    /// Returns the twist with the passed angular velocity and the linear velocity at `point`.
+    #[inline]
+    pub fn new(angular: Vector3D, linear: Vector3D, point: Vector3D) -> Self {
+        Self {
+            angular,
+            linear,
+            point,
+        }
+    }
+
+    /// Returns the same velocity field with its linear velocity given at `point` instead.
+    #[inline]
+    pub fn at(&self, point: Vector3D) -> Self {
+        Self::new(self.angular, self.velocity_at(point), point)
+    }
+
+    /// Returns the velocity of the body point currently at `point`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::screw::Twist;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // a wheel of radius 0.3 m rolling at 2 m/s along x, spinning about -z
+    /// let wheel = Twist::new(Vector3D::new(0.0, 0.0, -2.0 / 0.3), Vector3D::new(2.0, 0.0, 0.0), Vector3D::new(0.0, 0.3, 0.0));
+    ///
+    /// assert!(wheel.velocity_at(Vector3D::origin()).magnitude() < 1e-12);
+    /// assert!((wheel.velocity_at(Vector3D::new(0.0, 0.6, 0.0)).x - 4.0).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn velocity_at(&self, point: Vector3D) -> Vector3D {
+        self.linear + self.angular.cross(point - self.point)
+    }
+}
+
+impl Add for Twist {
+    type Output = Self;
+    /// Returns the sum of the velocity fields, with its linear velocity at the point of
+    /// `self`.
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let rhs = rhs.at(self.point);
+        Self::new(
+            self.angular + rhs.angular,
+            self.linear + rhs.linear,
+            self.point,
+        )
+    }
+}
@@ -0,0 +1,265 @@
+//! # Series
+//!
+//! Sampled `(x, y)` data ready to be plotted, like a shear force diagram, a component of
+//! a trajectory over time or the gain of a frequency response.
+//!
+//! With the `plotters` feature a series can be rendered straight to a PNG or SVG file.
+
+use crate::integrators::{Rk45Solution, StateVector};
+
+/// A named sequence of `(x, y)` points sorted by x.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DataSeries {
+    /// Name of the series, used as the title of a plot.
+    pub name: String,
+    /// Label of the x axis, including the unit.
+    pub x_label: String,
+    /// Label of the y axis, including the unit.
+    pub y_label: String,
+    /// The sampled points in increasing x.
+    pub points: Vec<(f64, f64)>,
+}
+
+impl DataSeries {
+    /// Returns a series with the passed name and points, which are sorted by x.
+    pub fn new(name: &str, mut points: Vec<(f64, f64)>) -> Self {
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self {
+            name: String::from(name),
+            points,
+            ..Self::default()
+        }
+    }
+
+    /// Returns this series with the passed axis labels.
+    pub fn with_labels(mut self, x_label: &str, y_label: &str) -> Self {
+        self.x_label = String::from(x_label);
+        self.y_label = String::from(y_label);
+        self
+    }
+
+    /// Returns the series of `f` sampled at `samples` evenly spaced points from `a` to `b`
+    /// inclusive, or at `a` alone if `samples` is less than two.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::series::DataSeries;
+    ///
+    /// // bending moment of a 4 m simply supported beam under 10 kN/m
+    /// let moment = DataSeries::sample("M", |x| 5.0 * x * (4.0 - x), 0.0, 4.0, 41)
+    ///     .with_labels("x (m)", "M (kN.m)");
+    ///
+    /// let (x, m) = moment.max().unwrap();
+    /// assert!((x - 2.0).abs() < 1e-12 && (m - 20.0).abs() < 1e-12);
+    /// ```
+    pub fn sample<F: Fn(f64) -> f64>(name: &str, f: F, a: f64, b: f64, samples: usize) -> Self {
+        let step = (b - a) / samples.saturating_sub(1).max(1) as f64;
+        let points = (0..samples.max(1))
+            .map(|i| {
+                let x = a + step * i as f64;
+                (x, f(x))
+            })
+            .collect();
+        Self::new(name, points)
+    }
+
+    /// Returns the series of `f` from `a` to `b`, starting from `initial` evenly spaced
+    /// intervals and halving any interval whose midpoint is further than `tol` from the
+    /// straight line between its ends, at most `max_depth` times. Kinks and steep parts
+    /// get many points while straight parts get few.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::series::DataSeries;
+    ///
+    /// let series = DataSeries::sample_adaptive("|x|", |x: f64| x.abs().sqrt(), -1.0, 1.0, 4, 1e-3, 12);
+    /// let near_zero = series.points.iter().filter(|p| p.0.abs() < 0.1).count();
+    /// let far = series.points.iter().filter(|p| p.0 > 0.9).count();
+    ///
+    /// assert!(near_zero > far);
+    /// ```
+    pub fn sample_adaptive<F: Fn(f64) -> f64>(
+        name: &str,
+        f: F,
+        a: f64,
+        b: f64,
+        initial: usize,
+        tol: f64,
+        max_depth: usize,
+    ) -> Self {
+        let coarse = Self::sample(name, &f, a, b, initial.max(1) + 1).points;
+        let mut points = vec![coarse[0]];
+        for window in coarse.windows(2) {
+            let mut stack = vec![(window[0], window[1], 0)];
+            while let Some((left, right, depth)) = stack.pop() {
+                let x = 0.5 * (left.0 + right.0);
+                let mid = (x, f(x));
+                if depth >= max_depth || (mid.1 - 0.5 * (left.1 + right.1)).abs() <= tol {
+                    points.push(right);
+                } else {
+                    stack.push((mid, right, depth + 1));
+                    stack.push((left, mid, depth + 1));
+                }
+            }
+        }
+        Self::new(name, points)
+    }
+
+    /// Returns the series of one value of a trajectory, like the output of
+    /// [`crate::integrators::integrate`], against time.
+    pub fn from_trajectory<S, F>(name: &str, trajectory: &[(f64, S)], value: F) -> Self
+    where
+        F: Fn(&S) -> f64,
+    {
+        Self::new(
+            name,
+            trajectory.iter().map(|(t, s)| (*t, value(s))).collect(),
+        )
+    }
+
+    /// Returns the point with the smallest y, or None if the series is empty.
+    #[inline]
+    pub fn min(&self) -> Option<(f64, f64)> {
+        self.points
+            .iter()
+            .copied()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Returns the point with the largest y, or None if the series is empty.
+    #[inline]
+    pub fn max(&self) -> Option<(f64, f64)> {
+        self.points
+            .iter()
+            .copied()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Returns the point with the largest magnitude of y, like the critical section of a
+    /// bending moment diagram, or None if the series is empty.
+    #[inline]
+    pub fn abs_max(&self) -> Option<(f64, f64)> {
+        self.points
+            .iter()
+            .copied()
+            .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+    }
+
+    /// Returns the smallest and largest x, or None if the series is empty.
+    #[inline]
+    pub fn x_range(&self) -> Option<(f64, f64)> {
+        Some((self.points.first()?.0, self.points.last()?.0))
+    }
+
+    /// Returns the smallest and largest y, or None if the series is empty.
+    #[inline]
+    pub fn y_range(&self) -> Option<(f64, f64)> {
+        Some((self.min()?.1, self.max()?.1))
+    }
+}
+
+impl<S: StateVector> Rk45Solution<S> {
+    /// Returns the series of one value of the dense solution sampled at `samples` evenly
+    /// spaced times, or None if dense output was not requested.
+    pub fn sample_series<F>(&self, name: &str, samples: usize, value: F) -> Option<DataSeries>
+    where
+        F: Fn(&S) -> f64,
+    {
+        let t0 = self.segments.first()?.t0;
+        let t1 = self.segments.last()?.t1;
+        let series = DataSeries::sample(
+            name,
+            |t| self.evaluate(t.min(t1)).as_ref().map_or(f64::NAN, &value),
+            t0,
+            t1,
+            samples,
+        );
+        Some(series)
+    }
+}
+
+#[cfg(feature = "plotters")]
+mod render {
+    use std::error::Error;
+    use std::path::Path;
+
+    use plotters::coord::Shift;
+    use plotters::prelude::*;
+
+    use super::DataSeries;
+
+    impl DataSeries {
+        /// Renders this series as a line plot with its minimum and maximum marked to a PNG
+        /// file of `width` by `height` pixels.
+        pub fn render_png<P: AsRef<Path>>(
+            &self,
+            path: P,
+            width: u32,
+            height: u32,
+        ) -> Result<(), Box<dyn Error>> {
+            let root = BitMapBackend::new(path.as_ref(), (width, height)).into_drawing_area();
+            self.draw(&root)?;
+            root.present()?;
+            Ok(())
+        }
+
+        /// Renders this series as a line plot with its minimum and maximum marked to an SVG
+        /// file of `width` by `height` pixels.
+        ///
+        /// # Example
+        /// ```rust
+        /// use i_mth::series::DataSeries;
+        ///
+        /// let moment = DataSeries::sample("Bending moment", |x| 5.0 * x * (4.0 - x), 0.0, 4.0, 41)
+        ///     .with_labels("x (m)", "M (kN.m)");
+        /// let path = std::env::temp_dir().join("i_mth_bending_moment.svg");
+        ///
+        /// moment.render_svg(&path, 640, 480).unwrap();
+        /// assert!(std::fs::read_to_string(&path).unwrap().contains("Bending moment"));
+        /// ```
+        pub fn render_svg<P: AsRef<Path>>(
+            &self,
+            path: P,
+            width: u32,
+            height: u32,
+        ) -> Result<(), Box<dyn Error>> {
+            let root = SVGBackend::new(path.as_ref(), (width, height)).into_drawing_area();
+            self.draw(&root)?;
+            root.present()?;
+            Ok(())
+        }
+
+        fn draw<DB>(&self, root: &DrawingArea<DB, Shift>) -> Result<(), Box<dyn Error>>
+        where
+            DB: DrawingBackend,
+            DB::ErrorType: 'static,
+        {
+            root.fill(&WHITE)?;
+            let (Some((x0, x1)), Some((y0, y1))) = (self.x_range(), self.y_range()) else {
+                return Ok(());
+            };
+            // keep a flat series and the extrema labels away from the edges
+            let pad = 0.1 * (y1 - y0).max(y0.abs().max(y1.abs())).max(f64::MIN_POSITIVE);
+            let mut chart = ChartBuilder::on(root)
+                .caption(&self.name, ("sans-serif", 24))
+                .margin(12)
+                .x_label_area_size(40)
+                .y_label_area_size(60)
+                .build_cartesian_2d(x0..x1, (y0 - pad)..(y1 + pad))?;
+            chart
+                .configure_mesh()
+                .x_desc(self.x_label.as_str())
+                .y_desc(self.y_label.as_str())
+                .draw()?;
+            chart.draw_series(LineSeries::new(self.points.iter().copied(), &BLUE))?;
+
+            let extrema = [self.min(), self.max()].into_iter().flatten();
+            chart.draw_series(extrema.map(|(x, y)| {
+                EmptyElement::at((x, y))
+                    + Circle::new((0, 0), 4, RED.filled())
+                    + Text::new(format!("({x:.3}, {y:.3})"), (6, -14), ("sans-serif", 14))
+            }))?;
+            Ok(())
+        }
+    }
+}
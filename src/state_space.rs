@@ -0,0 +1,356 @@
+//! # State Space
+//!
+//! Linear time invariant systems written in state-space form
+//!
+//! dx/dt = **A**x + **B**u\
+//! y = **C**x + **D**u
+//!
+//! where x is the state, u the input and y the output. Vibration problems like a
+//! mass-spring-damper fit this form once the second order equation is split into
+//! position and velocity states.
+
+use crate::error::ImthError;
+use crate::integrators::{integrate, Integrator};
+use crate::matrixn::MatrixN;
+use crate::series::DataSeries;
+
+/// A linear time invariant system in state-space form.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct StateSpace {
+    /// State matrix, n by n.
+    pub a: MatrixN,
+    /// Input matrix, n by m.
+    pub b: MatrixN,
+    /// Output matrix, p by n.
+    pub c: MatrixN,
+    /// Feedthrough matrix, p by m.
+    pub d: MatrixN,
+}
+
+impl StateSpace {
+    /// Returns a new system from the passed matrices, or None if their dimensions
+    /// are not consistent with each other.
+    pub fn new(a: MatrixN, b: MatrixN, c: MatrixN, d: MatrixN) -> Option<Self> {
+        let system = Self { a, b, c, d };
+        system.check().is_ok().then_some(system)
+    }
+
+    /// Returns an error if the dimensions of the matrices are not consistent with each
+    /// other, which the public fields allow after [`StateSpace::new`] has checked them.
+    fn check(&self) -> Result<(), ImthError> {
+        let n = self.a.rows();
+        if !self.a.is_square()
+            || self.b.rows() != n
+            || self.c.cols() != n
+            || self.d.rows() != self.c.rows()
+            || self.d.cols() != self.b.cols()
+        {
+            return Err(ImthError::InvalidArgument(
+                "state-space matrix dimensions do not match",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the matrices are inconsistent or the lengths of the state `x`
+    /// and input `u` do not match the system.
+    fn check_signals(&self, x: &[f64], u: &[f64]) -> Result<(), ImthError> {
+        self.check()?;
+        if x.len() != self.states() {
+            return Err(ImthError::InvalidArgument(
+                "state length does not match the system",
+            ));
+        }
+        if u.len() != self.inputs() {
+            return Err(ImthError::InvalidArgument(
+                "input length does not match the system",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns a mass-spring-damper system with the position and velocity of the mass as
+    /// states, the external force as input and the position as output.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::state_space::StateSpace;
+    ///
+    /// let system = StateSpace::mass_spring_damper(1.0, 4.0, 0.0);
+    ///
+    /// // released from x = 1 with no force, x(t) = cos(2t)
+    /// let x = system.response(&[1.0, 0.0], &[0.0], 1.0).unwrap();
+    /// assert!((x[0] - 2f64.cos()).abs() < 1e-12);
+    /// ```
+    pub fn mass_spring_damper(mass: f64, stiffness: f64, damping: f64) -> Self {
+        Self {
+            a: MatrixN::from_vec(2, 2, vec![0.0, 1.0, -stiffness / mass, -damping / mass])
+                .unwrap_or_default(),
+            b: MatrixN::column(&[0.0, 1.0 / mass]),
+            c: MatrixN::from_vec(1, 2, vec![1.0, 0.0]).unwrap_or_default(),
+            d: MatrixN::zeros(1, 1),
+        }
+    }
+
+    /// Returns the number of states of the system.
+    #[inline]
+    pub fn states(&self) -> usize {
+        self.a.rows()
+    }
+
+    /// Returns the number of inputs of the system.
+    #[inline]
+    pub fn inputs(&self) -> usize {
+        self.b.cols()
+    }
+
+    /// Returns the number of outputs of the system.
+    #[inline]
+    pub fn outputs(&self) -> usize {
+        self.c.rows()
+    }
+
+    /// Returns the state derivative **A**x + **B**u.
+    ///
+    /// Panics if the dimensions do not match, see [`StateSpace::try_derivative`].
+    #[inline]
+    pub fn derivative(&self, x: &[f64], u: &[f64]) -> Vec<f64> {
+        let ax = self.a.mul_vec(x);
+        let bu = self.b.mul_vec(u);
+        ax.iter().zip(bu).map(|(a, b)| a + b).collect()
+    }
+
+    /// Returns the state derivative **A**x + **B**u, or an error if the dimensions of the
+    /// matrices, `x` or `u` do not match.
+    #[inline]
+    pub fn try_derivative(&self, x: &[f64], u: &[f64]) -> Result<Vec<f64>, ImthError> {
+        self.check_signals(x, u)?;
+        Ok(self.derivative(x, u))
+    }
+
+    /// Returns the output **C**x + **D**u.
+    ///
+    /// Panics if the dimensions do not match, see [`StateSpace::try_output`].
+    #[inline]
+    pub fn output(&self, x: &[f64], u: &[f64]) -> Vec<f64> {
+        let cx = self.c.mul_vec(x);
+        let du = self.d.mul_vec(u);
+        cx.iter().zip(du).map(|(a, b)| a + b).collect()
+    }
+
+    /// Returns the output **C**x + **D**u, or an error if the dimensions of the matrices,
+    /// `x` or `u` do not match.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::matrixn::MatrixN;
+    /// use i_mth::state_space::StateSpace;
+    ///
+    /// let mut system = StateSpace::mass_spring_damper(1.0, 4.0, 0.0);
+    /// assert_eq!(Ok(vec![0.5]), system.try_output(&[0.5, 2.0], &[0.0]));
+    /// assert!(system.try_output(&[0.5], &[0.0]).is_err());
+    ///
+    /// // the public fields can be changed to sizes that no longer fit together
+    /// system.c = MatrixN::identity(3);
+    /// assert!(system.try_output(&[0.5, 2.0], &[0.0]).is_err());
+    /// ```
+    #[inline]
+    pub fn try_output(&self, x: &[f64], u: &[f64]) -> Result<Vec<f64>, ImthError> {
+        self.check_signals(x, u)?;
+        Ok(self.output(x, u))
+    }
+
+    /// Returns the exact discrete-time equivalent of the system for a sample time of `dt`
+    /// with the input held constant between samples (zero order hold), as the pair
+    /// `(Φ, Γ)` with x<sub>k+1</sub> = Φx<sub>k</sub> + Γu<sub>k</sub>.
+    ///
+    /// Fails if the dimensions of the matrices do not match or an entry of the scaled
+    /// system is not finite.
+    pub fn discretize(&self, dt: f64) -> Result<(MatrixN, MatrixN), ImthError> {
+        self.check()?;
+        let n = self.states();
+        let m = self.inputs();
+        let augmented = MatrixN::from_blocks(
+            &self.a,
+            &self.b,
+            &MatrixN::zeros(m, n),
+            &MatrixN::zeros(m, m),
+        )
+        .ok_or(ImthError::InvalidArgument(
+            "state-space matrix dimensions do not match",
+        ))?
+        .scale(dt);
+        let exp = augmented.exp().ok_or(ImthError::InvalidArgument(
+            "state-space system has an entry that is not finite",
+        ))?;
+        // both blocks lie inside the (n + m) square exponential
+        Ok((
+            exp.block(0, 0, n, n).unwrap_or_default(),
+            exp.block(0, n, n, m).unwrap_or_default(),
+        ))
+    }
+
+    /// Returns the exact state at time `t` starting from the state `x0` at time zero with
+    /// the input `u` held constant.
+    ///
+    /// Fails if the dimensions of `x0` or `u` do not match the system, or as
+    /// [`StateSpace::discretize`] does.
+    pub fn response(&self, x0: &[f64], u: &[f64], t: f64) -> Result<Vec<f64>, ImthError> {
+        self.check_signals(x0, u)?;
+        let (phi, gamma) = self.discretize(t)?;
+        let free = phi.mul_vec(x0);
+        let forced = gamma.mul_vec(u);
+        Ok(free.iter().zip(forced).map(|(a, b)| a + b).collect())
+    }
+
+    /// Returns the steady state response of output `output` to a sinusoidal input
+    /// `input` of angular frequency `omega` in rad.s<sup>-1</sup>, as the pair of the
+    /// amplitude ratio and the phase in radians. Returns None if the indices are out of
+    /// range, the dimensions of the matrices do not match or `omega` is an eigenfrequency of
+    /// an undamped system.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::state_space::StateSpace;
+    ///
+    /// // at resonance a mass-spring-damper lags by 90 degrees with a gain of 1/(cω)
+    /// let system = StateSpace::mass_spring_damper(1.0, 4.0, 0.5);
+    /// let (gain, phase) = system.frequency_response(0, 0, 2.0).unwrap();
+    ///
+    /// assert!((gain - 1.0).abs() < 1e-12);
+    /// assert!((phase + std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    /// ```
+    pub fn frequency_response(
+        &self,
+        input: usize,
+        output: usize,
+        omega: f64,
+    ) -> Option<(f64, f64)> {
+        if input >= self.inputs() || output >= self.outputs() || self.check().is_err() {
+            return None;
+        }
+        let n = self.states();
+        // (jωI - A)x = b split into its real and imaginary parts
+        let minus_a = self.a.scale(-1.0);
+        let omega_i = MatrixN::identity(n).scale(omega);
+        let system = MatrixN::from_blocks(&minus_a, &omega_i.scale(-1.0), &omega_i, &minus_a)?;
+        let mut rhs: Vec<f64> = (0..n)
+            .map(|i| self.b.get(i, input).unwrap_or(0.0))
+            .collect();
+        rhs.resize(2 * n, 0.0);
+        let x = system.solve(&rhs)?;
+
+        let c = self.c.row(output);
+        let re = c.iter().zip(&x[..n]).map(|(c, x)| c * x).sum::<f64>()
+            + self.d.get(output, input).unwrap_or(0.0);
+        let im = c.iter().zip(&x[n..]).map(|(c, x)| c * x).sum::<f64>();
+        Some((re.hypot(im), im.atan2(re)))
+    }
+
+    /// Returns the Bode diagram of output `output` against input `input` as the gain and
+    /// phase series sampled at `samples` logarithmically spaced frequencies from `omega_min`
+    /// to `omega_max`. Returns None if the indices are out of range or the frequencies are
+    /// not positive.
+    pub fn bode(
+        &self,
+        input: usize,
+        output: usize,
+        omega_min: f64,
+        omega_max: f64,
+        samples: usize,
+    ) -> Option<(DataSeries, DataSeries)> {
+        if omega_min <= 0.0 || omega_max <= 0.0 {
+            return None;
+        }
+        let (lo, hi) = (omega_min.log10(), omega_max.log10());
+        let step = (hi - lo) / samples.saturating_sub(1).max(1) as f64;
+        let mut gain = Vec::with_capacity(samples);
+        let mut phase = Vec::with_capacity(samples);
+        for i in 0..samples.max(1) {
+            let omega = 10f64.powf(lo + step * i as f64);
+            let (g, p) = self.frequency_response(input, output, omega)?;
+            gain.push((omega, g));
+            phase.push((omega, p));
+        }
+        Some((
+            DataSeries::new("gain", gain).with_labels("ω (rad/s)", "gain"),
+            DataSeries::new("phase", phase).with_labels("ω (rad/s)", "phase (rad)"),
+        ))
+    }
+
+    /// Simulates the system with a sequence of inputs, each held constant for `dt` seconds,
+    /// using the exact discretization. Returns the state at every sample including `x0`.
+    ///
+    /// Fails if the length of `x0` or of an input does not match the system, or as
+    /// [`StateSpace::discretize`] does.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::state_space::StateSpace;
+    ///
+    /// let system = StateSpace::mass_spring_damper(1.0, 4.0, 0.0);
+    /// let states = system.simulate_sampled(&[1.0, 0.0], &vec![vec![0.0]; 4], 0.25).unwrap();
+    /// assert_eq!(5, states.len());
+    /// assert!((states[4][0] - 2f64.cos()).abs() < 1e-12);
+    ///
+    /// assert!(system.simulate_sampled(&[1.0], &[vec![0.0]], 0.25).is_err());
+    /// ```
+    pub fn simulate_sampled(
+        &self,
+        x0: &[f64],
+        inputs: &[Vec<f64>],
+        dt: f64,
+    ) -> Result<Vec<Vec<f64>>, ImthError> {
+        self.check_signals(x0, &vec![0.0; self.inputs()])?;
+        if inputs.iter().any(|u| u.len() != self.inputs()) {
+            return Err(ImthError::InvalidArgument(
+                "input length does not match the system",
+            ));
+        }
+        let (phi, gamma) = self.discretize(dt)?;
+        let mut states = Vec::with_capacity(inputs.len() + 1);
+        states.push(x0.to_vec());
+        for u in inputs {
+            let x = &states[states.len() - 1];
+            let next = phi
+                .mul_vec(x)
+                .iter()
+                .zip(gamma.mul_vec(u))
+                .map(|(a, b)| a + b)
+                .collect();
+            states.push(next);
+        }
+        Ok(states)
+    }
+
+    /// Simulates the system with the input `u(t)` using the passed integrator for `steps`
+    /// steps of size `dt` from the state `x0` at time `t0`. Returns every `(t, x)` pair
+    /// including the initial one.
+    ///
+    /// Fails if the dimensions of the matrices do not match, or the length of `x0` or of
+    /// the input at `t0` does not match the system. The input must keep that length at
+    /// every time.
+    pub fn simulate<I, U>(
+        &self,
+        integrator: &I,
+        x0: Vec<f64>,
+        u: U,
+        t0: f64,
+        dt: f64,
+        steps: usize,
+    ) -> Result<Vec<(f64, Vec<f64>)>, ImthError>
+    where
+        I: Integrator,
+        U: Fn(f64) -> Vec<f64>,
+    {
+        self.check_signals(&x0, &u(t0))?;
+        integrate(
+            integrator,
+            |t, x: &Vec<f64>| self.derivative(x, &u(t)),
+            t0,
+            x0,
+            dt,
+            steps,
+        )
+    }
+}
@@ -0,0 +1,346 @@
+//! # Statistics
+//!
+//! Mean, covariance and RMS magnitude of sets of vectors, like the positions of a noisy
+//! sensor track or the velocities of a simulated trajectory.
+//!
+//! The running accumulators take one vector at a time with Welford's update, so long
+//! streams can be summarized without storing them and without the loss of precision of
+//! summing squares. The slice functions are built on them.
+//!
+//! Covariances are sample covariances, divided by N - 1 for N vectors.
+//!
+//! # Example
+//! ```rust
+//! use i_mth::statistics::RunningStats3D;
+//! use i_mth::vector3d::Vector3D;
+//!
+//! let mut stats = RunningStats3D::new();
+//! for n in 0..100 {
+//!     let t = n as f64 * 0.1;
+//!     stats.push(Vector3D::new(t.cos(), t.sin(), 0.5));
+//! }
+//!
+//! assert_eq!(100, stats.count());
+//! assert!((stats.mean().unwrap().z - 0.5).abs() < 1e-15);
+//! assert!(stats.covariance().unwrap()[(2, 2)].abs() < 1e-15);
+//! // every sample lies at 1.25^0.5 from the origin
+//! assert!((stats.rms_magnitude().unwrap() - 1.25f64.sqrt()).abs() < 1e-12);
+//! ```
+
+use crate::matrix3::Matrix3;
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+/// Returns the mean of the passed vectors, or None if there are none.
+pub fn mean_2d(vectors: &[Vector2D]) -> Option<Vector2D> {
+    vectors.iter().copied().collect::<RunningStats2D>().mean()
+}
+
+/// Returns the mean of the passed vectors, or None if there are none.
+///
+/// # Example
+/// ```rust
+/// use i_mth::statistics::mean_3d;
+/// use i_mth::vector3d::Vector3D;
+///
+/// let points = [Vector3D::new(1.0, 0.0, 2.0), Vector3D::new(3.0, -2.0, 0.0)];
+///
+/// assert_eq!(Some(Vector3D::new(2.0, -1.0, 1.0)), mean_3d(&points));
+/// assert_eq!(None, mean_3d(&[]));
+/// ```
+pub fn mean_3d(vectors: &[Vector3D]) -> Option<Vector3D> {
+    vectors.iter().copied().collect::<RunningStats3D>().mean()
+}
+
+/// Returns the sample covariance matrix of the passed vectors, or None if there are fewer
+/// than two.
+pub fn covariance_2d(vectors: &[Vector2D]) -> Option<[[f64; 2]; 2]> {
+    vectors
+        .iter()
+        .copied()
+        .collect::<RunningStats2D>()
+        .covariance()
+}
+
+/// Returns the sample covariance matrix of the passed vectors, or None if there are fewer
+/// than two. Its diagonal holds the variances of the components.
+///
+/// # Example
+/// ```rust
+/// use i_mth::statistics::covariance_3d;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // points along the line y = 2x spread only in that direction
+/// let points: Vec<Vector3D> = (0..5).map(|n| Vector3D::new(n as f64, 2.0 * n as f64, 1.0)).collect();
+/// let c = covariance_3d(&points).unwrap();
+///
+/// assert!((c[(0, 0)] - 2.5).abs() < 1e-12);
+/// assert!((c[(0, 1)] - 5.0).abs() < 1e-12);
+/// assert!((c[(1, 1)] - 10.0).abs() < 1e-12);
+/// assert_eq!(0.0, c[(2, 2)]);
+/// ```
+pub fn covariance_3d(vectors: &[Vector3D]) -> Option<Matrix3> {
+    vectors
+        .iter()
+        .copied()
+        .collect::<RunningStats3D>()
+        .covariance()
+}
+
+/// Returns the root mean square of the magnitudes of the passed vectors, or None if there
+/// are none.
+pub fn rms_magnitude_2d(vectors: &[Vector2D]) -> Option<f64> {
+    vectors
+        .iter()
+        .copied()
+        .collect::<RunningStats2D>()
+        .rms_magnitude()
+}
+
+/// Returns the root mean square of the magnitudes of the passed vectors, like the RMS
+/// speed of a set of particle velocities, or None if there are none.
+pub fn rms_magnitude_3d(vectors: &[Vector3D]) -> Option<f64> {
+    vectors
+        .iter()
+        .copied()
+        .collect::<RunningStats3D>()
+        .rms_magnitude()
+}
+
+/// Running mean, covariance and RMS magnitude of a stream of vectors in the plane.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct RunningStats2D {
+    count: usize,
+    mean: Vector2D,
+    // sums of the products of the deviations from the mean
+    comoments: [[f64; 2]; 2],
+    mean_squared_magnitude: f64,
+}
+
+impl RunningStats2D {
+    /// Returns an accumulator that has seen no vectors.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the passed vector to the statistics.
+    pub fn push(&mut self, v: Vector2D) {
+        self.count += 1;
+        let n = self.count as f64;
+        let before = v - self.mean;
+        self.mean += before.scale(1.0 / n);
+        let after = v - self.mean;
+        let (b, a) = ([before.x, before.y], [after.x, after.y]);
+        for (i, row) in self.comoments.iter_mut().enumerate() {
+            for (j, c) in row.iter_mut().enumerate() {
+                *c += b[i] * a[j];
+            }
+        }
+        self.mean_squared_magnitude += (v.squared_magnitude() - self.mean_squared_magnitude) / n;
+    }
+
+    /// Returns the statistics of the vectors seen by this and the passed accumulator
+    /// together, like the results of two threads.
+    pub fn merge(&self, other: &Self) -> Self {
+        if other.count == 0 {
+            return *self;
+        }
+        if self.count == 0 {
+            return *other;
+        }
+        let (n1, n2) = (self.count as f64, other.count as f64);
+        let n = n1 + n2;
+        let delta = other.mean - self.mean;
+        let d = [delta.x, delta.y];
+        let mut comoments = self.comoments;
+        for (i, row) in comoments.iter_mut().enumerate() {
+            for (j, c) in row.iter_mut().enumerate() {
+                *c += other.comoments[i][j] + d[i] * d[j] * n1 * n2 / n;
+            }
+        }
+        Self {
+            count: self.count + other.count,
+            mean: self.mean + delta.scale(n2 / n),
+            comoments,
+            mean_squared_magnitude: (n1 * self.mean_squared_magnitude
+                + n2 * other.mean_squared_magnitude)
+                / n,
+        }
+    }
+
+    /// Returns the number of vectors seen.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the vectors seen, or None if there were none.
+    #[inline]
+    pub fn mean(&self) -> Option<Vector2D> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the sample covariance matrix of the vectors seen, or None if there were
+    /// fewer than two.
+    pub fn covariance(&self) -> Option<[[f64; 2]; 2]> {
+        let scale = 1.0 / self.count.checked_sub(1).filter(|&n| n > 0)? as f64;
+        Some(self.comoments.map(|row| row.map(|c| c * scale)))
+    }
+
+    /// Returns the sample variance of each component of the vectors seen, or None if there
+    /// were fewer than two.
+    #[inline]
+    pub fn variance(&self) -> Option<Vector2D> {
+        let c = self.covariance()?;
+        Some(Vector2D::new(c[0][0], c[1][1]))
+    }
+
+    /// Returns the root mean square of the magnitudes of the vectors seen, or None if there
+    /// were none.
+    #[inline]
+    pub fn rms_magnitude(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.mean_squared_magnitude.sqrt())
+    }
+}
+
+impl Extend<Vector2D> for RunningStats2D {
+    fn extend<I: IntoIterator<Item = Vector2D>>(&mut self, iter: I) {
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
+
+impl FromIterator<Vector2D> for RunningStats2D {
+    fn from_iter<I: IntoIterator<Item = Vector2D>>(iter: I) -> Self {
+        let mut stats = Self::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+/// Running mean, covariance and RMS magnitude of a stream of vectors in space.
+///
+/// # Example
+/// ```rust
+/// use i_mth::statistics::{covariance_3d, RunningStats3D};
+/// use i_mth::vector3d::Vector3D;
+///
+/// let points: Vec<Vector3D> = (0..50)
+///     .map(|n| Vector3D::new((n * n % 7) as f64, n as f64 * 0.3, (n % 5) as f64))
+///     .collect();
+/// // two halves summarized separately and merged match the whole
+/// let first: RunningStats3D = points[..20].iter().copied().collect();
+/// let second: RunningStats3D = points[20..].iter().copied().collect();
+/// let merged = first.merge(&second);
+///
+/// assert_eq!(50, merged.count());
+/// assert!(merged.covariance().unwrap().is_approx_equal_to(&covariance_3d(&points).unwrap(), 1e-12));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct RunningStats3D {
+    count: usize,
+    mean: Vector3D,
+    // sums of the products of the deviations from the mean
+    comoments: Matrix3,
+    mean_squared_magnitude: f64,
+}
+
+impl RunningStats3D {
+    /// Returns an accumulator that has seen no vectors.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the passed vector to the statistics.
+    pub fn push(&mut self, v: Vector3D) {
+        self.count += 1;
+        let n = self.count as f64;
+        let before = v - self.mean;
+        self.mean += before.scale(1.0 / n);
+        let after = v - self.mean;
+        self.comoments = self.comoments
+            + Matrix3::from_row_vectors(
+                after.scale(before.x),
+                after.scale(before.y),
+                after.scale(before.z),
+            );
+        self.mean_squared_magnitude += (v.squared_magnitude() - self.mean_squared_magnitude) / n;
+    }
+
+    /// Returns the statistics of the vectors seen by this and the passed accumulator
+    /// together, like the results of two threads.
+    pub fn merge(&self, other: &Self) -> Self {
+        if other.count == 0 {
+            return *self;
+        }
+        if self.count == 0 {
+            return *other;
+        }
+        let (n1, n2) = (self.count as f64, other.count as f64);
+        let n = n1 + n2;
+        let d = other.mean - self.mean;
+        let spread = Matrix3::from_row_vectors(d.scale(d.x), d.scale(d.y), d.scale(d.z));
+        Self {
+            count: self.count + other.count,
+            mean: self.mean + d.scale(n2 / n),
+            comoments: self.comoments + other.comoments + spread.scale(n1 * n2 / n),
+            mean_squared_magnitude: (n1 * self.mean_squared_magnitude
+                + n2 * other.mean_squared_magnitude)
+                / n,
+        }
+    }
+
+    /// Returns the number of vectors seen.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the vectors seen, or None if there were none.
+    #[inline]
+    pub fn mean(&self) -> Option<Vector3D> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the sample covariance matrix of the vectors seen, or None if there were
+    /// fewer than two.
+    #[inline]
+    pub fn covariance(&self) -> Option<Matrix3> {
+        let n = self.count.checked_sub(1).filter(|&n| n > 0)?;
+        Some(self.comoments.scale(1.0 / n as f64))
+    }
+
+    /// Returns the sample variance of each component of the vectors seen, or None if there
+    /// were fewer than two.
+    #[inline]
+    pub fn variance(&self) -> Option<Vector3D> {
+        let c = self.covariance()?;
+        Some(Vector3D::new(c[(0, 0)], c[(1, 1)], c[(2, 2)]))
+    }
+
+    /// Returns the root mean square of the magnitudes of the vectors seen, or None if there
+    /// were none.
+    #[inline]
+    pub fn rms_magnitude(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.mean_squared_magnitude.sqrt())
+    }
+}
+
+impl Extend<Vector3D> for RunningStats3D {
+    fn extend<I: IntoIterator<Item = Vector3D>>(&mut self, iter: I) {
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
+
+impl FromIterator<Vector3D> for RunningStats3D {
+    fn from_iter<I: IntoIterator<Item = Vector3D>>(iter: I) -> Self {
+        let mut stats = Self::new();
+        stats.extend(iter);
+        stats
+    }
+}
@@ -0,0 +1,158 @@
+//! # Strain
+//!
+//! Plane strain at a point and the strain gauge rosettes used to measure it.
+//!
+//! Normal strains are positive in extension and γ<sub>xy</sub> is the engineering shear
+//! strain, twice the tensor shear strain. Angles are in radians measured anticlockwise from
+//! the x axis.
+
+use crate::matrix3::Matrix3;
+use crate::vector3d::Vector3D;
+
+/// The in-plane components of strain at a point.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct StrainState2D {
+    /// Normal strain along x.
+    pub epsilon_x: f64,
+    /// Normal strain along y.
+    pub epsilon_y: f64,
+    /// Engineering shear strain in the xy plane.
+    pub gamma_xy: f64,
+}
+
+impl StrainState2D {
+    /// Returns the strain state with the passed normal and engineering shear strains.
+    #[inline]
+    pub fn new(epsilon_x: f64, epsilon_y: f64, gamma_xy: f64) -> Self {
+        Self {
+            epsilon_x,
+            epsilon_y,
+            gamma_xy,
+        }
+    }
+
+    /// Returns the strain state recovered from three gauge readings taken at the passed
+    /// angles, or None if the angles do not determine it, like when two gauges are parallel.
+    ///
+    /// Each gauge at angle θ reads\
+    /// ε(θ) = ε<sub>x</sub>cos<sup>2</sup>θ + ε<sub>y</sub>sin<sup>2</sup>θ + γ<sub>xy</sub>sinθcosθ\
+    /// which gives three linear equations in the unknown strains.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::f64::consts::FRAC_PI_4;
+    /// use i_mth::strain::StrainState2D;
+    ///
+    /// // a 0/45/90 rectangular rosette, readings in microstrain
+    /// let readings = [400e-6, 250e-6, -100e-6];
+    /// let strain = StrainState2D::from_rosette(readings, [0.0, FRAC_PI_4, 2.0 * FRAC_PI_4]).unwrap();
+    ///
+    /// assert!((strain.epsilon_x - 400e-6).abs() < 1e-15);
+    /// assert!((strain.epsilon_y + 100e-6).abs() < 1e-15);
+    /// assert!((strain.gamma_xy - 200e-6).abs() < 1e-15);
+    /// assert!(StrainState2D::from_rosette(readings, [0.0, 0.0, FRAC_PI_4]).is_none());
+    /// ```
+    pub fn from_rosette(readings: [f64; 3], angles: [f64; 3]) -> Option<Self> {
+        let rows = angles.map(|theta| {
+            let (s, c) = theta.sin_cos();
+            [c * c, s * s, s * c]
+        });
+        // every entry is at most one so the determinant measures how far the gauges are
+        // from being parallel
+        let m = Matrix3::new(rows);
+        if m.determinant().abs() <= crate::EPSILON {
+            return None;
+        }
+        let strains = m.inverse()? * Vector3D::new(readings[0], readings[1], readings[2]);
+        Some(Self::new(strains.x, strains.y, strains.z))
+    }
+
+    /// Returns the strain tensor as a symmetric matrix, with the tensor shear strain
+    /// γ<sub>xy</sub> / 2 off the diagonal and no strain out of the plane.
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix3 {
+        let half_gamma = 0.5 * self.gamma_xy;
+        Matrix3::new([
+            [self.epsilon_x, half_gamma, 0.0],
+            [half_gamma, self.epsilon_y, 0.0],
+            [0.0, 0.0, 0.0],
+        ])
+    }
+
+    /// Returns the average normal strain (ε<sub>x</sub> + ε<sub>y</sub>) / 2, the center of
+    /// Mohr's circle.
+    #[inline]
+    pub fn average(&self) -> f64 {
+        0.5 * (self.epsilon_x + self.epsilon_y)
+    }
+
+    /// Returns the radius of Mohr's circle of strain, half the maximum in-plane engineering
+    /// shear strain.
+    #[inline]
+    pub fn radius(&self) -> f64 {
+        (0.5 * (self.epsilon_x - self.epsilon_y)).hypot(0.5 * self.gamma_xy)
+    }
+
+    /// Returns the strain state in axes turned anticlockwise by `angle` from x and y.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::f64::consts::FRAC_PI_2;
+    /// use i_mth::strain::StrainState2D;
+    ///
+    /// let strain = StrainState2D::new(300e-6, -100e-6, 150e-6);
+    /// let turned = strain.transform(FRAC_PI_2);
+    ///
+    /// assert!((turned.epsilon_x - strain.epsilon_y).abs() < 1e-15);
+    /// assert!((turned.epsilon_y - strain.epsilon_x).abs() < 1e-15);
+    /// assert!((turned.gamma_xy + strain.gamma_xy).abs() < 1e-15);
+    /// ```
+    pub fn transform(&self, angle: f64) -> Self {
+        let turned = self
+            .to_matrix()
+            .rotated(&Matrix3::rotation_z(angle).transpose());
+        Self::new(turned[(0, 0)], turned[(1, 1)], 2.0 * turned[(0, 1)])
+    }
+
+    /// Returns the normal strain along the direction at the passed angle from x.
+    #[inline]
+    pub fn normal_at(&self, angle: f64) -> f64 {
+        self.transform(angle).epsilon_x
+    }
+
+    /// Returns the principal strains (ε<sub>1</sub>, ε<sub>2</sub>) with ε<sub>1</sub> ≥
+    /// ε<sub>2</sub>.
+    #[inline]
+    pub fn principal(&self) -> (f64, f64) {
+        let center = self.average();
+        let radius = self.radius();
+        (center + radius, center - radius)
+    }
+
+    /// Returns the angle from x to the direction of the major principal strain
+    /// ε<sub>1</sub>, in (-π/2, π/2].
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::strain::StrainState2D;
+    ///
+    /// let strain = StrainState2D::new(200e-6, 0.0, 200e-6);
+    /// let (e1, _) = strain.principal();
+    /// let theta = strain.principal_angle();
+    ///
+    /// assert!((theta - std::f64::consts::FRAC_PI_8).abs() < 1e-12);
+    /// assert!((strain.normal_at(theta) - e1).abs() < 1e-15);
+    /// assert!(strain.transform(theta).gamma_xy.abs() < 1e-15);
+    /// ```
+    #[inline]
+    pub fn principal_angle(&self) -> f64 {
+        0.5 * self.gamma_xy.atan2(self.epsilon_x - self.epsilon_y)
+    }
+
+    /// Returns the maximum in-plane engineering shear strain γ<sub>max</sub> =
+    /// ε<sub>1</sub> - ε<sub>2</sub>.
+    #[inline]
+    pub fn max_shear(&self) -> f64 {
+        2.0 * self.radius()
+    }
+}
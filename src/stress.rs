@@ -0,0 +1,216 @@
+//! # Stress
+//!
+//! The state of stress at a point of a loaded body, its principal values and the scalar
+//! measures used by yield criteria.
+//!
+//! Normal stresses are positive in tension. The shear stress τ<sub>xy</sub> acts in y on
+//! the face whose outward normal is x, and equals τ<sub>yx</sub> by moment equilibrium.
+
+use crate::matrix3::Matrix3;
+use crate::vector3d::Vector3D;
+
+/// The six independent components of the symmetric Cauchy stress tensor at a point, in Pa.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct StressState3D {
+    /// Normal stress on the x face.
+    pub sigma_x: f64,
+    /// Normal stress on the y face.
+    pub sigma_y: f64,
+    /// Normal stress on the z face.
+    pub sigma_z: f64,
+    /// Shear stress in the xy plane.
+    pub tau_xy: f64,
+    /// Shear stress in the yz plane.
+    pub tau_yz: f64,
+    /// Shear stress in the zx plane.
+    pub tau_zx: f64,
+}
+
+impl StressState3D {
+    /// Returns the stress state with the passed normal and shear stresses.
+    #[inline]
+    pub fn new(
+        sigma_x: f64,
+        sigma_y: f64,
+        sigma_z: f64,
+        tau_xy: f64,
+        tau_yz: f64,
+        tau_zx: f64,
+    ) -> Self {
+        Self {
+            sigma_x,
+            sigma_y,
+            sigma_z,
+            tau_xy,
+            tau_yz,
+            tau_zx,
+        }
+    }
+
+    /// Returns the stress state held by the passed tensor, or None if it is not symmetric
+    /// within [`Matrix3::DEFAULT_TOL`] relative to its largest entry.
+    pub fn from_matrix(m: &Matrix3) -> Option<Self> {
+        let scale = m.rows.iter().flatten().fold(0.0f64, |s, e| s.max(e.abs()));
+        if !m.is_symmetric(Matrix3::DEFAULT_TOL * scale) {
+            return None;
+        }
+        Some(Self::from_upper(m))
+    }
+
+    /// Returns the stress state held by the diagonal and upper triangle of the passed
+    /// tensor.
+    #[inline]
+    fn from_upper(m: &Matrix3) -> Self {
+        Self::new(
+            m[(0, 0)],
+            m[(1, 1)],
+            m[(2, 2)],
+            m[(0, 1)],
+            m[(1, 2)],
+            m[(0, 2)],
+        )
+    }
+
+    /// Returns the stress tensor as a symmetric matrix.
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix3 {
+        Matrix3::new([
+            [self.sigma_x, self.tau_xy, self.tau_zx],
+            [self.tau_xy, self.sigma_y, self.tau_yz],
+            [self.tau_zx, self.tau_yz, self.sigma_z],
+        ])
+    }
+
+    /// Returns the stress invariants (I<sub>1</sub>, I<sub>2</sub>, I<sub>3</sub>), the
+    /// coefficients of the characteristic equation\
+    /// σ<sup>3</sup> - I<sub>1</sub>σ<sup>2</sup> + I<sub>2</sub>σ - I<sub>3</sub> = 0\
+    /// whose roots are the principal stresses.
+    pub fn invariants(&self) -> (f64, f64, f64) {
+        let i1 = self.sigma_x + self.sigma_y + self.sigma_z;
+        let i2 =
+            self.sigma_x * self.sigma_y + self.sigma_y * self.sigma_z + self.sigma_z * self.sigma_x
+                - self.tau_xy * self.tau_xy
+                - self.tau_yz * self.tau_yz
+                - self.tau_zx * self.tau_zx;
+        (i1, i2, self.to_matrix().determinant())
+    }
+
+    /// Returns the mean (hydrostatic) stress I<sub>1</sub> / 3.
+    #[inline]
+    pub fn mean_stress(&self) -> f64 {
+        (self.sigma_x + self.sigma_y + self.sigma_z) / 3.0
+    }
+
+    /// Returns the principal stresses σ<sub>1</sub> ≥ σ<sub>2</sub> ≥ σ<sub>3</sub>.
+    #[inline]
+    pub fn principal(&self) -> [f64; 3] {
+        self.principal_directions().0
+    }
+
+    /// Returns the principal stresses in decreasing order with their unit directions as the
+    /// columns of a matrix.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::stress::StressState3D;
+    ///
+    /// // stresses in MPa
+    /// let s = StressState3D::new(60.0, 20.0, -30.0, 20.0, 0.0, 0.0);
+    /// let (principal, directions) = s.principal_directions();
+    ///
+    /// let r = (20.0f64 * 20.0 + 20.0 * 20.0).sqrt();
+    /// assert!((principal[0] - (40.0 + r)).abs() < 1e-9);
+    /// assert!((principal[1] - (40.0 - r)).abs() < 1e-9);
+    /// assert!((principal[2] + 30.0).abs() < 1e-9);
+    /// assert!(directions.column(2).is_parallel_to(i_mth::vector3d::Vector3D::k(), 1e-9));
+    ///
+    /// let (i1, i2, i3) = s.invariants();
+    /// let [a, b, c] = principal;
+    /// assert!((i1 - (a + b + c)).abs() < 1e-9);
+    /// assert!((i2 - (a * b + b * c + c * a)).abs() < 1e-9);
+    /// assert!((i3 - a * b * c).abs() < 1e-6);
+    /// assert!((s.max_shear() - 0.5 * (a - c)).abs() < 1e-9);
+    /// ```
+    pub fn principal_directions(&self) -> ([f64; 3], Matrix3) {
+        // to_matrix is symmetric by construction so the solver always succeeds
+        self.to_matrix()
+            .symmetric_eigen()
+            .unwrap_or(([0.0; 3], Matrix3::IDENTITY))
+    }
+
+    /// Returns the deviatoric part of this stress state, which is what is left once the
+    /// mean stress is taken off every normal stress.
+    #[inline]
+    pub fn deviatoric(&self) -> Self {
+        Self::from_upper(&self.to_matrix().deviatoric_part())
+    }
+
+    /// Returns this stress state turned by the passed rotation, see [`Matrix3::rotated`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::constants::PI;
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::stress::StressState3D;
+    ///
+    /// // pure shear is a tension and a compression on planes at 45°
+    /// let s = StressState3D::new(0.0, 0.0, 0.0, 40.0, 0.0, 0.0);
+    /// let turned = s.rotated(&Matrix3::rotation_z(-PI / 4.0));
+    ///
+    /// assert!((turned.sigma_x - 40.0).abs() < 1e-12 && (turned.sigma_y + 40.0).abs() < 1e-12);
+    /// assert!(turned.tau_xy.abs() < 1e-12);
+    /// assert!((turned.von_mises() - s.von_mises()).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn rotated(&self, rotation: &Matrix3) -> Self {
+        Self::from_upper(&self.to_matrix().rotated(rotation))
+    }
+
+    /// Returns the von Mises equivalent stress\
+    /// σ<sub>vm</sub> = √(½[(σ<sub>x</sub> - σ<sub>y</sub>)<sup>2</sup> + (σ<sub>y</sub> - σ<sub>z</sub>)<sup>2</sup> + (σ<sub>z</sub> - σ<sub>x</sub>)<sup>2</sup>] + 3(τ<sub>xy</sub><sup>2</sup> + τ<sub>yz</sub><sup>2</sup> + τ<sub>zx</sub><sup>2</sup>))\
+    /// which yielding is predicted at once it reaches the uniaxial yield stress.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::stress::StressState3D;
+    ///
+    /// // pure shear yields at τ = σy / √3
+    /// let s = StressState3D::new(0.0, 0.0, 0.0, 100.0, 0.0, 0.0);
+    /// assert!((s.von_mises() - 100.0 * 3.0f64.sqrt()).abs() < 1e-9);
+    ///
+    /// // a hydrostatic stress never yields
+    /// assert_eq!(0.0, StressState3D::new(-50.0, -50.0, -50.0, 0.0, 0.0, 0.0).von_mises());
+    /// ```
+    pub fn von_mises(&self) -> f64 {
+        let normal = (self.sigma_x - self.sigma_y).powi(2)
+            + (self.sigma_y - self.sigma_z).powi(2)
+            + (self.sigma_z - self.sigma_x).powi(2);
+        let shear = self.tau_xy.powi(2) + self.tau_yz.powi(2) + self.tau_zx.powi(2);
+        (0.5 * normal + 3.0 * shear).sqrt()
+    }
+
+    /// Returns the absolute maximum shear stress (σ<sub>1</sub> - σ<sub>3</sub>) / 2, the
+    /// measure used by the Tresca yield criterion.
+    #[inline]
+    pub fn max_shear(&self) -> f64 {
+        let [s1, _, s3] = self.principal();
+        0.5 * (s1 - s3)
+    }
+
+    /// Returns the traction vector acting on the plane with the passed normal, or None if
+    /// the normal has no direction.
+    #[inline]
+    pub fn traction(&self, normal: Vector3D) -> Option<Vector3D> {
+        Some(self.to_matrix() * normal.normalized()?)
+    }
+
+    /// Returns the normal and shear stress on the plane with the passed normal, or None if
+    /// the normal has no direction.
+    pub fn on_plane(&self, normal: Vector3D) -> Option<(f64, f64)> {
+        let n = normal.normalized()?;
+        let t = self.to_matrix() * n;
+        let sigma = t.dot(n);
+        let tau = (t.squared_magnitude() - sigma * sigma).max(0.0).sqrt();
+        Some((sigma, tau))
+    }
+}
@@ -0,0 +1,172 @@
+//! # Torsion
+//!
+//! Torsion of circular shafts in the elastic range: shear stress across the section, the
+//! angle of twist of uniform and stepped shafts and the torque a shaft transmits.
+//!
+//! The shear stress grows linearly from the axis, τ = Tr / J, and a uniform length twists
+//! by φ = TL / (GJ), where J is the polar moment of inertia of the section.
+
+use crate::constants::PI;
+use crate::conversions::rpm_to_rad_per_sec;
+
+/// The cross section of a circular shaft.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ShaftSection {
+    /// A solid round bar.
+    Solid {
+        /// Diameter of the bar in m.
+        diameter: f64,
+    },
+    /// A tube.
+    Hollow {
+        /// Outer diameter of the tube in m.
+        outer_diameter: f64,
+        /// Inner diameter of the tube in m.
+        inner_diameter: f64,
+    },
+}
+
+impl ShaftSection {
+    /// Returns the outer radius of the section, where the shear stress is largest.
+    #[inline]
+    pub fn outer_radius(&self) -> f64 {
+        match *self {
+            ShaftSection::Solid { diameter } => 0.5 * diameter,
+            ShaftSection::Hollow { outer_diameter, .. } => 0.5 * outer_diameter,
+        }
+    }
+
+    /// Returns the polar moment of inertia J of the section in m<sup>4</sup>,\
+    /// J = π(D<sub>o</sub><sup>4</sup> - D<sub>i</sub><sup>4</sup>) / 32\
+    /// with D<sub>i</sub> = 0 for a solid bar.
+    #[inline]
+    pub fn polar_moment(&self) -> f64 {
+        match *self {
+            ShaftSection::Solid { diameter } => PI * diameter.powi(4) / 32.0,
+            ShaftSection::Hollow {
+                outer_diameter,
+                inner_diameter,
+            } => PI * (outer_diameter.powi(4) - inner_diameter.powi(4)) / 32.0,
+        }
+    }
+
+    /// Returns the shear stress at the outer surface of the section under the passed torque.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::torsion::ShaftSection;
+    ///
+    /// // a 50 mm bar carrying 1.5 kN.m
+    /// let section = ShaftSection::Solid { diameter: 0.05 };
+    /// let tau = section.max_shear_stress(1500.0);
+    ///
+    /// assert!((tau - 16.0 * 1500.0 / (std::f64::consts::PI * 0.05f64.powi(3))).abs() < 1e-6);
+    /// ```
+    #[inline]
+    pub fn max_shear_stress(&self, torque: f64) -> f64 {
+        shear_stress(torque, self.outer_radius(), self.polar_moment())
+    }
+}
+
+/// A length of shaft with a uniform section, material and internal torque.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ShaftSegment {
+    /// Length of the segment in m.
+    pub length: f64,
+    /// Cross section of the segment.
+    pub section: ShaftSection,
+    /// Shear modulus of the material in Pa.
+    pub shear_modulus: f64,
+    /// Internal torque carried by the segment in N.m.
+    pub torque: f64,
+}
+
+impl ShaftSegment {
+    /// Returns the segment with the passed length, section, shear modulus and torque.
+    #[inline]
+    pub fn new(length: f64, section: ShaftSection, shear_modulus: f64, torque: f64) -> Self {
+        Self {
+            length,
+            section,
+            shear_modulus,
+            torque,
+        }
+    }
+
+    /// Returns the angle of twist of this segment in rad.
+    #[inline]
+    pub fn angle_of_twist(&self) -> f64 {
+        angle_of_twist(
+            self.torque,
+            self.length,
+            self.shear_modulus,
+            self.section.polar_moment(),
+        )
+    }
+
+    /// Returns the shear stress at the outer surface of this segment.
+    #[inline]
+    pub fn max_shear_stress(&self) -> f64 {
+        self.section.max_shear_stress(self.torque)
+    }
+}
+
+/// Returns the shear stress τ = Tr / J at radius `radius` of a section with polar moment
+/// of inertia `polar_moment` carrying `torque`.
+#[inline]
+pub fn shear_stress(torque: f64, radius: f64, polar_moment: f64) -> f64 {
+    torque * radius / polar_moment
+}
+
+/// Returns the angle of twist φ = TL / (GJ) in rad of a uniform shaft of length `length`.
+#[inline]
+pub fn angle_of_twist(torque: f64, length: f64, shear_modulus: f64, polar_moment: f64) -> f64 {
+    torque * length / (shear_modulus * polar_moment)
+}
+
+/// Returns the total angle of twist in rad of a stepped shaft, the sum of the twists of
+/// its segments from one end to the other.
+///
+/// # Example
+/// ```rust
+/// use i_mth::torsion::{stepped_angle_of_twist, ShaftSection, ShaftSegment};
+///
+/// // a steel shaft stepping down from 60 mm to a 40/30 mm tube
+/// let g = 80e9;
+/// let segments = [
+///     ShaftSegment::new(0.8, ShaftSection::Solid { diameter: 0.06 }, g, 2000.0),
+///     ShaftSegment::new(
+///         0.5,
+///         ShaftSection::Hollow { outer_diameter: 0.04, inner_diameter: 0.03 },
+///         g,
+///         800.0,
+///     ),
+/// ];
+/// let phi = stepped_angle_of_twist(&segments);
+///
+/// let pi = std::f64::consts::PI;
+/// let j1 = pi * 0.06f64.powi(4) / 32.0;
+/// let j2 = pi * (0.04f64.powi(4) - 0.03f64.powi(4)) / 32.0;
+/// assert!((phi - (2000.0 * 0.8 / (g * j1) + 800.0 * 0.5 / (g * j2))).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn stepped_angle_of_twist(segments: &[ShaftSegment]) -> f64 {
+    segments.iter().map(ShaftSegment::angle_of_twist).sum()
+}
+
+/// Returns the torque in N.m transmitted by a shaft delivering `power` in W while turning
+/// at `rpm` revolutions per minute, T = P / ω with ω = 2πn / 60.
+///
+/// # Example
+/// ```rust
+/// use i_mth::torsion::torque_from_power;
+///
+/// // a 15 kW motor at 1450 rpm
+/// let torque = torque_from_power(15e3, 1450.0);
+///
+/// assert!((torque - 98.786).abs() < 1e-3);
+/// ```
+#[inline]
+pub fn torque_from_power(power: f64, rpm: f64) -> f64 {
+    power / rpm_to_rad_per_sec(rpm)
+}
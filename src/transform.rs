@@ -0,0 +1,316 @@
+//! # Transforms
+//!
+//! Rigid motions in space as a rotation followed by a translation, x' = **R**x + t.
+//! A lighter alternative to 4 by 4 homogeneous matrices that keeps the rotation exact
+//! to invert, and dual quaternions for compact chains of rigid motions.
+
+use std::ops::Mul;
+
+use crate::frame::Frame;
+use crate::matrix3::Matrix3;
+use crate::rotations::Quaternion;
+use crate::vector3d::Vector3D;
+
+/// A rigid transform: a rotation followed by a translation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Transform3D {
+    /// Rotation matrix, assumed orthogonal with a determinant of one.
+    pub rotation: Matrix3,
+    /// Translation applied after the rotation.
+    pub translation: Vector3D,
+}
+
+impl Default for Transform3D {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Transform3D {
+    /// Returns the transform rotating by `rotation` and then translating by `translation`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::constants::PI;
+    /// use i_mth::matrix3::Matrix3;
+    /// use i_mth::transform::Transform3D;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let t = Transform3D::new(Matrix3::rotation_z(PI / 2.0), Vector3D::new(1.0, 0.0, 0.0));
+    /// let p = t.transform_point(Vector3D::i());
+    ///
+    /// assert!((p - Vector3D::new(1.0, 1.0, 0.0)).magnitude() < 1e-12);
+    /// assert!((t.inverse().transform_point(p) - Vector3D::i()).magnitude() < 1e-12);
+    /// assert!((t.transform_vector(Vector3D::i()) - Vector3D::j()).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn new(rotation: Matrix3, translation: Vector3D) -> Self {
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    /// Returns the transform that leaves everything in place.
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(Matrix3::identity(), Vector3D::origin())
+    }
+
+    /// Returns the pure translation by the passed vector.
+    #[inline]
+    pub fn from_translation(translation: Vector3D) -> Self {
+        Self::new(Matrix3::identity(), translation)
+    }
+
+    /// Returns the pure rotation by the passed matrix.
+    #[inline]
+    pub fn from_rotation(rotation: Matrix3) -> Self {
+        Self::new(rotation, Vector3D::origin())
+    }
+
+    /// Returns the passed point moved by this transform.
+    #[inline]
+    pub fn transform_point(&self, p: Vector3D) -> Vector3D {
+        self.rotation * p + self.translation
+    }
+
+    /// Returns the passed free vector rotated by this transform. Free vectors like
+    /// forces and velocities are not translated.
+    #[inline]
+    pub fn transform_vector(&self, v: Vector3D) -> Vector3D {
+        self.rotation * v
+    }
+
+    /// Returns the transform that applies `other` first and then this transform.
+    #[inline]
+    pub fn compose(&self, other: &Self) -> Self {
+        Self::new(
+            self.rotation * other.rotation,
+            self.transform_point(other.translation),
+        )
+    }
+
+    /// Returns the transform undoing this transform.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.transpose();
+        Self::new(rotation, (rotation * self.translation).scale(-1.0))
+    }
+}
+
+impl Mul for Transform3D {
+    type Output = Self;
+    /// Composes the transforms so that `(a * b).transform_point(p)` is
+    /// `a.transform_point(b.transform_point(p))`.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.compose(&rhs)
+    }
+}
+
+impl From<Frame> for Transform3D {
+    /// Returns the transform taking coordinates in the frame to parent coordinates.
+    #[inline]
+    fn from(frame: Frame) -> Self {
+        Self::new(
+            Matrix3::from_column_vectors(frame.x_axis, frame.y_axis, frame.z_axis),
+            frame.origin,
+        )
+    }
+}
+
+/// A unit dual quaternion **r** + ε**d** for a rigid transform, with the rotation
+/// quaternion **r** and **d** = ½t**r** for the translation t, so it rotates first and
+/// then translates like a [`Transform3D`].\
+/// It takes 8 numbers instead of 12 and blends smoothly along screw motions.
+///
+/// # Example
+/// ```rust
+/// use i_mth::rotations::Quaternion;
+/// use i_mth::transform::DualQuaternion;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // a two link arm: each joint turns by 90° about z and the links are 1 m long along x
+/// let joint = Quaternion::from_axis_angle(Vector3D::k(), std::f64::consts::FRAC_PI_2).unwrap();
+/// let link = DualQuaternion::from_rotation_translation(joint, Vector3D::i());
+/// let tip = (link * link).transform_point(Vector3D::origin());
+///
+/// assert!(tip.is_approx_equal_to(Vector3D::new(1.0, 1.0, 0.0), 1e-15));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DualQuaternion {
+    /// Real part, the rotation.
+    pub real: Quaternion,
+    /// Dual part, holding the translation.
+    pub dual: Quaternion,
+}
+
+impl Default for DualQuaternion {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl DualQuaternion {
+    /// Returns the dual quaternion with the passed real and dual parts.
+    #[inline]
+    pub const fn new(real: Quaternion, dual: Quaternion) -> Self {
+        Self { real, dual }
+    }
+
+    /// Returns the dual quaternion that leaves everything in place.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self::new(Quaternion::identity(), Quaternion::new(0.0, 0.0, 0.0, 0.0))
+    }
+
+    /// Returns the transform rotating by the unit quaternion `rotation` and then translating
+    /// by `translation`.
+    #[inline]
+    pub fn from_rotation_translation(rotation: Quaternion, translation: Vector3D) -> Self {
+        let dual = Quaternion::from_parts(0.0, translation) * rotation;
+        Self::new(rotation, dual.scale(0.5))
+    }
+
+    /// Returns the dual quaternion of the passed transform, or None if its rotation is not
+    /// a rotation matrix within [`Matrix3::DEFAULT_TOL`].
+    #[inline]
+    pub fn from_transform(transform: &Transform3D) -> Option<Self> {
+        Some(Self::from_rotation_translation(
+            Quaternion::from_matrix(&transform.rotation)?,
+            transform.translation,
+        ))
+    }
+
+    /// Returns the matrix and translation form of this transform.
+    #[inline]
+    pub fn to_transform(&self) -> Transform3D {
+        Transform3D::new(self.real.to_matrix(), self.translation())
+    }
+
+    /// Returns the rotation of this transform.
+    #[inline]
+    pub fn rotation(&self) -> Quaternion {
+        self.real
+    }
+
+    /// Returns the translation of this transform.
+    #[inline]
+    pub fn translation(&self) -> Vector3D {
+        (self.dual * self.real.conjugate()).vector().scale(2.0)
+    }
+
+    /// Returns the passed point moved by this transform.
+    #[inline]
+    pub fn transform_point(&self, p: Vector3D) -> Vector3D {
+        self.real.rotate(p) + self.translation()
+    }
+
+    /// Returns the passed free vector rotated by this transform.
+    #[inline]
+    pub fn transform_vector(&self, v: Vector3D) -> Vector3D {
+        self.real.rotate(v)
+    }
+
+    /// Returns this dual quaternion scaled so its real part is a unit quaternion and the
+    /// dual part is perpendicular to it, or None if the real part is zero.
+    pub fn normalized(&self) -> Option<Self> {
+        let real = self.real.normalized()?;
+        let dual = self.dual.scale(1.0 / self.real.norm());
+        Some(Self::new(real, dual - real.scale(real.dot(dual))))
+    }
+
+    /// Returns the transform undoing this unit dual quaternion.
+    #[inline]
+    pub const fn inverse(&self) -> Self {
+        Self::new(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// Returns this transform raised to the power `s`, moving `s` times as far along the
+    /// same screw axis, so half of it applied twice is the whole.
+    pub fn pow(&self, s: f64) -> Self {
+        let real = if self.real.w < 0.0 {
+            -self.real
+        } else {
+            self.real
+        };
+        let t = (self.dual * self.real.conjugate()).vector().scale(2.0);
+        let v = real.vector();
+        let sin_half = v.magnitude();
+        if sin_half < 1e-12 {
+            return Self::from_rotation_translation(Quaternion::identity(), t.scale(s));
+        }
+        // screw axis direction l with moment m, turning by θ while sliding by d along it
+        let angle = 2.0 * sin_half.atan2(real.w);
+        let l = v.scale(1.0 / sin_half);
+        let d = t.dot(l);
+        let cot = real.w / sin_half;
+        let m = (t.cross(l) + l.cross(t.cross(l)).scale(cot)).scale(0.5);
+
+        let (sin, cos) = (0.5 * s * angle).sin_cos();
+        let d = s * d;
+        Self::new(
+            Quaternion::from_parts(cos, l.scale(sin)),
+            Quaternion::from_parts(-0.5 * d * sin, m.scale(sin) + l.scale(0.5 * d * cos)),
+        )
+    }
+
+    /// Returns the screw linear interpolation from this unit dual quaternion at `t` = 0 to
+    /// the passed one at `t` = 1, which turns and slides at constant rates about and along a
+    /// fixed screw axis, the shorter way round.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::rotations::Quaternion;
+    /// use i_mth::transform::DualQuaternion;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // a quarter turn about the vertical axis through (1, 0, 0) while rising by 2 m
+    /// let turn = Quaternion::from_axis_angle(Vector3D::k(), std::f64::consts::FRAC_PI_2).unwrap();
+    /// let centre = Vector3D::new(1.0, 0.0, 0.0);
+    /// let end = DualQuaternion::from_rotation_translation(
+    ///     turn,
+    ///     centre - turn.rotate(centre) + Vector3D::new(0.0, 0.0, 2.0),
+    /// );
+    /// let halfway = DualQuaternion::identity().sclerp(end, 0.5);
+    ///
+    /// // halfway it has turned by 45° and risen by 1 m, points on the axis stay on it
+    /// let (_, angle) = halfway.rotation().to_axis_angle().unwrap();
+    /// assert!((angle - std::f64::consts::FRAC_PI_4).abs() < 1e-15);
+    /// let p = halfway.transform_point(centre);
+    /// assert!(p.is_approx_equal_to(Vector3D::new(1.0, 0.0, 1.0), 1e-15));
+    /// assert!((halfway * halfway).to_transform().transform_point(Vector3D::origin())
+    ///     .is_approx_equal_to(end.transform_point(Vector3D::origin()), 1e-15));
+    /// ```
+    pub fn sclerp(&self, other: DualQuaternion, t: f64) -> Self {
+        let other = if self.real.dot(other.real) < 0.0 {
+            Self::new(-other.real, -other.dual)
+        } else {
+            other
+        };
+        *self * (self.inverse() * other).pow(t)
+    }
+}
+
+impl Mul for DualQuaternion {
+    type Output = Self;
+    /// Composes the transforms so that `(a * b).transform_point(p)` is
+    /// `a.transform_point(b.transform_point(p))`.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.real * rhs.real,
+            self.real * rhs.dual + self.dual * rhs.real,
+        )
+    }
+}
+
+impl From<DualQuaternion> for Transform3D {
+    #[inline]
+    fn from(q: DualQuaternion) -> Self {
+        q.to_transform()
+    }
+}
@@ -0,0 +1,751 @@
+//! # Trusses
+//!
+//! Statically determinate pin-jointed trusses in the plane and in space, solved by the
+//! method of joints.
+//!
+//! Every member is a two-force member, so its force acts along the line between its joints.
+//! Writing force equilibrium at each joint gives two equations per joint in the plane and
+//! three in space, which are solved together for the member forces and support reactions.
+//! Member forces are positive in tension and negative in compression.
+
+use crate::error::{ImthError, ModelError};
+use crate::matrixn::{MatrixN, SolveDiagnostics};
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+/// A straight two-force member joining two joints, given by their indices.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[repr(C)]
+pub struct Member {
+    /// Index of the joint at the start of the member.
+    pub start: usize,
+    /// Index of the joint at the end of the member.
+    pub end: usize,
+}
+
+impl Member {
+    /// Returns the member joining the passed joints.
+    #[inline]
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+}
+
+/// A support of a plane truss.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Support2D {
+    /// A pin that stops the joint moving in any direction, giving two reaction components.
+    Pin,
+    /// A roller that only stops the joint moving along the passed normal to its surface,
+    /// giving one reaction component.
+    Roller(Vector2D),
+}
+
+/// A support of a space truss.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Support3D {
+    /// A ball and socket that stops the joint moving in any direction, giving three
+    /// reaction components.
+    BallAndSocket,
+    /// A joint that can only slide along the passed direction, like a collar on a smooth
+    /// rod, giving two reaction components perpendicular to it.
+    Slider(Vector3D),
+    /// A roller or ball on a smooth surface that only stops the joint moving along the
+    /// passed normal, giving one reaction component.
+    Roller(Vector3D),
+}
+
+/// The member forces and support reactions of a solved truss.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct TrussSolution<V> {
+    /// Axial force in each member in N, in the order of `members`, positive in tension.
+    pub member_forces: Vec<f64>,
+    /// Reaction force at each support as `(joint, R)`, in the order of `supports`.
+    pub reactions: Vec<(usize, V)>,
+}
+
+impl<V> TrussSolution<V> {
+    /// Returns the index and force of the member in the largest tension, or None if no
+    /// member is in tension.
+    pub fn max_tension(&self) -> Option<(usize, f64)> {
+        self.member_forces
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|&(_, f)| f > 0.0)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Returns the index and force of the member in the largest compression, or None if no
+    /// member is in compression.
+    pub fn max_compression(&self) -> Option<(usize, f64)> {
+        self.member_forces
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|&(_, f)| f < 0.0)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Returns this solution with each reaction passed through `f`.
+    fn map_reactions<W>(self, f: impl Fn(V) -> W) -> TrussSolution<W> {
+        TrussSolution {
+            member_forces: self.member_forces,
+            reactions: self
+                .reactions
+                .into_iter()
+                .map(|(joint, r)| (joint, f(r)))
+                .collect(),
+        }
+    }
+}
+
+/// A pin-jointed truss in the plane.
+///
+/// # Example
+/// ```rust
+/// use i_mth::truss::{Support2D, Truss2D};
+/// use i_mth::vector2d::Vector2D;
+///
+/// // a 4 m wide, 3 m high triangular truss carrying 12 kN down at its apex
+/// let truss = Truss2D::new(vec![
+///     Vector2D::new(0.0, 0.0),
+///     Vector2D::new(4.0, 0.0),
+///     Vector2D::new(2.0, 3.0),
+/// ])
+/// .with_member(0, 1)
+/// .with_member(0, 2)
+/// .with_member(1, 2)
+/// .with_support(0, Support2D::Pin)
+/// .with_support(1, Support2D::Roller(Vector2D::j()))
+/// .with_load(2, Vector2D::new(0.0, -12e3));
+/// let solution = truss.solve().unwrap();
+///
+/// // each sloping member is 13^0.5 m long and carries half the load vertically
+/// let sloped = -6e3 * 13f64.sqrt() / 3.0;
+/// assert!((solution.member_forces[1] - sloped).abs() < 1e-9);
+/// assert!((solution.member_forces[2] - sloped).abs() < 1e-9);
+/// assert!((solution.member_forces[0] - 4e3).abs() < 1e-9);
+/// assert!((solution.reactions[1].1 - Vector2D::new(0.0, 6e3)).magnitude() < 1e-9);
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Truss2D {
+    /// Positions of the joints in m.
+    pub joints: Vec<Vector2D>,
+    /// The members joining the joints.
+    pub members: Vec<Member>,
+    /// Supports as `(joint, support)` pairs.
+    pub supports: Vec<(usize, Support2D)>,
+    /// External loads as `(joint, F)` pairs in N.
+    pub loads: Vec<(usize, Vector2D)>,
+}
+
+impl Truss2D {
+    /// Returns a truss with the passed joints and no members, supports or loads.
+    #[inline]
+    pub fn new(joints: Vec<Vector2D>) -> Self {
+        Self {
+            joints,
+            ..Self::default()
+        }
+    }
+
+    /// Returns this truss with a member joining the passed joints.
+    #[inline]
+    pub fn with_member(mut self, start: usize, end: usize) -> Self {
+        self.members.push(Member::new(start, end));
+        self
+    }
+
+    /// Returns this truss with the passed support at `joint`.
+    #[inline]
+    pub fn with_support(mut self, joint: usize, support: Support2D) -> Self {
+        self.supports.push((joint, support));
+        self
+    }
+
+    /// Returns this truss with the passed load at `joint`.
+    #[inline]
+    pub fn with_load(mut self, joint: usize, force: Vector2D) -> Self {
+        self.loads.push((joint, force));
+        self
+    }
+
+    /// Returns the length of the passed member, or None if there is no such member.
+    #[inline]
+    pub fn member_length(&self, member: usize) -> Option<f64> {
+        let m = self.members.get(member)?;
+        Some((*self.joints.get(m.end)? - *self.joints.get(m.start)?).magnitude())
+    }
+
+    /// Returns Ok if this truss is set up so that it can be solved, or the first problem
+    /// found otherwise. The checks are, in order, that every member, support and load refers
+    /// to an existing joint, that no member has zero length or repeats another, that every
+    /// joint has a member, that rollers have a direction, that the supports stop the truss
+    /// moving as a rigid body and that there are as many unknown forces as equations.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::error::ModelError;
+    /// use i_mth::truss::{Support2D, Truss2D};
+    /// use i_mth::vector2d::Vector2D;
+    ///
+    /// let truss = Truss2D::new(vec![
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(4.0, 0.0),
+    ///     Vector2D::new(2.0, 3.0),
+    /// ])
+    /// .with_member(0, 1)
+    /// .with_member(0, 2)
+    /// .with_member(1, 2);
+    ///
+    /// // three rollers on the same floor cannot stop it sliding sideways
+    /// let sliding = truss
+    ///     .clone()
+    ///     .with_support(0, Support2D::Roller(Vector2D::j()))
+    ///     .with_support(1, Support2D::Roller(Vector2D::j()))
+    ///     .with_support(2, Support2D::Roller(Vector2D::j()));
+    /// assert_eq!(Err(ModelError::ImproperSupports), sliding.validate());
+    ///
+    /// let supported = truss
+    ///     .with_support(0, Support2D::Pin)
+    ///     .with_support(1, Support2D::Roller(Vector2D::j()));
+    /// assert_eq!(Ok(()), supported.validate());
+    ///
+    /// let misplaced = supported.with_load(3, Vector2D::new(0.0, -1e3));
+    /// assert_eq!(
+    ///     Err(ModelError::UnknownLoadJoint { load: 0, joint: 3 }),
+    ///     misplaced.validate()
+    /// );
+    /// ```
+    #[inline]
+    pub fn validate(&self) -> Result<(), ModelError> {
+        self.assembly()?.validate()
+    }
+
+    /// Returns the member forces and support reactions of this truss.
+    ///
+    /// Returns [`ImthError::InvalidModel`] with the problem found by [`Truss2D::validate`]
+    /// if the truss is set up wrongly, or an invalid argument error if it passes those checks
+    /// but is still geometrically unstable, like when three joints of a triangle lie on a line.
+    pub fn solve(&self) -> Result<TrussSolution<Vector2D>, ImthError> {
+        let assembly = self.assembly()?;
+        assembly.validate()?;
+        Ok(assembly
+            .solve()?
+            .map_reactions(|r| Vector2D::new(r[0], r[1])))
+    }
+
+    /// Returns the solution of [`Truss2D::solve`] together with diagnostics of the solve of
+    /// the joint equilibrium equations, whose large condition number warns of a truss
+    /// that is nearly a mechanism. Estimating it costs another pass over the factorized
+    /// equations, so [`Truss2D::solve`] leaves it out.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::truss::{Support2D, Truss2D};
+    /// use i_mth::vector2d::Vector2D;
+    ///
+    /// let triangle = |height: f64| {
+    ///     Truss2D::new(vec![
+    ///         Vector2D::new(0.0, 0.0),
+    ///         Vector2D::new(4.0, 0.0),
+    ///         Vector2D::new(2.0, height),
+    ///     ])
+    ///     .with_member(0, 1)
+    ///     .with_member(0, 2)
+    ///     .with_member(1, 2)
+    ///     .with_support(0, Support2D::Pin)
+    ///     .with_support(1, Support2D::Roller(Vector2D::j()))
+    ///     .with_load(2, Vector2D::new(0.0, -12e3))
+    /// };
+    ///
+    /// let (solution, diagnostics) = triangle(3.0).solve_with_diagnostics().unwrap();
+    /// assert_eq!(triangle(3.0).solve().unwrap(), solution);
+    /// assert!(!diagnostics.is_ill_conditioned(1e6));
+    ///
+    /// // nearly flat, so nearly a mechanism
+    /// let (_, diagnostics) = triangle(1e-7).solve_with_diagnostics().unwrap();
+    /// assert!(diagnostics.is_ill_conditioned(1e6));
+    /// ```
+    pub fn solve_with_diagnostics(
+        &self,
+    ) -> Result<(TrussSolution<Vector2D>, SolveDiagnostics), ImthError> {
+        let assembly = self.assembly()?;
+        assembly.validate()?;
+        let (solution, diagnostics) = assembly.solve_with_diagnostics()?;
+        Ok((
+            solution.map_reactions(|r| Vector2D::new(r[0], r[1])),
+            diagnostics,
+        ))
+    }
+
+    /// Returns the coefficient matrix **A** and right hand side b of the joint equilibrium
+    /// equations **A**x = b of this truss, whose unknowns x are the member forces in the
+    /// order of `members` followed by the reaction components, or the problem found by
+    /// [`Truss2D::validate`].\
+    /// With the `precision` feature, solving them in extended precision with
+    /// `precision::solve` checks the member forces of a truss that is nearly a mechanism.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::truss::{Support2D, Truss2D};
+    /// use i_mth::vector2d::Vector2D;
+    ///
+    /// // a very flat triangle, whose members carry large forces from a small load
+    /// let truss = Truss2D::new(vec![
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(2.0, 0.0),
+    ///     Vector2D::new(1.0, 1e-6),
+    /// ])
+    ///     .with_member(0, 1)
+    ///     .with_member(1, 2)
+    ///     .with_member(0, 2)
+    ///     .with_support(0, Support2D::Pin)
+    ///     .with_support(1, Support2D::Roller(Vector2D::j()))
+    ///     .with_load(2, Vector2D::new(0.0, -1.0));
+    ///
+    /// let forces = truss.solve().unwrap().member_forces;
+    /// let (a, b) = truss.equilibrium_equations().unwrap();
+    /// let x = a.solve(&b).unwrap();
+    /// assert_eq!(3 + 3, x.len());
+    /// for (f, x) in forces.iter().zip(&x) {
+    ///     assert!((f - x).abs() <= 1e-9 * f.abs().max(1.0));
+    /// }
+    /// ```
+    pub fn equilibrium_equations(&self) -> Result<(MatrixN, Vec<f64>), ImthError> {
+        let assembly = self.assembly()?;
+        assembly.validate()?;
+        Ok(assembly.equations())
+    }
+
+    /// Returns the joint equilibrium equations of this truss.
+    fn assembly(&self) -> Result<Assembly<'_>, ModelError> {
+        let mut restraints = Vec::new();
+        for (support, &(joint, kind)) in self.supports.iter().enumerate() {
+            let mut restrain = |d: Vector2D| restraints.push((support, joint, [d.x, d.y, 0.0]));
+            match kind {
+                Support2D::Pin => {
+                    restrain(Vector2D::i());
+                    restrain(Vector2D::j());
+                }
+                Support2D::Roller(normal) => restrain(
+                    normal
+                        .normalized()
+                        .ok_or(ModelError::DegenerateSupport(support))?,
+                ),
+            }
+        }
+        Ok(Assembly {
+            dimensions: 2,
+            joints: self.joints.iter().map(|p| [p.x, p.y, 0.0]).collect(),
+            members: &self.members,
+            supports: self.supports.iter().map(|s| s.0).collect(),
+            restraints,
+            loads: self
+                .loads
+                .iter()
+                .map(|(j, f)| (*j, [f.x, f.y, 0.0]))
+                .collect(),
+        })
+    }
+}
+
+/// A pin-jointed truss in space, with ball and socket joints.
+///
+/// # Example
+/// ```rust
+/// use i_mth::truss::{Support3D, Truss3D};
+/// use i_mth::vector3d::Vector3D;
+///
+/// // a tripod of three legs meeting 4 m above the ground, carrying 9 kN down
+/// let top = Vector3D::new(0.0, 0.0, 4.0);
+/// let feet = [
+///     Vector3D::new(3.0, 0.0, 0.0),
+///     Vector3D::new(-1.5, 2.0, 0.0),
+///     Vector3D::new(-1.5, -2.0, 0.0),
+/// ];
+/// let truss = Truss3D::new(vec![top, feet[0], feet[1], feet[2]])
+///     .with_member(0, 1)
+///     .with_member(0, 2)
+///     .with_member(0, 3)
+///     .with_support(1, Support3D::BallAndSocket)
+///     .with_support(2, Support3D::BallAndSocket)
+///     .with_support(3, Support3D::BallAndSocket)
+///     .with_load(0, Vector3D::new(0.0, 0.0, -9e3));
+/// let solution = truss.solve().unwrap();
+///
+/// // every leg is in compression and the feet carry the whole load
+/// assert!(solution.member_forces.iter().all(|&f| f < 0.0));
+/// let total = solution
+///     .reactions
+///     .iter()
+///     .fold(Vector3D::origin(), |acc, (_, r)| acc + *r);
+/// assert!((total - Vector3D::new(0.0, 0.0, 9e3)).magnitude() < 1e-9);
+/// assert!((solution.member_forces[0] + 3e3 * 5.0 / 4.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Truss3D {
+    /// Positions of the joints in m.
+    pub joints: Vec<Vector3D>,
+    /// The members joining the joints.
+    pub members: Vec<Member>,
+    /// Supports as `(joint, support)` pairs.
+    pub supports: Vec<(usize, Support3D)>,
+    /// External loads as `(joint, F)` pairs in N.
+    pub loads: Vec<(usize, Vector3D)>,
+}
+
+impl Truss3D {
+    /// Returns a truss with the passed joints and no members, supports or loads.
+    #[inline]
+    pub fn new(joints: Vec<Vector3D>) -> Self {
+        Self {
+            joints,
+            ..Self::default()
+        }
+    }
+
+    /// Returns this truss with a member joining the passed joints.
+    #[inline]
+    pub fn with_member(mut self, start: usize, end: usize) -> Self {
+        self.members.push(Member::new(start, end));
+        self
+    }
+
+    /// Returns this truss with the passed support at `joint`.
+    #[inline]
+    pub fn with_support(mut self, joint: usize, support: Support3D) -> Self {
+        self.supports.push((joint, support));
+        self
+    }
+
+    /// Returns this truss with the passed load at `joint`.
+    #[inline]
+    pub fn with_load(mut self, joint: usize, force: Vector3D) -> Self {
+        self.loads.push((joint, force));
+        self
+    }
+
+    /// Returns the length of the passed member, or None if there is no such member.
+    #[inline]
+    pub fn member_length(&self, member: usize) -> Option<f64> {
+        let m = self.members.get(member)?;
+        Some((*self.joints.get(m.end)? - *self.joints.get(m.start)?).magnitude())
+    }
+
+    /// Returns Ok if this truss is set up so that it can be solved, or the first problem
+    /// found otherwise, with the same checks as [`Truss2D::validate`]. In space the supports
+    /// must stop all six rigid body motions, three translations and three rotations.
+    #[inline]
+    pub fn validate(&self) -> Result<(), ModelError> {
+        self.assembly()?.validate()
+    }
+
+    /// Returns the member forces and support reactions of this truss.
+    ///
+    /// Returns [`ImthError::InvalidModel`] with the problem found by [`Truss3D::validate`]
+    /// if the truss is set up wrongly, or an invalid argument error if it passes those checks
+    /// but is still geometrically unstable.
+    pub fn solve(&self) -> Result<TrussSolution<Vector3D>, ImthError> {
+        let assembly = self.assembly()?;
+        assembly.validate()?;
+        Ok(assembly
+            .solve()?
+            .map_reactions(|r| Vector3D::new(r[0], r[1], r[2])))
+    }
+
+    /// Returns the solution of [`Truss3D::solve`] together with diagnostics of the solve of
+    /// the joint equilibrium equations, like [`Truss2D::solve_with_diagnostics`].
+    pub fn solve_with_diagnostics(
+        &self,
+    ) -> Result<(TrussSolution<Vector3D>, SolveDiagnostics), ImthError> {
+        let assembly = self.assembly()?;
+        assembly.validate()?;
+        let (solution, diagnostics) = assembly.solve_with_diagnostics()?;
+        Ok((
+            solution.map_reactions(|r| Vector3D::new(r[0], r[1], r[2])),
+            diagnostics,
+        ))
+    }
+
+    /// Returns the coefficient matrix **A** and right hand side b of the joint equilibrium
+    /// equations **A**x = b of this truss, whose unknowns x are the member forces in the
+    /// order of `members` followed by the reaction components, or the problem found by
+    /// [`Truss3D::validate`].\
+    /// With the `precision` feature, solving them in extended precision with
+    /// `precision::solve` checks the member forces of a truss that is nearly a mechanism.
+    pub fn equilibrium_equations(&self) -> Result<(MatrixN, Vec<f64>), ImthError> {
+        let assembly = self.assembly()?;
+        assembly.validate()?;
+        Ok(assembly.equations())
+    }
+
+    /// Returns the joint equilibrium equations of this truss.
+    fn assembly(&self) -> Result<Assembly<'_>, ModelError> {
+        let mut restraints = Vec::new();
+        for (support, &(joint, kind)) in self.supports.iter().enumerate() {
+            let mut restrain = |d: Vector3D| restraints.push((support, joint, [d.x, d.y, d.z]));
+            match kind {
+                Support3D::BallAndSocket => {
+                    restrain(Vector3D::i());
+                    restrain(Vector3D::j());
+                    restrain(Vector3D::k());
+                }
+                Support3D::Slider(axis) => {
+                    let axis = axis
+                        .normalized()
+                        .ok_or(ModelError::DegenerateSupport(support))?;
+                    // any vector not along the axis gives the two perpendicular directions
+                    let other = if axis.x.abs() < 0.9 {
+                        Vector3D::i()
+                    } else {
+                        Vector3D::j()
+                    };
+                    let u = axis.cross(other).normalize_or_zero();
+                    restrain(u);
+                    restrain(axis.cross(u));
+                }
+                Support3D::Roller(normal) => restrain(
+                    normal
+                        .normalized()
+                        .ok_or(ModelError::DegenerateSupport(support))?,
+                ),
+            }
+        }
+        Ok(Assembly {
+            dimensions: 3,
+            joints: self.joints.iter().map(|p| [p.x, p.y, p.z]).collect(),
+            members: &self.members,
+            supports: self.supports.iter().map(|s| s.0).collect(),
+            restraints,
+            loads: self
+                .loads
+                .iter()
+                .map(|(j, f)| (*j, [f.x, f.y, f.z]))
+                .collect(),
+        })
+    }
+}
+
+/// The joint equilibrium equations of a plane or space truss, with plane trusses padded
+/// to three coordinates so both share one solver.
+struct Assembly<'a> {
+    dimensions: usize,
+    joints: Vec<[f64; 3]>,
+    members: &'a [Member],
+    /// Joint of each support.
+    supports: Vec<usize>,
+    /// Unit direction of every reaction component as `(support, joint, direction)`.
+    restraints: Vec<(usize, usize, [f64; 3])>,
+    loads: Vec<(usize, [f64; 3])>,
+}
+
+impl Assembly<'_> {
+    /// Returns the number of unknown forces and of equilibrium equations.
+    #[inline]
+    fn counts(&self) -> (usize, usize) {
+        (
+            self.members.len() + self.restraints.len(),
+            self.dimensions * self.joints.len(),
+        )
+    }
+
+    /// Returns the vector from the start to the end of the passed member.
+    #[inline]
+    fn delta(&self, member: &Member) -> [f64; 3] {
+        let (p, q) = (self.joints[member.start], self.joints[member.end]);
+        [q[0] - p[0], q[1] - p[1], q[2] - p[2]]
+    }
+
+    /// Returns the first problem that stops these equations being solved.
+    fn validate(&self) -> Result<(), ModelError> {
+        let joints = self.joints.len();
+        for (member, m) in self.members.iter().enumerate() {
+            if let Some(&joint) = [m.start, m.end].iter().find(|&&j| j >= joints) {
+                return Err(ModelError::UnknownMemberJoint { member, joint });
+            }
+        }
+        if let Some((support, &joint)) =
+            self.supports.iter().enumerate().find(|(_, &j)| j >= joints)
+        {
+            return Err(ModelError::UnknownSupportJoint { support, joint });
+        }
+        if let Some((load, &(joint, _))) =
+            self.loads.iter().enumerate().find(|(_, l)| l.0 >= joints)
+        {
+            return Err(ModelError::UnknownLoadJoint { load, joint });
+        }
+
+        let mut connected = vec![false; joints];
+        for (i, m) in self.members.iter().enumerate() {
+            if self.delta(m) == [0.0; 3] {
+                return Err(ModelError::ZeroLengthMember(i));
+            }
+            let key = (m.start.min(m.end), m.start.max(m.end));
+            if let Some(j) = self.members[..i]
+                .iter()
+                .position(|n| (n.start.min(n.end), n.start.max(n.end)) == key)
+            {
+                return Err(ModelError::DuplicateMember(j, i));
+            }
+            connected[m.start] = true;
+            connected[m.end] = true;
+        }
+        if let Some(joint) = connected.iter().position(|&c| !c) {
+            return Err(ModelError::DisconnectedJoint(joint));
+        }
+
+        if !self.supports_are_proper() {
+            return Err(ModelError::ImproperSupports);
+        }
+
+        let (unknowns, equations) = self.counts();
+        if unknowns > equations {
+            return Err(ModelError::StaticallyIndeterminate {
+                unknowns,
+                equations,
+            });
+        }
+        if unknowns < equations {
+            return Err(ModelError::Mechanism {
+                unknowns,
+                equations,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns true if the reactions can stop every rigid body motion of the truss, which
+    /// holds when the rows [**d**, **p** × **d**] of the reaction directions **d** at
+    /// points **p** span all three plane or six space motions.
+    fn supports_are_proper(&self) -> bool {
+        // positions are taken about the middle of the joints and scaled by the size of the
+        // truss so the force and moment columns are alike in size
+        let n = self.joints.len().max(1) as f64;
+        let center = [0, 1, 2].map(|k| self.joints.iter().map(|p| p[k]).sum::<f64>() / n);
+        let size = self
+            .joints
+            .iter()
+            .map(|p| {
+                (0..3)
+                    .map(|k| (p[k] - center[k]).powi(2))
+                    .sum::<f64>()
+                    .sqrt()
+            })
+            .fold(0.0, f64::max);
+        let size = if size > 0.0 { size } else { 1.0 };
+        let rows: Vec<Vec<f64>> = self
+            .restraints
+            .iter()
+            .map(|&(_, joint, d)| {
+                let p = [0, 1, 2].map(|k| (self.joints[joint][k] - center[k]) / size);
+                let m = [
+                    p[1] * d[2] - p[2] * d[1],
+                    p[2] * d[0] - p[0] * d[2],
+                    p[0] * d[1] - p[1] * d[0],
+                ];
+                if self.dimensions == 2 {
+                    vec![d[0], d[1], m[2]]
+                } else {
+                    vec![d[0], d[1], d[2], m[0], m[1], m[2]]
+                }
+            })
+            .collect();
+        rank(rows, crate::EPSILON) == 3 * (self.dimensions - 1)
+    }
+
+    /// Returns the coefficient matrix and right hand side of the equilibrium equations,
+    /// with the member forces followed by the reaction components as unknowns.
+    fn equations(&self) -> (MatrixN, Vec<f64>) {
+        let d = self.dimensions;
+        let (unknowns, equations) = self.counts();
+        let mut a = MatrixN::zeros(equations, unknowns);
+        for (col, m) in self.members.iter().enumerate() {
+            let delta = self.delta(m);
+            let length = delta.iter().map(|c| c * c).sum::<f64>().sqrt();
+            // a member in tension pulls each of its joints towards the other one
+            for k in 0..d {
+                a[(m.start * d + k, col)] += delta[k] / length;
+                a[(m.end * d + k, col)] -= delta[k] / length;
+            }
+        }
+        for (i, &(_, joint, direction)) in self.restraints.iter().enumerate() {
+            for k in 0..d {
+                a[(joint * d + k, self.members.len() + i)] += direction[k];
+            }
+        }
+        let mut b = vec![0.0; equations];
+        for &(joint, force) in &self.loads {
+            for k in 0..d {
+                b[joint * d + k] -= force[k];
+            }
+        }
+        (a, b)
+    }
+
+    /// Returns the member forces and the reaction at each support, once the equations
+    /// have passed [`Assembly::validate`].
+    fn solve(&self) -> Result<TrussSolution<[f64; 3]>, ImthError> {
+        let (a, b) = self.equations();
+        let x = a.solve(&b).ok_or(ImthError::InvalidArgument(
+            "truss is geometrically unstable",
+        ))?;
+        Ok(self.solution(&x))
+    }
+
+    /// Returns the solution of [`Assembly::solve`] with the diagnostics of the solve.
+    fn solve_with_diagnostics(
+        &self,
+    ) -> Result<(TrussSolution<[f64; 3]>, SolveDiagnostics), ImthError> {
+        let (a, b) = self.equations();
+        let (x, diagnostics) = a
+            .solve_with_diagnostics(&b)
+            .ok_or(ImthError::InvalidArgument(
+                "truss is geometrically unstable",
+            ))?;
+        Ok((self.solution(&x), diagnostics))
+    }
+
+    /// Returns the member forces and the reaction at each support from the solution x of
+    /// the equations.
+    fn solution(&self, x: &[f64]) -> TrussSolution<[f64; 3]> {
+        let (member_forces, components) = x.split_at(self.members.len());
+        let mut reactions: Vec<(usize, [f64; 3])> =
+            self.supports.iter().map(|&j| (j, [0.0; 3])).collect();
+        for (&(support, _, direction), r) in self.restraints.iter().zip(components) {
+            for (sum, c) in reactions[support].1.iter_mut().zip(direction) {
+                *sum += r * c;
+            }
+        }
+        TrussSolution {
+            member_forces: member_forces.to_vec(),
+            reactions,
+        }
+    }
+}
+
+/// Returns the number of rows of the passed matrix that are linearly independent, found by
+/// Gaussian elimination treating pivots no larger than `tol` as zero.
+fn rank(mut rows: Vec<Vec<f64>>, tol: f64) -> usize {
+    let cols = rows.first().map_or(0, Vec::len);
+    let mut rank = 0;
+    for col in 0..cols {
+        let Some(pivot) = (rank..rows.len())
+            .max_by(|&i, &j| rows[i][col].abs().total_cmp(&rows[j][col].abs()))
+            .filter(|&i| rows[i][col].abs() > tol)
+        else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let pivot_row = rows[rank].clone();
+        for row in rows.iter_mut().skip(rank + 1) {
+            let factor = row[col] / pivot_row[col];
+            for (e, p) in row.iter_mut().zip(&pivot_row) {
+                *e -= factor * p;
+            }
+        }
+        rank += 1;
+    }
+    rank
+}
@@ -0,0 +1,392 @@
+//! # Units
+//!
+//! Quantity newtypes holding a value in SI units, so a length can not be passed where a
+//! force is expected. Quantities of the same kind add and subtract, scale by an f64, and
+//! multiply or divide into the quantity the physics says they should:
+//!
+//! - Length / Time = Velocity, Velocity / Time = Acceleration
+//! - Mass × Acceleration = Force
+//! - Force × Length = Moment, see [`Force::work`] for the energy of a force along a distance
+//! - Energy / Time = Power, Moment × AngularVelocity = Power
+//!
+//! # Example
+//! ```rust
+//! use i_mth::units::{Force, Length, Mass, Acceleration};
+//!
+//! let weight = Mass::new(20.0) * Acceleration::new(9.81);
+//! let moment = weight * Length::new(1.5);
+//!
+//! assert!((weight - Force::new(196.2)).abs() < Force::new(1e-12));
+//! assert!((moment.value() - 294.3).abs() < 1e-12);
+//! println!("{}", moment); // 294.3 N·m
+//! ```
+
+use std::fmt;
+use std::ops::*;
+
+use crate::vector3d::Vector3D;
+
+/// Defines a quantity newtype over an f64 in the passed SI unit, with the arithmetic
+/// every quantity shares.
+macro_rules! quantity {
+    ($(#[$meta:meta])* $name:ident, $unit:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+        pub struct $name(pub f64);
+
+        impl $name {
+            #[doc = concat!("Returns a quantity of the passed value in ", $unit, ".")]
+            #[inline]
+            pub const fn new(value: f64) -> Self {
+                Self(value)
+            }
+
+            #[doc = concat!("Returns the value of the quantity in ", $unit, ".")]
+            #[inline]
+            pub const fn value(&self) -> f64 {
+                self.0
+            }
+
+            /// Returns the magnitude of the quantity.
+            #[inline]
+            pub fn abs(&self) -> Self {
+                Self(self.0.abs())
+            }
+
+            /// Returns the quantity formatted in engineering notation with an SI prefix,
+            /// like 1.5 kN for 1500 N.
+            #[inline]
+            pub fn engineering(&self) -> Engineering {
+                Engineering::new(self.0, $unit)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $name {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl SubAssign for $name {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl Mul<f64> for $name {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: f64) -> Self {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl Mul<$name> for f64 {
+            type Output = $name;
+            #[inline]
+            fn mul(self, rhs: $name) -> $name {
+                $name(self * rhs.0)
+            }
+        }
+
+        impl Div<f64> for $name {
+            type Output = Self;
+            #[inline]
+            fn div(self, rhs: f64) -> Self {
+                Self(self.0 / rhs)
+            }
+        }
+
+        /// The ratio of two quantities of the same kind is dimensionless.
+        impl Div for $name {
+            type Output = f64;
+            #[inline]
+            fn div(self, rhs: Self) -> f64 {
+                self.0 / rhs.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} {}", self.0, $unit)
+            }
+        }
+
+        impl fmt::LowerExp for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerExp::fmt(&self.0, f)?;
+                write!(f, " {}", $unit)
+            }
+        }
+    };
+}
+
+/// Implements `$lhs $op $rhs = $output` for quantities.
+macro_rules! relation {
+    ($lhs:ident * $rhs:ident = $output:ident) => {
+        impl Mul<$rhs> for $lhs {
+            type Output = $output;
+            #[inline]
+            fn mul(self, rhs: $rhs) -> $output {
+                $output(self.0 * rhs.0)
+            }
+        }
+    };
+    ($lhs:ident / $rhs:ident = $output:ident) => {
+        impl Div<$rhs> for $lhs {
+            type Output = $output;
+            #[inline]
+            fn div(self, rhs: $rhs) -> $output {
+                $output(self.0 / rhs.0)
+            }
+        }
+    };
+}
+
+quantity!(
+    /// A length in metres.
+    Length,
+    "m"
+);
+quantity!(
+    /// A mass in kilograms.
+    Mass,
+    "kg"
+);
+quantity!(
+    /// A duration in seconds.
+    Time,
+    "s"
+);
+quantity!(
+    /// A force in newtons.
+    Force,
+    "N"
+);
+quantity!(
+    /// A velocity in metres per second.
+    Velocity,
+    "m/s"
+);
+quantity!(
+    /// An acceleration in metres per second squared.
+    Acceleration,
+    "m/s²"
+);
+quantity!(
+    /// A moment or torque in newton metres.
+    Moment,
+    "N·m"
+);
+quantity!(
+    /// An energy in joules.
+    Energy,
+    "J"
+);
+quantity!(
+    /// An angular velocity in radians per second.
+    AngularVelocity,
+    "rad/s"
+);
+quantity!(
+    /// A power in watts.
+    Power,
+    "W"
+);
+
+relation!(Length / Time = Velocity);
+relation!(Length / Velocity = Time);
+relation!(Velocity * Time = Length);
+relation!(Time * Velocity = Length);
+relation!(Velocity / Time = Acceleration);
+relation!(Velocity / Acceleration = Time);
+relation!(Acceleration * Time = Velocity);
+relation!(Time * Acceleration = Velocity);
+relation!(Mass * Acceleration = Force);
+relation!(Acceleration * Mass = Force);
+relation!(Force / Mass = Acceleration);
+relation!(Force / Acceleration = Mass);
+relation!(Force * Length = Moment);
+relation!(Length * Force = Moment);
+relation!(Moment / Length = Force);
+relation!(Moment / Force = Length);
+relation!(Energy / Length = Force);
+relation!(Energy / Force = Length);
+relation!(Energy / Time = Power);
+relation!(Energy / Power = Time);
+relation!(Power * Time = Energy);
+relation!(Time * Power = Energy);
+relation!(Moment * AngularVelocity = Power);
+relation!(AngularVelocity * Moment = Power);
+relation!(Power / AngularVelocity = Moment);
+relation!(Power / Moment = AngularVelocity);
+
+impl Force {
+    /// Returns the force vector of the passed magnitude in N acting along the line from
+    /// `from` towards `to`, like the pull of a cable anchored at `from` and attached at
+    /// `to` seen from the anchor. Returns None if the points coincide.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::units::Force;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // a cable from A to B carrying 2 kN
+    /// let a = Vector3D::new(0.0, 0.0, 6.0);
+    /// let b = Vector3D::new(2.0, 3.0, 0.0);
+    /// let f = Force::from_points(2000.0, a, b).unwrap();
+    ///
+    /// assert!(f.is_approx_equal_to(Vector3D::new(4000.0 / 7.0, 6000.0 / 7.0, -12000.0 / 7.0), 1e-9));
+    /// ```
+    #[inline]
+    pub fn from_points(magnitude: f64, from: Vector3D, to: Vector3D) -> Option<Vector3D> {
+        Some((to - from).normalized()?.scale(magnitude))
+    }
+
+    /// Returns the force vector of this magnitude acting along the line from `from`
+    /// towards `to`, or None if the points coincide. See [`Force::from_points`].
+    #[inline]
+    pub fn along(&self, from: Vector3D, to: Vector3D) -> Option<Vector3D> {
+        Self::from_points(self.0, from, to)
+    }
+
+    /// Returns the work done by this force acting along the passed distance in its
+    /// own direction.
+    #[inline]
+    pub fn work(&self, distance: Length) -> Energy {
+        Energy(self.0 * distance.0)
+    }
+}
+
+impl Mass {
+    /// Returns the kinetic energy of this mass moving at the passed velocity.
+    #[inline]
+    pub fn kinetic_energy(&self, velocity: Velocity) -> Energy {
+        Energy(0.5 * self.0 * velocity.0 * velocity.0)
+    }
+}
+
+impl Moment {
+    /// Returns the work done by this torque turning through the passed angle in radians.
+    #[inline]
+    pub fn work(&self, angle: f64) -> Energy {
+        Energy(self.0 * angle)
+    }
+}
+
+/// SI prefixes for the exponents -24 to 24 in steps of 3.
+const SI_PREFIXES: [&str; 17] = [
+    "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+];
+
+/// A value displayed in engineering notation, with the exponent a multiple of three
+/// written as an SI prefix on the unit, like 1.5 kN or 250 µm.
+///
+/// The precision of the formatter sets the number of decimals, otherwise the value is
+/// rounded to 6 significant figures. Without a unit, or outside the range of the SI
+/// prefixes, the exponent is written out instead, like 1.5e3. Kilograms are prefixed
+/// from the gram, so 1500 kg is shown as 1.5 Mg.
+///
+/// # Example
+/// ```rust
+/// use i_mth::units::{Engineering, Force, Length};
+///
+/// assert_eq!("1.5 kN", Force::new(1500.0).engineering().to_string());
+/// assert_eq!("250 µm", Length::new(2.5e-4).engineering().to_string());
+/// assert_eq!("-12.35 MN·m", format!("{:.2}", Engineering::new(-12.345e6, "N·m")));
+/// assert_eq!("4.7e-3", Engineering::new(0.0047, "").to_string());
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Engineering {
+    /// The value in the base unit.
+    pub value: f64,
+    /// The base unit, empty for a dimensionless value.
+    pub unit: &'static str,
+}
+
+impl Engineering {
+    /// Returns the passed value in the passed unit ready to be displayed.
+    #[inline]
+    pub const fn new(value: f64, unit: &'static str) -> Self {
+        Self { value, unit }
+    }
+}
+
+impl fmt::Display for Engineering {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (value, unit) = match self.unit {
+            "kg" => (self.value * 1e3, "g"),
+            unit => (self.value, unit),
+        };
+        let (mantissa, exponent) = engineering_parts(value, f.precision().is_none());
+        let prefix = usize::try_from(exponent / 3 + 8)
+            .ok()
+            .and_then(|i| SI_PREFIXES.get(i))
+            .filter(|_| !unit.is_empty());
+
+        match f.precision() {
+            Some(p) => write!(f, "{mantissa:.p$}")?,
+            None => write!(f, "{mantissa}")?,
+        }
+        match prefix {
+            Some(prefix) => write!(f, " {prefix}{unit}"),
+            None => {
+                if exponent != 0 {
+                    write!(f, "e{exponent}")?;
+                }
+                if !unit.is_empty() {
+                    write!(f, " {unit}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Splits the passed value into a mantissa in [1, 1000) and an exponent that is a
+/// multiple of three, rounding the mantissa to 6 significant figures if `round` is set.
+fn engineering_parts(value: f64, round: bool) -> (f64, i32) {
+    if value == 0.0 || !value.is_finite() {
+        return (value, 0);
+    }
+    let round = |mantissa: f64| {
+        if !round {
+            return mantissa;
+        }
+        let scale = 10f64.powi(5 - mantissa.abs().log10().floor() as i32);
+        (mantissa * scale).round() / scale
+    };
+    let mut exponent = (value.abs().log10() / 3.0).floor() as i32 * 3;
+    let mut mantissa = round(value / 10f64.powi(exponent));
+    if mantissa.abs() >= 1000.0 {
+        exponent += 3;
+        mantissa = round(value / 10f64.powi(exponent));
+    }
+    (mantissa, exponent)
+}
@@ -1,19 +1,342 @@
 //! # Utilities
 //!
 //! A tool box of utility functions !!!
+//!
+//! The potential energy helpers share one sign convention: the energy is zero at the
+//! reference configuration (the datum height, infinite separation or the unstretched
+//! spring) and increases as work is done against the force, so a drop in potential
+//! energy equals the work done by the force.
+//!
+//! The formulas of scalars are generic over [`Real`], so they can be evaluated with
+//! measurements to carry the uncertainties of the inputs through to the result.
+
+use crate::constants::{EARTH_GRAVITY, G, PI};
+use crate::error::ImthError;
+use crate::scalar::Real;
+use crate::vector3d::Vector3D;
 
-use crate::constants::G;
 /// Returns the acceleration due to gravity of the celestial
 /// body with the passed values.
 #[inline]
-pub fn calc_acc_due_to_grav(mass_of_celestial_body: f64, radius_of_celestial_body: f64) -> f64 {
-    (G * mass_of_celestial_body) / radius_of_celestial_body.powf(2.0)
+pub fn calc_acc_due_to_grav<R: Real>(mass_of_celestial_body: R, radius_of_celestial_body: R) -> R {
+    (R::from(G) * mass_of_celestial_body) / radius_of_celestial_body.powi(2)
 }
 
 /// Calculates the escape velocity of the celestial body with the
 /// passed values.\
 /// [More Info](https://en.wikipedia.org/wiki/Escape_velocity#:~:text=More%20generally%2C%20escape%20velocity%20is,orbit%20(of%20any%20radius).)
+///
+/// # Example
+/// ```rust
+/// use i_mth::measurement::Measurement;
+/// use i_mth::utils::calc_escape_velocity;
+///
+/// // the Moon, with its mass known to 0.1 % and its radius to 1 km
+/// let mass = Measurement::new(7.342e22, 7.342e19);
+/// let radius = Measurement::new(1.7374e6, 1e3);
+/// let v = calc_escape_velocity(mass, radius);
+///
+/// assert_eq!(calc_escape_velocity(7.342e22, 1.7374e6), v.value);
+/// assert!((v.value - 2375.0).abs() < 1.0);
+/// // v ∝ √(M / R), so its relative uncertainty is half that of M and R in quadrature
+/// let relative = 0.5 * 1e-3f64.hypot(1e3 / 1.7374e6);
+/// assert!((v.relative_uncertainty() - relative).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn calc_escape_velocity<R: Real>(mass_of_celestial_body: R, radius_of_celestial_body: R) -> R{
+    ((R::from(2.0 * G) * mass_of_celestial_body) / (radius_of_celestial_body)).sqrt()
+}
+
+/// Returns the speed needed to stay on a circular orbit of the passed radius around
+/// the celestial body with the passed mass.\
+/// [More Info](https://en.wikipedia.org/wiki/Circular_orbit#Velocity)
+#[inline]
+pub fn circular_orbit_velocity<R: Real>(mass_of_celestial_body: R, radius: R) -> R {
+    ((R::from(G) * mass_of_celestial_body) / radius).sqrt()
+}
+
+/// Returns the time taken to complete one orbit with the passed semi-major axis around
+/// the celestial body with the passed mass (Kepler's third law). For circular orbits the
+/// semi-major axis is the radius of the orbit.\
+/// [More Info](https://en.wikipedia.org/wiki/Orbital_period)
+#[inline]
+pub fn orbital_period<R: Real>(mass_of_celestial_body: R, semi_major_axis: R) -> R {
+    R::from(2.0 * PI) * (semi_major_axis.powi(3) / (R::from(G) * mass_of_celestial_body)).sqrt()
+}
+
+/// Returns the altitude above the surface of the celestial body at which a circular orbit
+/// has the same period as the rotation of the body (a synchronous orbit). The altitude is
+/// measured above the equator, so the equatorial radius of the body is expected.
+/// Pass [`EARTH_EQUATORIAL_RADIUS`](crate::constants::EARTH_EQUATORIAL_RADIUS) and
+/// [`EARTH_SIDEREAL_DAY`](crate::constants::EARTH_SIDEREAL_DAY) for the geostationary
+/// altitude of the Earth.\
+/// [More Info](https://en.wikipedia.org/wiki/Geostationary_orbit)
+///
+/// # Example
+/// ```rust
+/// use i_mth::constants::{EARTH_EQUATORIAL_RADIUS, EARTH_MASS, EARTH_SIDEREAL_DAY};
+/// use i_mth::utils::synchronous_orbit_altitude;
+///
+/// let altitude = synchronous_orbit_altitude(EARTH_MASS, EARTH_EQUATORIAL_RADIUS, EARTH_SIDEREAL_DAY);
+///
+/// assert!((altitude / 1000.0 - 35_786.0).abs() < 1.0);
+/// ```
+#[inline]
+pub fn synchronous_orbit_altitude(
+    mass_of_celestial_body: f64,
+    equatorial_radius_of_celestial_body: f64,
+    rotation_period: f64,
+) -> f64 {
+    let radius = (G * mass_of_celestial_body * rotation_period.powi(2) / (4.0 * PI * PI)).cbrt();
+    radius - equatorial_radius_of_celestial_body
+}
+
+/// Returns the magnitude of the gravitational force between two bodies with the passed
+/// masses whose centres are `distance` apart (Newton's law of universal gravitation).\
+/// [More Info](https://en.wikipedia.org/wiki/Newton%27s_law_of_universal_gravitation)
+#[inline]
+pub fn gravitational_force_magnitude<R: Real>(m1: R, m2: R, distance: R) -> R {
+    R::from(G) * m1 * m2 / distance.powi(2)
+}
+
+/// Returns the gravitational force on body 1 of mass `m1` at `r1` due to body 2 of
+/// mass `m2` at `r2`, pointing from body 1 towards body 2.
+/// The force on body 2 is the negative of this force. Returns an error if the bodies are at
+/// the same position, where the force is undefined.
+///
+/// # Example
+/// ```rust
+/// use i_mth::constants::{EARTH_MASS, EARTH_RADIUS};
+/// use i_mth::utils::gravitational_force;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // weight of a 1 kg mass standing on the surface of the Earth
+/// let force = gravitational_force(1.0, EARTH_MASS, Vector3D::new(0.0, 0.0, EARTH_RADIUS), Vector3D::origin()).unwrap();
+///
+/// assert!((force.z + 9.82).abs() < 0.01);
+/// assert!(gravitational_force(1.0, 1.0, Vector3D::i(), Vector3D::i()).is_err());
+/// ```
 #[inline]
-pub fn calc_escape_velocity(mass_of_celestial_body: f64, radius_of_celestial_body: f64) -> f64{
-    ((2.0 * G * mass_of_celestial_body) / (radius_of_celestial_body)).sqrt()
-}
\ No newline at end of file
+pub fn gravitational_force(
+    m1: f64,
+    m2: f64,
+    r1: Vector3D,
+    r2: Vector3D,
+) -> Result<Vector3D, ImthError> {
+    let separation = r2 - r1;
+    let distance = separation.magnitude();
+    if distance == 0.0 {
+        return Err(ImthError::InvalidArgument(
+            "bodies must not be at the same position",
+        ));
+    }
+    Ok(separation.scale(G * m1 * m2 / distance.powi(3)))
+}
+
+/// Returns the gravitational potential energy of a mass `m` at height `h` above the datum
+/// in a uniform field of standard gravity, m g h. Negative below the datum.
+#[inline]
+pub fn gravitational_pe<R: Real>(m: R, h: R) -> R {
+    R::from(-EARTH_GRAVITY) * m * h
+}
+
+/// Returns the gravitational potential energy of two bodies with the passed masses whose
+/// centres are `r` apart, -G m<sub>1</sub> m<sub>2</sub> / r.
+/// Always negative, approaching zero as the bodies move infinitely far apart.
+///
+/// # Example
+/// ```rust
+/// use i_mth::constants::{EARTH_MASS, EARTH_RADIUS};
+/// use i_mth::utils::{gravitational_pe, universal_gravitational_pe};
+///
+/// // close to the surface both agree on the energy needed to climb 100 m
+/// let climb = universal_gravitational_pe(80.0, EARTH_MASS, EARTH_RADIUS + 100.0)
+///     - universal_gravitational_pe(80.0, EARTH_MASS, EARTH_RADIUS);
+///
+/// assert!((climb / gravitational_pe(80.0, 100.0) - 1.0).abs() < 0.01);
+/// ```
+#[inline]
+pub fn universal_gravitational_pe<R: Real>(m1: R, m2: R, r: R) -> R {
+    R::from(-G) * m1 * m2 / r
+}
+
+/// Returns the elastic potential energy stored in a linear spring of stiffness `k`
+/// stretched or compressed by `x` from its unstretched length, k x<sup>2</sup> / 2.
+/// Never negative.
+#[inline]
+pub fn spring_pe<R: Real>(k: R, x: R) -> R {
+    R::from(0.5) * k * x.powi(2)
+}
+
+/// Returns the kinetic energy of a particle of mass `m` moving with velocity `v`,
+/// m |v|<sup>2</sup> / 2.
+#[inline]
+pub fn linear_ke(m: f64, v: Vector3D) -> f64 {
+    0.5 * m * v.squared_magnitude()
+}
+
+/// Returns the kinetic energy of a body with moment of inertia `i` about its axis of
+/// rotation spinning at `omega` rad/s, I ω<sup>2</sup> / 2.
+#[inline]
+pub fn rotational_ke<R: Real>(i: R, omega: R) -> R {
+    R::from(0.5) * i * omega.powi(2)
+}
+
+/// Returns the kinetic energy of a rigid body in planar motion with mass `m`, velocity
+/// of the centre of mass `v_g`, moment of inertia `i_g` about the centre of mass and
+/// angular velocity `omega`, m |v<sub>G</sub>|<sup>2</sup> / 2 + I<sub>G</sub> ω<sup>2</sup> / 2.
+///
+/// # Example
+/// ```rust
+/// use i_mth::utils::ke;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // a 2 kg disc of radius 0.1 m rolling without slipping at 3 m/s
+/// let (m, r, v) = (2.0, 0.1, 3.0);
+/// let energy = ke(m, Vector3D::new(v, 0.0, 0.0), 0.5 * m * r * r, v / r);
+///
+/// assert!((energy - 0.75 * m * v * v).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn ke(m: f64, v_g: Vector3D, i_g: f64, omega: f64) -> f64 {
+    linear_ke(m, v_g) + rotational_ke(i_g, omega)
+}
+
+/// Returns the linear (viscous) drag force on a body moving with velocity `v` through
+/// a fluid, -b v, where `b` is the drag coefficient in kg s<sup>-1</sup>.
+/// A good model for slow, small bodies like dust settling in air.
+#[inline]
+pub fn linear_drag(b: f64, v: Vector3D) -> Vector3D {
+    v.scale(-b)
+}
+
+/// Returns the quadratic (aerodynamic) drag force on a body moving with velocity `v`
+/// through a fluid of density `rho`, -ρ C<sub>d</sub> A |v| v / 2, where `cd` is the drag
+/// coefficient and `area` the frontal area of the body.\
+/// [More Info](https://en.wikipedia.org/wiki/Drag_equation)
+///
+/// # Example
+/// ```rust
+/// use i_mth::utils::quadratic_drag;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // a cyclist at 10 m/s in sea level air
+/// let drag = quadratic_drag(1.225, 0.9, 0.5, Vector3D::new(10.0, 0.0, 0.0));
+///
+/// assert!((drag.x + 27.5625).abs() < 1e-9);
+/// ```
+#[inline]
+pub fn quadratic_drag(rho: f64, cd: f64, area: f64, v: Vector3D) -> Vector3D {
+    v.scale(-0.5 * rho * cd * area * v.magnitude())
+}
+
+/// Returns the terminal speed of a body of mass `mass` falling under quadratic drag
+/// through a fluid of density `rho`, sqrt(2 m g / (ρ C<sub>d</sub> A)), where `cd` is the
+/// drag coefficient, `area` the frontal area and `g` the magnitude of the gravitational
+/// acceleration. See [`quadratic_drag`].\
+/// [More Info](https://en.wikipedia.org/wiki/Terminal_velocity)
+///
+/// # Example
+/// ```rust
+/// use i_mth::utils::terminal_velocity;
+///
+/// // a skydiver falling belly down
+/// let v = terminal_velocity(80.0, 1.225, 1.0, 0.7, 9.81);
+///
+/// assert!((v - 42.7).abs() < 0.1);
+/// ```
+#[inline]
+pub fn terminal_velocity<R: Real>(mass: R, rho: R, cd: R, area: R, g: R) -> R {
+    (R::from(2.0) * mass * g.abs() / (rho * cd * area)).sqrt()
+}
+
+/// Returns the terminal speed of a body of mass `mass` falling under linear drag with the
+/// coefficient `b`, m g / b, where `g` is the magnitude of the gravitational acceleration.
+/// See [`linear_drag`].
+#[inline]
+pub fn linear_terminal_velocity<R: Real>(mass: R, b: R, g: R) -> R {
+    mass * g.abs() / b
+}
+
+/// Returns the magnitude of the centripetal acceleration of a body moving at speed `v`
+/// on a circle of radius `r`, v<sup>2</sup> / r.
+#[inline]
+pub fn centripetal_acceleration<R: Real>(v: R, r: R) -> R {
+    v.powi(2) / r
+}
+
+/// Returns the centripetal acceleration of a point at `r` from a point on the axis of a
+/// body spinning with angular velocity `omega`, ω × (ω × r). It points from the point
+/// towards the axis.
+///
+/// # Example
+/// ```rust
+/// use i_mth::utils::{centripetal_acceleration, centripetal_acceleration_vector};
+/// use i_mth::vector3d::Vector3D;
+///
+/// // a point 0.5 m from the axis of a disc spinning at 4 rad/s about z
+/// let omega = Vector3D::new(0.0, 0.0, 4.0);
+/// let a = centripetal_acceleration_vector(omega, Vector3D::new(0.5, 0.0, 0.3));
+///
+/// assert!((a.x + 8.0).abs() < 1e-12 && a.y.abs() < 1e-12 && a.z.abs() < 1e-12);
+/// assert!((a.magnitude() - centripetal_acceleration(4.0 * 0.5, 0.5)).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn centripetal_acceleration_vector(omega: Vector3D, r: Vector3D) -> Vector3D {
+    omega.cross(omega.cross(r))
+}
+
+/// Returns the magnitude of the net force needed to keep a body of mass `m` moving at
+/// speed `v` on a circle of radius `r`, m v<sup>2</sup> / r.
+#[inline]
+pub fn centripetal_force<R: Real>(m: R, v: R, r: R) -> R {
+    m * centripetal_acceleration(v, r)
+}
+
+/// Returns the torque (moment) of the force `f` applied at `r` from the reference point,
+/// **r** × **F**.
+///
+/// # Example
+/// ```rust
+/// use i_mth::utils::torque;
+/// use i_mth::vector3d::Vector3D;
+///
+/// let moment = torque(Vector3D::new(-0.2, 0.16, 0.0), Vector3D::new(400.0, 693.0, 0.0));
+///
+/// assert!((moment.z + 202.6).abs() < 1e-9);
+/// ```
+#[inline]
+pub fn torque(r: Vector3D, f: Vector3D) -> Vector3D {
+    r.cross(f)
+}
+
+/// Returns the torque about `point` of the force `force` applied at `application`.
+#[inline]
+pub fn torque_about(point: Vector3D, application: Vector3D, force: Vector3D) -> Vector3D {
+    torque(application - point, force)
+}
+
+/// Returns the sum of the torques about `point` of the passed `(application, force)` pairs.
+///
+/// # Example
+/// ```rust
+/// use i_mth::utils::net_torque;
+/// use i_mth::vector3d::Vector3D;
+///
+/// // a 4 m beam hinged at its left end, with 100 N down at 1 m and 50 N up at 4 m
+/// let forces = [
+///     (Vector3D::new(1.0, 0.0, 0.0), Vector3D::new(0.0, -100.0, 0.0)),
+///     (Vector3D::new(4.0, 0.0, 0.0), Vector3D::new(0.0, 50.0, 0.0)),
+/// ];
+/// let moment = net_torque(Vector3D::origin(), &forces);
+///
+/// assert!((moment.z - 100.0).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn net_torque(point: Vector3D, forces: &[(Vector3D, Vector3D)]) -> Vector3D {
+    forces
+        .iter()
+        .fold(Vector3D::origin(), |sum, &(application, force)| {
+            sum + torque_about(point, application, force)
+        })
+}
@@ -1,2 +1,22 @@
+pub mod bulk;
+pub mod ordering;
 pub mod vector2d;
-pub mod vector3d;
\ No newline at end of file
+pub mod vector3d;
+
+/// Returns `a * b + c`, as a single fused multiply-add when the `fma` feature is enabled.
+///
+/// A fused multiply-add rounds once instead of twice, which is both faster and more
+/// accurate on hardware with an FMA instruction, but much slower where it has to be
+/// emulated in software, so it is opt in.
+#[cfg(feature = "fma")]
+#[inline(always)]
+pub(crate) fn mul_add(a: f64, b: f64, c: f64) -> f64 {
+    a.mul_add(b, c)
+}
+
+/// Returns `a * b + c`, as a single fused multiply-add when the `fma` feature is enabled.
+#[cfg(not(feature = "fma"))]
+#[inline(always)]
+pub(crate) fn mul_add(a: f64, b: f64, c: f64) -> f64 {
+    a * b + c
+}
@@ -0,0 +1,314 @@
+//! # Bulk
+//!
+//! Operations over slices of vectors, written as plain chunked loops the compiler can
+//! vectorize, for data sets of many thousands of particles or sample points.
+//!
+//! With the `rayon` feature enabled the slices are split into blocks of [`BLOCK`] vectors
+//! that are processed on all cores.
+//!
+//! # Example
+//! ```rust
+//! use i_mth::bulk;
+//! use i_mth::vector3d::Vector3D;
+//!
+//! let mut forces = vec![Vector3D::new(0.0, -9.81, 0.0); 1000];
+//! bulk::scale_all_in_place(&mut forces, 2.0);
+//!
+//! assert!((bulk::sum_vectors(&forces).y + 19_620.0).abs() < 1e-9);
+//! ```
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::vector3d::Vector3D;
+
+/// Number of independent accumulators used by the reductions.
+const LANES: usize = 4;
+
+/// Number of vectors handed to a thread at a time when the `rayon` feature is enabled.
+pub const BLOCK: usize = 4096;
+
+/// Returns the sum of the passed vectors, or the zero vector for an empty slice.
+pub fn sum_vectors(vectors: &[Vector3D]) -> Vector3D {
+    #[cfg(feature = "rayon")]
+    return vectors
+        .par_chunks(BLOCK)
+        .map(sum_block)
+        .reduce(Vector3D::origin, |a, b| a + b);
+    #[cfg(not(feature = "rayon"))]
+    return sum_block(vectors);
+}
+
+fn sum_block(vectors: &[Vector3D]) -> Vector3D {
+    let chunks = vectors.chunks_exact(LANES);
+    let tail = chunks.remainder();
+    let mut lanes = [Vector3D::origin(); LANES];
+    for chunk in chunks {
+        for (lane, v) in lanes.iter_mut().zip(chunk) {
+            *lane += *v;
+        }
+    }
+    let sum = (lanes[0] + lanes[1]) + (lanes[2] + lanes[3]);
+    tail.iter().fold(sum, |sum, v| sum + *v)
+}
+
+/// Scales every vector in the slice by the passed value.
+pub fn scale_all_in_place(vectors: &mut [Vector3D], value: f64) {
+    for_each_block(vectors, |block| {
+        for v in block.iter_mut() {
+            v.x *= value;
+            v.y *= value;
+            v.z *= value;
+        }
+    });
+}
+
+/// Adds the matching vector of `other` scaled by the passed value to every vector in the
+/// slice, like advancing positions by velocities over a time step. Stops at the end of
+/// the shorter slice.
+pub fn add_scaled_all(vectors: &mut [Vector3D], other: &[Vector3D], value: f64) {
+    let add_block = |(block, other): (&mut [Vector3D], &[Vector3D])| {
+        for (v, o) in block.iter_mut().zip(other) {
+            *v = v.add_scaled(*o, value);
+        }
+    };
+    #[cfg(feature = "rayon")]
+    vectors
+        .par_chunks_mut(BLOCK)
+        .zip(other.par_chunks(BLOCK))
+        .for_each(add_block);
+    #[cfg(not(feature = "rayon"))]
+    add_block((vectors, other));
+}
+
+/// Returns the dot product of each pair of matching vectors, stopping at the end of the
+/// shorter slice.
+///
+/// # Example
+/// ```rust
+/// use i_mth::bulk::dot_pairs;
+/// use i_mth::vector3d::Vector3D;
+///
+/// let forces = [Vector3D::new(10.0, 0.0, 0.0), Vector3D::new(0.0, 5.0, 0.0)];
+/// let velocities = [Vector3D::new(2.0, 1.0, 0.0), Vector3D::new(3.0, 0.0, 0.0)];
+///
+/// assert_eq!(vec![20.0, 0.0], dot_pairs(&forces, &velocities));
+/// ```
+pub fn dot_pairs(a: &[Vector3D], b: &[Vector3D]) -> Vec<f64> {
+    #[cfg(feature = "rayon")]
+    return a
+        .par_iter()
+        .with_min_len(BLOCK)
+        .zip(b)
+        .map(|(a, b)| a.dot(*b))
+        .collect();
+    #[cfg(not(feature = "rayon"))]
+    return a.iter().zip(b).map(|(a, b)| a.dot(*b)).collect();
+}
+
+/// Normalizes every vector in the slice, leaving zero vectors unchanged.
+pub fn normalize_all(vectors: &mut [Vector3D]) {
+    for_each_block(vectors, |block| {
+        for v in block.iter_mut() {
+            *v = v.normalize_or(*v);
+        }
+    });
+}
+
+/// Runs the passed operation over blocks of the slice, in parallel with the `rayon`
+/// feature.
+#[inline]
+fn for_each_block<F>(vectors: &mut [Vector3D], op: F)
+where
+    F: Fn(&mut [Vector3D]) + Sync + Send,
+{
+    #[cfg(feature = "rayon")]
+    vectors.par_chunks_mut(BLOCK).for_each(op);
+    #[cfg(not(feature = "rayon"))]
+    op(vectors);
+}
+
+/// Vectors stored as three arrays of their x, y and z components (a structure of arrays),
+/// so that operations over every vector run over contiguous arrays of f64 that vectorize
+/// well. With the `rayon` feature enabled the arrays are processed in blocks of [`BLOCK`]
+/// on all cores.
+///
+/// # Example
+/// ```rust
+/// use i_mth::bulk::SoaVectors3D;
+/// use i_mth::vector3d::Vector3D;
+///
+/// let mut positions: SoaVectors3D = (0..1000).map(|n| Vector3D::new(n as f64, 0.0, 1.0)).collect();
+/// let velocities: SoaVectors3D = vec![Vector3D::new(0.0, 2.0, 0.0); 1000].into_iter().collect();
+///
+/// positions.add_scaled(&velocities, 0.5);
+/// assert_eq!(Some(Vector3D::new(10.0, 1.0, 1.0)), positions.get(10));
+/// assert!((positions.sum().x - 499_500.0).abs() < 1e-6);
+/// assert_eq!(2.0, velocities.magnitudes()[999]);
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct SoaVectors3D {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    z: Vec<f64>,
+}
+
+impl SoaVectors3D {
+    /// Returns an empty set of vectors.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the passed vectors split into their components.
+    pub fn from_vectors(vectors: &[Vector3D]) -> Self {
+        vectors.iter().copied().collect()
+    }
+
+    /// Returns the vectors joined back from their components.
+    pub fn to_vectors(&self) -> Vec<Vector3D> {
+        (0..self.len()).filter_map(|i| self.get(i)).collect()
+    }
+
+    /// Returns the number of vectors.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    /// Returns true if there are no vectors.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    /// Returns the x, y and z component arrays.
+    #[inline]
+    pub fn components(&self) -> (&[f64], &[f64], &[f64]) {
+        (&self.x, &self.y, &self.z)
+    }
+
+    /// Appends the passed vector.
+    #[inline]
+    pub fn push(&mut self, v: Vector3D) {
+        self.x.push(v.x);
+        self.y.push(v.y);
+        self.z.push(v.z);
+    }
+
+    /// Returns the vector at the passed index, or None if it is out of range.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Vector3D> {
+        Some(Vector3D::new(
+            *self.x.get(index)?,
+            self.y[index],
+            self.z[index],
+        ))
+    }
+
+    /// Returns the sum of the vectors, or the zero vector if there are none.
+    pub fn sum(&self) -> Vector3D {
+        Vector3D::new(
+            sum_values(&self.x),
+            sum_values(&self.y),
+            sum_values(&self.z),
+        )
+    }
+
+    /// Scales every vector by the passed value.
+    pub fn scale_in_place(&mut self, value: f64) {
+        for c in [&mut self.x, &mut self.y, &mut self.z] {
+            zip_blocks(c, &[], |block, _| {
+                for e in block.iter_mut() {
+                    *e *= value;
+                }
+            });
+        }
+    }
+
+    /// Adds the matching vector of `other` scaled by the passed value to every vector, like
+    /// advancing positions by velocities over a time step. Stops at the end of the shorter
+    /// set.
+    pub fn add_scaled(&mut self, other: &SoaVectors3D, value: f64) {
+        for (c, o) in [&mut self.x, &mut self.y, &mut self.z]
+            .into_iter()
+            .zip([&other.x, &other.y, &other.z])
+        {
+            zip_blocks(c, o, |block, other| {
+                for (e, o) in block.iter_mut().zip(other) {
+                    *e = crate::vectors::mul_add(*o, value, *e);
+                }
+            });
+        }
+    }
+
+    /// Returns the dot product of each pair of matching vectors, stopping at the end of the
+    /// shorter set.
+    pub fn dots(&self, other: &SoaVectors3D) -> Vec<f64> {
+        map_indices(self.len().min(other.len()), |i| {
+            self.x[i] * other.x[i] + self.y[i] * other.y[i] + self.z[i] * other.z[i]
+        })
+    }
+
+    /// Returns the magnitude of every vector.
+    pub fn magnitudes(&self) -> Vec<f64> {
+        map_indices(self.len(), |i| {
+            (self.x[i] * self.x[i] + self.y[i] * self.y[i] + self.z[i] * self.z[i]).sqrt()
+        })
+    }
+}
+
+impl Extend<Vector3D> for SoaVectors3D {
+    fn extend<I: IntoIterator<Item = Vector3D>>(&mut self, iter: I) {
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
+
+impl FromIterator<Vector3D> for SoaVectors3D {
+    fn from_iter<I: IntoIterator<Item = Vector3D>>(iter: I) -> Self {
+        let mut vectors = Self::new();
+        vectors.extend(iter);
+        vectors
+    }
+}
+
+/// Returns the sum of the passed values, in parallel blocks with the `rayon` feature.
+fn sum_values(values: &[f64]) -> f64 {
+    #[cfg(feature = "rayon")]
+    return values
+        .par_chunks(BLOCK)
+        .map(|b| b.iter().sum::<f64>())
+        .sum();
+    #[cfg(not(feature = "rayon"))]
+    return values.iter().sum();
+}
+
+/// Runs the passed operation over matching blocks of `values` and `other`, in parallel
+/// with the `rayon` feature. The blocks of `other` are empty past its end.
+#[inline]
+fn zip_blocks<F>(values: &mut [f64], other: &[f64], op: F)
+where
+    F: Fn(&mut [f64], &[f64]) + Sync + Send,
+{
+    #[cfg(feature = "rayon")]
+    values
+        .par_chunks_mut(BLOCK)
+        .enumerate()
+        .for_each(|(i, block)| op(block, other.get(i * BLOCK..).unwrap_or(&[])));
+    #[cfg(not(feature = "rayon"))]
+    op(values, other);
+}
+
+/// Returns `f` applied to every index below `n`, in parallel with the `rayon` feature.
+#[inline]
+fn map_indices<F>(n: usize, f: F) -> Vec<f64>
+where
+    F: Fn(usize) -> f64 + Sync + Send,
+{
+    #[cfg(feature = "rayon")]
+    return (0..n).into_par_iter().with_min_len(BLOCK).map(f).collect();
+    #[cfg(not(feature = "rayon"))]
+    return (0..n).map(f).collect();
+}
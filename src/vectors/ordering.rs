@@ -0,0 +1,69 @@
+//! # Ordering
+//!
+//! Adapters for ordering vectors with the standard library sort and selection APIs.
+
+use std::cmp::Ordering;
+
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+/// Wraps a vector so that it is ordered by its magnitude, allowing forces to be sorted,
+/// or the largest one picked, with `sort`, `max`, `BinaryHeap` and friends.
+///
+/// Vectors with the same magnitude compare as equal, and NaN magnitudes are ordered as
+/// by [`f64::total_cmp`].
+///
+/// # Example
+/// ```rust
+/// use i_mth::ordering::ByMagnitude;
+/// use i_mth::vector3d::Vector3D;
+///
+/// let members = [
+///     Vector3D::new(0.0, -300.0, 0.0),
+///     Vector3D::new(50.0, 0.0, 0.0),
+///     Vector3D::new(0.0, 400.0, 300.0),
+/// ];
+/// let critical = members.iter().copied().map(ByMagnitude).max().unwrap();
+///
+/// assert_eq!(Vector3D::new(0.0, 400.0, 300.0), critical.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ByMagnitude<V>(pub V);
+
+macro_rules! by_magnitude {
+    ($($vector:ident),+) => {
+        $(
+            impl PartialEq for ByMagnitude<$vector> {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    self.cmp(other) == Ordering::Equal
+                }
+            }
+
+            impl Eq for ByMagnitude<$vector> {}
+
+            impl PartialOrd for ByMagnitude<$vector> {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for ByMagnitude<$vector> {
+                #[inline]
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.0.cmp_magnitude(&other.0)
+                }
+            }
+
+            impl From<$vector> for ByMagnitude<$vector> {
+                #[inline]
+                fn from(vector: $vector) -> Self {
+                    Self(vector)
+                }
+            }
+        )+
+    };
+}
+
+by_magnitude!(Vector2D, Vector3D);
@@ -1,6 +1,9 @@
+use std::cmp::Ordering;
 use std::fmt;
 use std::ops::*;
+use crate::error::ImthError;
 use crate::vector3d::Vector3D;
+use super::mul_add;
 
 /// Represents a mathematical vector in 2 dimensional space.
 #[derive(Debug, PartialEq, Clone, Copy, Default)]
@@ -11,6 +14,9 @@ pub struct Vector2D {
 }
 
 impl Vector2D {
+    /// The default tolerance for the tolerant comparisons of this type, [`crate::EPSILON`].
+    pub const DEFAULT_TOL: f64 = crate::EPSILON;
+
     /// Returns a new vector with the components passed.\
     /// x is in the direction of the i-unit vector.\
     /// y is in the direction of the j-unit vector.
@@ -26,7 +32,7 @@ impl Vector2D {
     /// assert_eq!(1.0, vec2d.y);
     /// ```
     #[inline]
-    pub fn new(x: f64, y: f64) -> Self {
+    pub const fn new(x: f64, y: f64) -> Self {
         Self { x, y }
     }
 
@@ -42,25 +48,25 @@ impl Vector2D {
     /// assert_eq!(1.0, vec2d.y);
     /// ```
     #[inline]
-    pub fn set(value: f64) -> Self {
+    pub const fn set(value: f64) -> Self {
         Self { x: value, y: value }
     }
     /// Return the unit vector i == (i + 0j)
     #[inline]
-    pub fn i() -> Self {
+    pub const fn i() -> Self {
         Self { x: 1.0, y: 0.0 }
     }
 
     /// Returns the unit vector j == (0i + j)
     #[inline]
-    pub fn j() -> Self {
+    pub const fn j() -> Self {
         Self { x: 0.0, y: 1.0 }
     }
 
     /// Returns a vector pointing to the origin of the coordinate system
     /// (0i + 0j)
     #[inline]
-    pub fn origin() -> Self {
+    pub const fn origin() -> Self {
         Self { x: 0.0, y: 0.0 }
     }
 
@@ -89,7 +95,39 @@ impl Vector2D {
     /// Returns the dot product of this vector and the passed vector
     #[inline]
     pub fn dot(&self, other: Vector2D) -> f64 {
-        (self.x * other.x) + (self.y * other.y)
+        mul_add(self.y, other.y, self.x * other.x)
+    }
+
+    /// Returns the z component of the cross product of this vector and the passed vector,
+    /// which is positive when the passed vector lies anticlockwise of this vector.
+    #[inline]
+    pub fn cross(&self, other: Vector2D) -> f64 {
+        mul_add(self.x, other.y, -self.y * other.x)
+    }
+
+    /// Returns the components of this vector along the directions of `u` and `v`, so that
+    /// this vector is the sum of the unit vectors of `u` and `v` scaled by the returned pair,
+    /// like the forces in two members meeting at a joint. Returns None if either direction
+    /// is zero or the directions are parallel.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::vector2d::Vector2D;
+    ///
+    /// let load = Vector2D::new(0.0, -100.0);
+    /// let (fu, fv) = load.decompose_along(Vector2D::new(1.0, 1.0), Vector2D::new(-1.0, 1.0)).unwrap();
+    ///
+    /// assert!((fu + 100.0 / 2f64.sqrt()).abs() < 1e-9);
+    /// assert!((fv + 100.0 / 2f64.sqrt()).abs() < 1e-9);
+    /// assert_eq!(None, load.decompose_along(Vector2D::i(), Vector2D::new(-2.0, 0.0)));
+    /// ```
+    pub fn decompose_along(&self, u: Vector2D, v: Vector2D) -> Option<(f64, f64)> {
+        let (u, v) = (u.normalized()?, v.normalized()?);
+        let det = u.cross(v);
+        if det.abs() <= Self::DEFAULT_TOL {
+            return None;
+        }
+        Some((self.cross(v) / det, u.cross(*self) / det))
     }
 
     /// Multiples the x, y, and z components of this vector by the x, y, z components
@@ -123,11 +161,11 @@ impl Vector2D {
     /// Converts this vector to its unit form if arithmetically possible else
     /// Returns this vector unchanged if it fails. This operation can fail
     /// if you have a zero vector
+    /// and the failure is silent, see [`Vector2D::try_to_unit`] for a checked version.
     #[inline]
     pub fn to_unit(&mut self) {
-        let mag = self.magnitude();
-        if mag > 0.0 {
-            let inv_mag = 1.0 / mag;
+        let inv_mag = self.inverse_magnitude();
+        if inv_mag.is_finite() {
             self.x *= inv_mag;
             self.y *= inv_mag;
         }
@@ -137,22 +175,67 @@ impl Vector2D {
     /// else it returns None. This operation can fail if you have a zero vector.
     #[inline]
     pub fn normalized(&self) -> Option<Self> {
-        let mag = self.magnitude();
-        if mag > 0.0 {
-            let inv_mag = 1.0 / mag;
-            return Some(Vector2D {
-                x: self.x * inv_mag,
-                y: self.y * inv_mag,
-            });
+        let inv_mag = self.inverse_magnitude();
+        inv_mag.is_finite().then(|| self.scale(inv_mag))
+    }
+
+    /// Returns the normalized(unit) version of this vector, or the passed fallback if this
+    /// is a zero vector. The check is a single select on the reciprocal of the magnitude,
+    /// which keeps it cheap enough for normalizing large batches of directions every step.
+    #[inline]
+    pub fn normalize_or(self, fallback: Vector2D) -> Self {
+        let inv_mag = self.inverse_magnitude();
+        if inv_mag.is_finite() { self.scale(inv_mag) } else { fallback }
+    }
+
+    /// Returns the normalized(unit) version of this vector, or the zero vector if this
+    /// is a zero vector.
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        self.normalize_or(Self::origin())
+    }
+
+    /// Returns the normalized(unit) version of this vector, or a division by zero error if
+    /// this is a zero vector.
+    #[inline]
+    pub fn try_normalize(&self) -> Result<Self, ImthError> {
+        self.normalized().ok_or(ImthError::DivisionByZero)
+    }
+
+    /// Converts this vector to its unit form, or returns a division by zero error and
+    /// leaves it unchanged if this is a zero vector.
+    #[inline]
+    pub fn try_to_unit(&mut self) -> Result<(), ImthError> {
+        *self = self.try_normalize()?;
+        Ok(())
+    }
+
+    /// Returns this vector divided component by component by the passed vector, or a
+    /// division by zero error if any of its components is zero, where `/` gives infinite
+    /// or NaN components.
+    #[inline]
+    pub fn try_div(self, rhs: Vector2D) -> Result<Self, ImthError> {
+        if rhs.x == 0.0 || rhs.y == 0.0 {
+            return Err(ImthError::DivisionByZero);
         }
-        None
+        Ok(self / rhs)
+    }
+
+    /// Returns the reciprocal of the magnitude of this vector, which is not finite for a
+    /// zero vector.
+    #[inline]
+    fn inverse_magnitude(&self) -> f64 {
+        self.squared_magnitude().sqrt().recip()
     }
 
     /// Scales the passed vector by the passed value and performs vector
     /// addition on this vector and the other vector.
     #[inline]
     pub fn add_scaled(self, other: Vector2D, value: f64) -> Self {
-        self + other.scale(value)
+        Self {
+            x: mul_add(other.x, value, self.x),
+            y: mul_add(other.y, value, self.y),
+        }
     }
 
     /// Scales this vector by the passed value and performs vector
@@ -168,12 +251,48 @@ impl Vector2D {
         (self.x == other.x) && (self.y == other.y)
     }
 
+    /// Returns true if every component of this vector is within `tol` of the matching
+    /// component of the passed vector.
+    #[inline]
+    pub fn is_approx_equal_to(&self, other: Vector2D, tol: f64) -> bool {
+        ((self.x - other.x).abs() <= tol) && ((self.y - other.y).abs() <= tol)
+    }
+
+    /// Returns true if the magnitude of this vector is no more than `tol`.
+    #[inline]
+    pub fn is_zero(&self, tol: f64) -> bool {
+        self.squared_magnitude() <= tol * tol
+    }
+
+    /// Returns true if the sine of the angle between this vector and the passed vector
+    /// is no more than `tol`. Returns false if either vector is zero.
+    #[inline]
+    pub fn is_parallel_to(&self, other: Vector2D, tol: f64) -> bool {
+        let scale = self.magnitude() * other.magnitude();
+        scale > 0.0 && self.cross(other).abs() <= tol * scale
+    }
+
+    /// Returns true if the cosine of the angle between this vector and the passed vector
+    /// is no more than `tol`. Returns false if either vector is zero.
+    #[inline]
+    pub fn is_perpendicular_to(&self, other: Vector2D, tol: f64) -> bool {
+        let scale = self.magnitude() * other.magnitude();
+        scale > 0.0 && self.dot(other).abs() <= tol * scale
+    }
+
     /// Returns true if this vector has a greater magnitude(length) than the passed vector.
     #[inline]
     pub fn is_greater_than(&self, other: Vector2D) -> bool {
         self.squared_magnitude() > other.squared_magnitude()
     }
 
+    /// Returns the ordering of the magnitude of this vector against the magnitude of the
+    /// passed vector, using [`f64::total_cmp`] so it can be passed to `sort_by` and `max_by`.
+    #[inline]
+    pub fn cmp_magnitude(&self, other: &Vector2D) -> Ordering {
+        self.squared_magnitude().total_cmp(&other.squared_magnitude())
+    }
+
     /// If the components are matched x to x, y to y, and z to z, the method returns true only
     /// if every component of this vector is greater than every component of the passed vector.
     #[inline]
@@ -192,7 +311,7 @@ impl Vector2D {
 
     /// Converts this vector to a 3 Dimensional one by the addition of the
     /// z component passed
-    pub fn to_3d(&self, z: f64) -> Vector3D {
+    pub const fn to_3d(&self, z: f64) -> Vector3D {
         Vector3D {
             x: self.x,
             y: self.y,
@@ -201,7 +320,7 @@ impl Vector2D {
     }
     /// Returns the squared magnitude of this vector.
     pub fn squared_magnitude(&self) -> f64 {
-        (self.x * self.x) + (self.y * self.y)
+        self.dot(*self)
     }
     /// Returns the magnitude of this vector.
     #[inline]
@@ -292,6 +411,16 @@ impl fmt::Display for Vector2D {
     }
 }
 
+/// Formats the components in scientific notation, passing the precision on to each one.
+impl fmt::LowerExp for Vector2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.x, f)?;
+        f.write_str("i + ")?;
+        fmt::LowerExp::fmt(&self.y, f)?;
+        f.write_str("j")
+    }
+}
+
 impl fmt::Binary for Vector2D {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let magnitude = self.magnitude();
@@ -1,6 +1,10 @@
+use std::cmp::Ordering;
 use std::fmt;
 use std::ops::*;
+use crate::error::ImthError;
+use crate::matrix3::Matrix3;
 use crate::vector2d::Vector2D;
+use super::mul_add;
 
 /// Represents a mathematical vector in 3 Dimensional space.
 #[derive(Debug, PartialEq, Clone, Copy, Default)]
@@ -12,6 +16,8 @@ pub struct Vector3D {
 }
 
 impl Vector3D {
+    /// The default tolerance for the tolerant comparisons of this type, [`crate::EPSILON`].
+    pub const DEFAULT_TOL: f64 = crate::EPSILON;
 
     /// Returns a new vector with the passed components.\
     /// x is in the direction of the i-unit vector.\
@@ -30,7 +36,7 @@ impl Vector3D {
     /// assert_eq!(1.0, vec3d.z);
     /// ```
     #[inline]
-    pub fn new(x: f64, y: f64, z: f64) -> Self {
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
         Self { x, y, z }
     }
 
@@ -47,7 +53,7 @@ impl Vector3D {
     /// assert_eq!(1.0, vec3d.z);
     /// ```
     #[inline]
-    pub fn set(value: f64) -> Self {
+    pub const fn set(value: f64) -> Self {
         Self {
             x: value,
             y: value,
@@ -56,26 +62,26 @@ impl Vector3D {
     }
     /// Return the unit vector i == (i + 0j + 0k)
     #[inline]
-    pub fn i() -> Self {
+    pub const fn i() -> Self {
         Self { x: 1.0, y: 0.0, z: 0.0 }
     }
 
     #[inline]
     /// Returns the unit vector j == (0i + j + 0k)
-    pub fn j() -> Self {
+    pub const fn j() -> Self {
         Self { x: 0.0, y: 1.0, z: 0.0 }
     }
 
     /// Returns the unit vector k == (0i + 0j + k)
     #[inline]
-    pub fn k() -> Self {
+    pub const fn k() -> Self {
         Self { x: 0.0, y: 0.0, z: 1.0 }
     }
 
     /// Returns a vector pointing to the origin of the coordinate system
     /// (0i + 0j + 0k)
     #[inline]
-    pub fn origin() -> Self {
+    pub const fn origin() -> Self {
         Self { x: 0.0, y: 0.0, z: 0.0 }
     }
     
@@ -104,19 +110,39 @@ impl Vector3D {
     /// Returns the dot product of this vector and the passed vector
     #[inline]
     pub fn dot(&self, other: Vector3D) -> f64 {
-        (self.x * other.x) + (self.y * other.y) + (self.z * other.z) 
+        mul_add(self.z, other.z, mul_add(self.y, other.y, self.x * other.x))
     }
 
     /// Returns the cross product of this vector and the passed vector
     #[inline]
     pub fn cross(&self, other: Vector3D) -> Self {
         Self {
-            x: ((self.y * other.z) + (-self.z * other.y)),
-            y: ((self.z * other.x) + (-self.x * other.z)),
-            z: ((self.x * other.y) + (-self.y * other.x)),
+            x: mul_add(self.y, other.z, -self.z * other.y),
+            y: mul_add(self.z, other.x, -self.x * other.z),
+            z: mul_add(self.x, other.y, -self.y * other.x),
         }
     }
 
+    /// Returns the skew-symmetric cross product matrix of this vector, so that multiplying
+    /// it by a vector gives the cross product of this vector with it. [`Matrix3::vee`] gives the
+    /// vector back.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let omega = Vector3D::new(1.0, -2.0, 0.5);
+    /// let r = Vector3D::new(0.3, 4.0, -1.0);
+    ///
+    /// assert_eq!(omega.cross(r), omega.hat() * r);
+    /// assert_eq!(omega.hat().transpose(), -omega.hat());
+    /// assert_eq!(omega, omega.hat().vee());
+    /// ```
+    #[inline]
+    pub const fn hat(&self) -> Matrix3 {
+        Matrix3::new([[0.0, -self.z, self.y], [self.z, 0.0, -self.x], [-self.y, self.x, 0.0]])
+    }
+
     /// Multiples the x, y, and z components of this vector by the x, y, z components
     /// of the passed vector.
     #[inline]
@@ -145,10 +171,113 @@ impl Vector3D {
         self.cross(temp)
     }
 
+    /// Returns the components of this vector along the directions of `u` and `v`, so that
+    /// this vector is the sum of the unit vectors of `u` and `v` scaled by the returned pair,
+    /// like the forces in two members meeting at a joint. Returns None if either direction
+    /// is zero, the directions are parallel, or this vector is not in their plane.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// // a 100 N load split between members at 30 and 135 degrees to the x axis
+    /// let load = Vector3D::new(0.0, -100.0, 0.0);
+    /// let u = Vector3D::new(30f64.to_radians().cos(), 30f64.to_radians().sin(), 0.0);
+    /// let v = Vector3D::new(-1.0, 1.0, 0.0);
+    /// let (fu, fv) = load.decompose_along(u, v).unwrap();
+    ///
+    /// assert!((u.scale(fu) + v.normalized().unwrap().scale(fv)).is_approx_equal_to(load, 1e-9));
+    /// assert_eq!(None, load.decompose_along(Vector3D::i(), Vector3D::k()));
+    /// ```
+    pub fn decompose_along(&self, u: Vector3D, v: Vector3D) -> Option<(f64, f64)> {
+        let (u, v) = (u.normalized()?, v.normalized()?);
+        let n = u.cross(v);
+        let n2 = n.squared_magnitude();
+        if n2 <= Self::DEFAULT_TOL * Self::DEFAULT_TOL {
+            return None;
+        }
+        if self.dot(n).abs() > Self::DEFAULT_TOL * n2.sqrt() * self.magnitude().max(1.0) {
+            return None;
+        }
+        Some((self.cross(v).dot(n) / n2, u.cross(*self).dot(n) / n2))
+    }
+
+    /// Returns the components of this vector along the directions of `u`, `v` and `w`, so
+    /// that this vector is the sum of their unit vectors scaled by the returned values, like
+    /// the forces in three members meeting at a joint of a space truss. Returns None if any
+    /// direction is zero or the directions are coplanar.
+    pub fn decompose_along_axes(&self, u: Vector3D, v: Vector3D, w: Vector3D) -> Option<(f64, f64, f64)> {
+        let (u, v, w) = (u.normalized()?, v.normalized()?, w.normalized()?);
+        let det = u.triple_scalar_prod(v, w);
+        if det.abs() <= Self::DEFAULT_TOL {
+            return None;
+        }
+        Some((
+            self.triple_scalar_prod(v, w) / det,
+            u.triple_scalar_prod(*self, w) / det,
+            u.triple_scalar_prod(v, *self) / det,
+        ))
+    }
+
+    /// Returns a right-handed orthonormal basis whose first axis points along `v`, with the
+    /// other two chosen from `v` alone, or None if `v` is the zero vector. Uses the method of
+    /// Duff et al., which stays accurate for every direction.\
+    /// [More Info](https://jcgt.org/published/0006/01/01/)
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let (u, v, w) = Vector3D::orthonormal_basis_from(Vector3D::new(3.0, -1.0, 2.0)).unwrap();
+    ///
+    /// assert!(u.is_approx_equal_to(Vector3D::new(3.0, -1.0, 2.0).normalized().unwrap(), 1e-12));
+    /// assert!(u.is_perpendicular_to(v, 1e-12) && v.is_perpendicular_to(w, 1e-12));
+    /// assert!((v.magnitude() - 1.0).abs() < 1e-12 && (w.magnitude() - 1.0).abs() < 1e-12);
+    /// assert!(u.cross(v).is_approx_equal_to(w, 1e-12));
+    /// ```
+    pub fn orthonormal_basis_from(v: Vector3D) -> Option<(Vector3D, Vector3D, Vector3D)> {
+        let n = v.normalized()?;
+        let sign = 1f64.copysign(n.z);
+        let a = -1.0 / (sign + n.z);
+        let b = n.x * n.y * a;
+        let t = Vector3D::new(1.0 + sign * n.x * n.x * a, sign * b, -sign * n.x);
+        let bt = Vector3D::new(b, sign + n.y * n.y * a, -n.y);
+        Some((n, t, bt))
+    }
+
+    /// Returns the orthonormal vectors spanning the same space as `vectors`, found with the
+    /// modified Gram-Schmidt process in the order passed. Vectors whose part perpendicular to
+    /// the ones before is no longer than `tol` depend on them and are dropped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let vectors = [Vector3D::new(1.0, 1.0, 0.0), Vector3D::new(2.0, 2.0, 0.0), Vector3D::new(1.0, 0.0, 1.0)];
+    /// let basis = Vector3D::gram_schmidt(&vectors, 1e-9);
+    ///
+    /// assert_eq!(2, basis.len());
+    /// assert!(basis[0].is_approx_equal_to(Vector3D::new(1.0, 1.0, 0.0).normalized().unwrap(), 1e-12));
+    /// assert!(basis[0].is_perpendicular_to(basis[1], 1e-12));
+    /// ```
+    pub fn gram_schmidt(vectors: &[Vector3D], tol: f64) -> Vec<Vector3D> {
+        let mut basis: Vec<Vector3D> = Vec::with_capacity(3);
+        for &v in vectors {
+            let mut u = v;
+            for b in &basis {
+                u = u - b.scale(u.dot(*b));
+            }
+            if u.magnitude() > tol {
+                basis.extend(u.normalized());
+            }
+        }
+        basis
+    }
+
     /// Returns the squared magnitude of this vector.
     #[inline]
     pub fn squared_magnitude(&self) -> f64 {
-        (self.x * self.x) + (self.y * self.y) + (self.z * self.z)
+        self.dot(*self)
     }
 
     /// Returns the magnitude of this vector.
@@ -180,11 +309,11 @@ impl Vector3D {
     /// Converts this vector to its unit form if arithmetically possible else
     /// Returns this vector unchanged if it fails. This operation can fail
     /// if you have a zero vector
+    /// and the failure is silent, see [`Vector3D::try_to_unit`] for a checked version.
     #[inline]
     pub fn to_unit(&mut self) {
-        let mag = self.magnitude();
-        if mag > 0.0 {
-            let inv_mag = 1.0 / mag;
+        let inv_mag = self.inverse_magnitude();
+        if inv_mag.is_finite() {
             self.x *= inv_mag;
             self.y *= inv_mag;
             self.z *= inv_mag;
@@ -195,23 +324,101 @@ impl Vector3D {
     /// else it returns None. This operation can fail if you have a zero vector.
     #[inline]
     pub fn normalized(&self) -> Option<Self> {
-        let mag = self.magnitude();
-        if mag > 0.0 {
-            let inv_mag = 1.0 / mag;
-            return Some(Vector3D {
-                x: self.x * inv_mag,
-                y: self.y * inv_mag,
-                z: self.z * inv_mag,
-            })
+        let inv_mag = self.inverse_magnitude();
+        inv_mag.is_finite().then(|| self.scale(inv_mag))
+    }
+
+    /// Returns the normalized(unit) version of this vector, or the passed fallback if this
+    /// is a zero vector. The check is a single select on the reciprocal of the magnitude,
+    /// which keeps it cheap enough for normalizing large batches of directions every step.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let velocities = [Vector3D::new(3.0, 0.0, 4.0), Vector3D::origin()];
+    /// let headings: Vec<Vector3D> = velocities.iter().map(|v| v.normalize_or(Vector3D::i())).collect();
+    ///
+    /// assert!(headings[0].is_approx_equal_to(Vector3D::new(0.6, 0.0, 0.8), Vector3D::DEFAULT_TOL));
+    /// assert_eq!(Vector3D::i(), headings[1]);
+    /// assert_eq!(Vector3D::origin(), Vector3D::origin().normalize_or_zero());
+    /// ```
+    #[inline]
+    pub fn normalize_or(self, fallback: Vector3D) -> Self {
+        let inv_mag = self.inverse_magnitude();
+        if inv_mag.is_finite() { self.scale(inv_mag) } else { fallback }
+    }
+
+    /// Returns the normalized(unit) version of this vector, or the zero vector if this
+    /// is a zero vector.
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        self.normalize_or(Self::origin())
+    }
+
+    /// Returns the normalized(unit) version of this vector, or a division by zero error if
+    /// this is a zero vector.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::error::ImthError;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// assert_eq!(Ok(Vector3D::k()), Vector3D::new(0.0, 0.0, 2.0).try_normalize());
+    /// assert_eq!(Err(ImthError::DivisionByZero), Vector3D::origin().try_normalize());
+    /// ```
+    #[inline]
+    pub fn try_normalize(&self) -> Result<Self, ImthError> {
+        self.normalized().ok_or(ImthError::DivisionByZero)
+    }
+
+    /// Converts this vector to its unit form, or returns a division by zero error and
+    /// leaves it unchanged if this is a zero vector.
+    #[inline]
+    pub fn try_to_unit(&mut self) -> Result<(), ImthError> {
+        *self = self.try_normalize()?;
+        Ok(())
+    }
+
+    /// Returns this vector divided component by component by the passed vector, or a
+    /// division by zero error if any of its components is zero, where `/` gives infinite
+    /// or NaN components.
+    #[inline]
+    pub fn try_div(self, rhs: Vector3D) -> Result<Self, ImthError> {
+        if rhs.x == 0.0 || rhs.y == 0.0 || rhs.z == 0.0 {
+            return Err(ImthError::DivisionByZero);
         }
-        None
+        Ok(self / rhs)
+    }
+
+    /// Returns the component at the passed index, x for 0, y for 1 and z for 2, or an
+    /// error for any other index, where indexing panics.
+    #[inline]
+    pub fn try_index(&self, index: usize) -> Result<f64, ImthError> {
+        match index {
+            0 => Ok(self.x),
+            1 => Ok(self.y),
+            2 => Ok(self.z),
+            _ => Err(ImthError::InvalidArgument("vector index out of bounds")),
+        }
+    }
+
+    /// Returns the reciprocal of the magnitude of this vector, which is not finite for a
+    /// zero vector.
+    #[inline]
+    fn inverse_magnitude(&self) -> f64 {
+        self.squared_magnitude().sqrt().recip()
     }
 
     /// Scales the passed vector by the passed value and performs vector
     /// addition on this vector and the other vector.
     #[inline]
     pub fn add_scaled(self, other: Vector3D, value: f64) -> Self {
-        self + other.scale(value)
+        Self {
+            x: mul_add(other.x, value, self.x),
+            y: mul_add(other.y, value, self.y),
+            z: mul_add(other.z, value, self.z),
+        }
     }
 
     /// Scales this vector by the passed value and performs vector
@@ -223,25 +430,136 @@ impl Vector3D {
     
     /// Returns a 2 Dimensional vector by truncating the z component of this vector.
     #[inline]
-    pub fn to_2d(&self) -> Vector2D {
+    pub const fn to_2d(&self) -> Vector2D {
         Vector2D {
             x: self.x,
             y: self.y,
         }
     }
 
+    /// Returns the projection of this vector onto the xy plane as a 2 Dimensional vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use i_mth::vector2d::Vector2D;
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let v = Vector3D::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(Vector2D::new(1.0, 2.0), v.xy());
+    /// assert_eq!(Vector2D::new(1.0, 3.0), v.xz());
+    /// assert_eq!(Vector2D::new(2.0, 3.0), v.yz());
+    /// assert_eq!(Vector3D::new(1.0, 2.0, 0.0), v.with_z(0.0));
+    /// ```
+    #[inline]
+    pub const fn xy(&self) -> Vector2D {
+        Vector2D::new(self.x, self.y)
+    }
+
+    /// Returns the projection of this vector onto the xz plane as a 2 Dimensional vector.
+    #[inline]
+    pub const fn xz(&self) -> Vector2D {
+        Vector2D::new(self.x, self.z)
+    }
+
+    /// Returns the projection of this vector onto the yz plane as a 2 Dimensional vector.
+    #[inline]
+    pub const fn yz(&self) -> Vector2D {
+        Vector2D::new(self.y, self.z)
+    }
+
+    /// Returns a copy of this vector with the x component replaced by the passed value.
+    #[inline]
+    pub const fn with_x(&self, x: f64) -> Self {
+        Self { x, ..*self }
+    }
+
+    /// Returns a copy of this vector with the y component replaced by the passed value.
+    #[inline]
+    pub const fn with_y(&self, y: f64) -> Self {
+        Self { y, ..*self }
+    }
+
+    /// Returns a copy of this vector with the z component replaced by the passed value.
+    #[inline]
+    pub const fn with_z(&self, z: f64) -> Self {
+        Self { z, ..*self }
+    }
+
     /// Returns true if this vector is equal to the passed vector.
     #[inline]
     pub fn is_equal_to(&self, other: Vector3D) -> bool {
         (self.x == other.x) && (self.y == other.y) && (self.z == other.z)
     }
 
+    /// Returns true if every component of this vector is within `tol` of the matching
+    /// component of the passed vector.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let r = Vector3D::new(2.0, 0.0, 0.0);
+    /// let angle = std::f64::consts::FRAC_PI_3;
+    /// let rotated = Vector3D::new(r.x * angle.cos(), r.x * angle.sin(), 0.0);
+    ///
+    /// assert!(!rotated.is_equal_to(Vector3D::new(1.0, 3f64.sqrt(), 0.0)));
+    /// assert!(rotated.is_approx_equal_to(Vector3D::new(1.0, 3f64.sqrt(), 0.0), Vector3D::DEFAULT_TOL));
+    /// assert!(rotated.is_perpendicular_to(Vector3D::k(), Vector3D::DEFAULT_TOL));
+    /// assert!(rotated.cross(r).is_parallel_to(Vector3D::k(), Vector3D::DEFAULT_TOL));
+    /// ```
+    #[inline]
+    pub fn is_approx_equal_to(&self, other: Vector3D, tol: f64) -> bool {
+        ((self.x - other.x).abs() <= tol) && ((self.y - other.y).abs() <= tol) && ((self.z - other.z).abs() <= tol)
+    }
+
+    /// Returns true if the magnitude of this vector is no more than `tol`.
+    #[inline]
+    pub fn is_zero(&self, tol: f64) -> bool {
+        self.squared_magnitude() <= tol * tol
+    }
+
+    /// Returns true if the sine of the angle between this vector and the passed vector
+    /// is no more than `tol`. Returns false if either vector is zero.
+    #[inline]
+    pub fn is_parallel_to(&self, other: Vector3D, tol: f64) -> bool {
+        let scale = self.magnitude() * other.magnitude();
+        scale > 0.0 && self.cross(other).magnitude() <= tol * scale
+    }
+
+    /// Returns true if the cosine of the angle between this vector and the passed vector
+    /// is no more than `tol`. Returns false if either vector is zero.
+    #[inline]
+    pub fn is_perpendicular_to(&self, other: Vector3D, tol: f64) -> bool {
+        let scale = self.magnitude() * other.magnitude();
+        scale > 0.0 && self.dot(other).abs() <= tol * scale
+    }
+
     /// Returns true if this vector has a greater magnitude(length) than the passed vector. 
     #[inline]
     pub fn is_greater_than(&self, other: Vector3D) -> bool {
         self.squared_magnitude() > other.squared_magnitude()
     }
 
+    /// Returns the ordering of the magnitude of this vector against the magnitude of the
+    /// passed vector, using [`f64::total_cmp`] so it can be passed to `sort_by` and `max_by`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i_mth::vector3d::Vector3D;
+    ///
+    /// let mut forces = vec![Vector3D::new(0.0, -300.0, 0.0), Vector3D::new(50.0, 0.0, 0.0), Vector3D::new(0.0, 0.0, 120.0)];
+    /// forces.sort_by(|a, b| a.cmp_magnitude(b));
+    ///
+    /// assert_eq!(Vector3D::new(50.0, 0.0, 0.0), forces[0]);
+    /// assert_eq!(Vector3D::new(0.0, -300.0, 0.0), forces[2]);
+    /// ```
+    #[inline]
+    pub fn cmp_magnitude(&self, other: &Vector3D) -> Ordering {
+        self.squared_magnitude().total_cmp(&other.squared_magnitude())
+    }
+
     /// If the components are matched x to x, y to y, and z to z, the method returns true only
     /// if every component of this vector is greater than every component of the passed vector.
     #[inline]
@@ -354,6 +672,7 @@ impl DivAssign for Vector3D {
 }
 
 
+/// Panics for an index larger than 2, see [`Vector3D::try_index`].
 impl Index<usize> for Vector3D {
     type Output = f64;
     fn index(&self, index: usize) -> &f64 {
@@ -372,6 +691,27 @@ impl fmt::Display for Vector3D {
     }
 }
 
+/// Formats the components in scientific notation, passing the precision on to each one.
+///
+/// # Example
+/// ```rust
+/// use i_mth::vector3d::Vector3D;
+///
+/// let f = Vector3D::new(1500.0, -0.025, 0.0);
+///
+/// assert_eq!("1.50e3i + -2.50e-2j + 0.00e0k", format!("{:.2e}", f));
+/// ```
+impl fmt::LowerExp for Vector3D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.x, f)?;
+        f.write_str("i + ")?;
+        fmt::LowerExp::fmt(&self.y, f)?;
+        f.write_str("j + ")?;
+        fmt::LowerExp::fmt(&self.z, f)?;
+        f.write_str("k")
+    }
+}
+
 impl fmt::Binary for Vector3D {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let magnitude = self.magnitude();
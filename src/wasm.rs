@@ -0,0 +1,449 @@
+//! # WASM
+//!
+//! [`wasm_bindgen`] wrappers over the vector types, the beam solver and the projectile
+//! module, for statics and dynamics demos running in the browser. Enabled with the `wasm`
+//! feature and built with `wasm-pack build --features wasm`.
+//!
+//! The wrappers appear in JavaScript as `Vector2D`, `Vector3D`, `Beam`, `BeamSolution`,
+//! `Projectile` and `Flight`, with camelCase method names. Failures are thrown as JS
+//! errors carrying the message of the [`ImthError`](crate::error::ImthError). Diagrams and
+//! trajectories are returned as flat `Float64Array`s ready to hand to a plotting library.
+//!
+//! ```js
+//! import { Beam } from "i_mth";
+//!
+//! const beam = Beam.simplySupported(6.0);
+//! beam.pointLoad(2.0, 20e3);
+//! beam.uniformLoad(5e3, 3.0, 6.0);
+//! const solution = beam.solve();
+//! const [x0, r0, m0, x1, r1, m1] = solution.reactions();
+//! ```
+//!
+//! # Example
+//! ```rust
+//! use i_mth::wasm::{JsBeam, JsProjectile, JsVector3D};
+//!
+//! let r = JsVector3D::new(-0.2, 0.16, 0.0);
+//! let f = JsVector3D::new(400.0, 693.0, 0.0);
+//! assert_eq!(-202.6, r.cross(&f).z());
+//!
+//! let mut beam = JsBeam::simply_supported(6.0);
+//! beam.point_load(2.0, 20e3);
+//! beam.uniform_load(5e3, 3.0, 6.0);
+//! let solution = beam.solve().unwrap();
+//!
+//! let reactions = solution.reactions();
+//! assert!((reactions[1] - 17_083.333_333_333_332).abs() < 1e-6);
+//! assert!((solution.moment_at(2.0) - 34_166.666_666_666_664).abs() < 1e-6);
+//!
+//! // an all but horizontal throw off a 1 m ledge
+//! let stone = JsProjectile::new(0.1, 0.0, 0.0);
+//! let launch = JsVector3D::new(5.0, 0.0, 1e-12);
+//! let Ok(flight) = stone.fly(&JsVector3D::new(0.0, 0.0, 1.0), &launch) else {
+//!     panic!("the flight failed");
+//! };
+//! assert!((flight.impact_time().unwrap() - (2.0 / 9.806_65f64).sqrt()).abs() < 1e-9);
+//! assert!(stone.fly(&JsVector3D::new(0.0, 0.0, 0.0), &launch).is_ok());
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+use crate::beam::{Beam, BeamSolution, DistributedLoad, Support};
+use crate::particles::DragGenerator;
+use crate::projectile::{ProjectileFlight, ProjectileWithDrag};
+use crate::series::DataSeries;
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+/// Returns the points of a data series as a flat `[x0, y0, x1, y1, ...]` array.
+fn flatten(series: DataSeries) -> Vec<f64> {
+    series
+        .points
+        .into_iter()
+        .flat_map(|(x, y)| [x, y])
+        .collect()
+}
+
+/// A [`Vector2D`] for JavaScript.
+#[wasm_bindgen(js_name = Vector2D)]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct JsVector2D(Vector2D);
+
+#[wasm_bindgen(js_class = Vector2D)]
+impl JsVector2D {
+    /// Returns a new vector with the passed components.
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: f64, y: f64) -> Self {
+        Self(Vector2D::new(x, y))
+    }
+
+    /// Returns the x component.
+    #[wasm_bindgen(getter)]
+    pub fn x(&self) -> f64 {
+        self.0.x
+    }
+
+    /// Returns the y component.
+    #[wasm_bindgen(getter)]
+    pub fn y(&self) -> f64 {
+        self.0.y
+    }
+
+    /// Returns the sum of this and the passed vector.
+    pub fn add(&self, other: &JsVector2D) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    /// Returns the difference of this and the passed vector.
+    pub fn sub(&self, other: &JsVector2D) -> Self {
+        Self(self.0 - other.0)
+    }
+
+    /// Returns this vector scaled by the passed value.
+    pub fn scale(&self, value: f64) -> Self {
+        Self(self.0.scale(value))
+    }
+
+    /// Returns the dot product of this and the passed vector.
+    pub fn dot(&self, other: &JsVector2D) -> f64 {
+        self.0.dot(other.0)
+    }
+
+    /// Returns the z component of the cross product, like the moment of a force in the
+    /// plane.
+    pub fn cross(&self, other: &JsVector2D) -> f64 {
+        self.0.cross(other.0)
+    }
+
+    /// Returns the magnitude of this vector.
+    pub fn magnitude(&self) -> f64 {
+        self.0.magnitude()
+    }
+
+    /// Returns the unit vector along this vector, or undefined for a zero vector.
+    pub fn normalized(&self) -> Option<JsVector2D> {
+        self.0.normalized().map(Self)
+    }
+
+    /// Returns the vector written like `1i + 2j`.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<Vector2D> for JsVector2D {
+    #[inline]
+    fn from(v: Vector2D) -> Self {
+        Self(v)
+    }
+}
+
+impl From<JsVector2D> for Vector2D {
+    #[inline]
+    fn from(v: JsVector2D) -> Self {
+        v.0
+    }
+}
+
+/// A [`Vector3D`] for JavaScript.
+#[wasm_bindgen(js_name = Vector3D)]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct JsVector3D(Vector3D);
+
+#[wasm_bindgen(js_class = Vector3D)]
+impl JsVector3D {
+    /// Returns a new vector with the passed components.
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self(Vector3D::new(x, y, z))
+    }
+
+    /// Returns the x component.
+    #[wasm_bindgen(getter)]
+    pub fn x(&self) -> f64 {
+        self.0.x
+    }
+
+    /// Returns the y component.
+    #[wasm_bindgen(getter)]
+    pub fn y(&self) -> f64 {
+        self.0.y
+    }
+
+    /// Returns the z component.
+    #[wasm_bindgen(getter)]
+    pub fn z(&self) -> f64 {
+        self.0.z
+    }
+
+    /// Returns the sum of this and the passed vector.
+    pub fn add(&self, other: &JsVector3D) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    /// Returns the difference of this and the passed vector.
+    pub fn sub(&self, other: &JsVector3D) -> Self {
+        Self(self.0 - other.0)
+    }
+
+    /// Returns this vector scaled by the passed value.
+    pub fn scale(&self, value: f64) -> Self {
+        Self(self.0.scale(value))
+    }
+
+    /// Returns the dot product of this and the passed vector.
+    pub fn dot(&self, other: &JsVector3D) -> f64 {
+        self.0.dot(other.0)
+    }
+
+    /// Returns the cross product of this and the passed vector, like the moment r × F.
+    pub fn cross(&self, other: &JsVector3D) -> Self {
+        Self(self.0.cross(other.0))
+    }
+
+    /// Returns the magnitude of this vector.
+    pub fn magnitude(&self) -> f64 {
+        self.0.magnitude()
+    }
+
+    /// Returns the unit vector along this vector, or undefined for a zero vector.
+    pub fn normalized(&self) -> Option<JsVector3D> {
+        self.0.normalized().map(Self)
+    }
+
+    /// Returns the vector written like `1i + 2j + 3k`.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<Vector3D> for JsVector3D {
+    #[inline]
+    fn from(v: Vector3D) -> Self {
+        Self(v)
+    }
+}
+
+impl From<JsVector3D> for Vector3D {
+    #[inline]
+    fn from(v: JsVector3D) -> Self {
+        v.0
+    }
+}
+
+/// A [`Beam`] for JavaScript, set up by adding supports and loads in place.
+#[wasm_bindgen(js_name = Beam)]
+#[derive(Debug, Clone, Default)]
+pub struct JsBeam(Beam);
+
+#[wasm_bindgen(js_class = Beam)]
+impl JsBeam {
+    /// Returns an unsupported and unloaded beam of the passed length in m.
+    #[wasm_bindgen(constructor)]
+    pub fn new(length: f64) -> Self {
+        Self(Beam::new(length))
+    }
+
+    /// Returns a beam with a pin at the left end and a roller at the right end.
+    #[wasm_bindgen(js_name = simplySupported)]
+    pub fn simply_supported(length: f64) -> Self {
+        Self(Beam::simply_supported(length))
+    }
+
+    /// Returns a beam fixed at the left end and free at the right end.
+    pub fn cantilever(length: f64) -> Self {
+        Self(Beam::cantilever(length))
+    }
+
+    /// Adds a pin at the passed position in m.
+    pub fn pin(&mut self, x: f64) {
+        self.0.supports.push(Support::Pin(x));
+    }
+
+    /// Adds a roller at the passed position in m.
+    pub fn roller(&mut self, x: f64) {
+        self.0.supports.push(Support::Roller(x));
+    }
+
+    /// Adds a fixed support at the passed position in m.
+    pub fn fixed(&mut self, x: f64) {
+        self.0.supports.push(Support::Fixed(x));
+    }
+
+    /// Adds a point load of `p` N downwards at `x` m.
+    #[wasm_bindgen(js_name = pointLoad)]
+    pub fn point_load(&mut self, x: f64, p: f64) {
+        self.0.point_loads.push((x, p));
+    }
+
+    /// Adds a couple of `c` N.m anticlockwise at `x` m.
+    pub fn couple(&mut self, x: f64, c: f64) {
+        self.0.couples.push((x, c));
+    }
+
+    /// Adds a uniform load of `w` N/m downwards from `start` to `end` m.
+    #[wasm_bindgen(js_name = uniformLoad)]
+    pub fn uniform_load(&mut self, w: f64, start: f64, end: f64) {
+        let load = DistributedLoad::uniform(w, start, end);
+        self.0.distributed_loads.push(load);
+    }
+
+    /// Adds a load varying linearly from `w_start` N/m at `start` to `w_end` N/m at
+    /// `end`, downwards.
+    #[wasm_bindgen(js_name = trapezoidLoad)]
+    pub fn trapezoid_load(&mut self, w_start: f64, w_end: f64, start: f64, end: f64) {
+        let load = DistributedLoad::trapezoid(w_start, w_end, start, end);
+        self.0.distributed_loads.push(load);
+    }
+
+    /// Returns the solved beam, or throws if it cannot be solved.
+    pub fn solve(&self) -> Result<JsBeamSolution, JsError> {
+        Ok(JsBeamSolution(self.0.solve()?))
+    }
+}
+
+/// A [`BeamSolution`] for JavaScript.
+#[wasm_bindgen(js_name = BeamSolution)]
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct JsBeamSolution(BeamSolution);
+
+#[wasm_bindgen(js_class = BeamSolution)]
+impl JsBeamSolution {
+    /// Returns the support reactions as a flat `[x, force, moment, ...]` array, in the
+    /// order the supports were added.
+    pub fn reactions(&self) -> Vec<f64> {
+        self.0
+            .reactions
+            .iter()
+            .flat_map(|r| [r.position, r.force, r.moment])
+            .collect()
+    }
+
+    /// Returns the shear force in N at `x` m, or NaN off the beam.
+    #[wasm_bindgen(js_name = shearAt)]
+    pub fn shear_at(&self, x: f64) -> f64 {
+        self.0.shear.evaluate(x).unwrap_or(f64::NAN)
+    }
+
+    /// Returns the bending moment in N.m at `x` m, or NaN off the beam.
+    #[wasm_bindgen(js_name = momentAt)]
+    pub fn moment_at(&self, x: f64) -> f64 {
+        self.0.moment.evaluate(x).unwrap_or(f64::NAN)
+    }
+
+    /// Returns the positions where the shear force is zero or changes sign.
+    #[wasm_bindgen(js_name = zeroShear)]
+    pub fn zero_shear(&self) -> Vec<f64> {
+        self.0.zero_shear()
+    }
+
+    /// Returns the shear force diagram at about `samples` points as a flat
+    /// `[x0, V0, x1, V1, ...]` array.
+    #[wasm_bindgen(js_name = shearDiagram)]
+    pub fn shear_diagram(&self, samples: usize) -> Vec<f64> {
+        flatten(self.0.shear_diagram(samples))
+    }
+
+    /// Returns the bending moment diagram at about `samples` points as a flat
+    /// `[x0, M0, x1, M1, ...]` array.
+    #[wasm_bindgen(js_name = momentDiagram)]
+    pub fn moment_diagram(&self, samples: usize) -> Vec<f64> {
+        flatten(self.0.moment_diagram(samples))
+    }
+}
+
+/// A [`ProjectileWithDrag`] for JavaScript.
+#[wasm_bindgen(js_name = Projectile)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct JsProjectile(ProjectileWithDrag);
+
+#[wasm_bindgen(js_class = Projectile)]
+impl JsProjectile {
+    /// Returns a projectile of `mass` kg with drag `k1 |v| + k2 |v|²` under standard
+    /// gravity along -z, with the ground through the origin.
+    #[wasm_bindgen(constructor)]
+    pub fn new(mass: f64, k1: f64, k2: f64) -> Self {
+        Self(ProjectileWithDrag::new(mass, DragGenerator::new(k1, k2)))
+    }
+
+    /// Returns a projectile of `mass` kg with drag coefficient `cd` and frontal area
+    /// `area` m<sup>2</sup> in air of density `rho`.
+    pub fn quadratic(mass: f64, rho: f64, cd: f64, area: f64) -> Self {
+        Self(ProjectileWithDrag::new(
+            mass,
+            DragGenerator::quadratic(rho, cd, area),
+        ))
+    }
+
+    /// Sets the acceleration due to gravity in ms<sup>-2</sup>.
+    #[wasm_bindgen(js_name = setGravity)]
+    pub fn set_gravity(&mut self, gravity: &JsVector3D) {
+        self.0.gravity = gravity.0;
+    }
+
+    /// Sets the height of the ground above the origin in m.
+    #[wasm_bindgen(js_name = setGroundHeight)]
+    pub fn set_ground_height(&mut self, ground_height: f64) {
+        self.0.ground_height = ground_height;
+    }
+
+    /// Returns the flight launched from `position` with `velocity`, or throws if the
+    /// integration fails.
+    pub fn fly(&self, position: &JsVector3D, velocity: &JsVector3D) -> Result<JsFlight, JsError> {
+        Ok(JsFlight(self.0.fly(position.0, velocity.0)?))
+    }
+}
+
+/// A [`ProjectileFlight`] for JavaScript.
+#[wasm_bindgen(js_name = Flight)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct JsFlight(ProjectileFlight);
+
+#[wasm_bindgen(js_class = Flight)]
+impl JsFlight {
+    /// Returns the horizontal distance to the impact, or undefined if it did not land.
+    pub fn range(&self) -> Option<f64> {
+        self.0.range()
+    }
+
+    /// Returns the time of flight, or undefined if it did not land.
+    #[wasm_bindgen(js_name = impactTime)]
+    pub fn impact_time(&self) -> Option<f64> {
+        self.0.impact.map(|i| i.time)
+    }
+
+    /// Returns the position of the impact, or undefined if it did not land.
+    #[wasm_bindgen(js_name = impactPosition)]
+    pub fn impact_position(&self) -> Option<JsVector3D> {
+        self.0.impact.map(|i| JsVector3D(i.position))
+    }
+
+    /// Returns the greatest height reached above the origin.
+    #[wasm_bindgen(js_name = apexHeight)]
+    pub fn apex_height(&self) -> f64 {
+        self.0.apex().0
+    }
+
+    /// Returns the time the greatest height was reached.
+    #[wasm_bindgen(js_name = apexTime)]
+    pub fn apex_time(&self) -> f64 {
+        self.0.apex().1
+    }
+
+    /// Returns the positions at `samples` evenly spaced times over the flight as a flat
+    /// `[t0, x0, y0, z0, t1, ...]` array.
+    pub fn positions(&self, samples: usize) -> Vec<f64> {
+        let end = self.0.trajectory.points.last().map_or(0.0, |p| p.0);
+        let step = end / samples.saturating_sub(1).max(1) as f64;
+        (0..samples)
+            .filter_map(|n| {
+                let t = (n as f64 * step).min(end);
+                let (p, _) = self.0.state_at(t)?;
+                Some([t, p.x, p.y, p.z])
+            })
+            .flatten()
+            .collect()
+    }
+}